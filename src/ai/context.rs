@@ -82,16 +82,22 @@ impl ContextBuilder {
             }
 
             // GPU
-            if let Some(ref gpu) = sys.gpu {
-                ctx.push_str(&format!(
-                    "GPU: {} | Util: {}% | VRAM: {}/{} | Temp: {}°C | Power: {:.0}W\n",
-                    gpu.name,
-                    gpu.utilization,
-                    format_bytes(gpu.memory_used),
-                    format_bytes(gpu.memory_total),
-                    gpu.temperature,
-                    gpu.power_draw,
-                ));
+            for gpu in &sys.gpus {
+                ctx.push_str(&format!("GPU: {} | Util: {}%", gpu.name, gpu.utilization));
+                if gpu.memory_total > 0 {
+                    ctx.push_str(&format!(
+                        " | VRAM: {}/{}",
+                        format_bytes(gpu.memory_used),
+                        format_bytes(gpu.memory_total),
+                    ));
+                }
+                if let Some(t) = gpu.temperature {
+                    ctx.push_str(&format!(" | Temp: {}°C", t));
+                }
+                if let Some(w) = gpu.power_draw {
+                    ctx.push_str(&format!(" | Power: {:.0}W", w));
+                }
+                ctx.push('\n');
             }
 
             // Battery
@@ -168,6 +174,32 @@ impl ContextBuilder {
         }
         ctx.push('\n');
 
+        // ── Top Processes by GPU ───────────────────────────────
+        let by_gpu = top_gpu_processes(processes, CONTEXT_TOP_GPU_COUNT);
+        if !by_gpu.is_empty() {
+            ctx.push_str(&format!(
+                "## Top {} Processes by GPU Usage\n",
+                CONTEXT_TOP_GPU_COUNT
+            ));
+            ctx.push_str(&format!(
+                "{:<8} {:<25} {:>12} {:>7} {:>7}\n",
+                "PID", "NAME", "GPU_MEM", "GPU%", "CPU%"
+            ));
+            ctx.push_str(&"-".repeat(70));
+            ctx.push('\n');
+            for p in by_gpu {
+                ctx.push_str(&format!(
+                    "{:<8} {:<25} {:>12} {:>6.1}% {:>6.1}%\n",
+                    p.pid,
+                    truncate_str(&p.name, 25),
+                    format_bytes(p.gpu_memory_bytes),
+                    p.gpu_usage,
+                    p.cpu_usage,
+                ));
+            }
+            ctx.push('\n');
+        }
+
         // ── Process Groups (aggregate by name) ─────────────────
         ctx.push_str("## Process Groups (aggregated by name)\n");
         let groups = aggregate_by_name(processes);
@@ -375,11 +407,12 @@ impl ContextBuilder {
                 }
             }
 
-            if let Some(ref gpu) = sys.gpu {
-                ctx.push_str(&format!(
-                    "GPU: {} | Util: {}% | Temp: {}°C\n",
-                    gpu.name, gpu.utilization, gpu.temperature,
-                ));
+            for gpu in &sys.gpus {
+                ctx.push_str(&format!("GPU: {} | Util: {}%", gpu.name, gpu.utilization));
+                if let Some(t) = gpu.temperature {
+                    ctx.push_str(&format!(" | Temp: {}°C", t));
+                }
+                ctx.push('\n');
             }
 
             ctx.push('\n');
@@ -433,6 +466,25 @@ impl ContextBuilder {
         }
         ctx.push('\n');
 
+        // Top GPU processes (light: 3), only on machines using a GPU
+        let by_gpu = top_gpu_processes(processes, CONTEXT_LIGHT_TOP_GPU);
+        if !by_gpu.is_empty() {
+            ctx.push_str(&format!(
+                "## Top {} Processes by GPU Usage\n",
+                CONTEXT_LIGHT_TOP_GPU
+            ));
+            for p in by_gpu {
+                ctx.push_str(&format!(
+                    "  PID:{} {} gpu_mem={} gpu={:.0}%\n",
+                    p.pid,
+                    truncate_str(&p.name, 25),
+                    format_bytes(p.gpu_memory_bytes),
+                    p.gpu_usage,
+                ));
+            }
+            ctx.push('\n');
+        }
+
         // Process groups (light: 5)
         let groups = aggregate_by_name(processes);
         if !groups.is_empty() {
@@ -502,6 +554,18 @@ impl ContextBuilder {
     }
 }
 
+/// Processes on a GPU, by GPU memory then utilization (descending).
+fn top_gpu_processes(processes: &[ProcessInfo], limit: usize) -> Vec<&ProcessInfo> {
+    let mut by_gpu: Vec<&ProcessInfo> = processes.iter().filter(|p| p.uses_gpu()).collect();
+    by_gpu.sort_by(|a, b| {
+        (b.gpu_memory_bytes, b.gpu_usage)
+            .partial_cmp(&(a.gpu_memory_bytes, a.gpu_usage))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    by_gpu.truncate(limit);
+    by_gpu
+}
+
 /// Group processes by name: (name, count, total_cpu, total_memory)
 fn aggregate_by_name(processes: &[ProcessInfo]) -> Vec<(String, usize, f32, u64)> {
     use std::collections::HashMap;
@@ -4,90 +4,107 @@ use std::time::Instant;
 use crate::config::Config;
 use crate::constants::*;
 use crate::models::{
-    Alert, AlertCategory, AlertSeverity, ProcessInfo, ProcessStatus, SystemSnapshot,
+    format_bytes, Alert, AlertCategory, AlertSeverity, ProcessInfo, ProcessStatus, SystemSnapshot,
 };
+use crate::thermal::thresholds::{Levels, ThermalThresholds};
 use crate::thermal::ThermalSnapshot;
 
+use super::rules::{compile_rules, RuleEngine};
+
 /// The alert detection engine. Analyzes process and system data
 /// to generate warnings, threats, and anomalies.
 ///
-/// Open/Closed Principle: new detection rules can be added as methods
-/// without modifying existing ones.
+/// Threshold checks (CPU, memory, disk I/O, GPU utilization, system load) run
+/// through the declarative `RuleEngine`; checks that need more than one metric
+/// or some history (zombies, name patterns, memory leaks, VRAM share) remain
+/// methods here.
 pub struct AlertDetector {
     config: Config,
+    /// Built-in and user-defined threshold rules
+    rules: RuleEngine,
+    /// Per-sensor thermal thresholds and aliases
+    thermal_thresholds: ThermalThresholds,
     /// Track memory over time per PID to detect leaks
     memory_history: HashMap<u32, VecDeque<u64>>,
     /// Max history entries per process
     max_history: usize,
     /// Cooldown tracking: (PID, category) -> last fire time
     alert_cooldowns: HashMap<(u32, AlertCategory), Instant>,
+    /// Conditions that held in the current sample, before cooldowns
+    firing: Vec<Alert>,
 }
 
 impl AlertDetector {
     pub fn new(config: Config) -> Self {
         Self {
+            rules: RuleEngine::new(compile_rules(&config)),
+            thermal_thresholds: ThermalThresholds::from_config(&config.thermal),
             config,
             memory_history: HashMap::new(),
             max_history: MAX_MEMORY_HISTORY, // ~30 seconds of history at 1s interval
             alert_cooldowns: HashMap::new(),
+            firing: Vec::new(),
         }
     }
 
-    /// Get the thermal warning threshold from config.
-    pub fn config_thermal_warning(&self) -> f32 {
-        self.config.thermal.warning_threshold
+    /// Replace the detection config (thresholds, patterns) on reload.
+    /// History and cooldowns are kept so a reload doesn't re-fire alerts.
+    pub fn set_config(&mut self, config: Config) {
+        self.rules.set_rules(compile_rules(&config));
+        self.thermal_thresholds = ThermalThresholds::from_config(&config.thermal);
+        self.config = config;
     }
 
-    /// Get the thermal critical threshold from config.
-    pub fn config_thermal_critical(&self) -> f32 {
-        self.config.thermal.critical_threshold
+    /// Every condition that held in the latest sample (`analyze` plus
+    /// `check_thermal`), including alerts suppressed by a cooldown. The alert
+    /// lifecycle uses this to tell a still-firing alert from a resolved one.
+    pub fn firing(&self) -> &[Alert] {
+        &self.firing
     }
 
-    /// Get the thermal emergency threshold from config.
-    pub fn config_thermal_emergency(&self) -> f32 {
-        self.config.thermal.emergency_threshold
+    /// Thermal thresholds (global, per category, per sensor) and aliases.
+    pub fn thermal_thresholds(&self) -> &ThermalThresholds {
+        &self.thermal_thresholds
     }
 
     /// Run all detection rules and return any triggered alerts.
-    /// Applies a 60-second cooldown per (PID, category) to prevent flooding.
+    /// Threshold rules use their own per-rule cooldowns; the remaining checks
+    /// apply a 60-second cooldown per (PID, category) to prevent flooding.
     pub fn analyze(&mut self, system: &SystemSnapshot, processes: &[ProcessInfo]) -> Vec<Alert> {
+        let now = Instant::now();
+        let mut alerts = self.rules.evaluate(system, processes, now);
         let mut raw_alerts = Vec::new();
 
-        // System-wide checks
-        self.check_system_memory(&mut raw_alerts, system);
-        self.check_system_cpu(&mut raw_alerts, system);
-
         // Build PID -> process name lookup for parent resolution (zombie filtering)
         let pid_names: std::collections::HashMap<u32, &str> =
             processes.iter().map(|p| (p.pid, p.name.as_str())).collect();
+        let vram_total: u64 = system.gpus.iter().map(|g| g.memory_total).sum();
 
         // Per-process checks
         for proc in processes {
-            self.check_cpu_usage(&mut raw_alerts, proc);
-            self.check_memory_usage(&mut raw_alerts, proc);
             self.check_zombie(&mut raw_alerts, proc, &pid_names);
             self.check_suspicious(&mut raw_alerts, proc);
             self.check_security_threats(&mut raw_alerts, proc);
             self.check_memory_leak(&mut raw_alerts, proc);
-            self.check_high_disk_io(&mut raw_alerts, proc);
+            self.check_gpu_hog(&mut raw_alerts, proc, vram_total);
         }
 
+        // A new sample: check_thermal() adds to this afterwards
+        self.firing = self.rules.firing().to_vec();
+        self.firing.extend(raw_alerts.iter().cloned());
+
         // Apply cooldown deduplication
-        let now = Instant::now();
         let cooldown = std::time::Duration::from_secs(ALERT_COOLDOWN_SECS);
-        let alerts: Vec<Alert> = raw_alerts
-            .into_iter()
-            .filter(|alert| {
-                let key = (alert.pid, alert.category);
-                match self.alert_cooldowns.get(&key) {
-                    Some(last_fired) if now.duration_since(*last_fired) < cooldown => false,
-                    _ => {
-                        self.alert_cooldowns.insert(key, now);
-                        true
-                    }
+        alerts.extend(raw_alerts.into_iter().filter(|alert| {
+            let key = (alert.pid, alert.category);
+            match self.alert_cooldowns.get(&key) {
+                Some(last_fired) if now.duration_since(*last_fired) < cooldown => false,
+                _ => {
+                    self.alert_cooldowns.insert(key, now);
+                    true
                 }
-            })
-            .collect();
+            }
+        }));
 
         // Clean up history and cooldowns for dead processes
         let active_pids: std::collections::HashSet<u32> = processes.iter().map(|p| p.pid).collect();
@@ -99,116 +116,6 @@ impl AlertDetector {
         alerts
     }
 
-    fn check_system_memory(&self, alerts: &mut Vec<Alert>, system: &SystemSnapshot) {
-        let pct = system.memory_percent();
-        if pct >= self.config.sys_mem_critical_percent {
-            alerts.push(Alert::new(
-                AlertSeverity::Danger,
-                AlertCategory::SystemOverload,
-                "SYSTEM",
-                0,
-                format!(
-                    "System memory critically high: {:.1}% ({} / {})",
-                    pct,
-                    crate::models::format_bytes(system.used_memory),
-                    crate::models::format_bytes(system.total_memory),
-                ),
-                pct as f64,
-                self.config.sys_mem_critical_percent as f64,
-            ));
-        } else if pct >= self.config.sys_mem_warning_percent {
-            alerts.push(Alert::new(
-                AlertSeverity::Warning,
-                AlertCategory::SystemOverload,
-                "SYSTEM",
-                0,
-                format!(
-                    "System memory high: {:.1}% ({} / {})",
-                    pct,
-                    crate::models::format_bytes(system.used_memory),
-                    crate::models::format_bytes(system.total_memory),
-                ),
-                pct as f64,
-                self.config.sys_mem_warning_percent as f64,
-            ));
-        }
-    }
-
-    fn check_system_cpu(&self, alerts: &mut Vec<Alert>, system: &SystemSnapshot) {
-        if system.global_cpu_usage >= self.config.cpu_critical_threshold {
-            alerts.push(Alert::new(
-                AlertSeverity::Critical,
-                AlertCategory::SystemOverload,
-                "SYSTEM",
-                0,
-                format!(
-                    "System CPU critically high: {:.1}%",
-                    system.global_cpu_usage
-                ),
-                system.global_cpu_usage as f64,
-                self.config.cpu_critical_threshold as f64,
-            ));
-        }
-    }
-
-    fn check_cpu_usage(&self, alerts: &mut Vec<Alert>, proc: &ProcessInfo) {
-        if proc.cpu_usage >= self.config.cpu_critical_threshold {
-            alerts.push(Alert::new(
-                AlertSeverity::Critical,
-                AlertCategory::HighCpu,
-                &proc.name,
-                proc.pid,
-                format!("{} using {:.1}% CPU", proc.name, proc.cpu_usage),
-                proc.cpu_usage as f64,
-                self.config.cpu_critical_threshold as f64,
-            ));
-        } else if proc.cpu_usage >= self.config.cpu_warning_threshold {
-            alerts.push(Alert::new(
-                AlertSeverity::Warning,
-                AlertCategory::HighCpu,
-                &proc.name,
-                proc.pid,
-                format!("{} using {:.1}% CPU", proc.name, proc.cpu_usage),
-                proc.cpu_usage as f64,
-                self.config.cpu_warning_threshold as f64,
-            ));
-        }
-    }
-
-    fn check_memory_usage(&self, alerts: &mut Vec<Alert>, proc: &ProcessInfo) {
-        if proc.memory_bytes >= self.config.mem_critical_threshold_bytes {
-            alerts.push(Alert::new(
-                AlertSeverity::Critical,
-                AlertCategory::HighMemory,
-                &proc.name,
-                proc.pid,
-                format!(
-                    "{} using {} RAM ({:.1}%)",
-                    proc.name,
-                    proc.memory_display(),
-                    proc.memory_percent
-                ),
-                proc.memory_bytes as f64,
-                self.config.mem_critical_threshold_bytes as f64,
-            ));
-        } else if proc.memory_bytes >= self.config.mem_warning_threshold_bytes {
-            alerts.push(Alert::new(
-                AlertSeverity::Warning,
-                AlertCategory::HighMemory,
-                &proc.name,
-                proc.pid,
-                format!(
-                    "{} using {} RAM ({:.1}%)",
-                    proc.name,
-                    proc.memory_display(),
-                    proc.memory_percent
-                ),
-                proc.memory_bytes as f64,
-                self.config.mem_warning_threshold_bytes as f64,
-            ));
-        }
-    }
-
     fn check_zombie(
         &self,
         alerts: &mut Vec<Alert>,
@@ -341,22 +248,27 @@ impl AlertDetector {
         }
     }
 
-    fn check_high_disk_io(&self, alerts: &mut Vec<Alert>, proc: &ProcessInfo) {
-        let total_io = proc.disk_read_bytes + proc.disk_write_bytes;
-        if total_io > HIGH_DISK_IO_THRESHOLD {
+    /// Warn when one process holds most of the machine's VRAM.
+    fn check_gpu_hog(&self, alerts: &mut Vec<Alert>, proc: &ProcessInfo, vram_total: u64) {
+        if vram_total == 0 || proc.gpu_memory_bytes == 0 {
+            return;
+        }
+        let share = proc.gpu_memory_bytes as f64 / vram_total as f64 * 100.0;
+        let threshold = self.config.gpu_vram_warning_percent as f64;
+        if share >= threshold {
             alerts.push(Alert::new(
-                AlertSeverity::Info,
-                AlertCategory::HighDiskIo,
+                AlertSeverity::Warning,
+                AlertCategory::GpuHog,
                 &proc.name,
                 proc.pid,
                 format!(
-                    "High disk I/O: {} (R: {}, W: {})",
+                    "{} holds {} of GPU memory ({:.0}% of VRAM)",
                     proc.name,
-                    proc.disk_read_display(),
-                    proc.disk_write_display(),
+                    format_bytes(proc.gpu_memory_bytes),
+                    share
                 ),
-                total_io as f64,
-                HIGH_DISK_IO_THRESHOLD as f64,
+                share,
+                threshold,
             ));
         }
     }
@@ -364,57 +276,19 @@ impl AlertDetector {
     /// Check thermal data for temperature-related alerts.
     /// Uses the same cooldown system as process alerts (PID 0 for system-level).
     pub fn check_thermal(&mut self, thermal: &ThermalSnapshot) -> Vec<Alert> {
-        let warning = self.config.thermal.warning_threshold;
-        let critical = self.config.thermal.critical_threshold;
-        let emergency = self.config.thermal.emergency_threshold;
-
         let mut raw_alerts = Vec::new();
 
-        // Check CPU package temperature
-        if let Some(pkg) = thermal.cpu_package {
-            self.emit_thermal_alert(
-                &mut raw_alerts,
-                "CPU Package",
-                pkg,
-                warning,
-                critical,
-                emergency,
-            );
-        }
-
-        // Check individual CPU cores
-        for core in &thermal.cpu_cores {
-            self.emit_thermal_alert(
-                &mut raw_alerts,
-                &core.name,
-                core.value,
-                warning,
-                critical,
-                emergency,
-            );
+        // Every temperature sensor against its own thresholds (fans are RPM)
+        for (category, sensor, temp) in thermal.readings() {
+            if category == "fan" {
+                continue;
+            }
+            let levels = self.thermal_thresholds.levels(category, &sensor);
+            let name = self.thermal_thresholds.display_name(&sensor);
+            Self::emit_thermal_alert(&mut raw_alerts, name, temp, levels);
         }
 
-        // Check GPU temperatures
-        if let Some(gpu) = thermal.gpu_temp {
-            self.emit_thermal_alert(
-                &mut raw_alerts,
-                "GPU Core",
-                gpu,
-                warning,
-                critical,
-                emergency,
-            );
-        }
-        if let Some(hotspot) = thermal.gpu_hotspot {
-            self.emit_thermal_alert(
-                &mut raw_alerts,
-                "GPU Hot Spot",
-                hotspot,
-                warning,
-                critical,
-                emergency,
-            );
-        }
+        self.firing.extend(raw_alerts.iter().cloned());
 
         // Apply cooldown deduplication
         let now = Instant::now();
@@ -434,15 +308,12 @@ impl AlertDetector {
             .collect()
     }
 
-    fn emit_thermal_alert(
-        &self,
-        alerts: &mut Vec<Alert>,
-        sensor_name: &str,
-        temp: f32,
-        warning: f32,
-        critical: f32,
-        emergency: f32,
-    ) {
+    fn emit_thermal_alert(alerts: &mut Vec<Alert>, sensor_name: &str, temp: f32, levels: Levels) {
+        let Levels {
+            warning,
+            critical,
+            emergency,
+        } = levels;
         // Use a stable pseudo-PID derived from sensor name so different sensors
         // don't collide in the cooldown dedup map (which keys on (pid, category)).
         let pseudo_pid = sensor_name
@@ -511,6 +382,7 @@ mod tests {
             gpu_hotspot: None,
             ssd_temps: Vec::new(),
             fan_rpms: Vec::new(),
+            fan_pwm: Vec::new(),
             motherboard_temps: Vec::new(),
             ram_temps: Vec::new(),
             max_temp: cpu_pkg.unwrap_or(0.0).max(gpu.unwrap_or(0.0)),
@@ -586,6 +458,35 @@ mod tests {
         assert!(alerts2.is_empty());
     }
 
+    #[test]
+    fn thermal_per_category_threshold_and_alias() {
+        let mut config = Config::default();
+        config.thermal.thresholds = vec![crate::config::ThermalThresholdConfig {
+            category: Some("ssd".to_string()),
+            sensor: None,
+            warning: Some(70.0),
+            critical: None,
+            emergency: None,
+        }];
+        config.thermal.sensor_aliases.insert(
+            "Samsung SSD 980 PRO".to_string(),
+            "scratch-nvme".to_string(),
+        );
+        let mut det = AlertDetector::new(config);
+
+        // 75°C: fine for the CPU, too hot for the NVMe
+        let mut snap = make_thermal(Some(75.0), None);
+        snap.ssd_temps.push(crate::thermal::SensorReading {
+            name: "Samsung SSD 980 PRO".to_string(),
+            value: 75.0,
+        });
+        let alerts = det.check_thermal(&snap);
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].category, AlertCategory::ThermalWarning);
+        assert_eq!(alerts[0].process_name, "scratch-nvme");
+        assert!(alerts[0].message.contains("threshold: 70°C"));
+    }
+
     // ── Zombie filter tests ───────────────────────────────────────
 
     fn make_system_snapshot() -> SystemSnapshot {
@@ -607,7 +508,8 @@ mod tests {
             networks: Vec::new(),
             disks: Vec::new(),
             cpu_temp: None,
-            gpu: None,
+            gpus: vec![],
+            cgroups: vec![],
             battery: None,
         }
     }
@@ -627,6 +529,10 @@ mod tests {
             start_time: 0,
             parent_pid: None,
             thread_count: None,
+            gpu_usage: 0.0,
+            gpu_memory_bytes: 0,
+            net_rx_bytes_per_sec: 0,
+            net_tx_bytes_per_sec: 0,
         }
     }
 
@@ -645,6 +551,10 @@ mod tests {
             start_time: 0,
             parent_pid,
             thread_count: None,
+            gpu_usage: 0.0,
+            gpu_memory_bytes: 0,
+            net_rx_bytes_per_sec: 0,
+            net_tx_bytes_per_sec: 0,
         }
     }
 
@@ -722,4 +632,81 @@ mod tests {
             "Parent ignore matching should be case-insensitive"
         );
     }
+
+    // ── Rule integration ──────────────────────────────────────────
+
+    #[test]
+    fn analyze_evaluates_builtin_threshold_rules() {
+        let mut det = make_detector();
+        let mut hog = make_running_process(300, "hog");
+        hog.cpu_usage = 99.0;
+        let alerts = det.analyze(&make_system_snapshot(), &[hog]);
+        let cpu = alerts
+            .iter()
+            .find(|a| a.category == AlertCategory::HighCpu)
+            .expect("Expected a CPU alert");
+        assert_eq!(cpu.severity, AlertSeverity::Critical);
+        assert_eq!(cpu.message, "hog using 99.0% CPU");
+    }
+
+    #[test]
+    fn gpu_hog_on_utilization_and_vram_share() {
+        let mut det = make_detector();
+        let mut system = make_system_snapshot();
+        system.gpus.push(crate::models::GpuInfo {
+            id: "nvidia0".into(),
+            vendor: crate::models::GpuVendor::Nvidia,
+            name: "Test GPU".into(),
+            utilization: 100,
+            memory_used: 20 << 30,
+            memory_total: 24 << 30,
+            temperature: Some(70),
+            power_draw: Some(300.0),
+            fan_speed: None,
+        });
+        let mut trainer = make_running_process(500, "python3");
+        trainer.gpu_usage = 97.0;
+        trainer.gpu_memory_bytes = 20 << 30;
+        let mut viewer = make_running_process(501, "blender");
+        viewer.gpu_usage = 5.0;
+        viewer.gpu_memory_bytes = 1 << 30;
+
+        let alerts = det.analyze(&system, &[trainer, viewer]);
+        let hog: Vec<&Alert> = alerts
+            .iter()
+            .filter(|a| a.category == AlertCategory::GpuHog)
+            .collect();
+        assert!(hog.iter().all(|a| a.pid == 500));
+        assert!(hog
+            .iter()
+            .any(|a| a.message == "python3 using 97% GPU (20.0 GiB VRAM)"));
+        assert!(hog.iter().any(|a| a
+            .message
+            .contains("holds 20.0 GiB of GPU memory (83% of VRAM)")));
+    }
+
+    #[test]
+    fn set_config_applies_user_rules() {
+        let mut det = make_detector();
+        let mut config = Config::default();
+        config.rules.push(crate::config::AlertRuleConfig {
+            name: Some("many-threads".into()),
+            process: Some("java*".into()),
+            cgroup: None,
+            metric: "thread_count".into(),
+            op: ">".into(),
+            value: crate::config::RuleValue::Number(500.0),
+            sustained: None,
+            severity: "warning".into(),
+            cooldown: None,
+        });
+        det.set_config(config);
+
+        let mut java = make_running_process(400, "java");
+        java.thread_count = Some(800);
+        let alerts = det.analyze(&make_system_snapshot(), &[java]);
+        assert!(alerts
+            .iter()
+            .any(|a| a.pid == 400 && a.message.contains("[many-threads]")));
+    }
 }
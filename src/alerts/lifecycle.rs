@@ -0,0 +1,276 @@
+//! Alert lifecycle: firing → resolved, with acknowledgement.
+//!
+//! Detection emits an `Alert` whenever a condition fires (subject to
+//! cooldowns). The tracker groups those by (category, process/app) and keeps
+//! one `ActiveAlert` per key while the condition keeps being observed. Once a
+//! key has gone unobserved for `ALERT_RESOLVE_SECS` it is resolved. An
+//! acknowledged key stays firing but its re-notifications are silenced.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+use crate::constants::ALERT_RESOLVE_SECS;
+use crate::models::{Alert, AlertCategory};
+
+/// Identity of an alert across repeated firings: category + process/app name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AlertKey {
+    pub category: AlertCategory,
+    pub subject: String,
+}
+
+impl AlertKey {
+    pub fn of(alert: &Alert) -> Self {
+        Self {
+            category: alert.category,
+            subject: alert.process_name.clone(),
+        }
+    }
+}
+
+impl fmt::Display for AlertKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.category, self.subject)
+    }
+}
+
+/// A firing alert tracked by the lifecycle.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActiveAlert {
+    pub key: AlertKey,
+    /// Most recent observation (most severe since an escalation).
+    pub alert: Alert,
+    /// When the alert started firing.
+    pub since: DateTime<Local>,
+    /// Whether a human acknowledged it (silences re-notifications).
+    pub acknowledged: bool,
+    #[serde(skip, default = "Instant::now")]
+    last_seen: Instant,
+}
+
+impl ActiveAlert {
+    /// A firing alert rebuilt from stored events (historical replay).
+    pub fn replayed(alert: Alert, since: DateTime<Local>) -> Self {
+        Self {
+            key: AlertKey::of(&alert),
+            alert,
+            since,
+            acknowledged: false,
+            last_seen: Instant::now(),
+        }
+    }
+
+    /// How long the alert has been (or was) firing, e.g. `"3m 20s"`.
+    pub fn duration_display(&self) -> String {
+        let secs = Local::now()
+            .signed_duration_since(self.since)
+            .num_seconds()
+            .max(0);
+        if secs < 60 {
+            format!("{}s", secs)
+        } else if secs < 3600 {
+            format!("{}m {}s", secs / 60, secs % 60)
+        } else {
+            format!("{}h {}m", secs / 3600, (secs % 3600) / 60)
+        }
+    }
+}
+
+/// Tracks which alerts are currently firing.
+pub struct AlertTracker {
+    active: HashMap<AlertKey, ActiveAlert>,
+    resolve_after: Duration,
+}
+
+impl Default for AlertTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AlertTracker {
+    pub fn new() -> Self {
+        Self {
+            active: HashMap::new(),
+            resolve_after: Duration::from_secs(ALERT_RESOLVE_SECS),
+        }
+    }
+
+    /// Feed one sample of currently-true conditions (fired or merely held
+    /// back by a cooldown). Returns the alerts that resolved.
+    pub fn update(&mut self, observed: &[Alert], now: Instant) -> Vec<ActiveAlert> {
+        for alert in observed {
+            let key = AlertKey::of(alert);
+            match self.active.get_mut(&key) {
+                Some(entry) => {
+                    // Escalation re-arms notifications for acknowledged alerts
+                    if alert.severity > entry.alert.severity {
+                        entry.acknowledged = false;
+                    }
+                    if alert.severity >= entry.alert.severity {
+                        entry.alert = alert.clone();
+                    }
+                    entry.last_seen = now;
+                }
+                None => {
+                    self.active.insert(
+                        key.clone(),
+                        ActiveAlert {
+                            key,
+                            alert: alert.clone(),
+                            since: alert.timestamp,
+                            acknowledged: false,
+                            last_seen: now,
+                        },
+                    );
+                }
+            }
+        }
+
+        let expired: Vec<AlertKey> = self
+            .active
+            .values()
+            .filter(|a| now.duration_since(a.last_seen) >= self.resolve_after)
+            .map(|a| a.key.clone())
+            .collect();
+        let mut resolved: Vec<ActiveAlert> = expired
+            .iter()
+            .filter_map(|k| self.active.remove(k))
+            .collect();
+        resolved.sort_by_key(|a| a.since);
+        resolved
+    }
+
+    /// Acknowledge a firing alert. Returns false if it isn't firing or was
+    /// already acknowledged.
+    pub fn acknowledge(&mut self, key: &AlertKey) -> bool {
+        match self.active.get_mut(key) {
+            Some(entry) if !entry.acknowledged => {
+                entry.acknowledged = true;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether notifications for this alert are silenced by an ack.
+    pub fn is_acknowledged(&self, alert: &Alert) -> bool {
+        self.active
+            .get(&AlertKey::of(alert))
+            .is_some_and(|a| a.acknowledged && alert.severity <= a.alert.severity)
+    }
+
+    /// Firing alerts, oldest first.
+    pub fn active(&self) -> Vec<ActiveAlert> {
+        let mut list: Vec<ActiveAlert> = self.active.values().cloned().collect();
+        list.sort_by_key(|a| a.since);
+        list
+    }
+}
+
+/// Keys of firing, unacknowledged alerts matching an `ack` argument:
+/// `all`, a PID, or a case-insensitive process/app name.
+pub fn match_ack_target(active: &[ActiveAlert], target: &str) -> Vec<AlertKey> {
+    let target = target.trim().to_lowercase();
+    let pid: Option<u32> = target.parse().ok();
+    active
+        .iter()
+        .filter(|a| !a.acknowledged)
+        .filter(|a| {
+            target == "all" || pid == Some(a.alert.pid) || a.key.subject.to_lowercase() == target
+        })
+        .map(|a| a.key.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::AlertSeverity;
+
+    fn make_alert(severity: AlertSeverity, name: &str, pid: u32) -> Alert {
+        Alert::new(
+            severity,
+            AlertCategory::HighCpu,
+            name,
+            pid,
+            format!("{} using 95.0% CPU", name),
+            95.0,
+            90.0,
+        )
+    }
+
+    #[test]
+    fn resolves_after_condition_clears() {
+        let mut tracker = AlertTracker::new();
+        let t0 = Instant::now();
+        let alert = make_alert(AlertSeverity::Critical, "node", 42);
+
+        assert!(tracker.update(std::slice::from_ref(&alert), t0).is_empty());
+        assert!(tracker
+            .update(&[alert], t0 + Duration::from_secs(5))
+            .is_empty());
+        assert_eq!(tracker.active().len(), 1);
+
+        // Still inside the grace period
+        assert!(tracker.update(&[], t0 + Duration::from_secs(10)).is_empty());
+        let resolved = tracker.update(&[], t0 + Duration::from_secs(15));
+        assert_eq!(resolved.len(), 1);
+        assert_eq!(resolved[0].key.subject, "node");
+        assert!(tracker.active().is_empty());
+    }
+
+    #[test]
+    fn keyed_by_category_and_app_not_pid() {
+        let mut tracker = AlertTracker::new();
+        let t0 = Instant::now();
+        tracker.update(
+            &[
+                make_alert(AlertSeverity::Warning, "chrome", 1),
+                make_alert(AlertSeverity::Warning, "chrome", 2),
+            ],
+            t0,
+        );
+        assert_eq!(tracker.active().len(), 1);
+    }
+
+    #[test]
+    fn ack_silences_until_escalation() {
+        let mut tracker = AlertTracker::new();
+        let t0 = Instant::now();
+        let warn = make_alert(AlertSeverity::Warning, "node", 42);
+        tracker.update(std::slice::from_ref(&warn), t0);
+
+        let key = AlertKey::of(&warn);
+        assert!(tracker.acknowledge(&key));
+        assert!(!tracker.acknowledge(&key), "second ack is a no-op");
+        assert!(tracker.is_acknowledged(&warn));
+
+        let crit = make_alert(AlertSeverity::Critical, "node", 42);
+        assert!(!tracker.is_acknowledged(&crit));
+        tracker.update(std::slice::from_ref(&crit), t0 + Duration::from_secs(1));
+        assert!(!tracker.is_acknowledged(&crit));
+        assert!(!tracker.active()[0].acknowledged);
+    }
+
+    #[test]
+    fn match_ack_target_by_name_pid_and_all() {
+        let mut tracker = AlertTracker::new();
+        tracker.update(
+            &[
+                make_alert(AlertSeverity::Warning, "Node", 42),
+                make_alert(AlertSeverity::Warning, "java", 7),
+            ],
+            Instant::now(),
+        );
+        let active = tracker.active();
+        assert_eq!(match_ack_target(&active, "node").len(), 1);
+        assert_eq!(match_ack_target(&active, "7")[0].subject, "java");
+        assert_eq!(match_ack_target(&active, "all").len(), 2);
+        assert!(match_ack_target(&active, "postgres").is_empty());
+    }
+}
@@ -1,3 +1,9 @@
 mod detector;
+pub mod lifecycle;
+pub mod rules;
+pub mod silence;
 
 pub use detector::AlertDetector;
+pub use lifecycle::{ActiveAlert, AlertKey, AlertTracker};
+pub use rules::AlertRule;
+pub use silence::{MaintenanceWindow, Silence, Suppressor};
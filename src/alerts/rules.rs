@@ -0,0 +1,1122 @@
+//! Declarative alert rules.
+//!
+//! A rule compares one metric of a process (or of the whole system) against a
+//! threshold, optionally only after the condition has held for a while:
+//!
+//! ```toml
+//! [[rules]]
+//! name = "postgres-memory"
+//! process = "postgres*"
+//! metric = "memory_bytes"
+//! op = ">"
+//! value = "4GiB"
+//! for = "2m"
+//! severity = "critical"
+//! ```
+//!
+//! A rule with `cgroup = "<glob>"` instead compares the aggregate usage of
+//! each matching cgroup v2 group (a systemd unit, slice or container).
+//!
+//! The CPU / memory / disk I/O thresholds from `Config` are expressed as
+//! built-in rules, so they share the same evaluation and cooldown path.
+
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+use crate::config::{AlertRuleConfig, Config, RuleValue};
+use crate::constants::{ALERT_COOLDOWN_SECS, HIGH_DISK_IO_THRESHOLD};
+use crate::models::{
+    format_bytes, Alert, AlertCategory, AlertSeverity, CgroupInfo, ProcessInfo, SystemSnapshot,
+};
+
+/// A metric a rule can compare. Unprefixed metrics are read from each
+/// `ProcessInfo` (or `CgroupInfo` for cgroup rules); `system.*` metrics from
+/// the `SystemSnapshot`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Metric {
+    CpuUsage,
+    MemoryBytes,
+    MemoryPercent,
+    DiskReadBytes,
+    DiskWriteBytes,
+    DiskIoBytes,
+    ThreadCount,
+    GpuUsage,
+    GpuMemoryBytes,
+    NetRxBytes,
+    NetTxBytes,
+    NetIoBytes,
+    /// Tasks in a cgroup (cgroup rules only)
+    Pids,
+    SystemCpuUsage,
+    SystemMemoryBytes,
+    SystemMemoryPercent,
+    SystemSwapPercent,
+    SystemLoadAvg1,
+    SystemLoadAvg5,
+    SystemLoadAvg15,
+}
+
+impl Metric {
+    /// All metrics with their config names.
+    const NAMES: &'static [(&'static str, Metric)] = &[
+        ("cpu_usage", Metric::CpuUsage),
+        ("memory_bytes", Metric::MemoryBytes),
+        ("memory_percent", Metric::MemoryPercent),
+        ("disk_read_bytes", Metric::DiskReadBytes),
+        ("disk_write_bytes", Metric::DiskWriteBytes),
+        ("disk_io_bytes", Metric::DiskIoBytes),
+        ("thread_count", Metric::ThreadCount),
+        ("gpu_usage", Metric::GpuUsage),
+        ("gpu_memory_bytes", Metric::GpuMemoryBytes),
+        ("net_rx_bytes", Metric::NetRxBytes),
+        ("net_tx_bytes", Metric::NetTxBytes),
+        ("net_io_bytes", Metric::NetIoBytes),
+        ("pids", Metric::Pids),
+        ("system.cpu_usage", Metric::SystemCpuUsage),
+        ("system.memory_bytes", Metric::SystemMemoryBytes),
+        ("system.memory_percent", Metric::SystemMemoryPercent),
+        ("system.swap_percent", Metric::SystemSwapPercent),
+        ("system.load_avg_1", Metric::SystemLoadAvg1),
+        ("system.load_avg_5", Metric::SystemLoadAvg5),
+        ("system.load_avg_15", Metric::SystemLoadAvg15),
+    ];
+
+    /// Parse a metric name as written in config.toml.
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let s = s.trim().to_lowercase();
+        Self::NAMES
+            .iter()
+            .find(|(name, _)| *name == s)
+            .map(|(_, m)| *m)
+            .ok_or_else(|| format!("unknown metric '{}'", s))
+    }
+
+    /// Whether the metric is read from the system snapshot.
+    pub fn is_system(self) -> bool {
+        matches!(
+            self,
+            Metric::SystemCpuUsage
+                | Metric::SystemMemoryBytes
+                | Metric::SystemMemoryPercent
+                | Metric::SystemSwapPercent
+                | Metric::SystemLoadAvg1
+                | Metric::SystemLoadAvg5
+                | Metric::SystemLoadAvg15
+        )
+    }
+
+    /// Alert category used for alerts raised on this metric.
+    pub fn category(self) -> AlertCategory {
+        match self {
+            Metric::CpuUsage => AlertCategory::HighCpu,
+            Metric::MemoryBytes | Metric::MemoryPercent | Metric::ThreadCount => {
+                AlertCategory::HighMemory
+            }
+            Metric::DiskReadBytes | Metric::DiskWriteBytes | Metric::DiskIoBytes => {
+                AlertCategory::HighDiskIo
+            }
+            Metric::GpuUsage | Metric::GpuMemoryBytes => AlertCategory::GpuHog,
+            Metric::NetRxBytes | Metric::NetTxBytes | Metric::NetIoBytes => {
+                AlertCategory::NetworkHog
+            }
+            _ => AlertCategory::SystemOverload,
+        }
+    }
+
+    /// Read the metric from a process (`None` for system metrics or missing data).
+    fn process_value(self, proc: &ProcessInfo) -> Option<f64> {
+        match self {
+            Metric::CpuUsage => Some(proc.cpu_usage as f64),
+            Metric::MemoryBytes => Some(proc.memory_bytes as f64),
+            Metric::MemoryPercent => Some(proc.memory_percent as f64),
+            Metric::DiskReadBytes => Some(proc.disk_read_bytes as f64),
+            Metric::DiskWriteBytes => Some(proc.disk_write_bytes as f64),
+            Metric::DiskIoBytes => Some((proc.disk_read_bytes + proc.disk_write_bytes) as f64),
+            Metric::ThreadCount => proc.thread_count.map(|n| n as f64),
+            Metric::GpuUsage => Some(proc.gpu_usage as f64),
+            Metric::GpuMemoryBytes => Some(proc.gpu_memory_bytes as f64),
+            Metric::NetRxBytes => Some(proc.net_rx_bytes_per_sec as f64),
+            Metric::NetTxBytes => Some(proc.net_tx_bytes_per_sec as f64),
+            Metric::NetIoBytes => Some(proc.net_bytes_per_sec() as f64),
+            _ => None,
+        }
+    }
+
+    /// Read the metric from a cgroup (`None` for metrics cgroups don't have).
+    fn cgroup_value(self, cgroup: &CgroupInfo) -> Option<f64> {
+        match self {
+            Metric::CpuUsage => Some(cgroup.cpu_usage as f64),
+            Metric::MemoryBytes => Some(cgroup.memory_bytes as f64),
+            Metric::DiskReadBytes => Some(cgroup.io_read_bytes_per_sec as f64),
+            Metric::DiskWriteBytes => Some(cgroup.io_write_bytes_per_sec as f64),
+            Metric::DiskIoBytes => Some(cgroup.io_bytes_per_sec() as f64),
+            Metric::Pids => Some(cgroup.pids as f64),
+            _ => None,
+        }
+    }
+
+    /// Read the metric from the system snapshot (`None` for process metrics).
+    fn system_value(self, system: &SystemSnapshot) -> Option<f64> {
+        match self {
+            Metric::SystemCpuUsage => Some(system.global_cpu_usage as f64),
+            Metric::SystemMemoryBytes => Some(system.used_memory as f64),
+            Metric::SystemMemoryPercent => Some(system.memory_percent() as f64),
+            Metric::SystemSwapPercent => Some(system.swap_percent() as f64),
+            Metric::SystemLoadAvg1 => Some(system.load_avg_1),
+            Metric::SystemLoadAvg5 => Some(system.load_avg_5),
+            Metric::SystemLoadAvg15 => Some(system.load_avg_15),
+            _ => None,
+        }
+    }
+}
+
+/// Comparison operator of a rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    Gt,
+    Ge,
+    Lt,
+    Le,
+    Eq,
+    Ne,
+}
+
+impl CompareOp {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.trim() {
+            ">" => Ok(CompareOp::Gt),
+            ">=" => Ok(CompareOp::Ge),
+            "<" => Ok(CompareOp::Lt),
+            "<=" => Ok(CompareOp::Le),
+            "==" | "=" => Ok(CompareOp::Eq),
+            "!=" => Ok(CompareOp::Ne),
+            other => Err(format!("unknown operator '{}'", other)),
+        }
+    }
+
+    fn holds(self, value: f64, threshold: f64) -> bool {
+        match self {
+            CompareOp::Gt => value > threshold,
+            CompareOp::Ge => value >= threshold,
+            CompareOp::Lt => value < threshold,
+            CompareOp::Le => value <= threshold,
+            CompareOp::Eq => (value - threshold).abs() < f64::EPSILON,
+            CompareOp::Ne => (value - threshold).abs() >= f64::EPSILON,
+        }
+    }
+}
+
+/// A compiled alert rule.
+#[derive(Debug, Clone)]
+pub struct AlertRule {
+    /// Rule name (cooldown key; shown in messages for user rules).
+    pub name: String,
+    /// Lowercased process name glob (`None` = every process).
+    pub process: Option<String>,
+    /// Lowercased cgroup glob; set for rules that target cgroups.
+    pub cgroup: Option<String>,
+    pub metric: Metric,
+    pub op: CompareOp,
+    pub threshold: f64,
+    /// How long the condition must hold before the rule fires.
+    pub sustained: Duration,
+    pub severity: AlertSeverity,
+    /// Minimum time between two alerts for the same (rule, target).
+    pub cooldown: Duration,
+    /// Built-in rules keep the original alert messages (no rule name suffix).
+    builtin: bool,
+}
+
+impl AlertRule {
+    /// Compile a rule from its config.toml form.
+    pub fn compile(cfg: &AlertRuleConfig) -> Result<Self, String> {
+        let metric = Metric::parse(&cfg.metric)?;
+        let op = CompareOp::parse(&cfg.op)?;
+        let threshold = match &cfg.value {
+            RuleValue::Number(n) => *n,
+            RuleValue::Text(s) => parse_quantity(s)?,
+        };
+        let sustained = cfg.sustained.as_ref().map(parse_duration).transpose()?;
+        let cooldown = cfg.cooldown.as_ref().map(parse_duration).transpose()?;
+        let severity = parse_severity(&cfg.severity)?;
+        let process = if metric.is_system() {
+            None
+        } else {
+            cfg.process.as_ref().map(|p| p.to_lowercase())
+        };
+        let cgroup = cfg.cgroup.as_ref().map(|c| c.trim().to_lowercase());
+        if cgroup.is_some() {
+            if process.is_some() {
+                return Err("set either process or cgroup, not both".to_string());
+            }
+            if metric.cgroup_value(&CgroupInfo::default()).is_none() {
+                return Err(format!(
+                    "metric '{}' is not available for cgroups",
+                    cfg.metric.trim()
+                ));
+            }
+        } else if metric == Metric::Pids {
+            return Err("metric 'pids' needs a cgroup".to_string());
+        }
+        let name = cfg.name.clone().unwrap_or_else(|| {
+            format!(
+                "{}{} {} {}",
+                cfg.process
+                    .as_deref()
+                    .or(cfg.cgroup.as_deref())
+                    .map(|p| format!("{} ", p))
+                    .unwrap_or_default(),
+                cfg.metric.trim(),
+                cfg.op.trim(),
+                match &cfg.value {
+                    RuleValue::Number(n) => n.to_string(),
+                    RuleValue::Text(s) => s.trim().to_string(),
+                }
+            )
+        });
+
+        Ok(Self {
+            name,
+            process,
+            cgroup,
+            metric,
+            op,
+            threshold,
+            sustained: sustained.unwrap_or(Duration::ZERO),
+            severity,
+            cooldown: cooldown.unwrap_or(Duration::from_secs(ALERT_COOLDOWN_SECS)),
+            builtin: false,
+        })
+    }
+
+    /// A built-in rule with the default cooldown and no sustain period.
+    fn builtin(
+        name: &str,
+        metric: Metric,
+        op: CompareOp,
+        threshold: f64,
+        severity: AlertSeverity,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            process: None,
+            cgroup: None,
+            metric,
+            op,
+            threshold,
+            sustained: Duration::ZERO,
+            severity,
+            cooldown: Duration::from_secs(ALERT_COOLDOWN_SECS),
+            builtin: true,
+        }
+    }
+
+    fn matches_process(&self, proc: &ProcessInfo) -> bool {
+        match &self.process {
+            Some(pattern) => glob_match(pattern, &proc.name.to_lowercase()),
+            None => true,
+        }
+    }
+
+    fn matches_cgroup(&self, cgroup: &CgroupInfo) -> bool {
+        self.cgroup.as_deref().is_some_and(|pattern| {
+            glob_match(pattern, &cgroup.path.to_lowercase())
+                || glob_match(pattern, &cgroup.name().to_lowercase())
+        })
+    }
+
+    /// Build the alert for a process that satisfied the rule.
+    fn process_alert(&self, proc: &ProcessInfo, value: f64) -> Alert {
+        let message = match self.metric {
+            Metric::CpuUsage => format!("{} using {:.1}% CPU", proc.name, proc.cpu_usage),
+            Metric::MemoryBytes | Metric::MemoryPercent => format!(
+                "{} using {} RAM ({:.1}%)",
+                proc.name,
+                proc.memory_display(),
+                proc.memory_percent
+            ),
+            Metric::DiskReadBytes | Metric::DiskWriteBytes | Metric::DiskIoBytes => format!(
+                "High disk I/O: {} (R: {}, W: {})",
+                proc.name,
+                proc.disk_read_display(),
+                proc.disk_write_display(),
+            ),
+            Metric::GpuUsage | Metric::GpuMemoryBytes => format!(
+                "{} using {:.0}% GPU ({} VRAM)",
+                proc.name,
+                proc.gpu_usage,
+                format_bytes(proc.gpu_memory_bytes),
+            ),
+            Metric::NetRxBytes | Metric::NetTxBytes | Metric::NetIoBytes => format!(
+                "High network traffic: {} (RX: {}/s, TX: {}/s)",
+                proc.name,
+                format_bytes(proc.net_rx_bytes_per_sec),
+                format_bytes(proc.net_tx_bytes_per_sec),
+            ),
+            _ => format!("{} has {:.0} threads", proc.name, value),
+        };
+        Alert::new(
+            self.severity,
+            self.metric.category(),
+            &proc.name,
+            proc.pid,
+            self.decorate(message),
+            value,
+            self.threshold,
+        )
+    }
+
+    /// Build the alert for a cgroup that satisfied the rule.
+    fn cgroup_alert(&self, cgroup: &CgroupInfo, value: f64) -> Alert {
+        let name = cgroup.display_name();
+        let message = match self.metric {
+            Metric::CpuUsage => format!("{} using {:.1}% CPU", name, cgroup.cpu_usage),
+            Metric::MemoryBytes => {
+                format!("{} using {} RAM", name, format_bytes(cgroup.memory_bytes))
+            }
+            Metric::DiskReadBytes | Metric::DiskWriteBytes | Metric::DiskIoBytes => format!(
+                "High disk I/O: {} (R: {}/s, W: {}/s)",
+                name,
+                format_bytes(cgroup.io_read_bytes_per_sec),
+                format_bytes(cgroup.io_write_bytes_per_sec),
+            ),
+            _ => format!("{} has {} tasks", name, cgroup.pids),
+        };
+        Alert::new(
+            self.severity,
+            self.metric.category(),
+            &name,
+            0,
+            self.decorate(message),
+            value,
+            self.threshold,
+        )
+    }
+
+    /// Build the alert for a system metric that satisfied the rule.
+    fn system_alert(&self, system: &SystemSnapshot, value: f64) -> Alert {
+        let level = match (self.op, self.severity >= AlertSeverity::Critical) {
+            (CompareOp::Gt | CompareOp::Ge, true) => " critically high",
+            (CompareOp::Gt | CompareOp::Ge, false) => " high",
+            (CompareOp::Lt | CompareOp::Le, true) => " critically low",
+            (CompareOp::Lt | CompareOp::Le, false) => " low",
+            _ => "",
+        };
+        let message = match self.metric {
+            Metric::SystemCpuUsage => format!("System CPU{}: {:.1}%", level, value),
+            Metric::SystemSwapPercent => format!(
+                "System swap{}: {:.1}% ({} / {})",
+                level,
+                system.swap_percent(),
+                format_bytes(system.used_swap),
+                format_bytes(system.total_swap),
+            ),
+            Metric::SystemLoadAvg1 | Metric::SystemLoadAvg5 | Metric::SystemLoadAvg15 => {
+                format!("System load{}: {:.2}", level, value)
+            }
+            _ => format!(
+                "System memory{}: {:.1}% ({} / {})",
+                level,
+                system.memory_percent(),
+                format_bytes(system.used_memory),
+                format_bytes(system.total_memory),
+            ),
+        };
+        Alert::new(
+            self.severity,
+            self.metric.category(),
+            "SYSTEM",
+            0,
+            self.decorate(message),
+            value,
+            self.threshold,
+        )
+    }
+
+    /// Append the sustain period and rule name to user rule messages.
+    fn decorate(&self, mut message: String) -> String {
+        if self.builtin {
+            return message;
+        }
+        if !self.sustained.is_zero() {
+            message.push_str(&format!(" for {}", format_duration(self.sustained)));
+        }
+        message.push_str(&format!(" [{}]", self.name));
+        message
+    }
+}
+
+/// Compile the built-in rules (from the `Config` thresholds) followed by the
+/// user's `[[rules]]`. Invalid user rules are skipped; `Config::load` has
+/// already warned about them.
+pub fn compile_rules(config: &Config) -> Vec<AlertRule> {
+    let mut rules = Vec::new();
+    if config.builtin_rules {
+        use AlertSeverity::*;
+        use CompareOp::{Ge, Gt};
+        rules.extend([
+            AlertRule::builtin(
+                "builtin.system_memory_critical",
+                Metric::SystemMemoryPercent,
+                Ge,
+                config.sys_mem_critical_percent as f64,
+                Danger,
+            ),
+            AlertRule::builtin(
+                "builtin.system_memory_warning",
+                Metric::SystemMemoryPercent,
+                Ge,
+                config.sys_mem_warning_percent as f64,
+                Warning,
+            ),
+            AlertRule::builtin(
+                "builtin.system_cpu_critical",
+                Metric::SystemCpuUsage,
+                Ge,
+                config.cpu_critical_threshold as f64,
+                Critical,
+            ),
+            AlertRule::builtin(
+                "builtin.cpu_critical",
+                Metric::CpuUsage,
+                Ge,
+                config.cpu_critical_threshold as f64,
+                Critical,
+            ),
+            AlertRule::builtin(
+                "builtin.cpu_warning",
+                Metric::CpuUsage,
+                Ge,
+                config.cpu_warning_threshold as f64,
+                Warning,
+            ),
+            AlertRule::builtin(
+                "builtin.memory_critical",
+                Metric::MemoryBytes,
+                Ge,
+                config.mem_critical_threshold_bytes as f64,
+                Critical,
+            ),
+            AlertRule::builtin(
+                "builtin.memory_warning",
+                Metric::MemoryBytes,
+                Ge,
+                config.mem_warning_threshold_bytes as f64,
+                Warning,
+            ),
+            AlertRule::builtin(
+                "builtin.gpu_usage",
+                Metric::GpuUsage,
+                Ge,
+                config.gpu_warning_threshold as f64,
+                Warning,
+            ),
+            AlertRule::builtin(
+                "builtin.net_io",
+                Metric::NetIoBytes,
+                Ge,
+                config.net_warning_threshold_bytes as f64,
+                Warning,
+            ),
+            AlertRule::builtin(
+                "builtin.disk_io",
+                Metric::DiskIoBytes,
+                Gt,
+                HIGH_DISK_IO_THRESHOLD as f64,
+                Info,
+            ),
+        ]);
+    }
+    rules.extend(
+        config
+            .rules
+            .iter()
+            .filter_map(|r| AlertRule::compile(r).ok()),
+    );
+    rules
+}
+
+/// What a rule was evaluated against.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Target {
+    System,
+    Process(u32),
+    /// cgroup path
+    Cgroup(String),
+}
+
+/// Evaluates rules against each sample, tracking how long each condition has
+/// held and when each rule last fired.
+pub struct RuleEngine {
+    rules: Vec<AlertRule>,
+    /// (rule name, target) -> when the condition started holding
+    pending_since: HashMap<(String, Target), Instant>,
+    /// (rule name, target) -> last fire time
+    last_fired: HashMap<(String, Target), Instant>,
+    /// Matches from the last evaluation, including cooldown-suppressed ones
+    firing: Vec<Alert>,
+}
+
+impl RuleEngine {
+    pub fn new(rules: Vec<AlertRule>) -> Self {
+        Self {
+            rules,
+            pending_since: HashMap::new(),
+            last_fired: HashMap::new(),
+            firing: Vec::new(),
+        }
+    }
+
+    /// Replace the rule set on config reload. State is kept for rules whose
+    /// name is unchanged, so a reload neither re-fires nor restarts `for` timers.
+    pub fn set_rules(&mut self, rules: Vec<AlertRule>) {
+        let names: HashSet<&str> = rules.iter().map(|r| r.name.as_str()).collect();
+        self.pending_since
+            .retain(|(name, _), _| names.contains(name.as_str()));
+        self.last_fired
+            .retain(|(name, _), _| names.contains(name.as_str()));
+        self.rules = rules;
+    }
+
+    /// Evaluate all rules against one sample.
+    ///
+    /// When several rules on the same metric match the same target (e.g. the
+    /// warning and critical CPU rules), only the most severe one is reported.
+    pub fn evaluate(
+        &mut self,
+        system: &SystemSnapshot,
+        processes: &[ProcessInfo],
+        now: Instant,
+    ) -> Vec<Alert> {
+        // (rule index, target, alert) for every rule whose condition held long enough
+        let mut candidates: Vec<(usize, Target, Alert)> = Vec::new();
+
+        for (idx, rule) in self.rules.iter().enumerate() {
+            if rule.metric.is_system() {
+                if let Some(value) = rule.metric.system_value(system) {
+                    let target = Target::System;
+                    if Self::sustained(&mut self.pending_since, rule, &target, value, now) {
+                        candidates.push((idx, target, rule.system_alert(system, value)));
+                    }
+                }
+                continue;
+            }
+            if rule.cgroup.is_some() {
+                for cgroup in system.cgroups.iter().filter(|c| rule.matches_cgroup(c)) {
+                    let Some(value) = rule.metric.cgroup_value(cgroup) else {
+                        continue;
+                    };
+                    let target = Target::Cgroup(cgroup.path.clone());
+                    if Self::sustained(&mut self.pending_since, rule, &target, value, now) {
+                        candidates.push((idx, target, rule.cgroup_alert(cgroup, value)));
+                    }
+                }
+                continue;
+            }
+            for proc in processes.iter().filter(|p| rule.matches_process(p)) {
+                let Some(value) = rule.metric.process_value(proc) else {
+                    continue;
+                };
+                let target = Target::Process(proc.pid);
+                if Self::sustained(&mut self.pending_since, rule, &target, value, now) {
+                    candidates.push((idx, target, rule.process_alert(proc, value)));
+                }
+            }
+        }
+
+        // Keep the most severe match per (target, metric)
+        let mut best: HashMap<(&Target, Metric), usize> = HashMap::new();
+        for (i, (idx, target, alert)) in candidates.iter().enumerate() {
+            let key = (target, self.rules[*idx].metric);
+            match best.get(&key) {
+                Some(&j) if candidates[j].2.severity >= alert.severity => {}
+                _ => {
+                    best.insert(key, i);
+                }
+            }
+        }
+        let keep: HashSet<usize> = best.into_values().collect();
+
+        let mut alerts = Vec::new();
+        self.firing.clear();
+        for (i, (idx, target, alert)) in candidates.into_iter().enumerate() {
+            if !keep.contains(&i) {
+                continue;
+            }
+            self.firing.push(alert.clone());
+            let rule = &self.rules[idx];
+            let key = (rule.name.clone(), target);
+            if let Some(last) = self.last_fired.get(&key) {
+                if now.duration_since(*last) < rule.cooldown {
+                    continue;
+                }
+            }
+            self.last_fired.insert(key, now);
+            alerts.push(alert);
+        }
+
+        // Forget state for processes and cgroups that are gone
+        let active: HashSet<u32> = processes.iter().map(|p| p.pid).collect();
+        let groups: HashSet<&str> = system.cgroups.iter().map(|c| c.path.as_str()).collect();
+        let live = |target: &Target| match target {
+            Target::System => true,
+            Target::Process(pid) => active.contains(pid),
+            Target::Cgroup(path) => groups.contains(path.as_str()),
+        };
+        self.pending_since.retain(|(_, target), _| live(target));
+        self.last_fired.retain(|(_, target), _| live(target));
+
+        alerts
+    }
+
+    /// Rule matches from the last `evaluate`, whether or not a cooldown
+    /// held them back.
+    pub fn firing(&self) -> &[Alert] {
+        &self.firing
+    }
+
+    /// Track whether the rule's condition holds for a target, returning true
+    /// once it has held for at least the rule's `for` duration.
+    fn sustained(
+        pending: &mut HashMap<(String, Target), Instant>,
+        rule: &AlertRule,
+        target: &Target,
+        value: f64,
+        now: Instant,
+    ) -> bool {
+        let key = (rule.name.clone(), target.clone());
+        if !rule.op.holds(value, rule.threshold) {
+            pending.remove(&key);
+            return false;
+        }
+        let since = *pending.entry(key).or_insert(now);
+        now.duration_since(since) >= rule.sustained
+    }
+}
+
+// ── Value parsing ─────────────────────────────────────────────────
+
+/// Parse a threshold such as `"4GiB"`, `"512 MB"`, `"90%"` or `"1.5"`.
+/// Binary (`KiB`, `MiB`, ...) and decimal (`KB`, `MB`, ...) units are supported.
+pub fn parse_quantity(s: &str) -> Result<f64, String> {
+    let trimmed = s.trim();
+    let split = trimmed
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == '+'))
+        .unwrap_or(trimmed.len());
+    let (number, unit) = trimmed.split_at(split);
+    let number: f64 = number
+        .parse()
+        .map_err(|_| format!("invalid value '{}'", s))?;
+    let multiplier: f64 = match unit.trim().to_lowercase().as_str() {
+        "" | "%" | "b" => 1.0,
+        "k" | "kb" => 1e3,
+        "m" | "mb" => 1e6,
+        "g" | "gb" => 1e9,
+        "t" | "tb" => 1e12,
+        "ki" | "kib" => 1024.0,
+        "mi" | "mib" => 1024.0 * 1024.0,
+        "gi" | "gib" => 1024.0 * 1024.0 * 1024.0,
+        "ti" | "tib" => 1024.0 * 1024.0 * 1024.0 * 1024.0,
+        other => return Err(format!("unknown unit '{}' in '{}'", other, s)),
+    };
+    Ok(number * multiplier)
+}
+
+/// Parse a duration such as `"2m"`, `"30s"`, `"1h"` or a bare number of seconds.
+pub fn parse_duration(v: &RuleValue) -> Result<Duration, String> {
+    let s = match v {
+        RuleValue::Number(n) => {
+            return Duration::try_from_secs_f64(*n).map_err(|_| format!("invalid duration '{}'", n))
+        }
+        RuleValue::Text(s) => s.trim(),
+    };
+    let split = s
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(s.len());
+    let (number, unit) = s.split_at(split);
+    let number: f64 = number
+        .parse()
+        .map_err(|_| format!("invalid duration '{}'", s))?;
+    let secs = match unit.trim() {
+        "" | "s" => number,
+        "m" => number * 60.0,
+        "h" => number * 3600.0,
+        "d" => number * 86400.0,
+        _ => return Err(format!("invalid duration '{}'", s)),
+    };
+    Duration::try_from_secs_f64(secs).map_err(|_| format!("invalid duration '{}'", s))
+}
+
+/// Format a duration compactly for alert messages (`90s`, `2m`, `1h`).
+pub(crate) fn format_duration(d: Duration) -> String {
+    let secs = d.as_secs();
+    if secs >= 3600 && secs.rem_euclid(3600) == 0 {
+        format!("{}h", secs / 3600)
+    } else if secs >= 60 && secs.rem_euclid(60) == 0 {
+        format!("{}m", secs / 60)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
+/// Parse a rule severity (unlike the Telegram filter, unknown values are an error).
+pub(crate) fn parse_severity(s: &str) -> Result<AlertSeverity, String> {
+    match s.trim().to_lowercase().as_str() {
+        "info" => Ok(AlertSeverity::Info),
+        "warning" | "warn" => Ok(AlertSeverity::Warning),
+        "critical" | "crit" => Ok(AlertSeverity::Critical),
+        "danger" => Ok(AlertSeverity::Danger),
+        other => Err(format!("unknown severity '{}'", other)),
+    }
+}
+
+/// Case-sensitive glob match supporting `*` and `?` (callers lowercase both sides).
+pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
+    let p: Vec<char> = pattern.chars().collect();
+    let t: Vec<char> = text.chars().collect();
+    let (mut pi, mut ti) = (0, 0);
+    // Position of the last `*` and the text index it was tried at
+    let mut star: Option<(usize, usize)> = None;
+
+    while ti < t.len() {
+        if pi < p.len() && (p[pi] == '?' || p[pi] == t[ti]) {
+            pi += 1;
+            ti += 1;
+        } else if pi < p.len() && p[pi] == '*' {
+            star = Some((pi, ti));
+            pi += 1;
+        } else if let Some((sp, st)) = star {
+            pi = sp + 1;
+            ti = st + 1;
+            star = Some((sp, st + 1));
+        } else {
+            return false;
+        }
+    }
+    p[pi..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ProcessStatus;
+
+    fn make_system(cpu: f32) -> SystemSnapshot {
+        SystemSnapshot {
+            global_cpu_usage: cpu,
+            cpu_usages: vec![cpu],
+            cpu_count: 1,
+            total_memory: 16_000_000_000,
+            used_memory: 4_000_000_000,
+            total_swap: 0,
+            used_swap: 0,
+            load_avg_1: 0.5,
+            load_avg_5: 0.3,
+            load_avg_15: 0.2,
+            uptime: 3600,
+            hostname: "test".to_string(),
+            os_name: "Linux".to_string(),
+            total_processes: 1,
+            networks: Vec::new(),
+            disks: Vec::new(),
+            cpu_temp: None,
+            gpus: vec![],
+            cgroups: vec![],
+            battery: None,
+        }
+    }
+
+    fn make_process(pid: u32, name: &str, cpu: f32, memory_bytes: u64) -> ProcessInfo {
+        ProcessInfo {
+            pid,
+            name: name.to_string(),
+            cmd: name.to_string(),
+            cpu_usage: cpu,
+            memory_bytes,
+            memory_percent: 0.0,
+            disk_read_bytes: 0,
+            disk_write_bytes: 0,
+            status: ProcessStatus::Running,
+            user: "test".to_string(),
+            start_time: 0,
+            parent_pid: None,
+            thread_count: None,
+            gpu_usage: 0.0,
+            gpu_memory_bytes: 0,
+            net_rx_bytes_per_sec: 0,
+            net_tx_bytes_per_sec: 0,
+        }
+    }
+
+    fn rule(process: Option<&str>, metric: &str, op: &str, value: &str) -> AlertRuleConfig {
+        AlertRuleConfig {
+            name: Some("test-rule".to_string()),
+            process: process.map(String::from),
+            cgroup: None,
+            metric: metric.to_string(),
+            op: op.to_string(),
+            value: RuleValue::Text(value.to_string()),
+            sustained: None,
+            severity: "critical".to_string(),
+            cooldown: None,
+        }
+    }
+
+    fn engine(cfgs: &[AlertRuleConfig]) -> RuleEngine {
+        RuleEngine::new(
+            cfgs.iter()
+                .map(|c| AlertRule::compile(c).unwrap())
+                .collect(),
+        )
+    }
+
+    const GIB: u64 = 1024 * 1024 * 1024;
+
+    #[test]
+    fn parse_quantity_units() {
+        assert_eq!(parse_quantity("4GiB").unwrap(), 4.0 * GIB as f64);
+        assert_eq!(parse_quantity("512 MiB").unwrap(), 512.0 * 1024.0 * 1024.0);
+        assert_eq!(parse_quantity("2GB").unwrap(), 2e9);
+        assert_eq!(parse_quantity("90%").unwrap(), 90.0);
+        assert_eq!(parse_quantity("1.5").unwrap(), 1.5);
+        assert!(parse_quantity("lots").is_err());
+        assert!(parse_quantity("4 parsecs").is_err());
+    }
+
+    #[test]
+    fn parse_duration_units() {
+        let text = |s: &str| parse_duration(&RuleValue::Text(s.to_string())).unwrap();
+        assert_eq!(text("2m"), Duration::from_secs(120));
+        assert_eq!(text("30s"), Duration::from_secs(30));
+        assert_eq!(text("1h"), Duration::from_secs(3600));
+        assert_eq!(text("45"), Duration::from_secs(45));
+        assert_eq!(
+            parse_duration(&RuleValue::Number(10.0)).unwrap(),
+            Duration::from_secs(10)
+        );
+        assert!(parse_duration(&RuleValue::Text("soon".into())).is_err());
+    }
+
+    #[test]
+    fn parse_duration_rejects_out_of_range_values() {
+        for n in [f64::INFINITY, f64::NAN, 1e30, -1.0] {
+            assert!(parse_duration(&RuleValue::Number(n)).is_err(), "{}", n);
+        }
+        for s in ["inf", "1e30", "99999999999999999999d"] {
+            assert!(parse_duration(&RuleValue::Text(s.into())).is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn glob_matching() {
+        assert!(glob_match("postgres*", "postgres"));
+        assert!(glob_match("postgres*", "postgres: writer"));
+        assert!(glob_match("*sql*", "mysqld"));
+        assert!(glob_match("node?", "node1"));
+        assert!(!glob_match("node?", "node"));
+        assert!(!glob_match("postgres*", "pg_ctl"));
+        assert!(glob_match("*", ""));
+    }
+
+    #[test]
+    fn compile_rejects_bad_rules() {
+        assert!(AlertRule::compile(&rule(None, "bogus", ">", "1")).is_err());
+        assert!(AlertRule::compile(&rule(None, "cpu_usage", "=>", "1")).is_err());
+        let mut bad_severity = rule(None, "cpu_usage", ">", "1");
+        bad_severity.severity = "meh".to_string();
+        assert!(AlertRule::compile(&bad_severity).is_err());
+    }
+
+    #[test]
+    fn process_rule_matches_glob_only() {
+        let mut eng = engine(&[rule(Some("postgres*"), "memory_bytes", ">", "4GiB")]);
+        let procs = vec![
+            make_process(10, "postgres", 1.0, 5 * GIB),
+            make_process(11, "java", 1.0, 8 * GIB),
+        ];
+        let alerts = eng.evaluate(&make_system(10.0), &procs, Instant::now());
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].pid, 10);
+        assert_eq!(alerts[0].category, AlertCategory::HighMemory);
+        assert_eq!(alerts[0].severity, AlertSeverity::Critical);
+        assert!(alerts[0].message.contains("[test-rule]"));
+    }
+
+    #[test]
+    fn sustained_rule_waits_for_duration() {
+        let mut cfg = rule(Some("postgres*"), "memory_bytes", ">", "4GiB");
+        cfg.sustained = Some(RuleValue::Text("2m".to_string()));
+        let mut eng = engine(&[cfg]);
+        let system = make_system(10.0);
+        let high = vec![make_process(10, "postgres", 1.0, 5 * GIB)];
+        let low = vec![make_process(10, "postgres", 1.0, GIB)];
+        let t0 = Instant::now();
+
+        assert!(eng.evaluate(&system, &high, t0).is_empty());
+        assert!(eng
+            .evaluate(&system, &high, t0 + Duration::from_secs(60))
+            .is_empty());
+        // Dipping below the threshold resets the timer
+        assert!(eng
+            .evaluate(&system, &low, t0 + Duration::from_secs(90))
+            .is_empty());
+        assert!(eng
+            .evaluate(&system, &high, t0 + Duration::from_secs(100))
+            .is_empty());
+        assert!(eng
+            .evaluate(&system, &high, t0 + Duration::from_secs(200))
+            .is_empty());
+        let alerts = eng.evaluate(&system, &high, t0 + Duration::from_secs(220));
+        assert_eq!(alerts.len(), 1);
+        assert!(alerts[0].message.contains("for 2m"));
+    }
+
+    #[test]
+    fn per_rule_cooldown() {
+        let mut cfg = rule(None, "system.cpu_usage", ">=", "90");
+        cfg.cooldown = Some(RuleValue::Text("5m".to_string()));
+        let mut eng = engine(&[cfg]);
+        let system = make_system(95.0);
+        let t0 = Instant::now();
+
+        assert_eq!(eng.evaluate(&system, &[], t0).len(), 1);
+        assert!(eng
+            .evaluate(&system, &[], t0 + Duration::from_secs(120))
+            .is_empty());
+        let alerts = eng.evaluate(&system, &[], t0 + Duration::from_secs(301));
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].pid, 0);
+        assert!(alerts[0].message.starts_with("System CPU critically high"));
+    }
+
+    #[test]
+    fn builtin_rules_report_most_severe_tier() {
+        let config = Config::default();
+        let mut eng = RuleEngine::new(compile_rules(&config));
+        let procs = vec![
+            make_process(1, "busy", 99.0, 1024),
+            make_process(2, "warm", config.cpu_warning_threshold + 1.0, 1024),
+        ];
+        let alerts = eng.evaluate(&make_system(10.0), &procs, Instant::now());
+        let cpu: Vec<_> = alerts
+            .iter()
+            .filter(|a| a.category == AlertCategory::HighCpu)
+            .collect();
+        assert_eq!(cpu.len(), 2);
+        let busy = cpu.iter().find(|a| a.pid == 1).unwrap();
+        assert_eq!(busy.severity, AlertSeverity::Critical);
+        assert_eq!(busy.message, "busy using 99.0% CPU");
+        let warm = cpu.iter().find(|a| a.pid == 2).unwrap();
+        assert_eq!(warm.severity, AlertSeverity::Warning);
+    }
+
+    #[test]
+    fn builtin_net_rule_flags_network_hogs() {
+        let config = Config::default();
+        let mut eng = RuleEngine::new(compile_rules(&config));
+        let mut upload = make_process(1, "rclone", 0.0, 1024);
+        upload.net_rx_bytes_per_sec = 1024 * 1024;
+        upload.net_tx_bytes_per_sec = config.net_warning_threshold_bytes;
+        let mut quiet = make_process(2, "curl", 0.0, 1024);
+        quiet.net_rx_bytes_per_sec = 1024;
+        let alerts = eng.evaluate(&make_system(10.0), &[upload, quiet], Instant::now());
+        let net: Vec<_> = alerts
+            .iter()
+            .filter(|a| a.category == AlertCategory::NetworkHog)
+            .collect();
+        assert_eq!(net.len(), 1);
+        assert_eq!(net[0].pid, 1);
+        assert_eq!(net[0].severity, AlertSeverity::Warning);
+        assert_eq!(
+            net[0].message,
+            "High network traffic: rclone (RX: 1.0 MiB/s, TX: 50.0 MiB/s)"
+        );
+    }
+
+    fn cgroup_rule(pattern: &str, metric: &str, value: &str) -> AlertRuleConfig {
+        let mut cfg = rule(None, metric, ">", value);
+        cfg.cgroup = Some(pattern.to_string());
+        cfg
+    }
+
+    fn cgroup(path: &str, memory_bytes: u64) -> CgroupInfo {
+        CgroupInfo {
+            path: path.to_string(),
+            memory_bytes,
+            ..CgroupInfo::default()
+        }
+    }
+
+    #[test]
+    fn cgroup_rule_targets_groups_not_processes() {
+        let mut eng = engine(&[cgroup_rule("*.service", "memory_bytes", "1GiB")]);
+        let mut system = make_system(10.0);
+        system.cgroups = vec![
+            cgroup("/system.slice", 8 << 30),
+            cgroup("/system.slice/postgresql.service", 3 << 30),
+            cgroup("/system.slice/cron.service", 1 << 20),
+        ];
+        let procs = vec![make_process(1, "postgres", 0.0, 5 << 30)];
+        let t0 = Instant::now();
+        let alerts = eng.evaluate(&system, &procs, t0);
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].process_name, "postgresql.service");
+        assert_eq!(alerts[0].pid, 0);
+        assert_eq!(alerts[0].category, AlertCategory::HighMemory);
+        assert_eq!(
+            alerts[0].message,
+            "postgresql.service using 3.0 GiB RAM [test-rule]"
+        );
+
+        // Cooldown is per group; a group that disappears is forgotten
+        assert!(eng
+            .evaluate(&system, &procs, t0 + Duration::from_secs(1))
+            .is_empty());
+        system.cgroups.remove(1);
+        eng.evaluate(&system, &procs, t0 + Duration::from_secs(2));
+        system
+            .cgroups
+            .push(cgroup("/system.slice/postgresql.service", 3 << 30));
+        assert_eq!(
+            eng.evaluate(&system, &procs, t0 + Duration::from_secs(3))
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn cgroup_rules_compile_checks() {
+        let mut both = cgroup_rule("*.service", "cpu_usage", "50");
+        both.process = Some("nginx".to_string());
+        assert!(AlertRule::compile(&both).is_err());
+        assert!(AlertRule::compile(&cgroup_rule("*", "gpu_usage", "50")).is_err());
+        assert!(AlertRule::compile(&rule(None, "pids", ">", "100")).is_err());
+
+        let pids = AlertRule::compile(&cgroup_rule("/Machine.slice/*", "pids", "500")).unwrap();
+        assert_eq!(pids.cgroup.as_deref(), Some("/machine.slice/*"));
+    }
+
+    #[test]
+    fn builtin_rules_can_be_disabled() {
+        let config = Config {
+            builtin_rules: false,
+            ..Config::default()
+        };
+        assert!(compile_rules(&config).is_empty());
+    }
+
+    #[test]
+    fn reload_keeps_cooldown_for_unchanged_rules() {
+        let cfg = rule(None, "system.cpu_usage", ">=", "90");
+        let mut eng = engine(std::slice::from_ref(&cfg));
+        let system = make_system(95.0);
+        let t0 = Instant::now();
+        assert_eq!(eng.evaluate(&system, &[], t0).len(), 1);
+
+        eng.set_rules(vec![AlertRule::compile(&cfg).unwrap()]);
+        assert!(eng
+            .evaluate(&system, &[], t0 + Duration::from_secs(1))
+            .is_empty());
+    }
+}
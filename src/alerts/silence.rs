@@ -0,0 +1,597 @@
+//! Alert silences and maintenance windows.
+//!
+//! A silence mutes matching alerts until it expires; it is created from the
+//! command palette and persisted in the event store. A maintenance window is
+//! a recurring silence from config.toml, opened on a cron schedule:
+//!
+//! ```toml
+//! [[maintenance]]
+//! name = "nightly-backup"
+//! schedule = "0 2 * * *"   # minute hour day month weekday
+//! duration = "1h"
+//! category = "disk"
+//! process = "pg_dump*"
+//! ```
+//!
+//! Suppressed alerts are still recorded (marked suppressed) but are not sent
+//! to Telegram or email.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Datelike, Local, NaiveDate, TimeZone, Timelike};
+
+use crate::config::{Config, MaintenanceWindowConfig};
+use crate::models::{Alert, AlertCategory, AlertSeverity};
+use crate::store::SilenceRow;
+
+use super::rules::{glob_match, parse_duration, parse_severity};
+
+/// Longest maintenance window accepted (a week).
+const MAX_WINDOW_SECS: u64 = 7 * 24 * 3600;
+
+const ALL_CATEGORIES: [AlertCategory; 17] = [
+    AlertCategory::HighCpu,
+    AlertCategory::HighMemory,
+    AlertCategory::HighDiskIo,
+    AlertCategory::GpuHog,
+    AlertCategory::NetworkHog,
+    AlertCategory::Zombie,
+    AlertCategory::Suspicious,
+    AlertCategory::SystemOverload,
+    AlertCategory::MemoryLeak,
+    AlertCategory::SecurityThreat,
+    AlertCategory::SecurityScore,
+    AlertCategory::ThermalWarning,
+    AlertCategory::ThermalCritical,
+    AlertCategory::ThermalEmergency,
+    AlertCategory::WindowsFirewall,
+    AlertCategory::WindowsDefender,
+    AlertCategory::WindowsUpdates,
+];
+
+/// Parse a category by its short label (`cpu`, `disk`) or its full name
+/// (`HighDiskIo`), case-insensitively.
+pub fn parse_category(s: &str) -> Result<AlertCategory, String> {
+    let wanted = s.trim().to_lowercase();
+    ALL_CATEGORIES
+        .iter()
+        .find(|c| {
+            c.to_string().to_lowercase() == wanted || format!("{:?}", c).to_lowercase() == wanted
+        })
+        .copied()
+        .ok_or_else(|| format!("unknown alert category '{}'", s))
+}
+
+// ── Matching ──────────────────────────────────────────────────────
+
+/// Which alerts a silence or maintenance window applies to. Empty fields
+/// match everything.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AlertMatcher {
+    pub category: Option<AlertCategory>,
+    /// Process name glob, stored lowercase.
+    pub process: Option<String>,
+    /// Only alerts at or below this severity.
+    pub max_severity: Option<AlertSeverity>,
+}
+
+impl AlertMatcher {
+    /// Build a matcher from the textual fields used in config and the store.
+    pub fn parse(
+        category: Option<&str>,
+        process: Option<&str>,
+        severity: Option<&str>,
+    ) -> Result<Self, String> {
+        Ok(Self {
+            category: category.map(parse_category).transpose()?,
+            process: process.map(|p| p.trim().to_lowercase()),
+            max_severity: severity.map(parse_severity).transpose()?,
+        })
+    }
+
+    pub fn matches(&self, alert: &Alert) -> bool {
+        self.category.is_none_or(|c| c == alert.category)
+            && self
+                .process
+                .as_deref()
+                .is_none_or(|p| glob_match(p, &alert.process_name.to_lowercase()))
+            && self.max_severity.is_none_or(|s| alert.severity <= s)
+    }
+
+    /// Short description, e.g. `CPU, process make*, <= WARN` or `all alerts`.
+    pub fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(c) = self.category {
+            parts.push(c.to_string());
+        }
+        if let Some(ref p) = self.process {
+            parts.push(format!("process {}", p));
+        }
+        if let Some(s) = self.max_severity {
+            parts.push(format!("<= {}", s));
+        }
+        if parts.is_empty() {
+            "all alerts".to_string()
+        } else {
+            parts.join(", ")
+        }
+    }
+}
+
+// ── Silences ──────────────────────────────────────────────────────
+
+/// A one-off silence stored in the event store.
+#[derive(Debug, Clone)]
+pub struct Silence {
+    pub id: i64,
+    pub matcher: AlertMatcher,
+    /// Expiry as Unix epoch milliseconds.
+    pub expires_ts: i64,
+    pub reason: Option<String>,
+}
+
+impl Silence {
+    pub fn from_row(row: &SilenceRow) -> Result<Self, String> {
+        Ok(Self {
+            id: row.id,
+            matcher: AlertMatcher::parse(
+                row.category.as_deref(),
+                row.process.as_deref(),
+                row.severity.as_deref(),
+            )?,
+            expires_ts: row.expires_ts,
+            reason: row.reason.clone(),
+        })
+    }
+
+    /// Time left before the silence expires, e.g. `"1h 20m"`.
+    pub fn remaining_display(&self, now: DateTime<Local>) -> String {
+        let secs = ((self.expires_ts - now.timestamp_millis()) / 1000).max(0);
+        if secs < 60 {
+            format!("{}s", secs)
+        } else if secs < 3600 {
+            format!("{}m", secs / 60)
+        } else {
+            format!("{}h {}m", secs / 3600, (secs % 3600) / 60)
+        }
+    }
+}
+
+// ── Cron schedules ────────────────────────────────────────────────
+
+/// A five-field cron schedule (`minute hour day month weekday`) supporting
+/// `*`, numbers, ranges (`1-5`), lists (`1,15`) and steps (`*/10`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct CronSchedule {
+    expr: String,
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    /// Whether day-of-month / day-of-week were restricted (not `*`).
+    days_restricted: bool,
+    weekdays_restricted: bool,
+}
+
+impl CronSchedule {
+    pub fn parse(expr: &str) -> Result<Self, String> {
+        let fields: Vec<&str> = expr.split_whitespace().collect();
+        if fields.len() != 5 {
+            return Err(format!(
+                "schedule '{}' must have 5 fields: minute hour day month weekday",
+                expr
+            ));
+        }
+        let mut weekdays = parse_cron_field(fields[4], 0, 7)?;
+        // 7 is Sunday too
+        if weekdays & (1 << 7) != 0 {
+            weekdays |= 1;
+        }
+        Ok(Self {
+            expr: fields.join(" "),
+            minutes: parse_cron_field(fields[0], 0, 59)?,
+            hours: parse_cron_field(fields[1], 0, 23)?,
+            days: parse_cron_field(fields[2], 1, 31)?,
+            months: parse_cron_field(fields[3], 1, 12)?,
+            weekdays,
+            days_restricted: fields[2] != "*",
+            weekdays_restricted: fields[4] != "*",
+        })
+    }
+
+    /// The schedule as written (whitespace normalized).
+    pub fn as_str(&self) -> &str {
+        &self.expr
+    }
+
+    /// Whether the schedule fires at this minute.
+    #[cfg(test)]
+    pub fn matches(&self, t: DateTime<Local>) -> bool {
+        self.matches_date(t.date_naive())
+            && bit(self.hours, t.hour())
+            && bit(self.minutes, t.minute())
+    }
+
+    /// Whether the schedule fires at all on `date`.
+    fn matches_date(&self, date: NaiveDate) -> bool {
+        let day = bit(self.days, date.day());
+        let weekday = bit(self.weekdays, date.weekday().num_days_from_sunday());
+        // Classic cron: when both day fields are restricted, either may match
+        let day_ok = if self.days_restricted && self.weekdays_restricted {
+            day || weekday
+        } else {
+            day && weekday
+        };
+        bit(self.months, date.month()) && day_ok
+    }
+
+    /// The latest minute of the day (0..1440) at or before `limit` that the
+    /// schedule fires at, read straight off the hour and minute masks.
+    fn last_minute_of_day(&self, limit: u32) -> Option<u32> {
+        let (limit_hour, limit_minute) = (limit / 60, limit % 60);
+        (0..=limit_hour).rev().find_map(|hour| {
+            if !bit(self.hours, hour) {
+                return None;
+            }
+            let max_minute = if hour == limit_hour { limit_minute } else { 59 };
+            let minutes = self.minutes & ((1u64 << (max_minute + 1)) - 1);
+            (minutes != 0).then(|| hour * 60 + 63 - minutes.leading_zeros())
+        })
+    }
+
+    /// When the schedule last fired at or before `now`, looking back at
+    /// most `days` days.
+    pub fn last_fired(&self, now: DateTime<Local>, days: u64) -> Option<DateTime<Local>> {
+        let today = now.date_naive();
+        for back in 0..=days {
+            let date = today - chrono::Days::new(back);
+            if !self.matches_date(date) {
+                continue;
+            }
+            let mut limit = if back == 0 {
+                now.hour() * 60 + now.minute()
+            } else {
+                24 * 60 - 1
+            };
+            while let Some(minute) = self.last_minute_of_day(limit) {
+                let naive = date.and_hms_opt(minute / 60, minute % 60, 0)?;
+                // Skipped by a DST jump: try the previous firing; repeated by
+                // one: take the latest occurrence that has already happened
+                let local = Local.from_local_datetime(&naive);
+                if let Some(t) = [local.latest(), local.earliest()]
+                    .into_iter()
+                    .flatten()
+                    .find(|t| *t <= now)
+                {
+                    return Some(t);
+                }
+                let Some(earlier) = minute.checked_sub(1) else {
+                    break;
+                };
+                limit = earlier;
+            }
+        }
+        None
+    }
+}
+
+fn bit(mask: u64, v: u32) -> bool {
+    mask & (1 << v) != 0
+}
+
+/// Parse one cron field into a bitmask of allowed values.
+fn parse_cron_field(field: &str, min: u32, max: u32) -> Result<u64, String> {
+    let mut mask = 0u64;
+    for item in field.split(',') {
+        let (range, step) = match item.split_once('/') {
+            Some((r, s)) => {
+                let step: u32 = s
+                    .parse()
+                    .ok()
+                    .filter(|&s| s > 0)
+                    .ok_or_else(|| format!("invalid step in '{}'", item))?;
+                (r, step)
+            }
+            None => (item, 1),
+        };
+        let (lo, hi) = if range == "*" {
+            (min, max)
+        } else if let Some((a, b)) = range.split_once('-') {
+            (
+                parse_cron_value(a, min, max)?,
+                parse_cron_value(b, min, max)?,
+            )
+        } else {
+            let v = parse_cron_value(range, min, max)?;
+            // `5/15` means "from 5, every 15"
+            (v, if step > 1 { max } else { v })
+        };
+        if lo > hi {
+            return Err(format!("invalid range '{}'", range));
+        }
+        for v in (lo..=hi).step_by(step as usize) {
+            mask |= 1 << v;
+        }
+    }
+    Ok(mask)
+}
+
+fn parse_cron_value(s: &str, min: u32, max: u32) -> Result<u32, String> {
+    s.parse()
+        .ok()
+        .filter(|v| (min..=max).contains(v))
+        .ok_or_else(|| format!("'{}' is not in {}-{}", s, min, max))
+}
+
+// ── Maintenance windows ───────────────────────────────────────────
+
+/// A compiled `[[maintenance]]` window.
+#[derive(Debug, Clone)]
+pub struct MaintenanceWindow {
+    pub name: String,
+    pub schedule: CronSchedule,
+    pub duration: Duration,
+    pub matcher: AlertMatcher,
+}
+
+impl MaintenanceWindow {
+    pub fn compile(cfg: &MaintenanceWindowConfig) -> Result<Self, String> {
+        let schedule = CronSchedule::parse(&cfg.schedule)?;
+        let duration = parse_duration(&cfg.duration)?;
+        if duration.as_secs() < 60 || duration.as_secs() > MAX_WINDOW_SECS {
+            return Err(format!(
+                "maintenance duration must be between 1m and 7d, got {}s",
+                duration.as_secs()
+            ));
+        }
+        Ok(Self {
+            name: cfg.name.clone().unwrap_or_else(|| cfg.schedule.clone()),
+            schedule,
+            duration,
+            matcher: AlertMatcher::parse(
+                cfg.category.as_deref(),
+                cfg.process.as_deref(),
+                cfg.severity.as_deref(),
+            )?,
+        })
+    }
+
+    /// Whether the window is open: the schedule fired less than `duration` ago.
+    pub fn is_open(&self, now: DateTime<Local>) -> bool {
+        let window = chrono::Duration::seconds(self.duration.as_secs() as i64);
+        let days = self.duration.as_secs() / (24 * 3600) + 1;
+        self.schedule
+            .last_fired(now, days)
+            .is_some_and(|start| now - start < window)
+    }
+}
+
+/// Compile the maintenance windows from config, skipping invalid ones
+/// (`Config::load` already warned about them).
+pub fn compile_windows(config: &Config) -> Vec<MaintenanceWindow> {
+    config
+        .maintenance
+        .iter()
+        .filter_map(|w| MaintenanceWindow::compile(w).ok())
+        .collect()
+}
+
+// ── Suppressor ────────────────────────────────────────────────────
+
+/// Decides whether an alert is suppressed by an active silence or an open
+/// maintenance window. Refreshed once per tick.
+#[derive(Default)]
+pub struct Suppressor {
+    silences: Vec<Silence>,
+    windows: Vec<MaintenanceWindow>,
+    /// Parallel to `windows`: whether each was open at the last refresh.
+    open: Vec<bool>,
+}
+
+impl Suppressor {
+    pub fn new(config: &Config) -> Self {
+        let windows = compile_windows(config);
+        Self {
+            open: vec![false; windows.len()],
+            silences: Vec::new(),
+            windows,
+        }
+    }
+
+    /// Replace the maintenance windows (config reload).
+    pub fn set_windows(&mut self, config: &Config) {
+        self.windows = compile_windows(config);
+        self.open = vec![false; self.windows.len()];
+    }
+
+    /// Take the current silences and re-evaluate which windows are open.
+    pub fn refresh(&mut self, silences: Vec<Silence>, now: DateTime<Local>) {
+        let now_ms = now.timestamp_millis();
+        self.silences = silences
+            .into_iter()
+            .filter(|s| s.expires_ts > now_ms)
+            .collect();
+        self.open = self.windows.iter().map(|w| w.is_open(now)).collect();
+    }
+
+    /// What suppresses this alert, if anything (e.g. `silence #3`).
+    pub fn suppressed_by(&self, alert: &Alert) -> Option<String> {
+        if let Some(s) = self.silences.iter().find(|s| s.matcher.matches(alert)) {
+            return Some(format!("silence #{}", s.id));
+        }
+        self.windows
+            .iter()
+            .zip(&self.open)
+            .find(|(w, open)| **open && w.matcher.matches(alert))
+            .map(|(w, _)| format!("maintenance '{}'", w.name))
+    }
+
+    pub fn is_suppressed(&self, alert: &Alert) -> bool {
+        self.suppressed_by(alert).is_some()
+    }
+
+    pub fn silences(&self) -> &[Silence] {
+        &self.silences
+    }
+
+    /// Maintenance windows with whether each is currently open.
+    pub fn windows(&self) -> impl Iterator<Item = (&MaintenanceWindow, bool)> {
+        self.windows.iter().zip(self.open.iter().copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RuleValue;
+
+    fn at(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> DateTime<Local> {
+        Local.with_ymd_and_hms(y, mo, d, h, mi, 0).single().unwrap()
+    }
+
+    fn make_alert(category: AlertCategory, severity: AlertSeverity, name: &str) -> Alert {
+        Alert::new(severity, category, name, 7, "test".into(), 1.0, 0.0)
+    }
+
+    #[test]
+    fn category_by_label_or_name() {
+        assert_eq!(parse_category("cpu").unwrap(), AlertCategory::HighCpu);
+        assert_eq!(
+            parse_category("HighDiskIo").unwrap(),
+            AlertCategory::HighDiskIo
+        );
+        assert_eq!(parse_category("gpu").unwrap(), AlertCategory::GpuHog);
+        assert_eq!(parse_category("net").unwrap(), AlertCategory::NetworkHog);
+        assert!(parse_category("fan").is_err());
+    }
+
+    #[test]
+    fn matcher_fields() {
+        let m = AlertMatcher::parse(Some("disk"), Some("Backup*"), Some("warning")).unwrap();
+        let hit = make_alert(
+            AlertCategory::HighDiskIo,
+            AlertSeverity::Warning,
+            "backup-job",
+        );
+        assert!(m.matches(&hit));
+        assert!(!m.matches(&make_alert(
+            AlertCategory::HighDiskIo,
+            AlertSeverity::Critical,
+            "backup-job"
+        )));
+        assert!(!m.matches(&make_alert(
+            AlertCategory::HighCpu,
+            AlertSeverity::Warning,
+            "backup"
+        )));
+        assert!(!m.matches(&make_alert(
+            AlertCategory::HighDiskIo,
+            AlertSeverity::Info,
+            "rsync"
+        )));
+        assert!(AlertMatcher::default().matches(&hit));
+    }
+
+    #[test]
+    fn cron_fields() {
+        let s = CronSchedule::parse("*/15 2-4 * * 1-5").unwrap();
+        // 2026-03-02 is a Monday
+        assert!(s.matches(at(2026, 3, 2, 3, 30)));
+        assert!(!s.matches(at(2026, 3, 2, 3, 31)));
+        assert!(!s.matches(at(2026, 3, 2, 5, 0)));
+        assert!(!s.matches(at(2026, 3, 1, 3, 30)), "Sunday");
+
+        let sunday = CronSchedule::parse("0 0 * * 7").unwrap();
+        assert!(sunday.matches(at(2026, 3, 1, 0, 0)));
+
+        assert!(CronSchedule::parse("0 2 * *").is_err());
+        assert!(CronSchedule::parse("60 * * * *").is_err());
+        assert!(CronSchedule::parse("*/0 * * * *").is_err());
+    }
+
+    #[test]
+    fn window_open_for_duration_after_schedule() {
+        let cfg = MaintenanceWindowConfig {
+            name: Some("nightly".into()),
+            schedule: "0 2 * * *".into(),
+            duration: RuleValue::Text("1h".into()),
+            category: None,
+            process: None,
+            severity: None,
+        };
+        let w = MaintenanceWindow::compile(&cfg).unwrap();
+        assert!(!w.is_open(at(2026, 3, 2, 1, 59)));
+        assert!(w.is_open(at(2026, 3, 2, 2, 0)));
+        assert!(w.is_open(at(2026, 3, 2, 2, 59)));
+        assert!(!w.is_open(at(2026, 3, 2, 3, 0)));
+
+        // A weekend window opened Friday 22:00, found without walking minutes
+        let weekend = MaintenanceWindow::compile(&MaintenanceWindowConfig {
+            schedule: "0 22 * * 5".into(),
+            duration: RuleValue::Text("3d".into()),
+            ..cfg.clone()
+        })
+        .unwrap();
+        // 2026-03-13 is a Friday
+        assert!(!weekend.is_open(at(2026, 3, 13, 21, 59)));
+        assert!(weekend.is_open(at(2026, 3, 15, 12, 0)));
+        assert!(weekend.is_open(at(2026, 3, 16, 21, 59)));
+        assert!(!weekend.is_open(at(2026, 3, 16, 22, 0)));
+
+        let quarterly = CronSchedule::parse("*/15 2-4 * * *").unwrap();
+        let now = at(2026, 3, 2, 3, 14);
+        assert_eq!(quarterly.last_fired(now, 1), Some(at(2026, 3, 2, 3, 0)));
+        let now = at(2026, 3, 2, 1, 0);
+        assert_eq!(quarterly.last_fired(now, 1), Some(at(2026, 3, 1, 4, 45)));
+        assert_eq!(quarterly.last_fired(now, 0), None);
+
+        let too_long = MaintenanceWindowConfig {
+            duration: RuleValue::Text("8d".into()),
+            ..cfg
+        };
+        assert!(MaintenanceWindow::compile(&too_long).is_err());
+    }
+
+    #[test]
+    fn suppressor_uses_silences_and_open_windows() {
+        let config = Config {
+            maintenance: vec![MaintenanceWindowConfig {
+                name: Some("deploy".into()),
+                schedule: "0 2 * * *".into(),
+                duration: RuleValue::Number(1800.0),
+                category: Some("cpu".into()),
+                process: None,
+                severity: None,
+            }],
+            ..Config::default()
+        };
+        let mut sup = Suppressor::new(&config);
+        let cpu = make_alert(AlertCategory::HighCpu, AlertSeverity::Critical, "make");
+        let mem = make_alert(AlertCategory::HighMemory, AlertSeverity::Warning, "java");
+
+        let now = at(2026, 3, 2, 2, 10);
+        sup.refresh(Vec::new(), now);
+        assert_eq!(
+            sup.suppressed_by(&cpu).as_deref(),
+            Some("maintenance 'deploy'")
+        );
+        assert!(!sup.is_suppressed(&mem));
+
+        let silence = Silence {
+            id: 3,
+            matcher: AlertMatcher::parse(None, Some("java"), None).unwrap(),
+            expires_ts: at(2026, 3, 2, 5, 0).timestamp_millis(),
+            reason: None,
+        };
+        let expired = Silence {
+            id: 4,
+            expires_ts: at(2026, 3, 2, 3, 0).timestamp_millis(),
+            ..silence.clone()
+        };
+        sup.refresh(vec![silence, expired], at(2026, 3, 2, 4, 0));
+        assert!(!sup.is_suppressed(&cpu), "window closed");
+        assert_eq!(sup.silences().len(), 1);
+        assert_eq!(sup.suppressed_by(&mem).as_deref(), Some("silence #3"));
+    }
+}
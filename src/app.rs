@@ -4,6 +4,7 @@
 //! Extracts the event loop from `main()` into a testable, well-structured unit.
 
 use std::io;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -22,24 +23,40 @@ use tokio::sync::mpsc;
 
 use crate::ai::client::AiEvent;
 use crate::ai::{ClaudeClient, ContextBuilder};
-use crate::alerts::AlertDetector;
-use crate::config::Config;
+use crate::alerts::lifecycle::match_ack_target;
+use crate::alerts::silence::AlertMatcher;
+use crate::alerts::{ActiveAlert, AlertDetector, AlertKey, AlertTracker, Silence, Suppressor};
+use crate::config::{CliOverrides, Config};
 use crate::constants::*;
-use crate::diagnostics::{DiagnosticEngine, SuggestedAction};
-use crate::notifications::{self, EmailNotifier, NotifyEvent};
-use crate::notifications::telegram::TelegramNotifier;
+use crate::diagnostics::{parse_time_arg, parse_time_range, DiagnosticEngine, SuggestedAction};
+use crate::ipc::{ClientRequest, IpcClient, IpcServer, LiveSnapshot, ServerMessage};
+use crate::models::{Alert, AlertSeverity, ProcessInfo, ResourceLimits};
+use crate::notifications::{self, EmailNotifier, NotifyEvent, SmtpConfig};
+use crate::notifications::telegram::{AlertContext, TelegramNotifier};
+use crate::notifications::webhook::WebhookNotifier;
+use crate::notifications::chat::ChatNotifier;
+use crate::notifications::escalation::{Escalation, EscalationChannel, Escalator};
+use crate::notifications::telegram_bot::{
+    self, BotCommand, BotListener, BotUpdate, CallbackAction, KillConfirmations,
+};
 use crate::plugins::market::MarketPlugin;
 use crate::plugins::registry::PluginRegistry;
 use crate::plugins::settings::SettingsPlugin;
 use crate::plugins::windows::WindowsPlugin;
 use crate::plugins::PluginAction;
-use crate::thermal::LhmClient;
-use crate::thermal::shutdown::{ShutdownEvent, ShutdownManager};
+use crate::replay::load_frame;
+use crate::thermal::fan::{FanController, FanCurveEditor};
+use crate::thermal::mitigation::Mitigator;
+use crate::thermal::shutdown::{
+    ShutdownContext, ShutdownEvent, ShutdownManager, ShutdownProgress, ShutdownStep,
+};
+use crate::thermal::thresholds::{Level, ThermalThresholds};
 use crate::ui::CommandResult;
+use crate::monitor::limits::CgroupLimiter;
 use crate::monitor::{ContainerInfo, DockerMonitor, SystemCollector};
 use crate::store::EventStore;
 use crate::ui::glyphs::{GlyphMode, Glyphs};
-use crate::ui::{self, AppState, Tab};
+use crate::ui::{self, AppState, CgroupRow, DetailTab, Tab};
 
 /// System prompt for auto-analysis (Dashboard insight card).
 const AUTO_ANALYSIS_PROMPT: &str = r#"You are Sentinel AI, a system analyst embedded in a terminal monitor.
@@ -94,14 +111,30 @@ Guidelines:
     )
 }
 
+/// How the application is driven.
+pub enum RunMode {
+    /// Interactive TUI with a local collector.
+    Tui,
+    /// No terminal; serves `sentinel attach` clients on `socket`.
+    Headless { socket: PathBuf },
+    /// TUI fed by a running daemon over `socket` instead of a local collector.
+    Attach { socket: PathBuf },
+}
+
 /// Main application struct.
 ///
 /// Owns all runtime resources: terminal, state, data collectors, AI channels.
 pub struct App {
     state: AppState,
     config: Config,
+    /// Command-line options re-applied over config.toml on SIGHUP.
+    cli_overrides: CliOverrides,
     collector: SystemCollector,
     detector: AlertDetector,
+    /// Firing → resolved lifecycle and acknowledgements.
+    alert_tracker: AlertTracker,
+    /// Silences and maintenance windows.
+    suppressor: Suppressor,
     claude_client: Option<ClaudeClient>,
     has_key: bool,
 
@@ -125,12 +158,38 @@ pub struct App {
     // Thermal monitoring (LHM)
     thermal_rx: mpsc::UnboundedReceiver<Option<crate::thermal::ThermalSnapshot>>,
     thermal_reload: Arc<tokio::sync::Notify>,
+    /// Thermal mitigation ladder stepped by the shutdown state machine.
+    mitigator: Mitigator,
+    /// What auto-shutdown runs, in order.
+    shutdown_steps: Vec<ShutdownStep>,
+    /// Report shutdown actions instead of running them.
+    shutdown_dry_run: bool,
+    shutdown_tx: mpsc::UnboundedSender<ShutdownProgress>,
+    shutdown_rx: mpsc::UnboundedReceiver<ShutdownProgress>,
 
     // Email notifications
     email_notifier: Option<EmailNotifier>,
 
     // Telegram notifications
     telegram_notifier: Option<TelegramNotifier>,
+    /// Bot command long-poll (when `telegram_commands` is on).
+    bot_listener: Option<BotListener>,
+    /// `/kill` requests waiting for their confirmation button.
+    kill_confirmations: KillConfirmations,
+
+    // Webhook notifications (one per configured endpoint)
+    webhook_notifiers: Vec<WebhookNotifier>,
+    chat_notifiers: Vec<ChatNotifier>,
+    /// Escalation chains across all notifier types.
+    escalator: Escalator,
+
+    // Daemon / attach IPC
+    /// Socket server (headless mode only).
+    ipc_server: Option<IpcServer>,
+    /// Connection to the daemon (attach mode; `None` once disconnected).
+    remote: Option<IpcClient>,
+    /// Whether this instance renders a daemon's data instead of collecting.
+    attached: bool,
 
     // Plugin system
     plugins: PluginRegistry,
@@ -157,16 +216,26 @@ impl App {
     ///
     /// This performs auth discovery, theme resolution, Docker setup,
     /// and optional Prometheus server startup.
+    ///
+    /// In headless mode, anything that touches the terminal (CJK probe)
+    /// or only feeds the TUI (AI auth discovery) is skipped. In attach mode,
+    /// collection, thermal polling, notifications and auto-shutdown are left
+    /// to the daemon.
     pub async fn new(
         config: &Config,
+        cli_overrides: CliOverrides,
         no_ai: bool,
         prometheus_addr: Option<&str>,
+        mode: RunMode,
     ) -> Result<Self> {
+        let headless = matches!(mode, RunMode::Headless { .. });
+        let attached = matches!(mode, RunMode::Attach { .. });
+
         let collector = SystemCollector::new();
         let detector = AlertDetector::new(config.clone());
 
         // Auto-discover auth
-        let (auth, has_key) = if no_ai {
+        let (auth, has_key) = if no_ai || headless {
             (None, false)
         } else {
             let a = ClaudeClient::discover_auth().await;
@@ -182,37 +251,58 @@ impl App {
             .unwrap_or_default();
 
         // Detect CJK font support before entering alternate screen
-        let cjk_supported = crate::utils::detect_cjk_support();
+        let cjk_supported = !headless && crate::utils::detect_cjk_support();
 
         // Load .env for SMTP/shutdown credentials (optional, never committed)
         let env_path = crate::constants::env_file_path();
         let _ = dotenvy::from_path(&env_path);
 
         // Create shutdown manager (double-gated: config + .env)
+        // When attached, the daemon owns the shutdown state machine.
+        let mitigator = Mitigator::from_config(&config.thermal);
         let shutdown_manager = ShutdownManager::new(
-            config.thermal.auto_shutdown_enabled,
-            config.thermal.emergency_threshold,
-            config.thermal.critical_threshold,
+            config.thermal.auto_shutdown_enabled && !attached,
             config.thermal.sustained_seconds,
             SHUTDOWN_GRACE_PERIOD_SECS,
             config.thermal.shutdown_schedule_start,
             config.thermal.shutdown_schedule_end,
-        );
+        )
+        .with_mitigation(mitigator.hold_secs());
 
         // Initialize email notifier (requires .env SMTP credentials)
-        let email_notifier = if config.notifications.email_enabled {
+        let email_notifier = if config.notifications.email_enabled && !attached {
             EmailNotifier::from_env()
         } else {
             None
         };
 
         // Initialize Telegram notifier (requires bot token + chat ID in config)
-        let telegram_notifier = if config.notifications.telegram_enabled {
+        let telegram_notifier = if config.notifications.telegram_enabled && !attached {
             TelegramNotifier::from_config(&config.notifications)
         } else {
             None
         };
 
+        let bot_listener = if config.notifications.telegram_commands {
+            telegram_notifier.as_ref().map(|tg| {
+                BotListener::spawn(tg.api_base(), tg.bot_token(), tg.chat_id())
+            })
+        } else {
+            None
+        };
+
+        let webhook_notifiers = if attached {
+            Vec::new()
+        } else {
+            WebhookNotifier::all_from_config(&config.notifications)
+        };
+
+        let chat_notifiers = if attached {
+            Vec::new()
+        } else {
+            ChatNotifier::all_from_config(&config.notifications)
+        };
+
         // Determine glyph mode: config override or auto-detect
         let glyphs = match GlyphMode::from_config(&config.unicode_mode) {
             Some(mode) => Glyphs::new(mode),
@@ -227,6 +317,12 @@ impl App {
         if let Some(method) = &auth_display {
             state.ai_auth_method = method.clone();
         }
+        state.thermal_thresholds = ThermalThresholds::from_config(&config.thermal);
+        // Fan curves (double-gated like auto-shutdown; the daemon owns them when attached)
+        state.fan_controller = FanController::new(
+            config.thermal.fan_control_enabled && !attached,
+            &config.thermal.fan_curves,
+        );
 
         // AI channels
         let (ai_tx, ai_rx) = mpsc::unbounded_channel::<AiEvent>();
@@ -252,20 +348,19 @@ impl App {
             });
         }
 
-        // Thermal monitoring (LHM HTTP polling)
+        // Thermal monitoring (sysfs and/or LHM HTTP polling)
+        let (shutdown_tx, shutdown_rx) = mpsc::unbounded_channel();
         let (thermal_tx, thermal_rx) = mpsc::unbounded_channel();
         let thermal_reload = Arc::new(tokio::sync::Notify::new());
-        {
+        if !attached {
             let tx = thermal_tx;
             let notify = Arc::clone(&thermal_reload);
-            let lhm_url = crate::thermal::resolve_lhm_url(&config.thermal.lhm_url);
-            let lhm_auth = crate::thermal::LhmAuth::from_config_or_env(&config.thermal);
+            let source = crate::thermal::source::from_config(&config.thermal);
             let poll_secs = config.thermal.poll_interval_secs;
-            eprintln!("Thermal: polling {} (auth: {})", lhm_url, if lhm_auth.is_some() { "yes" } else { "no" });
+            eprintln!("Thermal: polling {}", source.describe());
             tokio::spawn(async move {
-                let client = LhmClient::new(&lhm_url, lhm_auth);
                 loop {
-                    let snapshot = client.poll().await;
+                    let snapshot = source.poll().await;
                     if tx.send(snapshot).is_err() {
                         break;
                     }
@@ -311,6 +406,38 @@ impl App {
             }
         };
 
+        // Escalation progress survives restarts (the daemon owns it when attached)
+        let mut escalator = Escalator::new(&config.notifications);
+        if let (false, Some(store)) = (attached, event_store.as_ref()) {
+            let rows = store.escalations().unwrap_or_default();
+            escalator.restore(&rows, chrono::Local::now().timestamp_millis());
+        }
+
+        // Daemon socket server / attach client
+        let ipc_server = match mode {
+            RunMode::Headless { ref socket } => {
+                let server = IpcServer::bind(socket).map_err(|e| {
+                    anyhow::anyhow!("could not bind attach socket {}: {}", socket.display(), e)
+                })?;
+                eprintln!("Attach socket: {}", socket.display());
+                Some(server)
+            }
+            _ => None,
+        };
+        let remote = match mode {
+            RunMode::Attach { ref socket } => {
+                let client = IpcClient::connect(socket).await.map_err(|e| {
+                    anyhow::anyhow!(
+                        "no Sentinel daemon at {} ({}); start one with `sentinel daemon`",
+                        socket.display(),
+                        e
+                    )
+                })?;
+                Some(client)
+            }
+            _ => None,
+        };
+
         // ── Plugin system ─────────────────────────────────────────
         let (plugin_ai_tx, plugin_ai_rx) = mpsc::unbounded_channel::<AiEvent>();
 
@@ -350,8 +477,11 @@ impl App {
         Ok(Self {
             state,
             config: config.clone(),
+            cli_overrides,
             collector,
             detector,
+            alert_tracker: AlertTracker::new(),
+            suppressor: Suppressor::new(config),
             claude_client,
             has_key,
             ai_tx,
@@ -366,8 +496,21 @@ impl App {
             net_scan_interval: 10, // scan network sockets every ~10 ticks
             thermal_rx,
             thermal_reload,
+            mitigator,
+            shutdown_steps: ShutdownStep::all_from_config(&config.thermal.shutdown_actions),
+            shutdown_dry_run: crate::thermal::shutdown::dry_run_enabled(config.thermal.shutdown_dry_run),
+            shutdown_tx,
+            shutdown_rx,
             email_notifier,
             telegram_notifier,
+            bot_listener,
+            kill_confirmations: KillConfirmations::new(),
+            webhook_notifiers,
+            chat_notifiers,
+            escalator,
+            ipc_server,
+            remote,
+            attached,
             plugins,
             plugin_ai_tx,
             plugin_ai_rx,
@@ -393,9 +536,13 @@ impl App {
         terminal.clear()?;
 
         // Initial data collection
-        let (system, processes) = self.collector.collect();
-        let alerts_vec = self.detector.analyze(&system, &processes);
-        self.state.update(system, processes, alerts_vec);
+        if self.attached {
+            self.wait_for_first_snapshot().await;
+        } else {
+            let (system, processes) = self.collector.collect();
+            let alerts_vec = self.detector.analyze(&system, &processes);
+            self.state.update(system, processes, alerts_vec);
+        }
 
         // Main loop.
         //
@@ -436,6 +583,8 @@ impl App {
             self.drain_insight_events();
             self.drain_docker_events();
             self.drain_thermal_events();
+            self.drain_shutdown_progress();
+            self.drain_bot_updates();
             self.drain_command_ai_events();
             self.drain_plugin_ai_events();
 
@@ -443,7 +592,12 @@ impl App {
             self.plugins.tick_all();
 
             // ── 3. Tick-based logic ──────────────────────────────
-            self.tick_refresh();
+            if self.attached {
+                self.tick_remote();
+            } else {
+                self.tick_refresh();
+            }
+            self.tick_replay();
             self.tick_auto_analysis();
             self.tick_shutdown();
 
@@ -451,6 +605,11 @@ impl App {
             // runs inline on the next tick after popup opens.
             if self.state.detail_pending_pid.is_some() {
                 self.state.load_process_detail_extra();
+                if let (Some(ref store), Some(ref mut detail)) =
+                    (&self.event_store, &mut self.state.process_detail)
+                {
+                    detail.sockets = store.query_process_sockets(detail.pid).unwrap_or_default();
+                }
             }
 
             // ── 4. Render ────────────────────────────────────────
@@ -458,6 +617,8 @@ impl App {
         }
 
         // Cleanup
+        self.restore_mitigations("sentinel stopped");
+        self.mitigator.settle().await;
         disable_raw_mode()?;
         execute!(
             terminal.backend_mut(),
@@ -470,6 +631,85 @@ impl App {
         Ok(())
     }
 
+    /// Run without a terminal (daemon mode). Returns on SIGTERM or SIGINT.
+    ///
+    /// Drives the same refresh / security / thermal ticks as [`App::run`],
+    /// so the event store, alert detection, notifications and Prometheus
+    /// keep working. SIGHUP re-reads `config.toml`; SIGUSR1 aborts a pending
+    /// thermal shutdown (the headless Ctrl+X). Status-bar messages are
+    /// written to stderr instead of being rendered.
+    pub async fn run_headless(&mut self) -> Result<()> {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        let mut sigterm = signal(SignalKind::terminate())?;
+        let mut sigint = signal(SignalKind::interrupt())?;
+        let mut sighup = signal(SignalKind::hangup())?;
+        let mut sigusr1 = signal(SignalKind::user_defined1())?;
+
+        // Same cadence as the TUI poll loop so tick-based intervals
+        // (refresh, security, socket scans) keep their meaning.
+        let mut ticker = tokio::time::interval(Duration::from_millis(EVENT_POLL_MS));
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        log_headless(&format!(
+            "Sentinel v{} running headless (pid {})",
+            env!("CARGO_PKG_VERSION"),
+            std::process::id()
+        ));
+
+        let mut last_status: Option<String> = None;
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {}
+                _ = sigterm.recv() => {
+                    log_headless("SIGTERM received, shutting down");
+                    break;
+                }
+                _ = sigint.recv() => {
+                    log_headless("SIGINT received, shutting down");
+                    break;
+                }
+                _ = sighup.recv() => {
+                    log_headless("SIGHUP received, reloading config");
+                    let mut config = Config::load();
+                    self.cli_overrides.apply(&mut config);
+                    self.reload_config(config);
+                    self.thermal_reload.notify_one();
+                }
+                _ = sigusr1.recv() => {
+                    if self.state.shutdown_manager.abort() {
+                        self.state.set_status("Thermal shutdown ABORTED".to_string());
+                        self.restore_mitigations("shutdown aborted");
+                    }
+                }
+            }
+
+            self.drain_docker_events();
+            self.drain_thermal_events();
+            self.drain_shutdown_progress();
+            self.drain_bot_updates();
+            self.drain_ipc_requests();
+            self.plugins.tick_all();
+
+            self.tick_refresh();
+            self.tick_shutdown();
+
+            let status = self.state.status_message.as_ref().map(|(msg, _)| msg.clone());
+            if let Some(ref msg) = status {
+                if last_status.as_ref() != Some(msg) {
+                    log_headless(msg);
+                }
+            }
+            last_status = status;
+        }
+
+        self.sync_plugin_favorites();
+        self.restore_mitigations("sentinel stopped");
+        self.mitigator.settle().await;
+        log_headless(t!("app.stopped").trim());
+        Ok(())
+    }
+
     // ── Channel draining ─────────────────────────────────────────
 
     fn drain_ai_events(&mut self) {
@@ -521,15 +761,296 @@ impl App {
 
     fn drain_thermal_events(&mut self) {
         while let Ok(snapshot) = self.thermal_rx.try_recv() {
-            if let Some(ref snap) = snapshot {
-                // Push CPU package temp (or max CPU temp) to history ring buffer
-                let temp = snap.cpu_package.unwrap_or(snap.max_cpu_temp);
-                if self.state.temp_history.len() >= THERMAL_HISTORY_CAPACITY {
-                    self.state.temp_history.pop_front();
+            if let (Some(ref mut store), Some(ref snap)) = (&mut self.event_store, &snapshot) {
+                let _ = store.insert_thermal_snapshot(snap);
+            }
+            self.apply_thermal_snapshot(snapshot);
+        }
+    }
+
+    fn apply_thermal_snapshot(&mut self, snapshot: Option<crate::thermal::ThermalSnapshot>) {
+        if let Some(ref snap) = snapshot {
+            // Push CPU package temp (or max CPU temp) to history ring buffer
+            let temp = snap.cpu_package.unwrap_or(snap.max_cpu_temp);
+            if self.state.temp_history.len() >= THERMAL_HISTORY_CAPACITY {
+                self.state.temp_history.pop_front();
+            }
+            self.state.temp_history.push_back(temp);
+        }
+        let messages = self.state.fan_controller.tick(snapshot.as_ref());
+        if !messages.is_empty() {
+            self.state.set_status(messages.join("; "));
+        }
+        self.state.thermal = snapshot;
+    }
+
+    /// Answer Telegram bot commands and confirmation button presses.
+    fn drain_bot_updates(&mut self) {
+        let Some(ref mut listener) = self.bot_listener else {
+            return;
+        };
+        let updates: Vec<_> = std::iter::from_fn(|| listener.try_recv()).collect();
+        for update in updates {
+            match update {
+                BotUpdate::Command(command) => {
+                    let (text, keyboard) = self.bot_reply(command);
+                    if let Some(ref tg) = self.telegram_notifier {
+                        tg.reply(text, keyboard);
+                    }
+                }
+                BotUpdate::Callback {
+                    id,
+                    message_id,
+                    action,
+                } => {
+                    let outcome = match action {
+                        CallbackAction::ConfirmKill { pid, nonce } => self.bot_confirm_kill(pid, nonce),
+                        CallbackAction::Cancel => "Cancelled.".to_string(),
+                    };
+                    if let Some(ref tg) = self.telegram_notifier {
+                        tg.answer_callback(&id, &outcome);
+                        tg.edit_message(message_id, telegram_bot::escape_html(&outcome));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Build the reply (HTML text, optional inline keyboard) to a bot command.
+    ///
+    /// Reports come from the same `DiagnosticEngine` calls as the command
+    /// palette, so both show the same findings.
+    fn bot_reply(&mut self, command: BotCommand) -> (String, Option<serde_json::Value>) {
+        let hostname = gethostname();
+        let text = match command {
+            BotCommand::Status => match self.state.system {
+                Some(ref system) => {
+                    let report = DiagnosticEngine::resource_contention(
+                        system,
+                        &self.state.processes,
+                        &self.config.ignored_zombie_parents,
+                    );
+                    let mut summary = format!(
+                        "CPU {:.0}% | RAM {:.0}% | load {:.2} {:.2} {:.2}\n\
+                         {} processes | up {}h {}m | security {}/100",
+                        system.global_cpu_usage,
+                        system.memory_percent(),
+                        system.load_avg_1,
+                        system.load_avg_5,
+                        system.load_avg_15,
+                        self.state.processes.len(),
+                        system.uptime / 3600,
+                        (system.uptime % 3600) / 60,
+                        self.state.security.score,
+                    );
+                    if let Some(ref thermal) = self.state.thermal {
+                        summary.push_str(&format!(" | max {:.0}\u{b0}C", thermal.max_temp));
+                    }
+                    summary.push_str(&format!(
+                        "\n{} firing alert(s)\n\n{}",
+                        self.state.active_alerts.len(),
+                        report.to_text()
+                    ));
+                    format!(
+                        "<b>{}</b>\n{}",
+                        telegram_bot::escape_html(&hostname),
+                        telegram_bot::pre(&summary)
+                    )
+                }
+                None => "No system data available yet.".to_string(),
+            },
+            BotCommand::Top => {
+                let mut procs: Vec<&ProcessInfo> = self.state.processes.iter().collect();
+                procs.sort_by(|a, b| {
+                    b.cpu_usage
+                        .partial_cmp(&a.cpu_usage)
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                });
+                let mut lines = vec![format!("{:>7} {:>6} {:>10}  NAME", "PID", "CPU%", "MEM")];
+                for p in procs.iter().take(TELEGRAM_TOP_COUNT) {
+                    lines.push(format!(
+                        "{:>7} {:>6.1} {:>10}  {}",
+                        p.pid,
+                        p.cpu_usage,
+                        p.memory_display(),
+                        p.name
+                    ));
+                }
+                telegram_bot::pre(&lines.join("\n"))
+            }
+            BotCommand::Alerts => telegram_bot::pre(&self.firing_alerts_text()),
+            BotCommand::Timeline(minutes) => match self.event_store {
+                Some(ref store) => {
+                    telegram_bot::pre(&DiagnosticEngine::timeline_report(store, minutes).to_text())
+                }
+                None => "Event store not available.".to_string(),
+            },
+            BotCommand::Port(port) => match self.event_store {
+                Some(ref store) => {
+                    telegram_bot::pre(&DiagnosticEngine::port_diagnosis(store, port).to_text())
+                }
+                None => "Event store not available.".to_string(),
+            },
+            BotCommand::Ack(target) => {
+                let keys = match_ack_target(&self.state.active_alerts, &target);
+                if keys.is_empty() {
+                    format!(
+                        "No firing, unacknowledged alert matches '{}'.",
+                        telegram_bot::escape_html(&target)
+                    )
+                } else {
+                    let count = self.acknowledge_alerts(&keys);
+                    let mut lines = vec![format!("Acknowledged {} alert(s)", count)];
+                    lines.extend(keys.iter().map(|k| format!("  {}", k)));
+                    telegram_bot::pre(&lines.join("\n"))
                 }
-                self.state.temp_history.push_back(temp);
             }
-            self.state.thermal = snapshot;
+            BotCommand::Kill(pid) => {
+                if pid <= 1 || pid == std::process::id() {
+                    return (format!("Refusing to kill PID {}.", pid), None);
+                }
+                let Some(process) = self.state.processes.iter().find(|p| p.pid == pid) else {
+                    return (format!("No process with PID {}.", pid), None);
+                };
+                let text = format!(
+                    "Kill PID {} (<code>{}</code>, {:.1}% CPU, {})?\n\
+                     Confirm within {}s.",
+                    pid,
+                    telegram_bot::escape_html(&process.name),
+                    process.cpu_usage,
+                    process.memory_display(),
+                    TELEGRAM_KILL_CONFIRM_SECS,
+                );
+                let nonce = self.kill_confirmations.request(pid, &process.name);
+                return (text, Some(telegram_bot::kill_keyboard(pid, nonce)));
+            }
+            BotCommand::Help => "<b>Sentinel bot</b>\n\
+                 /status - system summary\n\
+                 /top - top processes by CPU\n\
+                 /alerts - firing alerts\n\
+                 /timeline [minutes] - what happened recently\n\
+                 /port &lt;number&gt; - who is using a port\n\
+                 /ack &lt;all|pid|name&gt; - acknowledge alerts\n\
+                 /kill &lt;pid&gt; - SIGTERM a process (asks first)"
+                .to_string(),
+            BotCommand::Usage(usage) => {
+                format!("Usage: {}", telegram_bot::escape_html(usage))
+            }
+            BotCommand::Unknown(name) => format!(
+                "Unknown command /{}. Try /help.",
+                telegram_bot::escape_html(&name)
+            ),
+        };
+        (text, None)
+    }
+
+    /// Execute a confirmed `/kill`. Re-checks that the PID still belongs to
+    /// the process named in the confirmation (guards against PID reuse).
+    fn bot_confirm_kill(&mut self, pid: u32, nonce: u64) -> String {
+        let Some(name) = self.kill_confirmations.confirm(pid, nonce) else {
+            return "Confirmation expired. Send /kill again.".to_string();
+        };
+        match self.state.processes.iter().find(|p| p.pid == pid) {
+            Some(p) if p.name == name => {}
+            _ => return format!("PID {} ({}) is gone; nothing killed.", pid, name),
+        }
+        let status = if unsafe { libc::kill(pid as i32, libc::SIGTERM) } == 0 {
+            format!("Sent SIGTERM to PID {} ({})", pid, name)
+        } else {
+            let err = std::io::Error::last_os_error();
+            format!("Failed to send SIGTERM to PID {} ({}): {}", pid, name, err)
+        };
+        self.state.set_status(format!("Telegram: {}", status));
+        status
+    }
+
+    /// Move `pids` into a Sentinel-managed limit group; (ok, status message).
+    fn limit_processes(&self, pids: &[u32], limits: &ResourceLimits) -> (bool, String) {
+        let Some(&pid) = pids.first() else {
+            return (false, "No process to limit".to_string());
+        };
+        let name = self
+            .state
+            .processes
+            .iter()
+            .find(|p| p.pid == pid)
+            .map(|p| p.name.clone())
+            .unwrap_or_else(|| "unknown".to_string());
+        let children = match pids.len() - 1 {
+            0 => String::new(),
+            n => format!(" + {} descendants", n),
+        };
+        match CgroupLimiter::new().apply(pids, limits) {
+            Ok(group) if limits.is_unlimited() => (
+                true,
+                format!("Lifted limits on PID {} ({}){} in {}", pid, name, children, group),
+            ),
+            Ok(group) => (
+                true,
+                format!(
+                    "Limited PID {} ({}){} to {} in {}",
+                    pid,
+                    name,
+                    children,
+                    limits.describe(),
+                    group
+                ),
+            ),
+            Err(e) => (false, format!("Limit failed for PID {} ({}): {}", pid, name, e)),
+        }
+    }
+
+    /// Daemon mode: execute kill/renice/limit requests from attached clients.
+    fn drain_ipc_requests(&mut self) {
+        let Some(ref mut server) = self.ipc_server else {
+            return;
+        };
+        let requests: Vec<_> = std::iter::from_fn(|| server.try_recv_request()).collect();
+        for pending in requests {
+            let (ok, message) = match pending.request {
+                ClientRequest::Signal { pid, signal } => {
+                    let name = self
+                        .state
+                        .processes
+                        .iter()
+                        .find(|p| p.pid == pid)
+                        .map(|p| p.name.clone())
+                        .unwrap_or_else(|| "unknown".to_string());
+                    let sig_name = ui::SIGNAL_LIST
+                        .iter()
+                        .find(|(num, _, _)| *num == signal)
+                        .map(|(_, name, _)| name.to_string())
+                        .unwrap_or_else(|| format!("signal {}", signal));
+                    if unsafe { libc::kill(pid as i32, signal) } == 0 {
+                        (true, format!("Sent {} to PID {} ({})", sig_name, pid, name))
+                    } else {
+                        let err = std::io::Error::last_os_error();
+                        (
+                            false,
+                            format!("Failed to send {} to PID {} ({}): {}", sig_name, pid, name, err),
+                        )
+                    }
+                }
+                ClientRequest::Renice { pid, nice } => {
+                    let nice = nice.clamp(NICE_MIN, NICE_MAX);
+                    if unsafe { libc::setpriority(libc::PRIO_PROCESS, pid, nice) } == 0 {
+                        (true, format!("Set nice {} for PID {}", nice, pid))
+                    } else {
+                        let err = std::io::Error::last_os_error();
+                        (false, format!("Renice failed for PID {}: {}", pid, err))
+                    }
+                }
+                ClientRequest::Limit {
+                    ref pids,
+                    ref limits,
+                } => self.limit_processes(pids, limits),
+                ClientRequest::Ack { ref keys } => {
+                    let count = self.acknowledge_alerts(keys);
+                    (true, format!("Acknowledged {} alert(s)", count))
+                }
+            };
+            log_headless(&format!("attach client: {}", message));
+            pending.respond(ok, message);
         }
     }
 
@@ -748,10 +1269,14 @@ impl App {
 
     /// Send SIGTERM to the currently selected process.
     fn send_sigterm(&mut self) {
-        let filtered = self.state.filtered_processes();
-        if let Some(proc) = filtered.get(self.state.selected_process) {
+        if let Some(proc) = self.state.selected_row_process() {
             let pid = proc.pid;
             let name = proc.name.clone();
+            let request = ClientRequest::Signal { pid, signal: libc::SIGTERM };
+            if let Some(status) = self.forward_to_daemon(request) {
+                self.state.set_status(status);
+                return;
+            }
             let sys = self.collector.system();
             if let Some(process) = sys.process(Pid::from_u32(pid)) {
                 if process.kill_with(Signal::Term).unwrap_or(false) {
@@ -767,10 +1292,14 @@ impl App {
 
     /// Send SIGKILL to the currently selected process.
     fn send_sigkill(&mut self) {
-        let filtered = self.state.filtered_processes();
-        if let Some(proc) = filtered.get(self.state.selected_process) {
+        if let Some(proc) = self.state.selected_row_process() {
             let pid = proc.pid;
             let name = proc.name.clone();
+            let request = ClientRequest::Signal { pid, signal: libc::SIGKILL };
+            if let Some(status) = self.forward_to_daemon(request) {
+                self.state.set_status(status);
+                return;
+            }
             let sys = self.collector.system();
             if let Some(process) = sys.process(Pid::from_u32(pid)) {
                 if process.kill() {
@@ -826,12 +1355,7 @@ impl App {
                     }
                 } else if self.state.active_tab == Tab::Processes && y >= PROCESS_TABLE_ROW_START {
                     let row_index = (y - PROCESS_TABLE_ROW_START) as usize;
-                    let max = if self.state.tree_view {
-                        self.state.tree_processes().len()
-                    } else {
-                        self.state.filtered_processes().len()
-                    };
-                    if row_index < max {
+                    if row_index < self.state.process_row_count() {
                         self.state.selected_process = row_index;
                     }
                 }
@@ -841,7 +1365,12 @@ impl App {
                     && mouse.row >= PROCESS_TABLE_ROW_START
                 {
                     let row_index = (mouse.row - PROCESS_TABLE_ROW_START) as usize;
-                    let proc_clone = {
+                    let proc_clone = if self.state.cgroup_view {
+                        match self.state.cgroup_rows().get(row_index) {
+                            Some((_, CgroupRow::Process(p))) => Some((*p).clone()),
+                            _ => None,
+                        }
+                    } else {
                         let filtered = self.state.filtered_processes();
                         if row_index < filtered.len() {
                             Some((*filtered[row_index]).clone())
@@ -867,6 +1396,7 @@ impl App {
         if key.code == KeyCode::Char('x') && key.modifiers.contains(KeyModifiers::CONTROL) {
             if self.state.shutdown_manager.abort() {
                 self.state.set_status("Thermal shutdown ABORTED".to_string());
+                self.restore_mitigations("shutdown aborted");
             }
             return false;
         }
@@ -901,6 +1431,16 @@ impl App {
             return self.handle_key_renice_dialog(key);
         }
 
+        // Limit dialog mode
+        if self.state.show_limit_dialog {
+            return self.handle_key_limit_dialog(key);
+        }
+
+        // Fan curve editor mode
+        if self.state.fan_editor.is_some() {
+            return self.handle_key_fan_editor(key);
+        }
+
         // AI typing mode
         if self.ai_typing {
             return self.handle_key_ai_typing(key);
@@ -935,6 +1475,16 @@ impl App {
             KeyCode::PageDown => {
                 self.state.detail_scroll += DETAIL_PAGE_STEP;
             }
+            KeyCode::Right | KeyCode::Tab | KeyCode::Char('l') => {
+                self.state.set_detail_tab(self.state.detail_tab.next());
+            }
+            KeyCode::Left | KeyCode::BackTab | KeyCode::Char('h') => {
+                self.state.set_detail_tab(self.state.detail_tab.prev());
+            }
+            KeyCode::Char(c @ '1'..='8') => {
+                let i = c as usize - '1' as usize;
+                self.state.set_detail_tab(DetailTab::ALL[i]);
+            }
             _ => {}
         }
         false
@@ -991,8 +1541,10 @@ impl App {
                     let (sig_num, sig_name, _) =
                         ui::SIGNAL_LIST[self.state.signal_picker_selected];
                     let name = self.state.signal_picker_name.clone();
-                    let result = unsafe { libc::kill(pid as i32, sig_num) };
-                    if result == 0 {
+                    let request = ClientRequest::Signal { pid, signal: sig_num };
+                    if let Some(status) = self.forward_to_daemon(request) {
+                        self.state.set_status(status);
+                    } else if unsafe { libc::kill(pid as i32, sig_num) } == 0 {
                         self.state
                             .set_status(format!("Sent {} to PID {} ({})", sig_name, pid, name));
                     } else {
@@ -1030,9 +1582,11 @@ impl App {
                 if let Some(pid) = self.state.renice_pid {
                     let name = self.state.renice_name.clone();
                     let nice = self.state.renice_value;
-                    let result =
-                        unsafe { libc::setpriority(libc::PRIO_PROCESS, pid, nice) };
-                    if result == 0 {
+                    if let Some(status) =
+                        self.forward_to_daemon(ClientRequest::Renice { pid, nice })
+                    {
+                        self.state.set_status(status);
+                    } else if unsafe { libc::setpriority(libc::PRIO_PROCESS, pid, nice) } == 0 {
                         self.state
                             .set_status(format!("Set nice {} for PID {} ({})", nice, pid, name));
                     } else {
@@ -1048,6 +1602,77 @@ impl App {
         false
     }
 
+    fn handle_key_limit_dialog(&mut self, key: crossterm::event::KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.state.close_limit_dialog();
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.state.limit_field = self.state.limit_field.prev();
+            }
+            KeyCode::Down | KeyCode::Char('j') | KeyCode::Tab => {
+                self.state.limit_field = self.state.limit_field.next();
+            }
+            KeyCode::Left | KeyCode::Char('h') => self.state.adjust_limit(false),
+            KeyCode::Right | KeyCode::Char('l') | KeyCode::Char(' ') => {
+                self.state.adjust_limit(true)
+            }
+            KeyCode::Backspace | KeyCode::Delete | KeyCode::Char('0') => {
+                self.state.clear_limit();
+            }
+            KeyCode::Enter => {
+                let pids = self.state.limit_targets();
+                let limits = self.state.limit_values.clone();
+                let status = match self.forward_to_daemon(ClientRequest::Limit {
+                    pids: pids.clone(),
+                    limits: limits.clone(),
+                }) {
+                    Some(status) => status,
+                    None => self.limit_processes(&pids, &limits).1,
+                };
+                self.state.set_status(status);
+                self.state.close_limit_dialog();
+            }
+            _ => {}
+        }
+        false
+    }
+
+    fn handle_key_fan_editor(&mut self, key: crossterm::event::KeyEvent) -> bool {
+        let Some(ref mut editor) = self.state.fan_editor else {
+            return false;
+        };
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.state.fan_editor = None;
+            }
+            KeyCode::Tab => editor.next_curve(),
+            KeyCode::Up | KeyCode::Char('k') => editor.select_point(-1),
+            KeyCode::Down | KeyCode::Char('j') => editor.select_point(1),
+            KeyCode::Left | KeyCode::Char('h') => editor.nudge_temp(-FAN_EDITOR_TEMP_STEP_C),
+            KeyCode::Right | KeyCode::Char('l') => editor.nudge_temp(FAN_EDITOR_TEMP_STEP_C),
+            KeyCode::Char('+') | KeyCode::Char('=') => editor.nudge_duty(FAN_EDITOR_DUTY_STEP_PCT),
+            KeyCode::Char('-') => editor.nudge_duty(-FAN_EDITOR_DUTY_STEP_PCT),
+            KeyCode::Char('a') => editor.add_point(),
+            KeyCode::Char('x') | KeyCode::Delete => editor.remove_point(),
+            KeyCode::Char('n') => editor.add_curve(),
+            KeyCode::Char('D') => editor.remove_curve(),
+            KeyCode::Char('s') => editor.cycle_sensor(),
+            KeyCode::Char('p') => editor.cycle_output(),
+            KeyCode::Enter => match editor.validate() {
+                Ok(()) => {
+                    let mut new_config = self.config.clone();
+                    new_config.thermal.fan_curves = editor.curves.clone();
+                    self.state.fan_editor = None;
+                    self.apply_config_change(new_config);
+                }
+                Err(e) => self.state.set_status(format!("Fan curves not saved: {}", e)),
+            },
+            _ => {}
+        }
+        false
+    }
+
     fn handle_key_ai_typing(&mut self, key: crossterm::event::KeyEvent) -> bool {
         match key.code {
             KeyCode::Esc => {
@@ -1145,6 +1770,10 @@ impl App {
             }
         }
 
+        if self.state.active_tab != Tab::AskAi && self.handle_key_replay(key) {
+            return false;
+        }
+
         match key.code {
             KeyCode::Char('q') | KeyCode::Char('Q') => {
                 if self.state.active_tab != Tab::AskAi {
@@ -1233,6 +1862,12 @@ impl App {
                 self.thermal_reload.notify_one();
                 self.state.set_status("Thermal data reload requested".into());
             }
+            KeyCode::Char('f') if self.state.active_tab == Tab::Thermal => {
+                self.state.fan_editor = Some(FanCurveEditor::new(
+                    &self.config.thermal.fan_curves,
+                    self.state.fan_controller.outputs(),
+                ));
+            }
             KeyCode::Char('r') if self.state.active_tab == Tab::Security => {
                 self.tick_security();
                 self.state.set_status("Security data refreshed".into());
@@ -1249,9 +1884,9 @@ impl App {
                 self.state.ai_scroll = 0;
             }
             KeyCode::End => {
-                let max = self.state.filtered_processes().len().saturating_sub(1);
+                let max = self.state.process_row_count().saturating_sub(1);
                 self.state.selected_process = max;
-                self.state.alert_scroll = self.state.alerts.len().saturating_sub(1);
+                self.state.alert_scroll = self.state.shown_alerts().len().saturating_sub(1);
             }
 
             // Sort
@@ -1287,6 +1922,11 @@ impl App {
                 self.state.open_renice_dialog();
             }
 
+            // cgroup limit dialog
+            KeyCode::Char('C') if self.state.active_tab == Tab::Processes => {
+                self.state.open_limit_dialog();
+            }
+
             // Zoom history charts (Dashboard tab)
             KeyCode::Char('+') | KeyCode::Char('=')
                 if self.state.active_tab == Tab::Dashboard =>
@@ -1335,14 +1975,25 @@ impl App {
             // Tree view toggle (Processes tab only)
             KeyCode::Char('t') if self.state.active_tab == Tab::Processes => {
                 self.state.tree_view = !self.state.tree_view;
+                self.state.cgroup_view = false;
                 self.state.selected_process = 0;
             }
 
+            // cgroup view toggle (Processes tab only)
+            KeyCode::Char('c') if self.state.active_tab == Tab::Processes => {
+                self.state.toggle_cgroup_view();
+            }
+
             // Ask AI about selected process (Processes tab only)
             KeyCode::Char('a') if self.state.active_tab == Tab::Processes => {
                 self.ask_ai_about_selected_process();
             }
 
+            // Acknowledge the selected alert (Alerts tab only)
+            KeyCode::Char('a') if self.state.active_tab == Tab::Alerts => {
+                self.ack_selected_alert();
+            }
+
             // Filter
             KeyCode::Char('/') => {
                 if self.state.active_tab != Tab::AskAi {
@@ -1354,16 +2005,13 @@ impl App {
                 }
             }
 
-            // Process detail popup
+            // Process detail popup (expand/collapse on a cgroup row)
             KeyCode::Enter if self.state.active_tab == Tab::Processes => {
-                let proc_clone = {
-                    let filtered = self.state.filtered_processes();
-                    filtered
-                        .get(self.state.selected_process)
-                        .map(|p| (*p).clone())
-                };
+                let proc_clone = self.state.selected_row_process().cloned();
                 if let Some(proc) = proc_clone {
                     self.state.open_process_detail(&proc);
+                } else if self.state.cgroup_view {
+                    self.state.toggle_selected_cgroup();
                 }
             }
 
@@ -1616,57 +2264,80 @@ impl App {
             cr.actions[sel].clone()
         };
 
-        let status = match &action {
-            SuggestedAction::KillProcess { pid, name, signal } => {
-                let sig_num = match *signal {
-                    "SIGTERM" => libc::SIGTERM,
-                    "SIGKILL" => libc::SIGKILL,
-                    "SIGHUP" => libc::SIGHUP,
-                    _ => libc::SIGTERM,
-                };
-                let result = unsafe { libc::kill(*pid as i32, sig_num) };
-                if result == 0 {
-                    format!("Sent {} to PID {} ({})", signal, pid, name)
-                } else {
-                    let err = std::io::Error::last_os_error();
-                    format!("Failed to send {} to PID {} ({}): {}", signal, pid, name, err)
+        // Process actions go to the daemon when attached.
+        let request = match &action {
+            SuggestedAction::KillProcess { pid, signal, .. } => Some(ClientRequest::Signal {
+                pid: *pid,
+                signal: signal_number(signal),
+            }),
+            SuggestedAction::ReniceProcess { pid, nice, .. } => {
+                Some(ClientRequest::Renice { pid: *pid, nice: *nice })
+            }
+            SuggestedAction::LimitProcess { pid, limits, .. } => Some(ClientRequest::Limit {
+                pids: vec![*pid],
+                limits: limits.clone(),
+            }),
+            SuggestedAction::FreePort { pid, .. } => Some(ClientRequest::Signal {
+                pid: *pid,
+                signal: libc::SIGTERM,
+            }),
+            _ => None,
+        };
+        let forwarded = request.and_then(|r| self.forward_to_daemon(r));
+
+        let status = if let Some(status) = forwarded {
+            status
+        } else {
+            match &action {
+                SuggestedAction::KillProcess { pid, name, signal } => {
+                    let sig_num = signal_number(signal);
+                    let result = unsafe { libc::kill(*pid as i32, sig_num) };
+                    if result == 0 {
+                        format!("Sent {} to PID {} ({})", signal, pid, name)
+                    } else {
+                        let err = std::io::Error::last_os_error();
+                        format!("Failed to send {} to PID {} ({}): {}", signal, pid, name, err)
+                    }
                 }
-            }
-            SuggestedAction::ReniceProcess { pid, name, nice } => {
-                let result =
-                    unsafe { libc::setpriority(libc::PRIO_PROCESS, *pid, *nice) };
-                if result == 0 {
-                    format!("Set nice {} for PID {} ({})", nice, pid, name)
-                } else {
-                    let err = std::io::Error::last_os_error();
-                    format!("Renice failed for PID {} ({}): {}", pid, name, err)
+                SuggestedAction::ReniceProcess { pid, name, nice } => {
+                    let result =
+                        unsafe { libc::setpriority(libc::PRIO_PROCESS, *pid, *nice) };
+                    if result == 0 {
+                        format!("Set nice {} for PID {} ({})", nice, pid, name)
+                    } else {
+                        let err = std::io::Error::last_os_error();
+                        format!("Renice failed for PID {} ({}): {}", pid, name, err)
+                    }
                 }
-            }
-            SuggestedAction::FreePort { port, pid, name } => {
-                let result = unsafe { libc::kill(*pid as i32, libc::SIGTERM) };
-                if result == 0 {
-                    format!("Sent SIGTERM to PID {} ({}) to free port {}", pid, name, port)
-                } else {
-                    let err = std::io::Error::last_os_error();
-                    format!("Failed to kill PID {} ({}): {}", pid, name, err)
-                }
-            }
-            SuggestedAction::CleanDirectory { path, size_bytes } => {
-                match std::fs::remove_dir_all(path) {
-                    Ok(_) => {
-                        // Recreate the directory so it exists but is empty
-                        let _ = std::fs::create_dir_all(path);
-                        format!(
-                            "Cleaned {} ({:.1} GB freed)",
-                            path,
-                            *size_bytes as f64 / (1024.0 * 1024.0 * 1024.0)
-                        )
+                SuggestedAction::LimitProcess { pid, limits, .. } => {
+                    self.limit_processes(&[*pid], limits).1
+                }
+                SuggestedAction::FreePort { port, pid, name } => {
+                    let result = unsafe { libc::kill(*pid as i32, libc::SIGTERM) };
+                    if result == 0 {
+                        format!("Sent SIGTERM to PID {} ({}) to free port {}", pid, name, port)
+                    } else {
+                        let err = std::io::Error::last_os_error();
+                        format!("Failed to kill PID {} ({}): {}", pid, name, err)
                     }
-                    Err(e) => format!("Failed to clean {}: {}", path, e),
                 }
-            }
-            SuggestedAction::Info(_) => {
-                action_label.clone()
+                SuggestedAction::CleanDirectory { path, size_bytes } => {
+                    match std::fs::remove_dir_all(path) {
+                        Ok(_) => {
+                            // Recreate the directory so it exists but is empty
+                            let _ = std::fs::create_dir_all(path);
+                            format!(
+                                "Cleaned {} ({:.1} GB freed)",
+                                path,
+                                *size_bytes as f64 / (1024.0 * 1024.0 * 1024.0)
+                            )
+                        }
+                        Err(e) => format!("Failed to clean {}: {}", path, e),
+                    }
+                }
+                SuggestedAction::Info(_) => {
+                    action_label.clone()
+                }
             }
         };
 
@@ -1745,6 +2416,48 @@ impl App {
                 }
             }
 
+            // Process snapshot diff between two points in time
+            "diff" | "compare" => {
+                let range = parse_time_range(
+                    parts.get(1).copied().unwrap_or("1h"),
+                    parts.get(2).copied().unwrap_or("now"),
+                    chrono::Local::now(),
+                );
+                match (range, &self.event_store) {
+                    (_, None) => {
+                        CommandResult::text_only("Event store not available.".to_string())
+                    }
+                    (Ok((from, to)), Some(store)) => {
+                        let report = DiagnosticEngine::process_diff(store, from, to);
+                        CommandResult::from_report(&report)
+                    }
+                    (Err(e), _) => CommandResult::text_only(format!(
+                        "{}\n\nUsage: diff [from] [to]\nExample: diff 2h now, diff 09:00 10:30",
+                        e
+                    )),
+                }
+            }
+
+            // Historical replay of the whole TUI
+            "replay" | "rewind" => match parts.get(1).copied() {
+                Some("off" | "live" | "stop") => {
+                    if self.state.stop_replay() {
+                        CommandResult::text_only("Back to live data.".to_string())
+                    } else {
+                        CommandResult::text_only("Not replaying.".to_string())
+                    }
+                }
+                arg => match parse_time_arg(arg.unwrap_or("1h"), chrono::Local::now())
+                    .and_then(|ts| self.seek_replay(ts))
+                {
+                    Ok(()) => CommandResult::text_only(self.replay_text()),
+                    Err(e) => CommandResult::text_only(format!(
+                        "{}\n\nUsage: replay [time|off]\nExample: replay 2h, replay 09:30",
+                        e
+                    )),
+                },
+            },
+
             // Anomaly scan
             "anomaly" | "anomalies" | "scan" => {
                 let minutes = parts.get(1).and_then(|s| s.parse().ok()).unwrap_or(30);
@@ -1756,6 +2469,19 @@ impl App {
                 }
             }
 
+            // Thermal history from the event store
+            "thermal-history" | "temp-history" => {
+                let minutes = parts.get(1).and_then(|s| s.parse().ok()).unwrap_or(60);
+                let sensor = parts.get(2..).map(|rest| rest.join(" ")).filter(|s| !s.is_empty());
+                if let Some(ref store) = self.event_store {
+                    let report =
+                        DiagnosticEngine::thermal_history(store, minutes, sensor.as_deref());
+                    CommandResult::from_report(&report)
+                } else {
+                    CommandResult::text_only("Event store not available.".to_string())
+                }
+            }
+
             // Disk analysis
             "disk" | "disks" | "storage" => {
                 if let Some(system) = &self.state.system {
@@ -1850,6 +2576,9 @@ impl App {
                                     "port_bind" => ">",
                                     "port_release" => "<",
                                     "alert" => "!",
+                                    "alert_resolved" => "v",
+                                    "alert_ack" => "a",
+                                    "alert_escalated" => ">",
                                     "cpu_spike" => "^",
                                     "memory_spike" => "~",
                                     "oom_kill" => "X",
@@ -1878,15 +2607,20 @@ impl App {
                                     "port_bind" => "Port bound",
                                     "port_release" => "Port released",
                                     "alert" => "Alert",
+                                    "alert_resolved" => "Alert resolved",
+                                    "alert_ack" => "Alert acknowledged",
+                                    "alert_escalated" => "Alert escalated",
                                     "cpu_spike" => "CPU spike",
                                     "memory_spike" => "Memory spike",
                                     "oom_kill" => "OOM Kill",
                                     other => other,
                                 };
 
+                                let suppressed_str = if e.suppressed { " (silenced)" } else { "" };
+
                                 lines.push(format!(
-                                    "  {} {}{}{}{}",
-                                    icon, kind_label, pid_str, name_str, severity_str
+                                    "  {} {}{}{}{}{}",
+                                    icon, kind_label, pid_str, name_str, severity_str, suppressed_str
                                 ));
                             }
 
@@ -2017,14 +2751,31 @@ impl App {
                     let mut text = snap.to_text();
                     text.push_str("\n\n");
                     // Add config info
-                    text.push_str(&format!("Warning threshold: {:.0}°C\n",
-                        self.detector.config_thermal_warning()));
-                    text.push_str(&format!("Critical threshold: {:.0}°C\n",
-                        self.detector.config_thermal_critical()));
-                    text.push_str(&format!("Emergency threshold: {:.0}°C\n",
-                        self.detector.config_thermal_emergency()));
-                    text.push_str(&format!("Auto-shutdown: {}\n",
-                        if self.state.shutdown_manager.is_enabled() { "ENABLED" } else { "disabled" }));
+                    let thresholds = self.detector.thermal_thresholds();
+                    let levels = thresholds.defaults();
+                    text.push_str(&format!("Warning threshold: {:.0}°C\n", levels.warning));
+                    text.push_str(&format!("Critical threshold: {:.0}°C\n", levels.critical));
+                    text.push_str(&format!("Emergency threshold: {:.0}°C\n", levels.emergency));
+                    for o in thresholds.overrides() {
+                        text.push_str(&format!("  Override {}\n", o.describe()));
+                    }
+                    for (name, alias) in &self.config.thermal.sensor_aliases {
+                        text.push_str(&format!("  Alias {} -> {}\n", name, alias));
+                    }
+                    text.push_str(&format!("Auto-shutdown: {}{}\n",
+                        if self.state.shutdown_manager.is_enabled() { "ENABLED" } else { "disabled" },
+                        if self.shutdown_dry_run { " (dry run)" } else { "" }));
+                    let actions: Vec<String> = self.shutdown_steps.iter().map(|s| s.describe()).collect();
+                    text.push_str(&format!("Shutdown actions: {}\n", actions.join(" -> ")));
+                    text.push_str(&format!("Fan control: {} ({} curves)\n",
+                        if self.state.fan_controller.is_enabled() { "ENABLED" } else { "disabled" },
+                        self.state.fan_controller.channels().len()));
+                    text.push_str(&format!("Mitigation ladder: {} steps, {} change(s) in effect\n",
+                        self.mitigator.steps().len(),
+                        self.mitigator.active_count()));
+                    for (i, step) in self.mitigator.steps().iter().enumerate() {
+                        text.push_str(&format!("  {}. {}\n", i + 1, step.describe()));
+                    }
                     if self.email_notifier.is_some() {
                         text.push_str("Email notifications: configured\n");
                     } else {
@@ -2118,25 +2869,155 @@ impl App {
                 }
             }
 
-            // Help
-            "help" | "?" | "commands" => CommandResult::text_only(
-                 "# Command Palette\n\n\
-                 System:\n\
-                 \x20 why / slow         - Resource contention analysis\n\
-                 \x20 disk               - Disk usage analysis\n\
-                 \x20 anomaly [minutes]  - Anomaly scan (default: 30 min)\n\
-                 \x20 timeline [minutes] - What happened recently\n\n\
-                 Thermal:\n\
-                 \x20 thermal            - Current thermal snapshot (LHM)\n\
-                 \x20 email-test         - Send a test notification email\n\
-                 \x20 telegram-test      - Send a test Telegram message\n\n\
-                 Network:\n\
-                 \x20 port <number>      - Who's using this port?\n\
-                 \x20 listeners          - All active port listeners\n\n\
+            // Acknowledge firing alerts
+            "ack" | "acknowledge" => match parts.get(1) {
+                Some(target) => {
+                    let keys = match_ack_target(&self.state.active_alerts, target);
+                    if keys.is_empty() {
+                        CommandResult::text_only(format!(
+                            "No firing, unacknowledged alert matches '{}'.",
+                            target
+                        ))
+                    } else if let Some(status) =
+                        self.forward_to_daemon(ClientRequest::Ack { keys: keys.clone() })
+                    {
+                        CommandResult::text_only(status)
+                    } else {
+                        let count = self.acknowledge_alerts(&keys);
+                        let mut lines = vec![format!("# Acknowledged {} alert(s)", count)];
+                        lines.extend(keys.iter().map(|k| format!("  {}", k)));
+                        lines.push(String::new());
+                        lines.push("Re-notifications are silenced until they resolve or escalate.".to_string());
+                        CommandResult::text_only(lines.join("\n"))
+                    }
+                }
+                None => CommandResult::text_only(format!(
+                    "Usage: ack <all|pid|name>\n\n{}",
+                    self.firing_alerts_text()
+                )),
+            },
+
+            // Silences and maintenance windows
+            "silence" | "silences" | "mute" => match parts.get(1).copied() {
+                None => self.silence_list(),
+                Some("rm" | "remove" | "clear") => self.silence_remove(parts.get(2).copied()),
+                Some(duration) => self.silence_add(duration, &parts[2..]),
+            },
+            "unsilence" | "unmute" => self.silence_remove(parts.get(1).copied()),
+            "escalations" | "escalation" => self.escalation_list(),
+
+            // Webhook test
+            "webhook-test" | "test-webhook" => {
+                if self.webhook_notifiers.is_empty() {
+                    CommandResult::text_only(
+                        "# Webhook Test\n\n\
+                         No webhooks configured.\n\n\
+                         Add to ~/.config/sentinel/config.toml:\n\
+                         [[notifications.webhooks]]\n\
+                         url = \"https://hooks.example.com/sentinel\"\n\
+                         secret = \"shared-secret\"   # optional HMAC signing\n\
+                         min_severity = \"warning\""
+                            .to_string(),
+                    )
+                } else {
+                    let configs = self.config.notifications.webhooks.clone();
+                    let hostname = gethostname();
+                    tokio::spawn(async move {
+                        for cfg in &configs {
+                            if let Ok(wh) = WebhookNotifier::from_config(cfg) {
+                                match wh.send_test(&hostname).await {
+                                    Ok(_) => eprintln!("Webhook test sent to {}", wh.name()),
+                                    Err(e) => eprintln!("Webhook test to {} failed: {}", wh.name(), e),
+                                }
+                            }
+                        }
+                    });
+                    let names: Vec<String> = self
+                        .webhook_notifiers
+                        .iter()
+                        .map(|w| format!("  {}", w.name()))
+                        .collect();
+                    CommandResult::text_only(format!(
+                        "# Webhook Test\n\nSending test payload to:\n{}",
+                        names.join("\n")
+                    ))
+                }
+            }
+
+            // Slack / Mattermost / Discord test
+            "chat-test" | "test-chat" => {
+                if self.chat_notifiers.is_empty() {
+                    CommandResult::text_only(
+                        "# Chat Test\n\n\
+                         No chat webhooks configured.\n\n\
+                         Add to ~/.config/sentinel/config.toml:\n\
+                         [[notifications.chat]]\n\
+                         format = \"mattermost\"   # or slack, discord\n\
+                         url = \"https://mm.example.com/hooks/xxx\"\n\
+                         min_severity = \"warning\"\n\
+                         digest_secs = 0            # >0 batches alerts"
+                            .to_string(),
+                    )
+                } else {
+                    let configs = self.config.notifications.chat.clone();
+                    let hostname = gethostname();
+                    tokio::spawn(async move {
+                        for cfg in &configs {
+                            if let Ok(chat) = ChatNotifier::from_config(cfg) {
+                                match chat.send_test(&hostname).await {
+                                    Ok(_) => eprintln!("Chat test sent to {}", chat.name()),
+                                    Err(e) => eprintln!("Chat test to {} failed: {}", chat.name(), e),
+                                }
+                            }
+                        }
+                    });
+                    let names: Vec<String> = self
+                        .chat_notifiers
+                        .iter()
+                        .map(|c| format!("  {}", c.name()))
+                        .collect();
+                    CommandResult::text_only(format!(
+                        "# Chat Test\n\nSending test message to:\n{}",
+                        names.join("\n")
+                    ))
+                }
+            }
+
+            // Help
+            "help" | "?" | "commands" => CommandResult::text_only(
+                 "# Command Palette\n\n\
+                 System:\n\
+                 \x20 why / slow         - Resource contention analysis\n\
+                 \x20 disk               - Disk usage analysis\n\
+                 \x20 anomaly [minutes]  - Anomaly scan (default: 30 min)\n\
+                 \x20 timeline [minutes] - What happened recently\n\n\
+                 Thermal:\n\
+                 \x20 thermal            - Current thermal snapshot (LHM)\n\
+                 \x20 thermal-history [minutes] [sensor]\n\
+                 \x20                    - Per-sensor min/avg/max & rate of rise (default: 60 min)\n\
+                 \x20 email-test         - Send a test notification email\n\
+                 \x20 telegram-test      - Send a test Telegram message\n\
+                 \x20 webhook-test       - Send a test payload to each webhook\n\
+                 \x20 chat-test          - Send a test Slack/Mattermost/Discord message\n\n\
+                 Network:\n\
+                 \x20 port <number>      - Who's using this port?\n\
+                 \x20 listeners          - All active port listeners\n\n\
                  Process:\n\
-                 \x20 pid <number>       - Deep process analysis\n\n\
+                 \x20 pid <number>       - Deep process analysis\n\
+                 \x20 diff [from] [to]   - Process changes between two times\n\
+                 \x20                    (now, 1h, 14:30, 2024-05-01T14:30; default: 1h now)\n\n\
+                 Replay:\n\
+                 \x20 replay [time]      - Show the dashboard, processes & alerts as recorded\n\
+                 \x20                    then (default: 1h ago); Esc or 'replay off' for live\n\n\
                  Events:\n\
                  \x20 events [minutes]   - Event timeline (default: 30 min)\n\n\
+                 Alerts:\n\
+                 \x20 ack <all|pid|name> - Acknowledge firing alerts\n\
+                 \x20 silence            - List silences & maintenance windows\n\
+                 \x20 silence <dur> [category=cpu] [process=glob] [severity=warning]\n\
+                 \x20                    - Mute matching alerts for a while\n\
+                 \x20 unsilence <id|all> - Remove a silence\n\
+                 \x20 escalations        - Escalation policies & progress\n\n\
                  Meta:\n\
                  \x20 config             - Show configuration & paths\n\
                  \x20 stats              - Event store statistics\n\
@@ -2185,12 +3066,7 @@ impl App {
             return;
         }
 
-        let proc_clone = {
-            let filtered = self.state.filtered_processes();
-            filtered
-                .get(self.state.selected_process)
-                .map(|p| (*p).clone())
-        };
+        let proc_clone = self.state.selected_row_process().cloned();
         if let Some(proc) = proc_clone {
             let question = format!(
                 "Tell me about this process: PID {} ({}) - \
@@ -2213,6 +3089,133 @@ impl App {
         }
     }
 
+    // ── Historical replay ────────────────────────────────────────
+
+    /// Replay keys (normal mode). Returns true if the key was handled.
+    fn handle_key_replay(&mut self, key: crossterm::event::KeyEvent) -> bool {
+        let Some(ref mut replay) = self.state.replay else {
+            return false;
+        };
+        match key.code {
+            KeyCode::Char(' ') => replay.toggle_playing(),
+            KeyCode::Char(']') => replay.faster(),
+            KeyCode::Char('[') => replay.slower(),
+            KeyCode::Char('.') => self.step_replay(true),
+            KeyCode::Char(',') => self.step_replay(false),
+            KeyCode::Char('>') => {
+                let ts = replay.frame_ts + REPLAY_JUMP_MINUTES * 60_000;
+                self.seek_replay_or_status(ts);
+            }
+            KeyCode::Char('<') => {
+                let ts = replay.frame_ts - REPLAY_JUMP_MINUTES * 60_000;
+                self.seek_replay_or_status(ts);
+            }
+            // An open Security detail popup closes first
+            KeyCode::Esc if !self.state.security.detail_popup => {
+                self.state.stop_replay();
+                self.state.set_status("Back to live data".to_string());
+            }
+            // The replayed PIDs may be gone or belong to other programs by now
+            KeyCode::Char('k' | 'K' | 'x' | 'n' | 'C')
+                if self.state.active_tab == Tab::Processes =>
+            {
+                self.state.set_status(
+                    "Process actions are disabled while replaying (Esc for live data)".to_string(),
+                );
+            }
+            // The selection points into the replayed alerts, not the firing ones
+            KeyCode::Char('a') if self.state.active_tab == Tab::Alerts => {
+                self.state.set_status(
+                    "Acknowledging is disabled while replaying (Esc for live data)".to_string(),
+                );
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Show the stored frame at `ts_ms` (clamped to the recorded range),
+    /// entering replay mode if needed.
+    fn seek_replay(&mut self, ts_ms: i64) -> Result<(), String> {
+        let Some(ref store) = self.event_store else {
+            return Err("Event store not available.".to_string());
+        };
+        let Some((first, last)) = store.system_snapshot_bounds().ok().flatten() else {
+            return Err("No system snapshots recorded yet.".to_string());
+        };
+        let ts = ts_ms.clamp(first, last);
+        let frame = load_frame(store, ts, self.state.system.as_ref())
+            .ok_or_else(|| "Could not read the recorded snapshot.".to_string())?;
+        self.state.show_replay_frame(frame, ts);
+
+        if let Some(ref mut replay) = self.state.replay {
+            if replay.playing && ts == last {
+                replay.playing = false;
+                self.state
+                    .set_status("Replay reached the latest snapshot".to_string());
+            }
+        }
+        Ok(())
+    }
+
+    fn seek_replay_or_status(&mut self, ts_ms: i64) {
+        if let Err(e) = self.seek_replay(ts_ms) {
+            self.state.set_status(e);
+        }
+    }
+
+    /// Step the replay to the next or previous stored snapshot.
+    fn step_replay(&mut self, forward: bool) {
+        let (Some(ref store), Some(ref replay)) = (&self.event_store, &self.state.replay) else {
+            return;
+        };
+        let ts = if forward {
+            store.next_system_snapshot_ts(replay.frame_ts)
+        } else {
+            store.prev_system_snapshot_ts(replay.frame_ts)
+        };
+        match ts.ok().flatten() {
+            Some(ts) => self.seek_replay_or_status(ts),
+            None if forward => self
+                .state
+                .set_status("Replay is at the latest snapshot".to_string()),
+            None => self
+                .state
+                .set_status("Replay is at the oldest snapshot".to_string()),
+        }
+    }
+
+    /// Advance a playing replay.
+    fn tick_replay(&mut self) {
+        let now = std::time::Instant::now();
+        if let Some(cursor) = self.state.replay.as_mut().and_then(|r| r.advance(now)) {
+            self.seek_replay_or_status(cursor);
+        }
+    }
+
+    /// Palette text describing the replay position and keys.
+    fn replay_text(&self) -> String {
+        let when = self
+            .state
+            .replay
+            .as_ref()
+            .and_then(|r| r.frame_time())
+            .map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string())
+            .unwrap_or_default();
+        format!(
+            "# Replay: {}\n\n\
+             Dashboard, processes and alerts show the recorded snapshot.\n\
+             Live data keeps being collected and alerted on.\n\n\
+             Keys:\n\
+             \x20 , / .    Previous / next snapshot\n\
+             \x20 < / >    Back / forward {} minutes\n\
+             \x20 Space    Play / pause\n\
+             \x20 [ / ]    Slower / faster\n\
+             \x20 Esc      Back to live data",
+            when, REPLAY_JUMP_MINUTES
+        )
+    }
+
     // ── Tick-based logic ─────────────────────────────────────────
 
     fn tick_refresh(&mut self) {
@@ -2235,6 +3238,19 @@ impl App {
                 new_alerts.extend(plugin_alerts);
             }
 
+            // Silences / maintenance windows: keep the alerts, mark them suppressed
+            self.refresh_suppressor();
+            for alert in &mut new_alerts {
+                alert.suppressed = self.suppressor.is_suppressed(alert);
+            }
+
+            // Lifecycle: everything currently true (including alerts held back
+            // by a cooldown) keeps its key firing; the rest resolve.
+            let mut observed = self.detector.firing().to_vec();
+            observed.extend(self.plugins.collect_firing_alerts());
+            observed.extend(new_alerts.iter().cloned());
+            let resolved = self.alert_tracker.update(&observed, std::time::Instant::now());
+
             // Record to event store
             if let Some(ref mut store) = self.event_store {
                 // System snapshot
@@ -2256,10 +3272,29 @@ impl App {
                     })
                     .to_string();
                     let severity = alert.severity.to_string().to_lowercase();
+                    let kind = crate::store::EventKind::Alert;
+                    let (pid, name) = (Some(alert.pid), Some(alert.process_name.as_str()));
+                    let _ = if alert.suppressed {
+                        store.insert_suppressed_event(kind, pid, name, Some(&detail), Some(&severity))
+                    } else {
+                        store.insert_event(kind, pid, name, Some(&detail), Some(&severity))
+                    };
+                }
+
+                // Record resolutions
+                for active in &resolved {
+                    let detail = serde_json::json!({
+                        "category": active.key.category.to_string(),
+                        "message": active.alert.message,
+                        "duration": active.duration_display(),
+                        "acknowledged": active.acknowledged,
+                    })
+                    .to_string();
+                    let severity = active.alert.severity.to_string().to_lowercase();
                     let _ = store.insert_event(
-                        crate::store::EventKind::Alert,
-                        Some(alert.pid),
-                        Some(&alert.process_name),
+                        crate::store::EventKind::AlertResolved,
+                        Some(active.alert.pid),
+                        Some(&active.key.subject),
                         Some(&detail),
                         Some(&severity),
                     );
@@ -2272,50 +3307,63 @@ impl App {
                 }
             }
 
-            // Update event ticker for dashboard (last 5 events)
-            if let Some(ref store) = self.event_store {
-                let five_min_ago =
-                    crate::store::now_epoch_ms_pub() - (5 * 60 * 1000);
-                if let Ok(events) = store.query_events_since(five_min_ago) {
-                    let now = crate::store::now_epoch_ms_pub();
-                    self.state.recent_events = events
-                        .iter()
-                        .take(8)
-                        .map(|e| {
-                            let age_ms = now - e.ts;
-                            let age = if age_ms < 60_000 {
-                                "now".to_string()
-                            } else {
-                                format!("{}m", age_ms / 60_000)
-                            };
-                            let icon = match e.kind.as_str() {
-                                "process_start" => "+",
-                                "process_exit" => "-",
-                                "port_bind" => ">",
-                                "port_release" => "<",
-                                "alert" => "!",
-                                "cpu_spike" => "^",
-                                "memory_spike" => "~",
-                                "oom_kill" => "X",
-                                _ => "?",
-                            };
-                            let name = e
-                                .name
-                                .as_deref()
-                                .unwrap_or("unknown");
-                            format!("[{}] {} {}", age, icon, name)
-                        })
-                        .collect();
-                }
-            }
+            self.refresh_recent_events();
 
-            // Send alerts to Telegram (grouped by parent app, severity-filtered + rate-limited)
+            // Send alerts to Telegram (grouped by parent app, severity-filtered + rate-limited).
+            // Acknowledged and silenced alerts are not notified.
+            let hostname = gethostname();
+            let resolved: Vec<ActiveAlert> = resolved
+                .into_iter()
+                .filter(|a| !self.suppressor.is_suppressed(&a.alert))
+                .collect();
             if let Some(ref mut tg) = self.telegram_notifier {
-                let hostname = gethostname();
-                tg.send_grouped_alerts(&new_alerts, &processes, &hostname, None);
+                let notify: Vec<Alert> = new_alerts
+                    .iter()
+                    .filter(|a| !a.suppressed && !self.alert_tracker.is_acknowledged(a))
+                    .cloned()
+                    .collect();
+                tg.send_grouped_alerts(&notify, &processes, &hostname, None);
+                tg.send_resolved(&resolved, &hostname);
+                tg.tick_digest(&hostname);
+            }
+            if !self.webhook_notifiers.is_empty() || !self.chat_notifiers.is_empty() {
+                let notify: Vec<Alert> = new_alerts
+                    .iter()
+                    .filter(|a| !a.suppressed && !self.alert_tracker.is_acknowledged(a))
+                    .cloned()
+                    .collect();
+                let context = alert_context(&system, processes.len(), &self.state);
+                for wh in &mut self.webhook_notifiers {
+                    wh.send_grouped_alerts(&notify, &processes, &hostname, Some(&context));
+                    wh.send_resolved(&resolved, &hostname);
+                }
+                for chat in &mut self.chat_notifiers {
+                    chat.send_grouped_alerts(&notify, &processes, &hostname, Some(&context));
+                    chat.send_resolved(&resolved, &hostname);
+                    chat.tick_digest(&hostname);
+                }
             }
+            self.send_resolved_email(&resolved, &hostname);
+            self.tick_escalations(&hostname);
 
+            self.state.active_alerts = self.alert_tracker.active();
             self.state.update(system, processes, new_alerts);
+            self.state.refresh_detail_threads();
+
+            // Stream the refreshed state to attached clients
+            if let Some(ref server) = self.ipc_server {
+                if server.client_count() > 0 {
+                    if let Some(ref system) = self.state.system {
+                        server.publish(LiveSnapshot {
+                            system: system.clone(),
+                            processes: self.state.processes.clone(),
+                            alerts: self.state.alerts.clone(),
+                            thermal: self.state.thermal.clone(),
+                            active_alerts: self.state.active_alerts.clone(),
+                        });
+                    }
+                }
+            }
 
             // Sync plugin favorites to SQLite (every ~10 refresh cycles)
             if self.state.tick_count % (REFRESH_THROTTLE_TICKS * 10) == 0 {
@@ -2332,20 +3380,359 @@ impl App {
                 }
             }
 
-            // Refresh security dashboard (every SECURITY_REFRESH_TICKS)
-            if self.state.tick_count % SECURITY_REFRESH_TICKS == 0 {
-                self.tick_security();
-            }
+            self.maybe_tick_security();
         } else {
             self.state.tick_count += 1;
         }
     }
 
+    /// Attach mode: apply snapshots streamed by the daemon in place of
+    /// local collection and alerting.
+    fn tick_remote(&mut self) {
+        let mut latest = None;
+        let mut disconnected = false;
+        if let Some(ref mut remote) = self.remote {
+            loop {
+                match remote.try_recv() {
+                    Ok(ServerMessage::Snapshot(snapshot)) => latest = Some(snapshot),
+                    Ok(ServerMessage::ActionResult { message, .. }) => {
+                        self.state.set_status(message);
+                    }
+                    Err(mpsc::error::TryRecvError::Empty) => break,
+                    Err(mpsc::error::TryRecvError::Disconnected) => {
+                        disconnected = true;
+                        break;
+                    }
+                }
+            }
+        }
+        if disconnected {
+            self.remote = None;
+            self.state
+                .set_status("Lost connection to the Sentinel daemon — data is stale".into());
+        }
+
+        match latest {
+            Some(snapshot) => self.apply_live_snapshot(*snapshot),
+            None => self.state.tick_count += 1,
+        }
+    }
+
+    /// Replace the displayed state with a snapshot received from the daemon.
+    fn apply_live_snapshot(&mut self, snapshot: LiveSnapshot) {
+        let LiveSnapshot {
+            system,
+            processes,
+            alerts,
+            thermal,
+            active_alerts,
+        } = snapshot;
+        self.apply_thermal_snapshot(thermal);
+        self.state.alerts = alerts;
+        self.state.active_alerts = active_alerts;
+        self.state.update(system, processes, Vec::new());
+        self.state.refresh_detail_threads();
+        self.refresh_recent_events();
+        self.maybe_tick_security();
+    }
+
+    /// Refresh security dashboard (every SECURITY_REFRESH_TICKS).
+    fn maybe_tick_security(&mut self) {
+        if self.state.tick_count % SECURITY_REFRESH_TICKS == 0 {
+            self.tick_security();
+        }
+    }
+
+    /// Attach mode: block (briefly) until the daemon sends its first frame,
+    /// so the first render has data.
+    async fn wait_for_first_snapshot(&mut self) {
+        let Some(ref mut remote) = self.remote else {
+            return;
+        };
+        let timeout = Duration::from_secs(ATTACH_FIRST_FRAME_TIMEOUT_SECS);
+        let first = tokio::time::timeout(timeout, async {
+            while let Some(msg) = remote.recv().await {
+                if let ServerMessage::Snapshot(snapshot) = msg {
+                    return Some(snapshot);
+                }
+            }
+            None
+        })
+        .await;
+        match first {
+            Ok(Some(snapshot)) => self.apply_live_snapshot(*snapshot),
+            _ => self
+                .state
+                .set_status("Waiting for data from the Sentinel daemon...".into()),
+        }
+    }
+
+    /// Reload silences from the event store and re-evaluate maintenance windows.
+    fn refresh_suppressor(&mut self) {
+        let silences = match self.event_store {
+            Some(ref store) => store
+                .active_silences()
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|row| Silence::from_row(row).ok())
+                .collect(),
+            None => Vec::new(),
+        };
+        self.suppressor.refresh(silences, chrono::Local::now());
+    }
+
+    /// `silence`: active silences and configured maintenance windows.
+    fn silence_list(&mut self) -> CommandResult {
+        self.refresh_suppressor();
+        let now = chrono::Local::now();
+        let mut lines = vec![format!("# Silences ({})", self.suppressor.silences().len())];
+        if self.suppressor.silences().is_empty() {
+            lines.push("  None. Usage: silence <duration> [category=..] [process=..] [severity=..]".to_string());
+        }
+        for s in self.suppressor.silences() {
+            lines.push(format!(
+                "  #{:<4} {} (expires in {}){}",
+                s.id,
+                s.matcher.describe(),
+                s.remaining_display(now),
+                s.reason.as_deref().map(|r| format!(" - {}", r)).unwrap_or_default()
+            ));
+        }
+        lines.push(String::new());
+        let windows: Vec<String> = self
+            .suppressor
+            .windows()
+            .map(|(w, open)| {
+                format!(
+                    "  {} [{}] every '{}' for {}m: {}",
+                    w.name,
+                    if open { "OPEN" } else { "closed" },
+                    w.schedule.as_str(),
+                    w.duration.as_secs() / 60,
+                    w.matcher.describe()
+                )
+            })
+            .collect();
+        lines.push(format!("# Maintenance Windows ({})", windows.len()));
+        if windows.is_empty() {
+            lines.push("  None. Add [[maintenance]] tables to config.toml".to_string());
+        }
+        lines.extend(windows);
+        CommandResult::text_only(lines.join("\n"))
+    }
+
+    /// `silence <duration> [category=..] [process=..] [severity=..] [reason]`
+    fn silence_add(&mut self, duration: &str, args: &[&str]) -> CommandResult {
+        let usage = "Usage: silence <duration> [category=cpu] [process=glob] [severity=warning] [reason]\n\
+                     Example: silence 2h category=disk process=backup*";
+        let duration = match crate::alerts::rules::parse_duration(
+            &crate::config::RuleValue::Text(duration.to_string()),
+        ) {
+            Ok(d) if d.as_secs() > 0 => d,
+            _ => return CommandResult::text_only(format!("Invalid duration '{}'.\n{}", duration, usage)),
+        };
+
+        let (mut category, mut process, mut severity) = (None, None, None);
+        let mut reason = Vec::new();
+        for arg in args {
+            match arg.split_once('=') {
+                Some(("category" | "cat", v)) => category = Some(v),
+                Some(("process" | "proc", v)) => process = Some(v),
+                Some(("severity" | "sev", v)) => severity = Some(v),
+                _ => reason.push(*arg),
+            }
+        }
+        let matcher = match AlertMatcher::parse(category, process, severity) {
+            Ok(m) => m,
+            Err(e) => return CommandResult::text_only(format!("{}\n{}", e, usage)),
+        };
+        let reason = (!reason.is_empty()).then(|| reason.join(" "));
+
+        let Some(ref store) = self.event_store else {
+            return CommandResult::text_only("Event store not available.".to_string());
+        };
+        let expires_ts = crate::store::now_epoch_ms_pub() + duration.as_millis() as i64;
+        match store.insert_silence(expires_ts, category, process, severity, reason.as_deref()) {
+            Ok(id) => {
+                self.refresh_suppressor();
+                let until = chrono::Local::now()
+                    + chrono::Duration::seconds(duration.as_secs() as i64);
+                CommandResult::text_only(format!(
+                    "# Silence #{} created\n\n  Matches: {}\n  Until:   {}\n\n\
+                     Matching alerts are still recorded but not sent to Telegram or email.",
+                    id,
+                    matcher.describe(),
+                    until.format("%Y-%m-%d %H:%M")
+                ))
+            }
+            Err(e) => CommandResult::text_only(format!("Could not save silence: {}", e)),
+        }
+    }
+
+    /// `unsilence <id|all>`
+    fn silence_remove(&mut self, target: Option<&str>) -> CommandResult {
+        let Some(ref store) = self.event_store else {
+            return CommandResult::text_only("Event store not available.".to_string());
+        };
+        let result = match target {
+            Some("all") => store
+                .clear_silences()
+                .map(|n| format!("Removed {} silence(s).", n)),
+            Some(id) => match id.trim_start_matches('#').parse::<i64>() {
+                Ok(id) => store.remove_silence(id).map(|removed| {
+                    if removed {
+                        format!("Removed silence #{}.", id)
+                    } else {
+                        format!("No silence #{}.", id)
+                    }
+                }),
+                Err(_) => Ok(format!("Invalid silence ID '{}'.", id)),
+            },
+            None => Ok("Usage: unsilence <id|all>".to_string()),
+        };
+        self.refresh_suppressor();
+        CommandResult::text_only(result.unwrap_or_else(|e| format!("Error: {}", e)))
+    }
+
+    /// Acknowledge the alert at the top of the Alerts tab view.
+    fn ack_selected_alert(&mut self) {
+        let Some(alert) = self.state.alerts.get(self.state.alert_scroll) else {
+            return;
+        };
+        let key = AlertKey::of(alert);
+        let firing = self
+            .state
+            .active_alerts
+            .iter()
+            .any(|a| a.key == key && !a.acknowledged);
+        if !firing {
+            self.state
+                .set_status(format!("{} is not firing or already acknowledged", key));
+            return;
+        }
+        let request = ClientRequest::Ack { keys: vec![key.clone()] };
+        if let Some(status) = self.forward_to_daemon(request) {
+            self.state.set_status(status);
+            return;
+        }
+        self.acknowledge_alerts(std::slice::from_ref(&key));
+        self.state.set_status(format!("Acknowledged: {}", key));
+    }
+
+    /// Firing alerts listing shared by the `ack` palette command and the
+    /// Telegram `/alerts` command.
+    fn firing_alerts_text(&self) -> String {
+        let mut lines = vec![format!(
+            "# Firing Alerts ({})",
+            self.state.active_alerts.len()
+        )];
+        for a in &self.state.active_alerts {
+            lines.push(format!(
+                "  {} {} PID {} for {}{}",
+                a.alert.severity,
+                a.key,
+                a.alert.pid,
+                a.duration_display(),
+                if a.acknowledged { " (acknowledged)" } else { "" }
+            ));
+        }
+        lines.join("\n")
+    }
+
+    /// Acknowledge firing alerts and record each ack in the event store.
+    /// Returns how many were newly acknowledged.
+    fn acknowledge_alerts(&mut self, keys: &[AlertKey]) -> usize {
+        let mut count = 0;
+        for key in keys {
+            let pid = self
+                .state
+                .active_alerts
+                .iter()
+                .find(|a| &a.key == key)
+                .map(|a| a.alert.pid);
+            if !self.alert_tracker.acknowledge(key) {
+                continue;
+            }
+            count += 1;
+            if let Some(ref store) = self.event_store {
+                let detail = serde_json::json!({ "category": key.category.to_string() }).to_string();
+                let _ = store.insert_event(
+                    crate::store::EventKind::AlertAck,
+                    pid,
+                    Some(&key.subject),
+                    Some(&detail),
+                    None,
+                );
+            }
+        }
+        self.state.active_alerts = self.alert_tracker.active();
+        count
+    }
+
+    /// Attach mode: hand a process action to the daemon instead of acting
+    /// locally. Returns the status text to show, or `None` when not attached.
+    fn forward_to_daemon(&self, request: ClientRequest) -> Option<String> {
+        if !self.attached {
+            return None;
+        }
+        Some(match self.remote {
+            Some(ref remote) => {
+                remote.send(request);
+                "Request sent to the Sentinel daemon...".to_string()
+            }
+            None => "Not connected to the Sentinel daemon".to_string(),
+        })
+    }
+
+    /// Rebuild the dashboard event ticker from the event store.
+    fn refresh_recent_events(&mut self) {
+        if let Some(ref store) = self.event_store {
+            let five_min_ago =
+                crate::store::now_epoch_ms_pub() - (5 * 60 * 1000);
+            if let Ok(events) = store.query_events_since(five_min_ago) {
+                let now = crate::store::now_epoch_ms_pub();
+                self.state.recent_events = events
+                    .iter()
+                    .take(8)
+                    .map(|e| {
+                        let age_ms = now - e.ts;
+                        let age = if age_ms < 60_000 {
+                            "now".to_string()
+                        } else {
+                            format!("{}m", age_ms / 60_000)
+                        };
+                        let icon = match e.kind.as_str() {
+                            "process_start" => "+",
+                            "process_exit" => "-",
+                            "port_bind" => ">",
+                            "port_release" => "<",
+                            "alert" => "!",
+                            "cpu_spike" => "^",
+                            "memory_spike" => "~",
+                            "oom_kill" => "X",
+                            _ => "?",
+                        };
+                        let name = e
+                            .name
+                            .as_deref()
+                            .unwrap_or("unknown");
+                        format!("[{}] {} {}", age, icon, name)
+                    })
+                    .collect();
+            }
+        }
+    }
+
     fn tick_auto_analysis(&mut self) {
         if !self.auto_analysis_enabled || !self.has_key || self.state.ai_insight_loading {
             return;
         }
 
+        // A replayed frame is not the current state of the system
+        if self.state.replay.is_some() {
+            return;
+        }
+
         // Skip if user isn't on the Dashboard tab — no point analyzing
         // when they can't see the insight card.
         if self.state.active_tab != Tab::Dashboard {
@@ -2416,6 +3803,20 @@ impl App {
 
     /// Apply a full config change: hot-reload affected state and persist to disk.
     fn apply_config_change(&mut self, new_config: Config) {
+        self.reload_config(new_config);
+        if let Err(e) = self.config.save() {
+            self.state
+                .set_status(format!("Config save failed: {}", e));
+        } else {
+            self.state.set_status("Settings saved".into());
+        }
+    }
+
+    /// Hot-reload runtime state from `new_config` without writing it to disk.
+    ///
+    /// Used by the Settings tab (via `apply_config_change`) and by SIGHUP
+    /// in headless mode.
+    fn reload_config(&mut self, new_config: Config) {
         // Hot-reload theme
         if new_config.theme != self.config.theme {
             if let Some(theme) = crate::ui::Theme::by_name(&new_config.theme) {
@@ -2447,6 +3848,40 @@ impl App {
             None
         };
 
+        // Restart the bot listener only when its settings change, so an
+        // unrelated save doesn't drop an in-flight poll
+        let (old_n, new_n) = (&self.config.notifications, &new_config.notifications);
+        if (
+            old_n.telegram_enabled,
+            old_n.telegram_commands,
+            &old_n.telegram_bot_token,
+            &old_n.telegram_chat_id,
+        ) != (
+            new_n.telegram_enabled,
+            new_n.telegram_commands,
+            &new_n.telegram_bot_token,
+            &new_n.telegram_chat_id,
+        ) {
+            self.bot_listener = match self.telegram_notifier {
+                Some(ref tg) if new_n.telegram_commands && !self.attached => Some(
+                    BotListener::spawn(tg.api_base(), tg.bot_token(), tg.chat_id()),
+                ),
+                _ => None,
+            };
+        }
+
+        // Hot-reload webhooks (resets their rate limits only when changed)
+        if new_config.notifications.webhooks != self.config.notifications.webhooks {
+            self.webhook_notifiers = WebhookNotifier::all_from_config(&new_config.notifications);
+        }
+        if new_config.notifications.chat != self.config.notifications.chat {
+            self.chat_notifiers = ChatNotifier::all_from_config(&new_config.notifications);
+        }
+        if new_config.notifications.escalations != self.config.notifications.escalations {
+            self.escalator
+                .set_policies(&new_config.notifications, self.event_store.as_ref());
+        }
+
         // Hot-reload email notifier
         if new_config.notifications.email_enabled != self.config.notifications.email_enabled {
             self.email_notifier = if new_config.notifications.email_enabled {
@@ -2456,14 +3891,29 @@ impl App {
             };
         }
 
-        // Persist to disk
-        self.config = new_config;
-        if let Err(e) = self.config.save() {
-            self.state
-                .set_status(format!("Config save failed: {}", e));
-        } else {
-            self.state.set_status("Settings saved".into());
+        // Hot-reload fan curves (the old controller hands its outputs back on drop)
+        let (old_t, new_t) = (&self.config.thermal, &new_config.thermal);
+        if (old_t.fan_control_enabled, &old_t.fan_curves) != (new_t.fan_control_enabled, &new_t.fan_curves) {
+            self.state.fan_controller = FanController::new(
+                new_t.fan_control_enabled && !self.attached,
+                &new_t.fan_curves,
+            );
         }
+
+        // Mitigation ladder; changes already applied are still undone on recovery
+        self.mitigator.reconfigure(new_t);
+        self.state.shutdown_manager.set_mitigation(self.mitigator.hold_secs());
+
+        // Shutdown actions only run when the grace period ends, so they can change any time
+        self.shutdown_steps = ShutdownStep::all_from_config(&new_t.shutdown_actions);
+        self.shutdown_dry_run = crate::thermal::shutdown::dry_run_enabled(new_t.shutdown_dry_run);
+
+        // Alert thresholds and patterns
+        self.state.thermal_thresholds = ThermalThresholds::from_config(new_t);
+        self.detector.set_config(new_config.clone());
+        self.suppressor.set_windows(&new_config);
+
+        self.config = new_config;
     }
 
     /// Tick the thermal shutdown state machine and send email notifications.
@@ -2473,11 +3923,12 @@ impl App {
             return;
         }
 
-        let max_temp = self.state.thermal.as_ref()
-            .map(|t| t.max_temp)
-            .unwrap_or(0.0);
+        // Each sensor against its own thresholds; `max_temp` is the worst offender
+        let (level, max_temp) = self.state.thermal.as_ref()
+            .and_then(|t| self.state.thermal_thresholds.hottest(t))
+            .unwrap_or((Level::Normal, 0.0));
 
-        let event = self.state.shutdown_manager.tick(max_temp);
+        let event = self.state.shutdown_manager.tick(level);
 
         // Get hostname for emails
         let hostname = gethostname();
@@ -2497,6 +3948,25 @@ impl App {
                     max_temp, elapsed_secs, required_secs
                 ));
             }
+            ShutdownEvent::Mitigate { step } => {
+                let summary = self.mitigator.apply(
+                    step,
+                    &self.state.processes,
+                    &self.state.containers,
+                );
+                self.state.set_status(format!(
+                    "THERMAL MITIGATION {:.1}°C — {} (Ctrl+X to abort)",
+                    max_temp, summary
+                ));
+                self.record_mitigation(&summary, "critical");
+                self.send_mitigation_notice(step, &summary, max_temp, &hostname);
+            }
+            ShutdownEvent::MitigationHolding { step, remaining_secs } => {
+                self.state.set_status(format!(
+                    "THERMAL MITIGATION step {}: {:.1}°C, escalating in {}s if still hot",
+                    step + 1, max_temp, remaining_secs
+                ));
+            }
             ShutdownEvent::GracePeriodStarted => {
                 self.state.set_status(
                     "SHUTDOWN GRACE PERIOD — Press Ctrl+X to ABORT".to_string()
@@ -2510,13 +3980,20 @@ impl App {
                 ));
             }
             ShutdownEvent::ShutdownNow => {
-                self.state.set_status("EXECUTING SHUTDOWN...".to_string());
+                self.state.set_status(if self.shutdown_dry_run {
+                    "SHUTDOWN DRY RUN — reporting actions, nothing is powered off".to_string()
+                } else {
+                    "EXECUTING SHUTDOWN...".to_string()
+                });
                 // Send final email before shutdown
                 self.send_thermal_email(NotifyEvent::ThermalEmergency, max_temp, &hostname);
-                // Execute shutdown
-                if let Err(e) = crate::thermal::shutdown::execute_shutdown() {
-                    self.state.set_status(format!("Shutdown failed: {}", e));
-                }
+                let ctx = ShutdownContext { hostname, max_temp };
+                tokio::spawn(crate::thermal::shutdown::run_shutdown_actions(
+                    self.shutdown_steps.clone(),
+                    ctx,
+                    self.shutdown_dry_run,
+                    self.shutdown_tx.clone(),
+                ));
             }
             ShutdownEvent::Recovered => {
                 self.state.set_status(format!(
@@ -2524,10 +4001,64 @@ impl App {
                     max_temp
                 ));
                 self.send_thermal_email(NotifyEvent::Recovered, max_temp, &hostname);
+                self.restore_mitigations("temperature recovered");
             }
         }
     }
 
+    /// Report shutdown action outcomes. Unless a shutdown or poweroff went
+    /// through, Sentinel carries on (suspend, dry run, failure): go back to
+    /// watching temperatures.
+    fn drain_shutdown_progress(&mut self) {
+        while let Ok(progress) = self.shutdown_rx.try_recv() {
+            match progress {
+                ShutdownProgress::Action(result) => {
+                    let (detail, severity) = match result {
+                        Ok(msg) => (msg, "info"),
+                        Err(e) => (format!("Shutdown action failed: {}", e), "critical"),
+                    };
+                    if let Some(ref store) = self.event_store {
+                        let _ = store.insert_event(
+                            crate::store::EventKind::ShutdownAction,
+                            None,
+                            Some("thermal"),
+                            Some(&detail),
+                            Some(severity),
+                        );
+                    }
+                    self.state.set_status(detail);
+                }
+                // The machine is going down; keep the mitigations and the Shutdown state
+                ShutdownProgress::Finished { powering_off: true } => {}
+                ShutdownProgress::Finished { powering_off: false } => {
+                    self.state.shutdown_manager.abort();
+                    self.restore_mitigations("shutdown actions finished");
+                }
+            }
+        }
+    }
+
+    /// Undo all thermal mitigations in effect and record it on the timeline.
+    fn restore_mitigations(&mut self, reason: &str) {
+        if let Some(summary) = self.mitigator.restore() {
+            let summary = format!("{}: {}", reason, summary);
+            self.record_mitigation(&summary, "info");
+        }
+    }
+
+    /// Record a mitigation step or restore in the event store.
+    fn record_mitigation(&self, detail: &str, severity: &str) {
+        if let Some(ref store) = self.event_store {
+            let _ = store.insert_event(
+                crate::store::EventKind::ThermalMitigation,
+                None,
+                Some("thermal"),
+                Some(detail),
+                Some(severity),
+            );
+        }
+    }
+
     /// Refresh the security dashboard state.
     fn tick_security(&mut self) {
         let slow_ops = self.state.security.slow_refresh_count
@@ -2571,7 +4102,7 @@ impl App {
         // Score alert: fire when score drops below threshold
         if score < SECURITY_SCORE_ALERT_THRESHOLD && prev >= SECURITY_SCORE_ALERT_THRESHOLD {
             let label = crate::security::state::score_label(score);
-            let alert = crate::models::Alert::new(
+            let mut alert = crate::models::Alert::new(
                 if score < 40 {
                     crate::models::AlertSeverity::Critical
                 } else {
@@ -2585,9 +4116,20 @@ impl App {
                 SECURITY_SCORE_ALERT_THRESHOLD as f64,
             );
 
-            // Send to Telegram
-            if let Some(ref mut tg) = self.telegram_notifier {
-                tg.send_alert(&alert, &gethostname());
+            alert.suppressed = self.suppressor.is_suppressed(&alert);
+
+            // Send to Telegram, webhooks and chat
+            if !alert.suppressed {
+                let hostname = gethostname();
+                if let Some(ref mut tg) = self.telegram_notifier {
+                    tg.send_alert(&alert, &hostname);
+                }
+                for wh in &mut self.webhook_notifiers {
+                    wh.send_alert(&alert, &hostname, None);
+                }
+                for chat in &mut self.chat_notifiers {
+                    chat.send_alert(&alert, &hostname, None);
+                }
             }
 
             // Add to alerts
@@ -2595,67 +4137,78 @@ impl App {
         }
     }
 
-    /// Send a thermal notification via Telegram in the background.
+    /// Send a thermal notification via Telegram, webhooks and chat in the background.
     fn send_thermal_telegram(&mut self, event: &NotifyEvent, temp: f32, hostname: &str) {
-        if let Some(ref mut tg) = self.telegram_notifier {
-            use crate::models::{AlertCategory, AlertSeverity};
-
-            let (severity, category, message) = match event {
-                NotifyEvent::ThermalCritical => (
-                    AlertSeverity::Critical,
-                    AlertCategory::ThermalCritical,
-                    format!("Temperature threshold exceeded: {:.1}\u{b0}C", temp),
-                ),
-                NotifyEvent::ShutdownImminent => (
-                    AlertSeverity::Danger,
-                    AlertCategory::ThermalEmergency,
-                    format!("Auto-shutdown imminent! {:.1}\u{b0}C sustained", temp),
-                ),
-                NotifyEvent::ThermalEmergency => (
-                    AlertSeverity::Danger,
-                    AlertCategory::ThermalEmergency,
-                    format!("EMERGENCY: Executing shutdown at {:.1}\u{b0}C", temp),
-                ),
-                NotifyEvent::Recovered => (
-                    AlertSeverity::Info,
-                    AlertCategory::ThermalWarning,
-                    format!("Temperature recovered to {:.1}\u{b0}C", temp),
-                ),
-                NotifyEvent::Test => return,
-            };
+        let Some(alert) = thermal_notify_alert(event, temp) else {
+            return;
+        };
+        self.broadcast_thermal_alert(&alert, hostname);
+    }
 
-            let alert = crate::models::Alert::new(
-                severity,
-                category,
-                "thermal",
-                0,
-                message,
-                temp as f64,
-                0.0,
-            );
-            tg.send_alert(&alert, hostname);
+    fn broadcast_thermal_alert(&mut self, alert: &Alert, hostname: &str) {
+        if let Some(ref mut tg) = self.telegram_notifier {
+            tg.send_alert(alert, hostname);
+        }
+        for wh in &mut self.webhook_notifiers {
+            wh.send_alert(alert, hostname, None);
+        }
+        for chat in &mut self.chat_notifiers {
+            chat.send_alert(alert, hostname, None);
         }
     }
 
     /// Send a thermal email notification in the background.
     fn send_thermal_email(&mut self, event: NotifyEvent, temp: f32, hostname: &str) {
+        // A silence or maintenance window covering thermal alerts mutes both channels
+        if thermal_notify_alert(&event, temp).is_some_and(|a| self.suppressor.is_suppressed(&a)) {
+            return;
+        }
+
         // Also notify via Telegram
         self.send_thermal_telegram(&event, temp, hostname);
 
-        if let Some(ref mut notifier) = self.email_notifier {
-            let sensor = self.state.thermal.as_ref()
-                .map(|t| {
-                    if t.max_cpu_temp >= t.max_gpu_temp {
-                        "CPU"
-                    } else {
-                        "GPU"
-                    }
-                })
-                .unwrap_or("Unknown");
+        let body = notifications::thermal_alert_body(&event, temp, &self.thermal_sensor_label(), hostname);
+        self.queue_thermal_email(event, body);
+    }
 
-            // Check rate limit synchronously before spawning
-            let body = notifications::thermal_alert_body(&event, temp, sensor, hostname);
+    /// Notify every channel that a mitigation step ran, listing what it did.
+    fn send_mitigation_notice(&mut self, step: usize, summary: &str, temp: f32, hostname: &str) {
+        let event = NotifyEvent::ThermalMitigation { step };
+        let Some(mut alert) = thermal_notify_alert(&event, temp) else {
+            return;
+        };
+        if self.suppressor.is_suppressed(&alert) {
+            return;
+        }
+        alert.message = format!("{} — {}", alert.message, summary);
+        // Each step is its own notification, not a repeat of the previous one
+        alert.process_name = format!("mitigation-{}", step + 1);
+        self.broadcast_thermal_alert(&alert, hostname);
+
+        let body = format!(
+            "{}\n\nActions:\n  {}",
+            notifications::thermal_alert_body(&event, temp, &self.thermal_sensor_label(), hostname),
+            summary
+        );
+        self.queue_thermal_email(event, body);
+    }
 
+    /// Hottest temperature sensor (by its alias, if set), for thermal email bodies.
+    fn thermal_sensor_label(&self) -> String {
+        self.state.thermal.as_ref()
+            .and_then(|t| {
+                t.readings()
+                    .into_iter()
+                    .filter(|(category, _, _)| *category != "fan")
+                    .max_by(|a, b| a.2.total_cmp(&b.2))
+            })
+            .map(|(_, sensor, _)| self.state.thermal_thresholds.display_name(&sensor).to_string())
+            .unwrap_or_else(|| "Unknown".to_string())
+    }
+
+    /// Email `body` in the background unless `event` is rate-limited.
+    fn queue_thermal_email(&mut self, event: NotifyEvent, body: String) {
+        if let Some(ref mut notifier) = self.email_notifier {
             // We can't easily clone the notifier for async, so we do a synchronous
             // rate-limit check and only fire if allowed. The actual send is fire-and-forget.
             if notifier.can_send_check(&event) {
@@ -2669,6 +4222,138 @@ impl App {
         }
     }
 
+    /// Email a RESOLVED notice for critical alerts that cleared this tick.
+    fn send_resolved_email(&mut self, resolved: &[ActiveAlert], hostname: &str) {
+        let critical: Vec<ActiveAlert> = resolved
+            .iter()
+            .filter(|a| a.alert.severity >= AlertSeverity::Critical)
+            .cloned()
+            .collect();
+        if critical.is_empty() {
+            return;
+        }
+        if let Some(ref mut notifier) = self.email_notifier {
+            let event = NotifyEvent::AlertResolved;
+            if notifier.can_send_check(&event) {
+                notifier.mark_sent(&event);
+                let body = notifications::resolved_alerts_body(&critical, hostname);
+                let smtp_config = notifier.config().clone();
+                tokio::spawn(async move {
+                    let mut temp_notifier = EmailNotifier::new(smtp_config);
+                    let _ = temp_notifier.notify(event, &body).await;
+                });
+            }
+        }
+    }
+
+    /// Advance escalation chains and notify every step that became due.
+    fn tick_escalations(&mut self, hostname: &str) {
+        let active = self.alert_tracker.active();
+        let suppressor = &self.suppressor;
+        let due = self.escalator.evaluate(
+            &active,
+            |a| suppressor.is_suppressed(a),
+            chrono::Local::now().timestamp_millis(),
+            self.event_store.as_ref(),
+        );
+        for escalation in &due {
+            self.send_escalation(escalation, hostname);
+            if let Some(ref store) = self.event_store {
+                let detail = serde_json::json!({
+                    "category": escalation.alert.key.category.to_string(),
+                    "message": escalation.alert.alert.message,
+                    "policy": escalation.policy,
+                    "step": escalation.step,
+                    "channel": escalation.channel.to_string(),
+                })
+                .to_string();
+                let severity = escalation.alert.alert.severity.to_string().to_lowercase();
+                let _ = store.insert_event(
+                    crate::store::EventKind::AlertEscalated,
+                    Some(escalation.alert.alert.pid),
+                    Some(&escalation.alert.key.subject),
+                    Some(&detail),
+                    Some(&severity),
+                );
+            }
+        }
+    }
+
+    /// Send one escalation step through its channel. Empty recipients use
+    /// the channel's default (configured chat/recipient, every webhook/chat).
+    fn send_escalation(&self, escalation: &Escalation, hostname: &str) {
+        let wanted = |name: &str| {
+            escalation.recipients.is_empty() || escalation.recipients.iter().any(|r| r == name)
+        };
+        match escalation.channel {
+            EscalationChannel::Telegram => {
+                if let Some(ref tg) = self.telegram_notifier {
+                    tg.send_escalation(escalation, &escalation.recipients, hostname);
+                }
+            }
+            EscalationChannel::Email => {
+                if let Some(ref notifier) = self.email_notifier {
+                    let body = notifications::escalation_body(escalation, hostname);
+                    let recipients = if escalation.recipients.is_empty() {
+                        vec![notifier.config().recipient.clone()]
+                    } else {
+                        escalation.recipients.clone()
+                    };
+                    for recipient in recipients {
+                        let smtp_config = SmtpConfig {
+                            recipient,
+                            ..notifier.config().clone()
+                        };
+                        let body = body.clone();
+                        tokio::spawn(async move {
+                            let mut temp_notifier = EmailNotifier::new(smtp_config);
+                            let _ = temp_notifier.notify(NotifyEvent::Escalation, &body).await;
+                        });
+                    }
+                }
+            }
+            EscalationChannel::Webhook => {
+                for wh in self.webhook_notifiers.iter().filter(|w| wanted(w.name())) {
+                    wh.send_escalation(escalation, hostname);
+                }
+            }
+            EscalationChannel::Chat => {
+                for chat in self.chat_notifiers.iter().filter(|c| wanted(c.name())) {
+                    chat.send_escalation(escalation, hostname);
+                }
+            }
+        }
+    }
+
+    /// `escalations`: configured policies and escalations in progress.
+    fn escalation_list(&self) -> CommandResult {
+        let policies = self.escalator.policies();
+        let mut lines = vec![format!("# Escalation Policies ({})", policies.len())];
+        if policies.is_empty() {
+            lines.push("  None. Add [[notifications.escalations]] tables to config.toml".to_string());
+        }
+        lines.extend(policies.iter().map(|p| format!("  {}", p.describe())));
+
+        let progress = self.escalator.in_progress();
+        lines.push(String::new());
+        lines.push(format!("# In Progress ({})", progress.len()));
+        if progress.is_empty() {
+            lines.push("  No unacknowledged alert is escalating.".to_string());
+        }
+        let now_ms = chrono::Local::now().timestamp_millis();
+        for p in &progress {
+            lines.push(format!(
+                "  {} {}: step {}/{} sent, firing {}m",
+                p.policy,
+                p.key,
+                p.steps_fired,
+                p.steps_total,
+                (now_ms - p.started_ts).max(0) / 60_000
+            ));
+        }
+        CommandResult::text_only(lines.join("\n"))
+    }
+
     // ── Security tab key handlers ─────────────────────────────
 
     /// Scroll up in the focused security panel.
@@ -2716,6 +4401,68 @@ impl App {
     }
 }
 
+/// System context attached to webhook payloads.
+fn alert_context(system: &crate::models::SystemSnapshot, process_count: usize, state: &AppState) -> AlertContext {
+    AlertContext {
+        cpu_pct: Some(system.global_cpu_usage),
+        mem_pct: Some(system.memory_percent()),
+        max_temp: state.thermal.as_ref().map(|t| t.max_temp),
+        security_score: Some(state.security.score),
+        process_count: Some(process_count),
+        uptime: Some(format!("{}h {}m", system.uptime / 3600, (system.uptime % 3600) / 60)),
+    }
+}
+
+/// The alert a thermal notification stands for (None for non-thermal events).
+fn thermal_notify_alert(event: &NotifyEvent, temp: f32) -> Option<Alert> {
+    use crate::models::AlertCategory;
+
+    let (severity, category, message) = match event {
+        NotifyEvent::ThermalCritical => (
+            AlertSeverity::Critical,
+            AlertCategory::ThermalCritical,
+            format!("Temperature threshold exceeded: {:.1}\u{b0}C", temp),
+        ),
+        NotifyEvent::ThermalMitigation { step } => (
+            AlertSeverity::Critical,
+            AlertCategory::ThermalEmergency,
+            format!("Thermal mitigation step {} at {:.1}\u{b0}C", step + 1, temp),
+        ),
+        NotifyEvent::ShutdownImminent => (
+            AlertSeverity::Danger,
+            AlertCategory::ThermalEmergency,
+            format!("Auto-shutdown imminent! {:.1}\u{b0}C sustained", temp),
+        ),
+        NotifyEvent::ThermalEmergency => (
+            AlertSeverity::Danger,
+            AlertCategory::ThermalEmergency,
+            format!("EMERGENCY: Executing shutdown at {:.1}\u{b0}C", temp),
+        ),
+        NotifyEvent::Recovered => (
+            AlertSeverity::Info,
+            AlertCategory::ThermalWarning,
+            format!("Temperature recovered to {:.1}\u{b0}C", temp),
+        ),
+        NotifyEvent::AlertResolved | NotifyEvent::Escalation | NotifyEvent::Test => return None,
+    };
+    Some(Alert::new(severity, category, "thermal", 0, message, temp as f64, 0.0))
+}
+
+/// Map a `SuggestedAction` signal name to its number (SIGTERM by default).
+fn signal_number(name: &str) -> i32 {
+    match name {
+        "SIGTERM" => libc::SIGTERM,
+        "SIGKILL" => libc::SIGKILL,
+        "SIGHUP" => libc::SIGHUP,
+        _ => libc::SIGTERM,
+    }
+}
+
+/// Write a timestamped line to stderr (headless mode has no status bar).
+fn log_headless(msg: &str) {
+    eprintln!("[{}] {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), msg);
+}
+
 /// Get the system hostname (best-effort).
 fn gethostname() -> String {
     std::fs::read_to_string("/etc/hostname")
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::Path;
 
 use crate::constants::*;
@@ -22,6 +23,12 @@ pub struct Config {
     pub sys_mem_warning_percent: f32,
     /// System memory usage threshold for critical (percent)
     pub sys_mem_critical_percent: f32,
+    /// Per-process GPU utilization threshold for a GPU-hog warning (percent)
+    pub gpu_warning_threshold: f32,
+    /// Share of total VRAM one process may hold before a GPU-hog warning (percent)
+    pub gpu_vram_warning_percent: f32,
+    /// Per-process network rate (received + sent) for a network-hog warning (bytes/s)
+    pub net_warning_threshold_bytes: u64,
     /// Max alerts to keep in history
     pub max_alerts: usize,
     /// Suspicious process name patterns
@@ -48,11 +55,96 @@ pub struct Config {
     pub security: SecurityConfig,
     /// Windows host monitoring configuration (#1)
     pub windows: WindowsConfig,
+    /// Whether the threshold settings above are evaluated as built-in alert rules
+    pub builtin_rules: bool,
+    /// User-defined alert rules (`[[rules]]` tables in config.toml)
+    pub rules: Vec<AlertRuleConfig>,
+    /// Recurring maintenance windows (`[[maintenance]]` tables in config.toml)
+    pub maintenance: Vec<MaintenanceWindowConfig>,
+}
+
+/// A user-defined alert rule as written in config.toml.
+///
+/// Values are kept as written (`"4GiB"`, `"2m"`) so saving the config from
+/// the settings editor doesn't rewrite them; `alerts::rules` compiles them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AlertRuleConfig {
+    /// Rule name, used in alert messages and as the cooldown key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Process name glob (`*`, `?`). Ignored for `system.*` metrics.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub process: Option<String>,
+    /// cgroup glob matched against the group path or name
+    /// (`/system.slice/nginx.service`, `*.service`); the rule then compares
+    /// the group's aggregate usage instead of each process.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cgroup: Option<String>,
+    /// Metric to compare, e.g. `memory_bytes` or `system.cpu_usage`.
+    pub metric: String,
+    /// Comparison operator: `>`, `>=`, `<`, `<=`, `==`, `!=`.
+    #[serde(default = "default_rule_op")]
+    pub op: String,
+    /// Threshold, either a number or a string with a unit (`"4GiB"`, `"90%"`).
+    pub value: RuleValue,
+    /// How long the condition must hold before firing (`"2m"`, `30`).
+    #[serde(default, rename = "for", skip_serializing_if = "Option::is_none")]
+    pub sustained: Option<RuleValue>,
+    /// Alert severity: info, warning, critical, or danger.
+    #[serde(default = "default_rule_severity")]
+    pub severity: String,
+    /// Minimum time between two alerts from this rule for the same target.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cooldown: Option<RuleValue>,
+}
+
+/// A rule value that may be written as a bare number or as a string with a unit.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RuleValue {
+    Number(f64),
+    Text(String),
+}
+
+/// A recurring maintenance window as written in config.toml.
+///
+/// While a window is open, matching alerts are recorded as suppressed and not
+/// sent to Telegram or email. `alerts::silence` compiles it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MaintenanceWindowConfig {
+    /// Window name, shown in the `silence` listing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Cron schedule for when the window opens: `minute hour day month weekday`.
+    pub schedule: String,
+    /// How long the window stays open (`"1h"`, `"30m"`, `1800`).
+    pub duration: RuleValue,
+    /// Only suppress this alert category (e.g. `cpu`, `disk`, `HighDiskIo`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    /// Only suppress alerts for processes matching this name glob.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub process: Option<String>,
+    /// Only suppress alerts at or below this severity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+}
+
+fn default_rule_op() -> String {
+    ">".to_string()
+}
+
+fn default_rule_severity() -> String {
+    "warning".to_string()
 }
 
 /// Thermal monitoring settings (LibreHardwareMonitor integration).
 #[derive(Debug, Clone)]
 pub struct ThermalConfig {
+    /// Backend: "auto" (sysfs, else LHM), "lhm", "sysfs" or "lm-sensors".
+    pub source: String,
+    /// Captured `sensors -j` output for the lm-sensors backend (None = run `sensors -j`).
+    pub lm_sensors_file: Option<String>,
     /// LHM HTTP JSON endpoint URL.
     pub lhm_url: String,
     /// HTTP Basic Auth username (None = no auth from config).
@@ -67,6 +159,10 @@ pub struct ThermalConfig {
     pub critical_threshold: f32,
     /// Temperature emergency threshold (Celsius).
     pub emergency_threshold: f32,
+    /// Per-category / per-sensor overrides (`[[thermal.thresholds]]` tables).
+    pub thresholds: Vec<ThermalThresholdConfig>,
+    /// Display names for sensors (`"Samsung SSD 980 PRO" = "scratch-nvme"`).
+    pub sensor_aliases: BTreeMap<String, String>,
     /// Sustained seconds at emergency before shutdown escalation.
     pub sustained_seconds: u64,
     /// Enable auto-shutdown state machine (OFF by default, also requires .env flag).
@@ -75,11 +171,120 @@ pub struct ThermalConfig {
     pub shutdown_schedule_start: u8,
     /// Schedule end hour (0-23).
     pub shutdown_schedule_end: u8,
+    /// Log shutdown actions instead of running them (also forced by .env flag).
+    pub shutdown_dry_run: bool,
+    /// What auto-shutdown does (`[[thermal.shutdown_actions]]`; empty = platform shutdown).
+    pub shutdown_actions: Vec<ShutdownActionConfig>,
+    /// Enable writing fan PWM from `fan_curves` (OFF by default, also requires .env flag).
+    pub fan_control_enabled: bool,
+    /// Temperature → duty curves (`[[thermal.fan_curves]]` tables).
+    pub fan_curves: Vec<FanCurveConfig>,
+    /// Process and container names (globs) mitigation never touches.
+    pub mitigation_protected: Vec<String>,
+    /// Steps run before auto-shutdown (`[[thermal.mitigation]]` tables).
+    pub mitigation: Vec<MitigationStepConfig>,
+}
+
+/// A threshold override as written in config.toml.
+///
+/// Targets a category, sensors matching a glob (name or alias), or both;
+/// `thermal::thresholds` compiles it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThermalThresholdConfig {
+    /// Category: "cpu", "gpu", "ssd", "ram" or "board".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    /// Sensor name or alias glob (case-insensitive).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sensor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warning: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub critical: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emergency: Option<f32>,
+}
+
+/// A fan curve as written in config.toml.
+///
+/// Maps one temperature to a PWM duty cycle; `thermal::fan` compiles it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FanCurveConfig {
+    /// Curve name, shown in the Thermal tab.
+    pub name: String,
+    /// PWM output: `<chip>/pwm<N>` (hwmon `name` or `hwmonN`) or an absolute sysfs path.
+    pub pwm: String,
+    /// Input temperature: cpu, gpu, ssd, ram, board, max, or a sensor name.
+    #[serde(default = "default_fan_sensor")]
+    pub sensor: String,
+    /// `[°C, duty %]` points in rising temperature order.
+    pub points: Vec<[f32; 2]>,
+    /// How far (°C) the temperature must fall before the duty is lowered.
+    #[serde(default = "default_fan_hysteresis")]
+    pub hysteresis: f32,
+}
+
+/// An auto-shutdown action as written in config.toml.
+///
+/// Actions run in order when the grace period ends; `thermal::shutdown`
+/// compiles them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShutdownActionConfig {
+    /// Action: "shutdown", "poweroff", "suspend", "stop_service", "command" or "webhook".
+    pub action: String,
+    /// Shell command for `command`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+    /// systemd unit for `stop_service`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service: Option<String>,
+    /// URL POSTed to by `webhook`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// How long to wait for the action (`"30s"`, `30`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<RuleValue>,
+}
+
+/// A thermal mitigation step as written in config.toml.
+///
+/// Once an emergency temperature has been sustained, steps run in order
+/// before auto-shutdown; `thermal::mitigation` compiles them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MitigationStepConfig {
+    /// Action: "renice", "cpu_quota", "sigstop" or "docker_pause".
+    pub action: String,
+    /// How many top CPU consumers (containers for `docker_pause`) to act on.
+    #[serde(default = "default_mitigation_top")]
+    pub top: usize,
+    /// Time at emergency after this step before the next one (`"90s"`, `60`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hold: Option<RuleValue>,
+    /// Nice value for `renice` (default 19).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nice: Option<i32>,
+    /// CPU cap for `cpu_quota`, in % of one CPU (default 50).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quota: Option<f32>,
+}
+
+fn default_mitigation_top() -> usize {
+    DEFAULT_MITIGATION_TOP
+}
+
+fn default_fan_sensor() -> String {
+    "cpu".to_string()
+}
+
+fn default_fan_hysteresis() -> f32 {
+    DEFAULT_FAN_HYSTERESIS_C
 }
 
 impl Default for ThermalConfig {
     fn default() -> Self {
         Self {
+            source: "auto".to_string(),
+            lm_sensors_file: None,
             lhm_url: DEFAULT_LHM_URL.to_string(),
             lhm_username: Some("TwisteD_Clawdbot".to_string()),
             lhm_password: Some("Test123!@".to_string()),
@@ -87,10 +292,21 @@ impl Default for ThermalConfig {
             warning_threshold: DEFAULT_THERMAL_WARNING_C,
             critical_threshold: DEFAULT_THERMAL_CRITICAL_C,
             emergency_threshold: DEFAULT_THERMAL_EMERGENCY_C,
+            thresholds: Vec::new(),
+            sensor_aliases: BTreeMap::new(),
             sustained_seconds: DEFAULT_THERMAL_SUSTAINED_SECS,
             auto_shutdown_enabled: false,
             shutdown_schedule_start: DEFAULT_SHUTDOWN_SCHEDULE_START,
             shutdown_schedule_end: DEFAULT_SHUTDOWN_SCHEDULE_END,
+            shutdown_dry_run: false,
+            shutdown_actions: Vec::new(),
+            fan_control_enabled: false,
+            fan_curves: Vec::new(),
+            mitigation_protected: DEFAULT_MITIGATION_PROTECTED
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            mitigation: Vec::new(),
         }
     }
 }
@@ -180,6 +396,90 @@ pub struct NotificationConfig {
     pub telegram_chat_id: Option<String>,
     /// Minimum severity for Telegram alerts: "warning", "critical", or "danger".
     pub telegram_min_severity: String,
+    /// Answer bot commands (`/status`, `/kill`, ...) from the configured chat.
+    pub telegram_commands: bool,
+    /// Webhook endpoints (`[[notifications.webhooks]]` tables in config.toml).
+    pub webhooks: Vec<WebhookConfig>,
+    /// Slack/Mattermost/Discord incoming webhooks (`[[notifications.chat]]` tables).
+    pub chat: Vec<ChatConfig>,
+    /// Escalation chains (`[[notifications.escalations]]` tables).
+    pub escalations: Vec<EscalationPolicyConfig>,
+}
+
+/// A webhook endpoint that receives alerts as JSON POSTs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WebhookConfig {
+    /// Label shown in the status bar and `webhook-test` output.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Endpoint URL (http or https).
+    pub url: String,
+    /// Extra request headers, e.g. `Authorization`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+    /// Shared secret for the `X-Sentinel-Signature-256` HMAC-SHA256 header.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    /// Minimum severity to send: "info", "warning", "critical", or "danger".
+    #[serde(default = "default_rule_severity")]
+    pub min_severity: String,
+}
+
+/// A Slack, Mattermost or Discord incoming webhook that receives formatted alerts.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChatConfig {
+    /// Label shown in `chat-test` output.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Message format: "slack", "mattermost", or "discord".
+    pub format: String,
+    /// Incoming-webhook URL.
+    pub url: String,
+    /// Minimum severity to send: "info", "warning", "critical", or "danger".
+    #[serde(default = "default_rule_severity")]
+    pub min_severity: String,
+    /// Batch alerts into one digest message every N seconds (0 = send immediately).
+    #[serde(default)]
+    pub digest_secs: u64,
+}
+
+/// An escalation chain as written in config.toml.
+///
+/// While a matching alert keeps firing unacknowledged, each step notifies its
+/// channel once the alert has been firing for the step's `delay`.
+/// `notifications::escalation` compiles it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EscalationPolicyConfig {
+    /// Policy name, shown in escalation messages and the `escalations` listing.
+    pub name: String,
+    /// Only escalate this alert category (e.g. `ThermalCritical`, `cpu`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    /// Only escalate alerts for processes matching this name glob.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub process: Option<String>,
+    /// Only escalate alerts at or above this severity.
+    #[serde(default = "default_escalation_severity")]
+    pub min_severity: String,
+    /// Steps, run in order of `delay`.
+    pub steps: Vec<EscalationStepConfig>,
+}
+
+/// One step of an escalation chain.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EscalationStepConfig {
+    /// Firing duration before this step runs (`"10m"`, `600`).
+    pub delay: RuleValue,
+    /// Channel: "telegram", "email", "webhook", or "chat".
+    pub channel: String,
+    /// Telegram chat IDs, email addresses, or webhook/chat names. Empty uses
+    /// the channel's configured default (all webhooks/chats).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recipients: Vec<String>,
+}
+
+fn default_escalation_severity() -> String {
+    "critical".to_string()
 }
 
 impl Default for NotificationConfig {
@@ -190,6 +490,10 @@ impl Default for NotificationConfig {
             telegram_bot_token: None,
             telegram_chat_id: None,
             telegram_min_severity: "warning".to_string(),
+            telegram_commands: false,
+            webhooks: Vec::new(),
+            chat: Vec::new(),
+            escalations: Vec::new(),
         }
     }
 }
@@ -206,6 +510,9 @@ impl Default for Config {
             // System-wide memory
             sys_mem_warning_percent: DEFAULT_SYS_MEM_WARNING_PCT,
             sys_mem_critical_percent: DEFAULT_SYS_MEM_CRITICAL_PCT,
+            gpu_warning_threshold: DEFAULT_GPU_WARNING_PCT,
+            gpu_vram_warning_percent: DEFAULT_GPU_VRAM_WARNING_PCT,
+            net_warning_threshold_bytes: DEFAULT_NET_WARNING_BYTES_PER_SEC,
             max_alerts: DEFAULT_MAX_ALERTS,
             suspicious_patterns: vec![
                 // Note: kworker is intentionally excluded — it's a legitimate
@@ -241,6 +548,9 @@ impl Default for Config {
             market: MarketConfig::default(),
             security: SecurityConfig::default(),
             windows: WindowsConfig::default(),
+            builtin_rules: true,
+            rules: Vec::new(),
+            maintenance: Vec::new(),
         }
     }
 }
@@ -258,6 +568,10 @@ pub(crate) struct FileConfig {
     pub(crate) mem_critical_threshold_mib: Option<u64>,
     pub(crate) sys_mem_warning_percent: Option<f32>,
     pub(crate) sys_mem_critical_percent: Option<f32>,
+    pub(crate) gpu_warning_threshold: Option<f32>,
+    pub(crate) gpu_vram_warning_percent: Option<f32>,
+    /// Network-hog threshold in MiB/s
+    pub(crate) net_warning_threshold_mib: Option<u64>,
     pub(crate) max_alerts: Option<usize>,
     pub(crate) suspicious_patterns: Option<Vec<String>>,
     pub(crate) security_threat_patterns: Option<Vec<String>>,
@@ -271,12 +585,17 @@ pub(crate) struct FileConfig {
     pub(crate) market: Option<FileMarketConfig>,
     pub(crate) security: Option<FileSecurityConfig>,
     pub(crate) windows: Option<FileWindowsConfig>,
+    pub(crate) builtin_rules: Option<bool>,
+    pub(crate) rules: Option<Vec<AlertRuleConfig>>,
+    pub(crate) maintenance: Option<Vec<MaintenanceWindowConfig>>,
 }
 
 /// TOML-deserializable thermal config section.
 #[derive(Debug, Deserialize, Default)]
 #[serde(default)]
 pub(crate) struct FileThermalConfig {
+    pub(crate) source: Option<String>,
+    pub(crate) lm_sensors_file: Option<String>,
     pub(crate) lhm_url: Option<String>,
     pub(crate) lhm_username: Option<String>,
     pub(crate) lhm_password: Option<String>,
@@ -284,10 +603,18 @@ pub(crate) struct FileThermalConfig {
     pub(crate) warning_threshold: Option<f32>,
     pub(crate) critical_threshold: Option<f32>,
     pub(crate) emergency_threshold: Option<f32>,
+    pub(crate) thresholds: Option<Vec<ThermalThresholdConfig>>,
+    pub(crate) sensor_aliases: Option<BTreeMap<String, String>>,
     pub(crate) sustained_seconds: Option<u64>,
     pub(crate) auto_shutdown_enabled: Option<bool>,
     pub(crate) shutdown_schedule_start: Option<u8>,
     pub(crate) shutdown_schedule_end: Option<u8>,
+    pub(crate) shutdown_dry_run: Option<bool>,
+    pub(crate) shutdown_actions: Option<Vec<ShutdownActionConfig>>,
+    pub(crate) fan_control_enabled: Option<bool>,
+    pub(crate) fan_curves: Option<Vec<FanCurveConfig>>,
+    pub(crate) mitigation_protected: Option<Vec<String>>,
+    pub(crate) mitigation: Option<Vec<MitigationStepConfig>>,
 }
 
 /// TOML-deserializable notification config section.
@@ -299,6 +626,10 @@ pub(crate) struct FileNotificationConfig {
     pub(crate) telegram_bot_token: Option<String>,
     pub(crate) telegram_chat_id: Option<String>,
     pub(crate) telegram_min_severity: Option<String>,
+    pub(crate) telegram_commands: Option<bool>,
+    pub(crate) webhooks: Option<Vec<WebhookConfig>>,
+    pub(crate) chat: Option<Vec<ChatConfig>>,
+    pub(crate) escalations: Option<Vec<EscalationPolicyConfig>>,
 }
 
 /// TOML-deserializable security config section (#16).
@@ -329,6 +660,34 @@ pub(crate) struct FileMarketConfig {
     pub(crate) default_chart_range: Option<String>,
 }
 
+/// Command-line options that take precedence over config.toml, kept so a
+/// reload (SIGHUP) re-applies them on top of the re-read file.
+#[derive(Debug, Clone, Default)]
+pub struct CliOverrides {
+    pub refresh_rate_ms: Option<u64>,
+    pub no_auto_analysis: bool,
+    pub theme: Option<String>,
+    pub lang: Option<String>,
+}
+
+impl CliOverrides {
+    /// Overwrite the overridden fields of `config`.
+    pub fn apply(&self, config: &mut Config) {
+        if let Some(rate) = self.refresh_rate_ms {
+            config.refresh_interval_ms = rate.max(MIN_REFRESH_MS);
+        }
+        if self.no_auto_analysis {
+            config.auto_analysis_interval_secs = 0;
+        }
+        if let Some(ref theme) = self.theme {
+            config.theme = theme.clone();
+        }
+        if let Some(ref lang) = self.lang {
+            config.lang = lang.clone();
+        }
+    }
+}
+
 impl Config {
     /// Load config from ~/.config/sentinel/config.toml, falling back to defaults
     /// for any missing fields. If the file doesn't exist, returns pure defaults.
@@ -375,6 +734,15 @@ impl Config {
         if let Some(v) = file_config.sys_mem_critical_percent {
             config.sys_mem_critical_percent = v.clamp(1.0, 100.0);
         }
+        if let Some(v) = file_config.gpu_warning_threshold {
+            config.gpu_warning_threshold = v.clamp(1.0, 100.0);
+        }
+        if let Some(v) = file_config.gpu_vram_warning_percent {
+            config.gpu_vram_warning_percent = v.clamp(1.0, 100.0);
+        }
+        if let Some(v) = file_config.net_warning_threshold_mib {
+            config.net_warning_threshold_bytes = v.max(1) * 1024 * 1024;
+        }
         if let Some(v) = file_config.max_alerts {
             config.max_alerts = v.max(MIN_MAX_ALERTS);
         }
@@ -414,6 +782,15 @@ impl Config {
 
         // Merge thermal config
         if let Some(t) = file_config.thermal {
+            if let Some(v) = t.source {
+                match crate::thermal::source::SourceKind::parse(&v) {
+                    Ok(_) => config.thermal.source = v.trim().to_lowercase(),
+                    Err(e) => eprintln!("Warning: {} in {}", e, config_path.display()),
+                }
+            }
+            if let Some(v) = t.lm_sensors_file {
+                config.thermal.lm_sensors_file = if v.is_empty() { None } else { Some(v) };
+            }
             if let Some(v) = t.lhm_url {
                 if !v.is_empty() {
                     config.thermal.lhm_url = v;
@@ -437,6 +814,22 @@ impl Config {
             if let Some(v) = t.emergency_threshold {
                 config.thermal.emergency_threshold = v.clamp(30.0, 150.0);
             }
+            if let Some(v) = t.thresholds {
+                for (i, entry) in v.iter().enumerate() {
+                    if let Err(e) = crate::thermal::thresholds::ThresholdOverride::compile(entry) {
+                        eprintln!(
+                            "Warning: ignoring thermal threshold {} in {}: {}",
+                            i + 1,
+                            config_path.display(),
+                            e
+                        );
+                    }
+                }
+                config.thermal.thresholds = v;
+            }
+            if let Some(v) = t.sensor_aliases {
+                config.thermal.sensor_aliases = v;
+            }
             if let Some(v) = t.sustained_seconds {
                 config.thermal.sustained_seconds = v.max(5);
             }
@@ -449,6 +842,54 @@ impl Config {
             if let Some(v) = t.shutdown_schedule_end {
                 config.thermal.shutdown_schedule_end = v.min(24);
             }
+            if let Some(v) = t.shutdown_dry_run {
+                config.thermal.shutdown_dry_run = v;
+            }
+            if let Some(v) = t.shutdown_actions {
+                for (i, action) in v.iter().enumerate() {
+                    if let Err(e) = crate::thermal::shutdown::ShutdownStep::compile(action) {
+                        eprintln!(
+                            "Warning: ignoring shutdown action {} in {}: {}",
+                            i + 1,
+                            config_path.display(),
+                            e
+                        );
+                    }
+                }
+                config.thermal.shutdown_actions = v;
+            }
+            if let Some(v) = t.fan_control_enabled {
+                config.thermal.fan_control_enabled = v;
+            }
+            if let Some(v) = t.fan_curves {
+                for curve in &v {
+                    if let Err(e) = crate::thermal::fan::FanCurve::compile(curve) {
+                        eprintln!(
+                            "Warning: ignoring fan curve '{}' in {}: {}",
+                            curve.name,
+                            config_path.display(),
+                            e
+                        );
+                    }
+                }
+                config.thermal.fan_curves = v;
+            }
+            if let Some(v) = t.mitigation_protected {
+                config.thermal.mitigation_protected = v;
+            }
+            if let Some(v) = t.mitigation {
+                for (i, step) in v.iter().enumerate() {
+                    if let Err(e) = crate::thermal::mitigation::MitigationStep::compile(step) {
+                        eprintln!(
+                            "Warning: ignoring mitigation step {} in {}: {}",
+                            i + 1,
+                            config_path.display(),
+                            e
+                        );
+                    }
+                }
+                config.thermal.mitigation = v;
+            }
         }
 
         // Merge notification config
@@ -468,6 +909,38 @@ impl Config {
             if let Some(v) = n.telegram_min_severity {
                 config.notifications.telegram_min_severity = v;
             }
+            if let Some(v) = n.telegram_commands {
+                config.notifications.telegram_commands = v;
+            }
+            if let Some(v) = n.webhooks {
+                for webhook in &v {
+                    if let Err(e) = crate::notifications::webhook::WebhookNotifier::from_config(webhook) {
+                        eprintln!("Warning: ignoring webhook in {}: {}", config_path.display(), e);
+                    }
+                }
+                config.notifications.webhooks = v;
+            }
+            if let Some(v) = n.chat {
+                for chat in &v {
+                    if let Err(e) = crate::notifications::chat::ChatNotifier::from_config(chat) {
+                        eprintln!("Warning: ignoring chat webhook in {}: {}", config_path.display(), e);
+                    }
+                }
+                config.notifications.chat = v;
+            }
+            if let Some(v) = n.escalations {
+                for policy in &v {
+                    if let Err(e) = crate::notifications::escalation::EscalationPolicy::compile(policy) {
+                        eprintln!(
+                            "Warning: ignoring escalation policy '{}' in {}: {}",
+                            policy.name,
+                            config_path.display(),
+                            e
+                        );
+                    }
+                }
+                config.notifications.escalations = v;
+            }
         }
 
         // Merge market config
@@ -518,6 +991,31 @@ impl Config {
             }
         }
 
+        // Alert rules: keep invalid ones (so saving doesn't drop them) but warn
+        if let Some(v) = file_config.builtin_rules {
+            config.builtin_rules = v;
+        }
+        if let Some(rules) = file_config.rules {
+            for rule in &rules {
+                if let Err(e) = crate::alerts::AlertRule::compile(rule) {
+                    eprintln!("Warning: ignoring alert rule in {}: {}", config_path.display(), e);
+                }
+            }
+            config.rules = rules;
+        }
+        if let Some(windows) = file_config.maintenance {
+            for window in &windows {
+                if let Err(e) = crate::alerts::MaintenanceWindow::compile(window) {
+                    eprintln!(
+                        "Warning: ignoring maintenance window in {}: {}",
+                        config_path.display(),
+                        e
+                    );
+                }
+            }
+            config.maintenance = windows;
+        }
+
         config
     }
 
@@ -577,6 +1075,9 @@ struct WriteConfig {
     mem_critical_threshold_mib: u64,
     sys_mem_warning_percent: f32,
     sys_mem_critical_percent: f32,
+    gpu_warning_threshold: f32,
+    gpu_vram_warning_percent: f32,
+    net_warning_threshold_mib: u64,
     max_alerts: usize,
     suspicious_patterns: Vec<String>,
     security_threat_patterns: Vec<String>,
@@ -590,10 +1091,18 @@ struct WriteConfig {
     market: WriteMarketConfig,
     security: WriteSecurityConfig,
     windows: WriteWindowsConfig,
+    builtin_rules: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    rules: Vec<AlertRuleConfig>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    maintenance: Vec<MaintenanceWindowConfig>,
 }
 
 #[derive(Debug, Serialize)]
 struct WriteThermalConfig {
+    source: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lm_sensors_file: Option<String>,
     lhm_url: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     lhm_username: Option<String>,
@@ -607,6 +1116,19 @@ struct WriteThermalConfig {
     auto_shutdown_enabled: bool,
     shutdown_schedule_start: u8,
     shutdown_schedule_end: u8,
+    shutdown_dry_run: bool,
+    fan_control_enabled: bool,
+    mitigation_protected: Vec<String>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    sensor_aliases: BTreeMap<String, String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    thresholds: Vec<ThermalThresholdConfig>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fan_curves: Vec<FanCurveConfig>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    mitigation: Vec<MitigationStepConfig>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    shutdown_actions: Vec<ShutdownActionConfig>,
 }
 
 #[derive(Debug, Serialize)]
@@ -618,6 +1140,13 @@ struct WriteNotificationConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     telegram_chat_id: Option<String>,
     telegram_min_severity: String,
+    telegram_commands: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    webhooks: Vec<WebhookConfig>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    chat: Vec<ChatConfig>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    escalations: Vec<EscalationPolicyConfig>,
 }
 
 #[derive(Debug, Serialize)]
@@ -652,6 +1181,9 @@ impl From<&Config> for WriteConfig {
             mem_critical_threshold_mib: c.mem_critical_threshold_bytes / (1024 * 1024),
             sys_mem_warning_percent: c.sys_mem_warning_percent,
             sys_mem_critical_percent: c.sys_mem_critical_percent,
+            gpu_warning_threshold: c.gpu_warning_threshold,
+            gpu_vram_warning_percent: c.gpu_vram_warning_percent,
+            net_warning_threshold_mib: c.net_warning_threshold_bytes / (1024 * 1024),
             max_alerts: c.max_alerts,
             suspicious_patterns: c.suspicious_patterns.clone(),
             security_threat_patterns: c.security_threat_patterns.clone(),
@@ -665,6 +1197,9 @@ impl From<&Config> for WriteConfig {
             market: WriteMarketConfig::from(&c.market),
             security: WriteSecurityConfig::from(&c.security),
             windows: WriteWindowsConfig::from(&c.windows),
+            builtin_rules: c.builtin_rules,
+            rules: c.rules.clone(),
+            maintenance: c.maintenance.clone(),
         }
     }
 }
@@ -672,6 +1207,8 @@ impl From<&Config> for WriteConfig {
 impl From<&ThermalConfig> for WriteThermalConfig {
     fn from(t: &ThermalConfig) -> Self {
         Self {
+            source: t.source.clone(),
+            lm_sensors_file: t.lm_sensors_file.clone(),
             lhm_url: t.lhm_url.clone(),
             lhm_username: t.lhm_username.clone(),
             lhm_password: t.lhm_password.clone(),
@@ -683,6 +1220,14 @@ impl From<&ThermalConfig> for WriteThermalConfig {
             auto_shutdown_enabled: t.auto_shutdown_enabled,
             shutdown_schedule_start: t.shutdown_schedule_start,
             shutdown_schedule_end: t.shutdown_schedule_end,
+            shutdown_dry_run: t.shutdown_dry_run,
+            fan_control_enabled: t.fan_control_enabled,
+            mitigation_protected: t.mitigation_protected.clone(),
+            sensor_aliases: t.sensor_aliases.clone(),
+            thresholds: t.thresholds.clone(),
+            fan_curves: t.fan_curves.clone(),
+            mitigation: t.mitigation.clone(),
+            shutdown_actions: t.shutdown_actions.clone(),
         }
     }
 }
@@ -695,6 +1240,10 @@ impl From<&NotificationConfig> for WriteNotificationConfig {
             telegram_bot_token: n.telegram_bot_token.clone(),
             telegram_chat_id: n.telegram_chat_id.clone(),
             telegram_min_severity: n.telegram_min_severity.clone(),
+            telegram_commands: n.telegram_commands,
+            webhooks: n.webhooks.clone(),
+            chat: n.chat.clone(),
+            escalations: n.escalations.clone(),
         }
     }
 }
@@ -742,6 +1291,8 @@ mod tests {
 
         let mut config = Config::default();
         config.cpu_warning_threshold = 42.0;
+        config.gpu_vram_warning_percent = 60.0;
+        config.net_warning_threshold_bytes = 200 * 1024 * 1024;
         config.theme = "nord".to_string();
         config.market.tickers = vec!["BTCUSDT".into(), "DOGEUSDT".into()];
 
@@ -751,6 +1302,8 @@ mod tests {
         let parsed: FileConfig = toml::from_str(&content).unwrap();
 
         assert_eq!(parsed.cpu_warning_threshold, Some(42.0));
+        assert_eq!(parsed.gpu_vram_warning_percent, Some(60.0));
+        assert_eq!(parsed.net_warning_threshold_mib, Some(200));
         assert_eq!(parsed.theme, Some("nord".to_string()));
         assert_eq!(
             parsed.market.unwrap().tickers,
@@ -758,6 +1311,32 @@ mod tests {
         );
     }
 
+    /// CLI overrides replace the file values and clamp the refresh rate.
+    #[test]
+    fn cli_overrides_apply_over_file_values() {
+        let mut config = Config {
+            theme: "nord".to_string(),
+            auto_analysis_interval_secs: 300,
+            ..Config::default()
+        };
+
+        CliOverrides::default().apply(&mut config);
+        assert_eq!(config.theme, "nord");
+        assert_eq!(config.auto_analysis_interval_secs, 300);
+
+        let overrides = CliOverrides {
+            refresh_rate_ms: Some(1),
+            no_auto_analysis: true,
+            theme: Some("gruvbox".to_string()),
+            lang: Some("ja".to_string()),
+        };
+        overrides.apply(&mut config);
+        assert_eq!(config.refresh_interval_ms, MIN_REFRESH_MS);
+        assert_eq!(config.auto_analysis_interval_secs, 0);
+        assert_eq!(config.theme, "gruvbox");
+        assert_eq!(config.lang, "ja");
+    }
+
     /// Config::save_to creates parent directories when missing.
     #[test]
     fn save_creates_parent_directories() {
@@ -884,4 +1463,355 @@ mod tests {
             content
         );
     }
+
+    /// `[[rules]]` tables parse with numeric or unit-suffixed values.
+    #[test]
+    fn parse_alert_rules() {
+        let fc: FileConfig = toml::from_str(
+            r#"
+            builtin_rules = false
+
+            [[rules]]
+            process = "postgres*"
+            metric = "memory_bytes"
+            op = ">"
+            value = "4GiB"
+            for = "2m"
+            severity = "critical"
+
+            [[rules]]
+            metric = "system.load_avg_1"
+            value = 8
+            cooldown = 300
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(fc.builtin_rules, Some(false));
+        let rules = fc.rules.unwrap();
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0].process.as_deref(), Some("postgres*"));
+        assert_eq!(rules[0].value, RuleValue::Text("4GiB".into()));
+        assert_eq!(rules[0].sustained, Some(RuleValue::Text("2m".into())));
+        assert_eq!(rules[1].op, ">");
+        assert_eq!(rules[1].severity, "warning");
+        assert_eq!(rules[1].value, RuleValue::Number(8.0));
+    }
+
+    /// User rules are written back as they were read.
+    #[test]
+    fn save_load_alert_rules_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+
+        let config = Config {
+            rules: vec![AlertRuleConfig {
+                name: Some("pg".into()),
+                process: Some("postgres*".into()),
+                cgroup: None,
+                metric: "memory_bytes".into(),
+                op: ">".into(),
+                value: RuleValue::Text("4GiB".into()),
+                sustained: Some(RuleValue::Text("2m".into())),
+                severity: "critical".into(),
+                cooldown: None,
+            }],
+            ..Config::default()
+        };
+        config.save_to(&path).unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let fc: FileConfig = toml::from_str(&content).unwrap();
+        assert_eq!(fc.builtin_rules, Some(true));
+        assert_eq!(fc.rules, Some(config.rules));
+    }
+
+    /// `[[notifications.webhooks]]` tables survive a save/load roundtrip.
+    #[test]
+    fn webhooks_roundtrip() {
+        let toml_str = r#"
+            [notifications]
+            telegram_enabled = false
+
+            [[notifications.webhooks]]
+            name = "ops"
+            url = "https://hooks.internal/sentinel"
+            secret = "s3cret"
+            min_severity = "critical"
+            headers = { Authorization = "Bearer abc" }
+        "#;
+        let fc: FileConfig = toml::from_str(toml_str).unwrap();
+        let webhooks = fc.notifications.unwrap().webhooks.unwrap();
+        assert_eq!(webhooks.len(), 1);
+        assert_eq!(webhooks[0].headers["Authorization"], "Bearer abc");
+        assert_eq!(webhooks[0].min_severity, "critical");
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        let config = Config {
+            notifications: NotificationConfig {
+                webhooks,
+                ..NotificationConfig::default()
+            },
+            ..Config::default()
+        };
+        config.save_to(&path).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        let fc: FileConfig = toml::from_str(&content).unwrap();
+        assert_eq!(
+            fc.notifications.unwrap().webhooks,
+            Some(config.notifications.webhooks)
+        );
+    }
+
+    /// `[[notifications.chat]]` tables default `min_severity` and `digest_secs`.
+    #[test]
+    fn chat_webhooks_parse_with_defaults() {
+        let toml_str = r#"
+            [[notifications.chat]]
+            format = "mattermost"
+            url = "https://mm.example.com/hooks/abc"
+
+            [[notifications.chat]]
+            name = "discord-ops"
+            format = "discord"
+            url = "https://discord.com/api/webhooks/1/xyz"
+            min_severity = "critical"
+            digest_secs = 300
+        "#;
+        let fc: FileConfig = toml::from_str(toml_str).unwrap();
+        let chat = fc.notifications.unwrap().chat.unwrap();
+        assert_eq!(chat.len(), 2);
+        assert_eq!(chat[0].min_severity, "warning");
+        assert_eq!(chat[0].digest_secs, 0);
+        assert_eq!(chat[1].name.as_deref(), Some("discord-ops"));
+        assert_eq!(chat[1].digest_secs, 300);
+    }
+
+    /// Nested `[[notifications.escalations.steps]]` tables survive a save/load roundtrip.
+    #[test]
+    fn escalations_roundtrip() {
+        let toml_str = r#"
+            [[notifications.escalations]]
+            name = "thermal"
+            category = "ThermalCritical"
+
+            [[notifications.escalations.steps]]
+            delay = "10m"
+            channel = "email"
+
+            [[notifications.escalations.steps]]
+            delay = 1200
+            channel = "telegram"
+            recipients = ["123456789"]
+        "#;
+        let fc: FileConfig = toml::from_str(toml_str).unwrap();
+        let escalations = fc.notifications.unwrap().escalations.unwrap();
+        assert_eq!(escalations[0].min_severity, "critical");
+        assert_eq!(escalations[0].steps.len(), 2);
+        assert_eq!(escalations[0].steps[1].delay, RuleValue::Number(1200.0));
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        let config = Config {
+            notifications: NotificationConfig {
+                escalations,
+                ..NotificationConfig::default()
+            },
+            ..Config::default()
+        };
+        config.save_to(&path).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        let fc: FileConfig = toml::from_str(&content).unwrap();
+        assert_eq!(
+            fc.notifications.unwrap().escalations,
+            Some(config.notifications.escalations)
+        );
+    }
+
+    /// `[[thermal.fan_curves]]` tables survive a save/load roundtrip.
+    #[test]
+    fn fan_curves_roundtrip() {
+        let toml_str = r#"
+            [thermal]
+            fan_control_enabled = true
+
+            [[thermal.fan_curves]]
+            name = "case"
+            pwm = "nct6798/pwm2"
+            points = [[40, 30], [70, 60], [85, 100]]
+        "#;
+        let fc: FileConfig = toml::from_str(toml_str).unwrap();
+        let curves = fc.thermal.unwrap().fan_curves.unwrap();
+        assert_eq!(curves[0].sensor, "cpu");
+        assert_eq!(curves[0].hysteresis, DEFAULT_FAN_HYSTERESIS_C);
+        assert_eq!(curves[0].points[1], [70.0, 60.0]);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        let config = Config {
+            thermal: ThermalConfig {
+                fan_curves: curves,
+                ..ThermalConfig::default()
+            },
+            ..Config::default()
+        };
+        config.save_to(&path).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        let fc: FileConfig = toml::from_str(&content).unwrap();
+        assert_eq!(fc.thermal.unwrap().fan_curves, Some(config.thermal.fan_curves));
+    }
+
+    /// `[[thermal.mitigation]]` steps and the protected list survive a save/load roundtrip.
+    #[test]
+    fn mitigation_roundtrip() {
+        let toml_str = r#"
+            [thermal]
+            mitigation_protected = ["postgres", "sshd"]
+
+            [[thermal.mitigation]]
+            action = "renice"
+
+            [[thermal.mitigation]]
+            action = "cpu_quota"
+            top = 5
+            quota = 25
+            hold = "2m"
+        "#;
+        let fc: FileConfig = toml::from_str(toml_str).unwrap();
+        let t = fc.thermal.unwrap();
+        let steps = t.mitigation.unwrap();
+        assert_eq!(steps[0].top, DEFAULT_MITIGATION_TOP);
+        assert_eq!(steps[1].quota, Some(25.0));
+        assert_eq!(steps[1].hold, Some(RuleValue::Text("2m".into())));
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        let config = Config {
+            thermal: ThermalConfig {
+                mitigation_protected: t.mitigation_protected.unwrap(),
+                mitigation: steps,
+                ..ThermalConfig::default()
+            },
+            ..Config::default()
+        };
+        config.save_to(&path).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        let fc: FileConfig = toml::from_str(&content).unwrap();
+        let t = fc.thermal.unwrap();
+        assert_eq!(t.mitigation, Some(config.thermal.mitigation));
+        assert_eq!(t.mitigation_protected, Some(vec!["postgres".into(), "sshd".into()]));
+    }
+
+    /// `[[thermal.shutdown_actions]]` and `shutdown_dry_run` survive a save/load roundtrip.
+    #[test]
+    fn shutdown_actions_roundtrip() {
+        let toml_str = r#"
+            [thermal]
+            shutdown_dry_run = true
+
+            [[thermal.shutdown_actions]]
+            action = "stop_service"
+            service = "postgresql"
+            timeout = "20s"
+
+            [[thermal.shutdown_actions]]
+            action = "poweroff"
+        "#;
+        let fc: FileConfig = toml::from_str(toml_str).unwrap();
+        let t = fc.thermal.unwrap();
+        assert_eq!(t.shutdown_dry_run, Some(true));
+        let actions = t.shutdown_actions.unwrap();
+        assert_eq!(actions[0].service.as_deref(), Some("postgresql"));
+        assert_eq!(actions[1].timeout, None);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        let config = Config {
+            thermal: ThermalConfig {
+                shutdown_dry_run: true,
+                shutdown_actions: actions,
+                ..ThermalConfig::default()
+            },
+            ..Config::default()
+        };
+        config.save_to(&path).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        let fc: FileConfig = toml::from_str(&content).unwrap();
+        let t = fc.thermal.unwrap();
+        assert_eq!(t.shutdown_dry_run, Some(true));
+        assert_eq!(t.shutdown_actions, Some(config.thermal.shutdown_actions));
+    }
+
+    /// `[[thermal.thresholds]]` and `[thermal.sensor_aliases]` survive a save/load roundtrip.
+    #[test]
+    fn thermal_thresholds_roundtrip() {
+        let toml_str = r#"
+            [thermal]
+            sensor_aliases = { "Samsung SSD 980 PRO" = "scratch-nvme" }
+
+            [[thermal.thresholds]]
+            category = "ssd"
+            warning = 70
+            critical = 80
+
+            [[thermal.thresholds]]
+            sensor = "scratch-nvme"
+            critical = 75
+        "#;
+        let fc: FileConfig = toml::from_str(toml_str).unwrap();
+        let t = fc.thermal.unwrap();
+        let thresholds = t.thresholds.unwrap();
+        assert_eq!(thresholds[0].category.as_deref(), Some("ssd"));
+        assert_eq!(thresholds[1].critical, Some(75.0));
+        let aliases = t.sensor_aliases.unwrap();
+        assert_eq!(aliases["Samsung SSD 980 PRO"], "scratch-nvme");
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        let config = Config {
+            thermal: ThermalConfig {
+                thresholds,
+                sensor_aliases: aliases,
+                ..ThermalConfig::default()
+            },
+            ..Config::default()
+        };
+        config.save_to(&path).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        let fc: FileConfig = toml::from_str(&content).unwrap();
+        let t = fc.thermal.unwrap();
+        assert_eq!(t.thresholds, Some(config.thermal.thresholds));
+        assert_eq!(t.sensor_aliases, Some(config.thermal.sensor_aliases));
+    }
+
+    /// `[[maintenance]]` tables parse and are written back unchanged.
+    #[test]
+    fn maintenance_windows_roundtrip() {
+        let toml_str = r#"
+            [[maintenance]]
+            name = "nightly-backup"
+            schedule = "0 2 * * *"
+            duration = "1h"
+            category = "disk"
+            process = "pg_dump*"
+        "#;
+        let fc: FileConfig = toml::from_str(toml_str).unwrap();
+        let windows = fc.maintenance.unwrap();
+        assert_eq!(windows.len(), 1);
+        assert_eq!(windows[0].schedule, "0 2 * * *");
+        assert_eq!(windows[0].duration, RuleValue::Text("1h".into()));
+        assert_eq!(windows[0].severity, None);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        let config = Config {
+            maintenance: windows,
+            ..Config::default()
+        };
+        config.save_to(&path).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        let fc: FileConfig = toml::from_str(&content).unwrap();
+        assert_eq!(fc.maintenance, Some(config.maintenance));
+    }
 }
@@ -49,6 +49,8 @@ pub const PROCESS_TABLE_ROW_START: u16 = 7;
 pub const PAGE_SIZE: usize = 20;
 /// Scroll step for PageUp/PageDown in detail popup.
 pub const DETAIL_PAGE_STEP: usize = 10;
+/// Most GPUs listed in the dashboard GPU panel (one line each).
+pub const MAX_DASHBOARD_GPU_ROWS: u16 = 6;
 
 // ── Process Management ────────────────────────────────────────────
 /// Minimum nice value (highest priority).
@@ -57,6 +59,14 @@ pub const NICE_MIN: i32 = -20;
 pub const NICE_MAX: i32 = 19;
 /// Nice value adjustment step for Up/Down arrows.
 pub const NICE_STEP: i32 = 5;
+/// CPU cap adjustment step (% of one CPU) in the limit dialog.
+pub const LIMIT_CPU_STEP_PCT: u32 = 10;
+/// Memory cap adjustment step (MiB) in the limit dialog.
+pub const LIMIT_MEMORY_STEP_MIB: u64 = 256;
+/// I/O cap adjustment step (MiB/s) in the limit dialog.
+pub const LIMIT_IO_STEP_MIB: u64 = 10;
+/// CPU cap (% of one CPU) suggested for CPU hogs by the contention report.
+pub const SUGGESTED_LIMIT_CPU_PCT: u32 = 50;
 /// Default signal picker selection index (SIGTERM).
 pub const DEFAULT_SIGNAL_INDEX: usize = 6;
 /// Maximum file descriptors to sample in process detail.
@@ -86,6 +96,12 @@ pub const DEFAULT_CPU_CRITICAL_PCT: f32 = 90.0;
 pub const DEFAULT_SYS_MEM_WARNING_PCT: f32 = 75.0;
 /// Default system memory critical (percent).
 pub const DEFAULT_SYS_MEM_CRITICAL_PCT: f32 = 90.0;
+/// Default per-process GPU utilization for a GPU-hog alert (percent).
+pub const DEFAULT_GPU_WARNING_PCT: f32 = 90.0;
+/// Default share of total VRAM one process may hold before a GPU-hog alert (percent).
+pub const DEFAULT_GPU_VRAM_WARNING_PCT: f32 = 80.0;
+/// Default per-process network rate (received + sent) for a network-hog alert (bytes/s).
+pub const DEFAULT_NET_WARNING_BYTES_PER_SEC: u64 = 50 * 1024 * 1024;
 /// Minimum samples before memory leak detection triggers.
 pub const LEAK_MIN_SAMPLES: usize = 10;
 /// Growth factor to consider a memory leak (1.2 = 20% growth).
@@ -96,6 +112,8 @@ pub const LEAK_MIN_MEMORY_BYTES: u64 = 100 * 1024 * 1024;
 pub const LEAK_ALERT_THRESHOLD_PCT: f64 = 20.0;
 /// Disk I/O threshold for high disk I/O alert (bytes).
 pub const HIGH_DISK_IO_THRESHOLD: u64 = 500 * 1024 * 1024;
+/// Seconds a firing alert's condition must stay clear before it is resolved.
+pub const ALERT_RESOLVE_SECS: u64 = 10;
 
 // ── Usage Color Thresholds ────────────────────────────────────────
 /// Usage percentage above which color is "critical".
@@ -110,6 +128,8 @@ pub const TEMP_CRITICAL_C: f32 = 90.0;
 pub const TEMP_HIGH_C: f32 = 75.0;
 /// Temperature above which color is "mid" (Celsius).
 pub const TEMP_MID_C: f32 = 60.0;
+/// How far below a sensor's warning threshold its color turns "mid" (Celsius).
+pub const TEMP_APPROACH_MARGIN_C: f32 = 10.0;
 
 // ── Thermal Monitoring ─────────────────────────────────────────────
 /// Default LibreHardwareMonitor HTTP JSON URL.
@@ -134,6 +154,40 @@ pub const DEFAULT_THERMAL_EMERGENCY_C: f32 = 100.0;
 pub const DEFAULT_THERMAL_SUSTAINED_SECS: u64 = 30;
 /// Thermal history ring buffer capacity (for sparklines).
 pub const THERMAL_HISTORY_CAPACITY: usize = 120;
+/// Rate of rise (°C per minute) flagged as a hot spell in thermal history.
+pub const THERMAL_RISE_WARN_C_PER_MIN: f32 = 5.0;
+/// Processes listed when correlating a thermal peak with workload.
+pub const THERMAL_HISTORY_TOP_PROCESSES: usize = 3;
+
+// ── Process Diff ──────────────────────────────────────────────────
+/// Processes read from each process snapshot being compared.
+pub const PROCESS_DIFF_SNAPSHOT_LIMIT: usize = 200;
+/// Memory growth (MiB) a process diff reports.
+pub const PROCESS_DIFF_MEM_GROWTH_MIB: u64 = 50;
+/// Memory growth (MiB) a process diff flags as a warning.
+pub const PROCESS_DIFF_MEM_WARN_MIB: u64 = 1024;
+/// CPU share change (percentage points of one core) a process diff reports.
+pub const PROCESS_DIFF_CPU_CHANGE_PCT: f32 = 20.0;
+/// CPU share rise (percentage points) a process diff flags as a warning.
+pub const PROCESS_DIFF_CPU_WARN_PCT: f32 = 50.0;
+/// Processes named per finding before "and N more".
+pub const PROCESS_DIFF_LIST_MAX: usize = 8;
+/// Look-back window (minutes) of the process diff added to AI context.
+pub const PROCESS_DIFF_CONTEXT_MINUTES: i64 = 60;
+
+// ── Historical Replay ─────────────────────────────────────────────
+/// Process rows read for a replayed frame (every row of one snapshot).
+pub const REPLAY_PROCESS_LIMIT: usize = 500;
+/// Look-back window (minutes) of alert events shown in a replayed frame.
+pub const REPLAY_ALERT_WINDOW_MINUTES: i64 = 60;
+/// Jump (minutes) of the replay `<` / `>` keys.
+pub const REPLAY_JUMP_MINUTES: i64 = 10;
+/// Playback speeds (replayed seconds per wall-clock second).
+pub const REPLAY_SPEEDS: &[u32] = &[1, 10, 60, 300, 900];
+/// Index into `REPLAY_SPEEDS` a replay starts at.
+pub const REPLAY_DEFAULT_SPEED_IDX: usize = 2;
+/// Wall-clock interval (ms) between frames while playing.
+pub const REPLAY_FRAME_MS: u64 = 500;
 
 // ── Market data (Binance) ─────────────────────────────────────────
 /// Default market data polling interval (seconds).
@@ -237,12 +291,53 @@ pub const ENV_TELEGRAM_CHAT_ID: &str = "SENTINEL_TELEGRAM_CHAT_ID";
 pub const DEFAULT_TELEGRAM_DIGEST_SECS: u64 = 0;
 /// Maximum alerts batched per digest message.
 pub const TELEGRAM_DIGEST_MAX_ALERTS: usize = 20;
+/// Long-poll timeout for the bot's `getUpdates` requests (seconds).
+pub const TELEGRAM_POLL_TIMEOUT_SECS: u64 = 30;
+/// Delay before polling again after a failed `getUpdates` (seconds).
+pub const TELEGRAM_POLL_RETRY_SECS: u64 = 5;
+/// Commands older than this when first seen are ignored (seconds), so a
+/// restart doesn't replay a backlog of stale commands.
+pub const TELEGRAM_COMMAND_MAX_AGE_SECS: i64 = 120;
+/// How long a `/kill` confirmation button stays valid (seconds).
+pub const TELEGRAM_KILL_CONFIRM_SECS: u64 = 60;
+/// Bot replies are truncated to this many characters (Telegram allows 4096).
+pub const TELEGRAM_REPLY_MAX_CHARS: usize = 3800;
+/// Processes listed by the bot's `/top` command.
+pub const TELEGRAM_TOP_COUNT: usize = 10;
+/// Escalation progress is written to the event store at least this often
+/// while an alert keeps firing.
+pub const ESCALATION_TOUCH_SECS: u64 = 60;
+/// Persisted escalation progress older than this is dropped at startup
+/// (the alert is treated as a new incident).
+pub const ESCALATION_STATE_GRACE_SECS: u64 = 600;
 /// Maximum worker entries shown in a grouped alert before truncation.
 pub const MAX_WORKER_DISPLAY: usize = 8;
 /// Multiplier threshold for "What's happening" severity descriptions.
 /// If combined usage exceeds threshold * this factor, it's considered "extreme".
 pub const WORKER_EXTREME_MULTIPLIER: f64 = 3.0;
 
+// ── Webhook Notifications ─────────────────────────────────────────
+/// Minimum interval between webhook posts per (category, app) pair (seconds).
+pub const WEBHOOK_RATE_LIMIT_SECS: u64 = 300;
+/// Delivery attempts per payload (first try + retries).
+pub const WEBHOOK_MAX_ATTEMPTS: u32 = 4;
+/// Delay before the first retry; doubles on each further retry (milliseconds).
+pub const WEBHOOK_RETRY_BASE_MS: u64 = 1000;
+/// HTTP timeout per webhook request (seconds).
+pub const WEBHOOK_TIMEOUT_SECS: u64 = 10;
+/// Header carrying `sha256=<hex HMAC of the body>` when a secret is set.
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Sentinel-Signature-256";
+
+// ── Chat Notifications (Slack / Mattermost / Discord) ─────────────
+/// Minimum interval between chat messages per (category, app) pair (seconds).
+pub const CHAT_RATE_LIMIT_SECS: u64 = 300;
+/// Maximum alerts batched per chat digest message.
+pub const CHAT_DIGEST_MAX_ALERTS: usize = 20;
+/// Alerts listed individually in a chat digest before "... and N more".
+pub const CHAT_DIGEST_DETAIL_LINES: usize = 10;
+/// Embed/attachment color for resolved alerts (green).
+pub const CHAT_RESOLVED_COLOR: u32 = 0x2EB67D;
+
 // ── Security Dashboard ────────────────────────────────────────────
 /// Security data refresh interval (ticks). At 1s/tick this is ~5s.
 pub const SECURITY_REFRESH_TICKS: u64 = 5;
@@ -318,6 +413,50 @@ pub const DEFAULT_SHUTDOWN_SCHEDULE_START: u8 = 0;
 pub const DEFAULT_SHUTDOWN_SCHEDULE_END: u8 = 24;
 /// Grace period before actual shutdown (seconds).
 pub const SHUTDOWN_GRACE_PERIOD_SECS: u64 = 30;
+/// Environment variable: force shutdown actions into dry-run (log only).
+pub const ENV_SHUTDOWN_DRY_RUN: &str = "SENTINEL_SHUTDOWN_DRY_RUN";
+/// Default timeout for one shutdown action (seconds).
+pub const DEFAULT_SHUTDOWN_ACTION_TIMEOUT_SECS: u64 = 30;
+
+// ── Thermal Mitigation ────────────────────────────────────────────
+/// Default wait (seconds) at emergency after a mitigation step before the next one.
+pub const DEFAULT_MITIGATION_HOLD_SECS: u64 = 60;
+/// Default number of top CPU consumers (or containers) a mitigation step targets.
+pub const DEFAULT_MITIGATION_TOP: usize = 3;
+/// Default nice value for `renice` mitigation steps.
+pub const DEFAULT_MITIGATION_NICE: i32 = 19;
+/// Default CPU cap (% of one CPU) for the throttle group of `cpu_quota` steps.
+pub const DEFAULT_MITIGATION_QUOTA_PCT: f32 = 50.0;
+/// Processes below this CPU usage (%) are never mitigation targets.
+pub const MITIGATION_MIN_CPU_PCT: f32 = 5.0;
+/// Process names (globs) never throttled, stopped or paused.
+pub const DEFAULT_MITIGATION_PROTECTED: &[&str] = &[
+    "sentinel*", "systemd*", "init", "sshd", "dockerd", "containerd*", "Xorg", "Xwayland",
+    "gnome-shell", "kwin*", "sway", "pipewire*", "pulseaudio",
+];
+/// cgroup v2 mount point.
+pub const CGROUP_ROOT: &str = "/sys/fs/cgroup";
+/// cgroup (under [`CGROUP_ROOT`]) that `cpu_quota` steps move processes into.
+pub const MITIGATION_CGROUP_NAME: &str = "sentinel-thermal";
+/// cgroup (under [`CGROUP_ROOT`]) holding the groups of the limit dialog.
+pub const LIMIT_CGROUP_NAME: &str = "sentinel-limits";
+/// `cpu.max` period (microseconds) for the throttle and limit cgroups.
+pub const CGROUP_CPU_PERIOD_US: u64 = 100_000;
+
+// ── Fan Control ───────────────────────────────────────────────────
+/// Environment variable: second gate for writing `pwm*` (with `fan_control_enabled`).
+pub const ENV_FAN_CONTROL: &str = "SENTINEL_FAN_CONTROL";
+/// Default fan curve hysteresis: how far (°C) a temperature must fall before
+/// the duty is lowered again.
+pub const DEFAULT_FAN_HYSTERESIS_C: f32 = 3.0;
+/// Duty (%) written when a curve's sensor can't be read.
+pub const FAN_FALLBACK_DUTY_PCT: f32 = 100.0;
+/// Points for a curve added in the curve editor (`[°C, duty %]`).
+pub const DEFAULT_FAN_CURVE: &[[f32; 2]] = &[[40.0, 30.0], [60.0, 50.0], [75.0, 80.0], [85.0, 100.0]];
+/// Temperature step (°C) for the curve editor's ←/→ keys.
+pub const FAN_EDITOR_TEMP_STEP_C: f32 = 1.0;
+/// Duty step (%) for the curve editor's +/- keys.
+pub const FAN_EDITOR_DUTY_STEP_PCT: f32 = 5.0;
 
 // ── AI / Claude API ───────────────────────────────────────────────
 /// Premium model — used only for interactive chat and process questions.
@@ -355,6 +494,8 @@ pub const CONTEXT_INITIAL_CAPACITY: usize = 8192;
 pub const CONTEXT_TOP_CPU_COUNT: usize = 25;
 /// Top processes by memory to include in AI context.
 pub const CONTEXT_TOP_MEM_COUNT: usize = 15;
+/// Top processes by GPU memory / utilization to include in AI context.
+pub const CONTEXT_TOP_GPU_COUNT: usize = 10;
 /// Maximum process groups in AI context.
 pub const CONTEXT_MAX_GROUPS: usize = 20;
 /// Maximum alerts to include in AI context.
@@ -369,6 +510,8 @@ pub const CONTEXT_MAX_CMD_LEN: usize = 120;
 pub const CONTEXT_LIGHT_TOP_CPU: usize = 10;
 /// Top processes by memory in light context.
 pub const CONTEXT_LIGHT_TOP_MEM: usize = 5;
+/// Top GPU processes in light context.
+pub const CONTEXT_LIGHT_TOP_GPU: usize = 3;
 /// Maximum process groups in light context.
 pub const CONTEXT_LIGHT_MAX_GROUPS: usize = 5;
 /// Maximum alerts in light context.
@@ -391,13 +534,26 @@ pub const MIN_DISK_SIZE_BYTES: u64 = 1_000_000_000;
 pub const MAX_HWMON_SENSORS: u32 = 32;
 /// Maximum thermal zone index to probe.
 pub const MAX_THERMAL_ZONES: u32 = 10;
+/// sysfs / lm-sensors temperatures at or above this are treated as
+/// disconnected inputs (Super I/O chips report 127 °C or -128 °C for unused
+/// pins).
+pub const SYSFS_MAX_PLAUSIBLE_TEMP_C: f32 = 127.0;
+/// Timeout for one `sensors -j` run (seconds).
+pub const LM_SENSORS_TIMEOUT_SECS: u64 = 3;
 /// Disk sector size (bytes) for I/O calculation.
 pub const SECTOR_SIZE_BYTES: u64 = 512;
 /// Minimum fields expected in a /proc/diskstats line.
 pub const MIN_DISKSTATS_FIELDS: usize = 14;
+/// PCI vendor IDs (`/sys/class/drm/card*/device/vendor`).
+pub const PCI_VENDOR_AMD: &str = "0x1002";
+pub const PCI_VENDOR_INTEL: &str = "0x8086";
 /// Docker container ID short display length.
 pub const DOCKER_SHORT_ID_LEN: usize = 12;
 
+// ── Daemon / Attach ───────────────────────────────────────────────
+/// How long `sentinel attach` waits for the daemon's first snapshot.
+pub const ATTACH_FIRST_FRAME_TIMEOUT_SECS: u64 = 5;
+
 // ── Popup Dimensions ──────────────────────────────────────────────
 /// Process detail popup max width.
 pub const DETAIL_POPUP_WIDTH: u16 = 80;
@@ -459,6 +615,11 @@ pub fn data_dir() -> PathBuf {
     home_dir().join(".local").join("share").join("sentinel")
 }
 
+/// Returns `~/.local/share/sentinel/sentinel.sock` (daemon ↔ attach socket).
+pub fn ipc_socket_path() -> PathBuf {
+    data_dir().join("sentinel.sock")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -9,13 +9,27 @@
 //! Each diagnostic function returns a `DiagnosticReport` with findings and
 //! optional suggested actions.
 
-use crate::models::{Alert, ProcessInfo, SystemSnapshot};
-use crate::store::EventStore;
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
+use serde::Serialize;
+
+use crate::alerts::rules::parse_duration;
+use crate::config::RuleValue;
+use crate::constants::{
+    LIMIT_MEMORY_STEP_MIB, PROCESS_DIFF_CONTEXT_MINUTES, PROCESS_DIFF_CPU_CHANGE_PCT,
+    PROCESS_DIFF_CPU_WARN_PCT, PROCESS_DIFF_LIST_MAX, PROCESS_DIFF_MEM_GROWTH_MIB,
+    PROCESS_DIFF_MEM_WARN_MIB, PROCESS_DIFF_SNAPSHOT_LIMIT, SUGGESTED_LIMIT_CPU_PCT,
+    THERMAL_HISTORY_TOP_PROCESSES, THERMAL_RISE_WARN_C_PER_MIN,
+};
+use crate::models::{Alert, ProcessInfo, ResourceLimits, SystemSnapshot};
+use crate::store::{format_age_ms, EventStore, ProcessSnapshotRow};
 
 // ── Finding types ─────────────────────────────────────────────────
 
 /// Severity of a diagnostic finding.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum FindingSeverity {
     Info,
     Warning,
@@ -23,7 +37,7 @@ pub enum FindingSeverity {
 }
 
 /// A single diagnostic finding.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct Finding {
     pub severity: FindingSeverity,
     pub title: String,
@@ -33,7 +47,8 @@ pub struct Finding {
 }
 
 /// An action the user can confirm and execute.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum SuggestedAction {
     /// Kill a process: (pid, signal_name)
     KillProcess {
@@ -43,6 +58,12 @@ pub enum SuggestedAction {
     },
     /// Renice a process
     ReniceProcess { pid: u32, name: String, nice: i32 },
+    /// Move a process into a Sentinel-managed cgroup with resource caps
+    LimitProcess {
+        pid: u32,
+        name: String,
+        limits: ResourceLimits,
+    },
     /// Free a port by killing the owning process
     FreePort { port: u16, pid: u32, name: String },
     /// Clean up a directory
@@ -52,7 +73,7 @@ pub enum SuggestedAction {
 }
 
 /// Complete diagnostic report from an analysis.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct DiagnosticReport {
     pub title: String,
     pub findings: Vec<Finding>,
@@ -114,6 +135,9 @@ impl DiagnosticReport {
                     SuggestedAction::ReniceProcess { pid, name, nice } => {
                         format!("  → Set nice {} for PID {} ({})", nice, pid, name)
                     }
+                    SuggestedAction::LimitProcess { pid, name, limits } => {
+                        format!("  → Limit PID {} ({}) to {}", pid, name, limits.describe())
+                    }
                     SuggestedAction::FreePort { port, pid, name } => {
                         format!("  → Kill PID {} ({}) to free port {}", pid, name, port)
                     }
@@ -132,6 +156,11 @@ impl DiagnosticReport {
         lines.join("\n")
     }
 
+    /// Render the report as pretty-printed JSON (for `sentinel report --format json`).
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_else(|_| "{}".to_string())
+    }
+
     /// Return the highest severity in the report.
     pub fn max_severity(&self) -> Option<FindingSeverity> {
         self.findings.iter().map(|f| f.severity).max()
@@ -247,6 +276,15 @@ impl DiagnosticEngine {
                     },
                 );
             }
+            // Under pressure, offer to cap the worst one rather than kill it
+            if cpu >= 70.0 {
+                let p = cpu_hogs[0];
+                let limits = ResourceLimits {
+                    cpu_percent: Some(SUGGESTED_LIMIT_CPU_PCT),
+                    ..ResourceLimits::default()
+                };
+                push_limit_suggestion(&mut report, p, limits);
+            }
         } else if cpu >= 50.0 {
             // No single hog, but system is busy — show top 3
             let summary: Vec<String> = top_cpu
@@ -296,6 +334,17 @@ impl DiagnosticEngine {
                 },
             );
         }
+        if mem_pct >= 75.0 {
+            if let Some(p) = mem_hogs.first() {
+                // Cap just above current usage so it stops growing (or is OOM-killed)
+                let step = LIMIT_MEMORY_STEP_MIB * 1024 * 1024;
+                let limits = ResourceLimits {
+                    memory_bytes: Some((p.memory_bytes / step + 1) * step),
+                    ..ResourceLimits::default()
+                };
+                push_limit_suggestion(&mut report, p, limits);
+            }
+        }
 
         // Zombie processes (filtered by parent ignore list)
         let pid_names: std::collections::HashMap<u32, &str> =
@@ -373,6 +422,7 @@ impl DiagnosticEngine {
         let starts = counts.get("process_start").copied().unwrap_or(0);
         let exits = counts.get("process_exit").copied().unwrap_or(0);
         let alerts = counts.get("alert").copied().unwrap_or(0);
+        let resolved = counts.get("alert_resolved").copied().unwrap_or(0);
         let port_binds = counts.get("port_bind").copied().unwrap_or(0);
         let port_releases = counts.get("port_release").copied().unwrap_or(0);
 
@@ -405,6 +455,13 @@ impl DiagnosticEngine {
                 "Check the Alerts tab for details",
             );
         }
+        if resolved > 0 {
+            report.push(
+                FindingSeverity::Info,
+                &format!("{} alert(s) resolved", resolved),
+                "",
+            );
+        }
 
         // Port changes
         if port_binds > 0 || port_releases > 0 {
@@ -676,6 +733,21 @@ impl DiagnosticEngine {
                     );
                 }
 
+                // Network trend
+                let peak_rx = history.iter().map(|h| h.net_rx).max().unwrap_or(0);
+                let peak_tx = history.iter().map(|h| h.net_tx).max().unwrap_or(0);
+                if peak_rx > 0 || peak_tx > 0 {
+                    report.push(
+                        FindingSeverity::Info,
+                        "Historical network traffic",
+                        &format!(
+                            "Peak RX: {}/s | Peak TX: {}/s",
+                            format_bytes(peak_rx),
+                            format_bytes(peak_tx),
+                        ),
+                    );
+                }
+
                 report.push(
                     FindingSeverity::Info,
                     "Tracking duration",
@@ -810,6 +882,253 @@ impl DiagnosticEngine {
         report
     }
 
+    // ── Thermal history ───────────────────────────────────────────
+
+    /// Per-sensor min/avg/max and rate of rise over the window, with the
+    /// heaviest processes at the hottest moment and the steepest rise.
+    pub fn thermal_history(
+        store: &EventStore,
+        minutes: u64,
+        sensor: Option<&str>,
+    ) -> DiagnosticReport {
+        let mut report =
+            DiagnosticReport::new(&format!("Thermal History: Last {} minutes", minutes));
+        let now = crate::store::now_epoch_ms_pub();
+        let since_ms = now - (minutes as i64 * 60 * 1000);
+
+        let stats = match store.thermal_stats(since_ms) {
+            Ok(s) => s,
+            Err(_) => {
+                report.push(FindingSeverity::Warning, "Could not read event store", "");
+                return report;
+            }
+        };
+        let stats: Vec<_> = stats
+            .into_iter()
+            .filter(|s| sensor.is_none_or(|name| s.sensor.eq_ignore_ascii_case(name)))
+            .collect();
+
+        if stats.is_empty() {
+            let title = match sensor {
+                Some(name) => format!("No readings for sensor '{}'", name),
+                None => "No thermal readings recorded".to_string(),
+            };
+            report.push(
+                FindingSeverity::Info,
+                &title,
+                "Readings are stored on every thermal poll while a thermal source is available",
+            );
+            return report;
+        }
+
+        for s in &stats {
+            let unit = if s.category == "fan" { " RPM" } else { "°C" };
+            let steep = s.category != "fan"
+                && s.max_rise_per_min
+                    .is_some_and(|r| r >= THERMAL_RISE_WARN_C_PER_MIN);
+            let mut detail = format!(
+                "{} samples | peak {}",
+                s.samples,
+                format_age_ms(now - s.peak_ts)
+            );
+            if let Some(trend) = s.trend_per_min {
+                detail.push_str(&format!(" | trend {:+.1}{}/min", trend, unit));
+            }
+            if let (Some(rise), Some(ts)) = (s.max_rise_per_min, s.max_rise_ts) {
+                detail.push_str(&format!(
+                    " | steepest rise {:+.1}{}/min ({})",
+                    rise,
+                    unit,
+                    format_age_ms(now - ts)
+                ));
+            }
+            report.push(
+                if steep {
+                    FindingSeverity::Warning
+                } else {
+                    FindingSeverity::Info
+                },
+                &format!(
+                    "{} [{}]: min {:.0}{unit} / avg {:.0}{unit} / max {:.0}{unit}",
+                    s.sensor, s.category, s.min, s.avg, s.max
+                ),
+                &detail,
+            );
+        }
+
+        // Correlate the hottest moment and the steepest rise with workload
+        let temps = || stats.iter().filter(|s| s.category != "fan");
+        let hottest = temps().max_by(|a, b| a.max.total_cmp(&b.max));
+        let steepest = temps()
+            .filter(|s| s.max_rise_per_min.is_some())
+            .max_by(|a, b| {
+                a.max_rise_per_min
+                    .unwrap()
+                    .total_cmp(&b.max_rise_per_min.unwrap())
+            });
+        if let Some(s) = hottest {
+            push_workload_at(
+                &mut report,
+                store,
+                s.peak_ts,
+                &format!("Workload at peak: {} {:.0}°C", s.sensor, s.max),
+            );
+        }
+        if let Some(s) = steepest {
+            if let (Some(rise), Some(ts)) = (s.max_rise_per_min, s.max_rise_ts) {
+                if rise >= THERMAL_RISE_WARN_C_PER_MIN && hottest.is_none_or(|h| h.peak_ts != ts) {
+                    push_workload_at(
+                        &mut report,
+                        store,
+                        ts,
+                        &format!("Workload during rise: {} {:+.1}°C/min", s.sensor, rise),
+                    );
+                }
+            }
+        }
+
+        report
+    }
+
+    // ── Process diff ──────────────────────────────────────────────
+
+    /// "What changed since then?" — Compare the process snapshots closest
+    /// to `from_ms` and `to_ms`: processes that appeared or disappeared, grew
+    /// in memory, or changed CPU share. Snapshots only hold the top CPU /
+    /// memory / network processes, so "appeared" can also mean "entered the
+    /// top list".
+    pub fn process_diff(store: &EventStore, from_ms: i64, to_ms: i64) -> DiagnosticReport {
+        let read = |ts_ms| store.query_top_processes_at(ts_ms, PROCESS_DIFF_SNAPSHOT_LIMIT);
+        let (before, after) = match (read(from_ms), read(to_ms)) {
+            (Ok(before), Ok(after)) => (before, after),
+            _ => {
+                let mut report = DiagnosticReport::new("Process Diff");
+                report.push(FindingSeverity::Warning, "Could not read event store", "");
+                return report;
+            }
+        };
+        let (Some(first), Some(last)) = (before.first(), after.first()) else {
+            let mut report = DiagnosticReport::new("Process Diff");
+            report.push(
+                FindingSeverity::Info,
+                "No process snapshots recorded",
+                "The top CPU / memory processes are stored on every refresh",
+            );
+            return report;
+        };
+        let mut report = DiagnosticReport::new(&format!(
+            "Process Diff: {} → {}",
+            format_snapshot_ts(first.ts),
+            format_snapshot_ts(last.ts)
+        ));
+        if first.ts == last.ts {
+            report.push(
+                FindingSeverity::Info,
+                "Both times resolve to the same snapshot",
+                "Pick times further apart, or wait for more snapshots",
+            );
+            return report;
+        }
+
+        // A PID reused under another name counts as an exit and a start
+        let key = |p: &ProcessSnapshotRow| (p.pid, p.name.clone());
+        let before_by_key: HashMap<_, &ProcessSnapshotRow> =
+            before.iter().map(|p| (key(p), p)).collect();
+        let after_keys: HashSet<_> = after.iter().map(key).collect();
+
+        let appeared: Vec<&ProcessSnapshotRow> = after
+            .iter()
+            .filter(|p| !before_by_key.contains_key(&key(p)))
+            .collect();
+        let disappeared: Vec<&ProcessSnapshotRow> = before
+            .iter()
+            .filter(|p| !after_keys.contains(&key(p)))
+            .collect();
+        if !appeared.is_empty() {
+            report.push(
+                FindingSeverity::Info,
+                &format!("{} process(es) appeared", appeared.len()),
+                &list_snapshot_processes(&appeared),
+            );
+        }
+        if !disappeared.is_empty() {
+            report.push(
+                FindingSeverity::Info,
+                &format!("{} process(es) disappeared", disappeared.len()),
+                &list_snapshot_processes(&disappeared),
+            );
+        }
+
+        let common: Vec<(&ProcessSnapshotRow, &ProcessSnapshotRow)> = after
+            .iter()
+            .filter_map(|a| before_by_key.get(&key(a)).map(|b| (*b, a)))
+            .collect();
+
+        // Memory growth, largest first
+        let mut grown: Vec<_> = common
+            .iter()
+            .filter(|(b, a)| a.mem_bytes >= b.mem_bytes + (PROCESS_DIFF_MEM_GROWTH_MIB << 20))
+            .collect();
+        grown.sort_by_key(|(b, a)| std::cmp::Reverse(a.mem_bytes - b.mem_bytes));
+        for (b, a) in grown.into_iter().take(PROCESS_DIFF_LIST_MAX) {
+            let growth = a.mem_bytes - b.mem_bytes;
+            report.push(
+                if growth >= PROCESS_DIFF_MEM_WARN_MIB << 20 {
+                    FindingSeverity::Warning
+                } else {
+                    FindingSeverity::Info
+                },
+                &format!(
+                    "{} (PID {}) grew by {}",
+                    a.name,
+                    a.pid,
+                    format_bytes(growth)
+                ),
+                &format!(
+                    "Memory {} → {}",
+                    format_bytes(b.mem_bytes),
+                    format_bytes(a.mem_bytes)
+                ),
+            );
+        }
+
+        // CPU share changes, largest first
+        let mut shifted: Vec<_> = common
+            .iter()
+            .filter(|(b, a)| (a.cpu - b.cpu).abs() >= PROCESS_DIFF_CPU_CHANGE_PCT)
+            .collect();
+        shifted.sort_by(|(b1, a1), (b2, a2)| {
+            (a2.cpu - b2.cpu).abs().total_cmp(&(a1.cpu - b1.cpu).abs())
+        });
+        for (b, a) in shifted.into_iter().take(PROCESS_DIFF_LIST_MAX) {
+            let change = a.cpu - b.cpu;
+            report.push(
+                if change >= PROCESS_DIFF_CPU_WARN_PCT {
+                    FindingSeverity::Warning
+                } else {
+                    FindingSeverity::Info
+                },
+                &format!(
+                    "{} (PID {}) CPU {} by {:.0} points",
+                    a.name,
+                    a.pid,
+                    if change > 0.0 { "rose" } else { "fell" },
+                    change.abs()
+                ),
+                &format!("{:.0}% → {:.0}%", b.cpu, a.cpu),
+            );
+        }
+
+        if report.findings.is_empty() {
+            report.push(
+                FindingSeverity::Info,
+                "No significant changes",
+                &format!("{} processes in both snapshots", common.len()),
+            );
+        }
+        report
+    }
+
     // ── Disk usage analysis ───────────────────────────────────────
 
     /// Analyze disk usage and find cleanup candidates.
@@ -926,6 +1245,13 @@ impl DiagnosticEngine {
             sections.push(anomalies.to_text());
         }
 
+        // Process changes over the last hour
+        let now = crate::store::now_epoch_ms_pub();
+        let diff = Self::process_diff(store, now - PROCESS_DIFF_CONTEXT_MINUTES * 60 * 1000, now);
+        if diff.max_severity().unwrap_or(FindingSeverity::Info) >= FindingSeverity::Warning {
+            sections.push(diff.to_text());
+        }
+
         // Port listeners summary
         if let Ok(listeners) = store.query_current_listeners() {
             if !listeners.is_empty() {
@@ -956,6 +1282,144 @@ fn format_bytes(bytes: u64) -> String {
     crate::models::format_bytes(bytes)
 }
 
+/// Add the top CPU consumers recorded closest to `ts_ms` as one finding.
+fn push_workload_at(report: &mut DiagnosticReport, store: &EventStore, ts_ms: i64, title: &str) {
+    let Ok(procs) = store.query_top_processes_at(ts_ms, THERMAL_HISTORY_TOP_PROCESSES) else {
+        return;
+    };
+    if procs.is_empty() {
+        return;
+    }
+    let detail = procs
+        .iter()
+        .map(|p| format!("{} (PID {}) {:.0}% CPU", p.name, p.pid, p.cpu))
+        .collect::<Vec<_>>()
+        .join(", ");
+    report.push(FindingSeverity::Info, title, &detail);
+}
+
+/// Local time of a snapshot for report titles.
+fn format_snapshot_ts(ts_ms: i64) -> String {
+    match Local.timestamp_millis_opt(ts_ms).single() {
+        Some(t) => t.format("%Y-%m-%d %H:%M:%S").to_string(),
+        None => ts_ms.to_string(),
+    }
+}
+
+/// `name (PID n) 12% CPU, 1.0 GiB; ...`, capped at `PROCESS_DIFF_LIST_MAX`.
+fn list_snapshot_processes(procs: &[&ProcessSnapshotRow]) -> String {
+    let mut parts: Vec<String> = procs
+        .iter()
+        .take(PROCESS_DIFF_LIST_MAX)
+        .map(|p| {
+            format!(
+                "{} (PID {}) {:.0}% CPU, {}",
+                p.name,
+                p.pid,
+                p.cpu,
+                format_bytes(p.mem_bytes)
+            )
+        })
+        .collect();
+    if procs.len() > PROCESS_DIFF_LIST_MAX {
+        parts.push(format!("and {} more", procs.len() - PROCESS_DIFF_LIST_MAX));
+    }
+    parts.join("; ")
+}
+
+/// Parse a point in time for `diff` (epoch ms): `now`, an age such as `1h`
+/// or `30m` (ago), a clock time `14:30[:00]` (the latest one not in the
+/// future), or a local `2024-05-01T14:30[:00]` / `2024-05-01`.
+pub fn parse_time_arg(arg: &str, now: DateTime<Local>) -> Result<i64, String> {
+    let arg = arg.trim();
+    let invalid = || {
+        format!(
+            "invalid time '{}' (use now, 1h, 14:30 or 2024-05-01T14:30)",
+            arg
+        )
+    };
+    if arg.eq_ignore_ascii_case("now") {
+        return Ok(now.timestamp_millis());
+    }
+    if arg.starts_with(|c: char| c.is_ascii_digit()) && !arg.contains([':', '-']) {
+        let age = parse_duration(&RuleValue::Text(arg.to_string())).map_err(|_| invalid())?;
+        return i64::try_from(age.as_millis())
+            .ok()
+            .and_then(|age| now.timestamp_millis().checked_sub(age))
+            .ok_or_else(invalid);
+    }
+    let local = |naive: NaiveDateTime| {
+        Local
+            .from_local_datetime(&naive)
+            .earliest()
+            .map(|t| t.timestamp_millis())
+            .ok_or_else(|| format!("'{}' does not exist in local time", arg))
+    };
+    let clock = |s: &str| {
+        NaiveTime::parse_from_str(s, "%H:%M:%S")
+            .or_else(|_| NaiveTime::parse_from_str(s, "%H:%M"))
+            .ok()
+    };
+    if let Some(time) = clock(arg) {
+        let today = now.date_naive().and_time(time);
+        let ts = local(today)?;
+        return if ts > now.timestamp_millis() {
+            local(today - chrono::Duration::days(1))
+        } else {
+            Ok(ts)
+        };
+    }
+    if let Some((date, time)) = arg.split_once('T') {
+        if let (Ok(date), Some(time)) = (NaiveDate::parse_from_str(date, "%Y-%m-%d"), clock(time)) {
+            return local(date.and_time(time));
+        }
+    } else if let Ok(date) = NaiveDate::parse_from_str(arg, "%Y-%m-%d") {
+        return local(date.and_time(NaiveTime::MIN));
+    }
+    Err(invalid())
+}
+
+/// Parse the `from` and `to` of a `diff` with [`parse_time_arg`]. A `from`
+/// later than `to` is an error rather than an inverted report.
+pub fn parse_time_range(from: &str, to: &str, now: DateTime<Local>) -> Result<(i64, i64), String> {
+    let (from_ms, to_ms) = (parse_time_arg(from, now)?, parse_time_arg(to, now)?);
+    if from_ms > to_ms {
+        return Err(format!(
+            "'{}' is later than '{}': give the earlier time first (e.g. diff {} {})",
+            from.trim(),
+            to.trim(),
+            to.trim(),
+            from.trim()
+        ));
+    }
+    Ok((from_ms, to_ms))
+}
+
+/// Suggest capping `p` with a cgroup limit instead of killing it.
+fn push_limit_suggestion(report: &mut DiagnosticReport, p: &ProcessInfo, limits: ResourceLimits) {
+    // `memory.max` is a hard cap: past it the kernel reclaims, then OOM-kills
+    let outcome = if limits.memory_bytes.is_some() {
+        "the kernel OOM-kills it if it grows past the memory cap"
+    } else {
+        "it keeps running"
+    };
+    report.push_with_action(
+        FindingSeverity::Info,
+        &format!("Cap {} instead of killing it", p.name),
+        &format!(
+            "Moves PID {} into its own cgroup limited to {}; {}",
+            p.pid,
+            limits.describe(),
+            outcome
+        ),
+        SuggestedAction::LimitProcess {
+            pid: p.pid,
+            name: p.name.clone(),
+            limits,
+        },
+    );
+}
+
 fn truncate_cmd(cmd: &str, max: usize) -> String {
     if cmd.len() <= max {
         cmd.to_string()
@@ -1047,7 +1511,8 @@ mod tests {
             networks: vec![],
             disks: vec![],
             cpu_temp: None,
-            gpu: None,
+            gpus: vec![],
+            cgroups: vec![],
             battery: None,
         }
     }
@@ -1067,6 +1532,10 @@ mod tests {
             start_time: 0,
             parent_pid: None,
             thread_count: None,
+            gpu_usage: 0.0,
+            gpu_memory_bytes: 0,
+            net_rx_bytes_per_sec: 0,
+            net_tx_bytes_per_sec: 0,
         }
     }
 
@@ -1102,6 +1571,27 @@ mod tests {
         assert_eq!(r.max_severity(), None);
     }
 
+    #[test]
+    fn report_to_json_includes_findings_and_actions() {
+        let mut r = DiagnosticReport::new("Test");
+        r.push_with_action(
+            FindingSeverity::Critical,
+            "Port 8080 busy",
+            "held by node",
+            SuggestedAction::FreePort {
+                port: 8080,
+                pid: 42,
+                name: "node".to_string(),
+            },
+        );
+        r.push(FindingSeverity::Info, "note", "");
+        let v: serde_json::Value = serde_json::from_str(&r.to_json()).unwrap();
+        assert_eq!(v["title"], "Test");
+        assert_eq!(v["findings"][0]["severity"], "critical");
+        assert_eq!(v["findings"][0]["action"]["free_port"]["port"], 8080);
+        assert!(v["findings"][1]["action"].is_null());
+    }
+
     // ── Resource contention ───────────────────────────────────────
 
     #[test]
@@ -1124,6 +1614,42 @@ mod tests {
         assert_eq!(report.max_severity(), Some(FindingSeverity::Critical));
     }
 
+    #[test]
+    fn contention_suggests_cgroup_limits_under_pressure() {
+        let limit_of = |report: &DiagnosticReport| {
+            report.findings.iter().find_map(|f| match &f.action {
+                Some(SuggestedAction::LimitProcess { pid, limits, .. }) => {
+                    Some((*pid, limits.clone()))
+                }
+                _ => None,
+            })
+        };
+
+        let sys = make_system(95.0, 4_000_000_000, 16_000_000_000);
+        let procs = vec![make_process(7, "hog", 80.0, 1024)];
+        let report = DiagnosticEngine::resource_contention(&sys, &procs, &[]);
+        let (pid, limits) = limit_of(&report).unwrap();
+        assert_eq!(pid, 7);
+        assert_eq!(limits.cpu_percent, Some(SUGGESTED_LIMIT_CPU_PCT));
+        assert!(report.to_text().contains("→ Limit PID 7 (hog) to CPU 50%"));
+
+        // Memory: capped at the next 256 MiB step above current usage
+        let sys = make_system(20.0, 15_000_000_000, 16_000_000_000);
+        let procs = vec![make_process(8, "big", 5.0, 3 << 30)];
+        let report = DiagnosticEngine::resource_contention(&sys, &procs, &[]);
+        let (_, limits) = limit_of(&report).unwrap();
+        assert_eq!(limits.memory_bytes, Some((3 << 30) + (256 << 20)));
+        assert!(report
+            .to_text()
+            .contains("OOM-kills it if it grows past the memory cap"));
+
+        // A busy process on a relaxed machine is left alone
+        let sys = make_system(60.0, 4_000_000_000, 16_000_000_000);
+        let procs = vec![make_process(7, "hog", 55.0, 1024)];
+        let report = DiagnosticEngine::resource_contention(&sys, &procs, &[]);
+        assert!(limit_of(&report).is_none());
+    }
+
     #[test]
     fn contention_high_memory() {
         let total = 16_000_000_000u64;
@@ -1244,6 +1770,125 @@ mod tests {
         assert!(report.to_text().contains("No anomalies"));
     }
 
+    // ── Thermal history ───────────────────────────────────────────
+
+    #[test]
+    fn thermal_history_empty_store() {
+        let store = EventStore::open(None).unwrap();
+        let report = DiagnosticEngine::thermal_history(&store, 60, None);
+        assert!(report.to_text().contains("No thermal readings"));
+    }
+
+    #[test]
+    fn thermal_history_reports_sensor_and_peak_workload() {
+        let mut store = EventStore::open(None).unwrap();
+        store
+            .insert_process_snapshots(&[make_process(7, "render", 350.0, 1024)])
+            .unwrap();
+        let mut snap = crate::thermal::ThermalSnapshot::empty();
+        snap.cpu_package = Some(88.0);
+        store.insert_thermal_snapshot(&snap).unwrap();
+
+        let text = DiagnosticEngine::thermal_history(&store, 60, None).to_text();
+        assert!(text.contains("CPU Package [cpu]: min 88°C / avg 88°C / max 88°C"));
+        assert!(text.contains("Workload at peak"));
+        assert!(text.contains("render (PID 7)"));
+
+        let filtered = DiagnosticEngine::thermal_history(&store, 60, Some("GPU")).to_text();
+        assert!(filtered.contains("No readings for sensor 'GPU'"));
+    }
+
+    // ── Process diff ──────────────────────────────────────────────
+
+    #[test]
+    fn process_diff_empty_store() {
+        let store = EventStore::open(None).unwrap();
+        let report = DiagnosticEngine::process_diff(&store, 0, i64::MAX);
+        assert!(report.to_text().contains("No process snapshots"));
+    }
+
+    #[test]
+    fn process_diff_lists_changes() {
+        let mut store = EventStore::open(None).unwrap();
+        store
+            .insert_process_snapshots(&[
+                make_process(1, "web", 10.0, 100 << 20),
+                make_process(2, "cron", 1.0, 10 << 20),
+                make_process(3, "worker", 5.0, 50 << 20),
+                make_process(5, "idle", 0.0, 20 << 20),
+            ])
+            .unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        store
+            .insert_process_snapshots(&[
+                make_process(1, "web", 12.0, (100 << 20) + (2 << 30)),
+                // PID reused by another program
+                make_process(2, "backup", 3.0, 10 << 20),
+                make_process(3, "worker", 80.0, 60 << 20),
+                make_process(4, "build", 95.0, 300 << 20),
+                make_process(5, "idle", 0.0, 20 << 20),
+            ])
+            .unwrap();
+
+        let now = crate::store::now_epoch_ms_pub();
+        let report = DiagnosticEngine::process_diff(&store, 0, now + 1000);
+        let text = report.to_text();
+        assert!(text.contains("2 process(es) appeared"), "{}", text);
+        assert!(text.contains("build (PID 4) 95% CPU"));
+        assert!(text.contains("backup (PID 2)"));
+        assert!(text.contains("1 process(es) disappeared"));
+        assert!(text.contains("cron (PID 2)"));
+        assert!(text.contains("web (PID 1) grew by 2.0 GiB"));
+        assert!(text.contains("worker (PID 3) CPU rose by 75 points"));
+        assert!(!text.contains("idle"));
+        assert_eq!(report.max_severity(), Some(FindingSeverity::Warning));
+
+        // Both ends closest to the same snapshot
+        let same = DiagnosticEngine::process_diff(&store, now, now + 1000);
+        assert!(same.to_text().contains("same snapshot"));
+    }
+
+    #[test]
+    fn parse_time_arg_forms() {
+        let now = Local.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap();
+        let at = |h, m| {
+            Local
+                .with_ymd_and_hms(2024, 5, 1, h, m, 0)
+                .unwrap()
+                .timestamp_millis()
+        };
+        assert_eq!(parse_time_arg("now", now), Ok(at(12, 0)));
+        assert_eq!(parse_time_arg("90m", now), Ok(at(10, 30)));
+        assert_eq!(parse_time_arg("09:15", now), Ok(at(9, 15)));
+        // A clock time later than now means yesterday
+        assert_eq!(
+            parse_time_arg("13:00", now),
+            Ok(at(13, 0) - 24 * 3600 * 1000)
+        );
+        assert_eq!(parse_time_arg("2024-05-01T08:45", now), Ok(at(8, 45)));
+        assert_eq!(parse_time_arg("2024-05-01", now), Ok(at(0, 0)));
+        assert!(parse_time_arg("yesterday", now).is_err());
+        assert!(parse_time_arg("25:00", now).is_err());
+    }
+
+    #[test]
+    fn parse_time_range_rejects_reversed_ends() {
+        let now = Local.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap();
+        let ms = now.timestamp_millis();
+        assert_eq!(
+            parse_time_range("2h", "now", now),
+            Ok((ms - 2 * 3600 * 1000, ms))
+        );
+        let err = parse_time_range("now", "2h", now).unwrap_err();
+        assert!(err.contains("diff 2h now"), "{}", err);
+        assert!(parse_time_range("1h", "soon", now).is_err());
+        // Ages too large to subtract from now are errors, not panics
+        for huge in ["9999999999999d", "99999999999999999999d"] {
+            let err = parse_time_range(huge, "now", now).unwrap_err();
+            assert!(err.starts_with("invalid time"), "{}", err);
+        }
+    }
+
     // ── Disk analysis ─────────────────────────────────────────────
 
     #[test]
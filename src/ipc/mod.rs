@@ -0,0 +1,424 @@
+//! Local IPC between a headless Sentinel (`sentinel daemon`) and TUI clients
+//! (`sentinel attach`).
+//!
+//! Transport is a Unix domain socket carrying newline-delimited JSON.
+//! The daemon publishes a [`ServerMessage::Snapshot`] after every refresh;
+//! clients render it instead of running their own collector, so only the
+//! daemon writes to the SQLite event store. Kill/renice requests travel
+//! the other way as [`ClientRequest`]s and are answered with
+//! [`ServerMessage::ActionResult`].
+//!
+//! The socket is created with mode 0600: only the daemon's user can attach.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::{mpsc, watch};
+
+use crate::alerts::{ActiveAlert, AlertKey};
+use crate::models::{Alert, ProcessInfo, ResourceLimits, SystemSnapshot};
+use crate::thermal::ThermalSnapshot;
+
+// ── Protocol ──────────────────────────────────────────────────────
+
+/// Live monitoring data published by the daemon after each refresh.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiveSnapshot {
+    pub system: SystemSnapshot,
+    pub processes: Vec<ProcessInfo>,
+    /// The daemon's full alert list (newest first).
+    pub alerts: Vec<Alert>,
+    pub thermal: Option<ThermalSnapshot>,
+    /// Alerts currently firing, with their acknowledgement state.
+    #[serde(default)]
+    pub active_alerts: Vec<ActiveAlert>,
+}
+
+/// Messages sent from the daemon to attached clients.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    Snapshot(Box<LiveSnapshot>),
+    /// Outcome of a [`ClientRequest`], already formatted for the status bar.
+    ActionResult { ok: bool, message: String },
+}
+
+/// Actions a client asks the daemon to perform.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientRequest {
+    /// Send a raw signal number (e.g. `libc::SIGTERM`) to a PID.
+    Signal { pid: u32, signal: i32 },
+    /// Set the nice value of a PID.
+    Renice { pid: u32, nice: i32 },
+    /// Move PIDs (the first is the one the limit was opened on) into a
+    /// Sentinel-managed cgroup with these caps.
+    Limit {
+        pids: Vec<u32>,
+        limits: ResourceLimits,
+    },
+    /// Acknowledge firing alerts.
+    Ack { keys: Vec<AlertKey> },
+}
+
+/// A client request paired with the channel its answer goes back on.
+pub struct PendingRequest {
+    pub request: ClientRequest,
+    reply: mpsc::UnboundedSender<ServerMessage>,
+}
+
+impl PendingRequest {
+    /// Send the result back to the requesting client (best-effort).
+    pub fn respond(&self, ok: bool, message: String) {
+        let _ = self.reply.send(ServerMessage::ActionResult { ok, message });
+    }
+}
+
+// ── Server (daemon side) ──────────────────────────────────────────
+
+/// Unix socket server run by the headless daemon.
+///
+/// Snapshots are serialized once per publish and fanned out through a
+/// `watch` channel, so a slow client only ever skips stale frames.
+pub struct IpcServer {
+    path: PathBuf,
+    frames: watch::Sender<Option<String>>,
+    requests: mpsc::UnboundedReceiver<PendingRequest>,
+}
+
+impl IpcServer {
+    /// Bind the socket at `path` and start accepting clients.
+    ///
+    /// A stale socket file left by a crashed daemon is removed; a socket
+    /// that still accepts connections is reported as `AddrInUse`.
+    pub fn bind(path: &Path) -> io::Result<Self> {
+        if path.exists() {
+            if std::os::unix::net::UnixStream::connect(path).is_ok() {
+                return Err(io::Error::new(
+                    io::ErrorKind::AddrInUse,
+                    format!("another Sentinel daemon is listening on {}", path.display()),
+                ));
+            }
+            std::fs::remove_file(path)?;
+        }
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let listener = UnixListener::bind(path)?;
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))?;
+        }
+
+        let (frames, frames_rx) = watch::channel::<Option<String>>(None);
+        let (req_tx, requests) = mpsc::unbounded_channel();
+
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(serve_client(stream, frames_rx.clone(), req_tx.clone()));
+            }
+        });
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            frames,
+            requests,
+        })
+    }
+
+    /// Publish a new snapshot to every attached client.
+    pub fn publish(&self, snapshot: LiveSnapshot) {
+        if let Ok(line) = serde_json::to_string(&ServerMessage::Snapshot(Box::new(snapshot))) {
+            self.frames.send_replace(Some(line));
+        }
+    }
+
+    /// Number of currently attached clients.
+    pub fn client_count(&self) -> usize {
+        // One receiver is held by the accept loop for cloning.
+        self.frames.receiver_count().saturating_sub(1)
+    }
+
+    /// Next pending client request, if any (non-blocking).
+    pub fn try_recv_request(&mut self) -> Option<PendingRequest> {
+        self.requests.try_recv().ok()
+    }
+}
+
+impl Drop for IpcServer {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// Per-connection loop: stream frames out, read requests in.
+async fn serve_client(
+    stream: UnixStream,
+    mut frames: watch::Receiver<Option<String>>,
+    requests: mpsc::UnboundedSender<PendingRequest>,
+) {
+    let (read_half, mut write_half) = stream.into_split();
+    let mut lines = BufReader::new(read_half).lines();
+    let (reply_tx, mut reply_rx) = mpsc::unbounded_channel::<ServerMessage>();
+
+    // Send the latest frame straight away so the client can render.
+    frames.mark_changed();
+
+    loop {
+        tokio::select! {
+            changed = frames.changed() => {
+                if changed.is_err() {
+                    break;
+                }
+                let frame = frames.borrow_and_update().clone();
+                if let Some(line) = frame {
+                    if write_line(&mut write_half, &line).await.is_err() {
+                        break;
+                    }
+                }
+            }
+            line = lines.next_line() => {
+                match line {
+                    Ok(Some(line)) => {
+                        if let Ok(request) = serde_json::from_str::<ClientRequest>(&line) {
+                            let pending = PendingRequest { request, reply: reply_tx.clone() };
+                            if requests.send(pending).is_err() {
+                                break;
+                            }
+                        }
+                    }
+                    _ => break,
+                }
+            }
+            Some(msg) = reply_rx.recv() => {
+                let Ok(line) = serde_json::to_string(&msg) else { continue };
+                if write_line(&mut write_half, &line).await.is_err() {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+async fn write_line<W: AsyncWriteExt + Unpin>(writer: &mut W, line: &str) -> io::Result<()> {
+    writer.write_all(line.as_bytes()).await?;
+    writer.write_all(b"\n").await?;
+    writer.flush().await
+}
+
+// ── Client (attach side) ──────────────────────────────────────────
+
+/// Connection to a running daemon, used by `sentinel attach`.
+pub struct IpcClient {
+    messages: mpsc::UnboundedReceiver<ServerMessage>,
+    requests: mpsc::UnboundedSender<ClientRequest>,
+}
+
+impl IpcClient {
+    /// Connect to the daemon socket at `path`.
+    pub async fn connect(path: &Path) -> io::Result<Self> {
+        let stream = UnixStream::connect(path).await?;
+        let (read_half, mut write_half) = stream.into_split();
+
+        let (msg_tx, messages) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let mut lines = BufReader::new(read_half).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                if let Ok(msg) = serde_json::from_str::<ServerMessage>(&line) {
+                    if msg_tx.send(msg).is_err() {
+                        break;
+                    }
+                }
+            }
+            // Dropping msg_tx signals disconnection to the receiver.
+        });
+
+        let (requests, mut req_rx) = mpsc::unbounded_channel::<ClientRequest>();
+        tokio::spawn(async move {
+            while let Some(request) = req_rx.recv().await {
+                let Ok(line) = serde_json::to_string(&request) else { continue };
+                if write_line(&mut write_half, &line).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok(Self { messages, requests })
+    }
+
+    /// Wait for the next message from the daemon. `None` once disconnected.
+    pub async fn recv(&mut self) -> Option<ServerMessage> {
+        self.messages.recv().await
+    }
+
+    /// Next message if one is ready (non-blocking).
+    pub fn try_recv(&mut self) -> Result<ServerMessage, mpsc::error::TryRecvError> {
+        self.messages.try_recv()
+    }
+
+    /// Queue a request for the daemon.
+    pub fn send(&self, request: ClientRequest) {
+        let _ = self.requests.send(request);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{AlertCategory, AlertSeverity, ProcessStatus};
+
+    fn make_snapshot() -> LiveSnapshot {
+        LiveSnapshot {
+            system: SystemSnapshot {
+                total_memory: 16 * 1024 * 1024 * 1024,
+                used_memory: 8 * 1024 * 1024 * 1024,
+                total_swap: 0,
+                used_swap: 0,
+                cpu_count: 4,
+                cpu_usages: vec![10.0, 20.0, 30.0, 40.0],
+                global_cpu_usage: 25.0,
+                uptime: 3600,
+                hostname: "test".into(),
+                os_name: "Linux".into(),
+                load_avg_1: 1.0,
+                load_avg_5: 0.5,
+                load_avg_15: 0.25,
+                total_processes: 1,
+                networks: Vec::new(),
+                disks: Vec::new(),
+                cpu_temp: None,
+                gpus: vec![],
+                cgroups: vec![],
+                battery: None,
+            },
+            processes: vec![ProcessInfo {
+                pid: 42,
+                name: "worker".into(),
+                cmd: "worker --fast".into(),
+                cpu_usage: 12.5,
+                memory_bytes: 1024,
+                memory_percent: 0.1,
+                disk_read_bytes: 0,
+                disk_write_bytes: 0,
+                status: ProcessStatus::Running,
+                user: "root".into(),
+                start_time: 0,
+                parent_pid: Some(1),
+                thread_count: Some(4),
+                gpu_usage: 0.0,
+                gpu_memory_bytes: 0,
+                net_rx_bytes_per_sec: 0,
+                net_tx_bytes_per_sec: 0,
+            }],
+            alerts: vec![Alert::new(
+                AlertSeverity::Warning,
+                AlertCategory::HighCpu,
+                "worker",
+                42,
+                "CPU high".into(),
+                95.0,
+                90.0,
+            )],
+            thermal: None,
+            active_alerts: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn client_request_json_shape() {
+        let req = ClientRequest::Signal { pid: 7, signal: 15 };
+        let json = serde_json::to_string(&req).unwrap();
+        assert_eq!(json, r#"{"type":"signal","pid":7,"signal":15}"#);
+        let back: ClientRequest = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, req);
+
+        let req = ClientRequest::Limit {
+            pids: vec![7, 8],
+            limits: ResourceLimits {
+                cpu_percent: Some(50),
+                ..ResourceLimits::default()
+            },
+        };
+        let json = serde_json::to_string(&req).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"limit","pids":[7,8],"limits":{"cpu_percent":50}}"#
+        );
+        assert_eq!(serde_json::from_str::<ClientRequest>(&json).unwrap(), req);
+    }
+
+    #[test]
+    fn snapshot_roundtrip() {
+        let msg = ServerMessage::Snapshot(Box::new(make_snapshot()));
+        let json = serde_json::to_string(&msg).unwrap();
+        match serde_json::from_str::<ServerMessage>(&json).unwrap() {
+            ServerMessage::Snapshot(snap) => {
+                assert_eq!(snap.processes[0].pid, 42);
+                assert_eq!(snap.alerts[0].category, AlertCategory::HighCpu);
+                assert_eq!(snap.system.cpu_count, 4);
+            }
+            other => panic!("unexpected message: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn server_streams_snapshots_and_relays_requests() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sentinel.sock");
+        let mut server = IpcServer::bind(&path).unwrap();
+
+        let mut client = IpcClient::connect(&path).await.unwrap();
+        // Wait for the server to register the connection before publishing.
+        for _ in 0..100 {
+            if server.client_count() == 1 {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        server.publish(make_snapshot());
+
+        match client.recv().await {
+            Some(ServerMessage::Snapshot(snap)) => assert_eq!(snap.processes.len(), 1),
+            other => panic!("expected snapshot, got {:?}", other),
+        }
+
+        client.send(ClientRequest::Renice { pid: 42, nice: 5 });
+        let pending = loop {
+            if let Some(p) = server.try_recv_request() {
+                break p;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        };
+        assert_eq!(pending.request, ClientRequest::Renice { pid: 42, nice: 5 });
+        pending.respond(true, "ok".into());
+
+        match client.recv().await {
+            Some(ServerMessage::ActionResult { ok, message }) => {
+                assert!(ok);
+                assert_eq!(message, "ok");
+            }
+            other => panic!("expected action result, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn bind_refuses_live_socket_and_replaces_stale_one() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sentinel.sock");
+
+        let server = IpcServer::bind(&path).unwrap();
+        let err = IpcServer::bind(&path).err().unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::AddrInUse);
+        drop(server);
+        assert!(!path.exists());
+
+        // A leftover file with nobody listening is cleaned up.
+        drop(std::os::unix::net::UnixListener::bind(&path).unwrap());
+        assert!(path.exists());
+        assert!(IpcServer::bind(&path).is_ok());
+    }
+}
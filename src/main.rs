@@ -17,50 +17,91 @@ mod config;
 pub mod constants;
 #[allow(dead_code)]
 mod diagnostics;
+mod ipc;
 mod metrics;
 mod models;
 mod monitor;
 mod notifications;
 mod plugins;
+mod replay;
+mod report;
 mod security;
 mod store;
+#[cfg(test)]
+mod test_support;
 mod thermal;
 mod ui;
 mod utils;
 
+use std::path::PathBuf;
+
 use anyhow::Result;
-use clap::Parser;
+use clap::{Parser, Subcommand};
 
-use config::Config;
-use constants::MIN_REFRESH_MS;
+use config::{CliOverrides, Config};
 
 /// Sentinel - AI-Powered Terminal System Monitor
 #[derive(Parser, Debug)]
 #[command(name = "sentinel", version, about = "A beautiful terminal process monitor with AI-powered analysis")]
 struct Cli {
     /// Disable all AI features (no API calls)
-    #[arg(long)]
+    #[arg(long, global = true)]
     no_ai: bool,
 
     /// Color theme (default, gruvbox, nord, catppuccin, dracula, solarized)
-    #[arg(long, short = 't')]
+    #[arg(long, short = 't', global = true)]
     theme: Option<String>,
 
     /// Refresh rate in milliseconds
-    #[arg(long, short = 'r')]
+    #[arg(long, short = 'r', global = true)]
     refresh_rate: Option<u64>,
 
     /// Disable auto-analysis on the dashboard
-    #[arg(long)]
+    #[arg(long, global = true)]
     no_auto_analysis: bool,
 
     /// Enable Prometheus metrics endpoint on the given address (e.g. "0.0.0.0:9100")
-    #[arg(long, value_name = "ADDR")]
+    #[arg(long, global = true, value_name = "ADDR")]
     prometheus: Option<String>,
 
     /// UI language (en, ja, es, de, zh)
-    #[arg(long, short = 'l', value_name = "LANG")]
+    #[arg(long, short = 'l', global = true, value_name = "LANG")]
     lang: Option<String>,
+
+    /// Run without the TUI (same as the `daemon` subcommand)
+    #[arg(long)]
+    headless: bool,
+
+    /// Daemon/attach socket path (default: ~/.local/share/sentinel/sentinel.sock)
+    #[arg(long, global = true, value_name = "PATH")]
+    socket: Option<PathBuf>,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Run headless: collect, record, alert and notify without a terminal.
+    /// SIGTERM/SIGINT stop cleanly, SIGHUP reloads config.toml, SIGUSR1
+    /// aborts a pending thermal shutdown.
+    Daemon,
+    /// Open the TUI on a running daemon's data instead of collecting locally.
+    /// Kill/renice actions are performed by the daemon.
+    Attach,
+    /// Print a diagnostic report (timeline, port, pid, anomaly, thermal-history, diff, disk, why) and exit.
+    Report {
+        #[command(subcommand)]
+        kind: report::ReportKind,
+
+        /// Output format
+        #[arg(long, short = 'f', value_enum, global = true, default_value_t = report::ReportFormat::Text)]
+        format: report::ReportFormat,
+
+        /// Event store database (default: ~/.local/share/sentinel/sentinel.db)
+        #[arg(long, global = true, value_name = "PATH")]
+        db: Option<PathBuf>,
+    },
 }
 
 #[tokio::main]
@@ -68,30 +109,67 @@ async fn main() -> Result<()> {
     let cli = Cli::parse();
 
     // Load and apply CLI overrides to config
+    let overrides = CliOverrides {
+        refresh_rate_ms: cli.refresh_rate,
+        no_auto_analysis: cli.no_auto_analysis,
+        theme: cli.theme.clone(),
+        lang: cli.lang.clone(),
+    };
     let mut config = Config::load();
-    if let Some(rate) = cli.refresh_rate {
-        config.refresh_interval_ms = rate.max(MIN_REFRESH_MS);
-    }
-    if cli.no_auto_analysis {
-        config.auto_analysis_interval_secs = 0;
-    }
-    if let Some(ref theme_name) = cli.theme {
-        config.theme = theme_name.clone();
-    }
-    if let Some(ref lang) = cli.lang {
-        config.lang = lang.clone();
-    }
+    overrides.apply(&mut config);
 
     // Set UI language (CLI > config > default "en")
     rust_i18n::set_locale(&config.lang);
 
+    let socket = cli.socket.unwrap_or_else(constants::ipc_socket_path);
+    let mode = match cli.command {
+        Some(Command::Daemon) => app::RunMode::Headless { socket },
+        Some(Command::Attach) => app::RunMode::Attach { socket },
+        // One-shot report: print and exit, no TUI or background tasks
+        Some(Command::Report { kind, format, db }) => {
+            return report::run(&kind, format, db.as_deref(), &config);
+        }
+        None if cli.headless => app::RunMode::Headless { socket },
+        None => app::RunMode::Tui,
+    };
+    let headless = matches!(mode, app::RunMode::Headless { .. });
+
     // Build and run the application
     let mut app = app::App::new(
         &config,
+        overrides,
         cli.no_ai,
         cli.prometheus.as_deref(),
+        mode,
     )
     .await?;
 
-    app.run().await
+    if headless {
+        app.run_headless().await
+    } else {
+        app.run().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn options_are_accepted_after_a_subcommand() {
+        let cli = Cli::try_parse_from([
+            "sentinel",
+            "daemon",
+            "--prometheus",
+            "0.0.0.0:9100",
+            "--refresh-rate",
+            "500",
+            "--no-ai",
+        ])
+        .unwrap();
+        assert!(matches!(cli.command, Some(Command::Daemon)));
+        assert_eq!(cli.prometheus.as_deref(), Some("0.0.0.0:9100"));
+        assert_eq!(cli.refresh_rate, Some(500));
+        assert!(cli.no_ai);
+    }
 }
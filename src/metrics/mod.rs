@@ -9,7 +9,7 @@
 use std::sync::{Arc, Mutex};
 
 use crate::constants::PROM_BUFFER_CAPACITY;
-use crate::models::{Alert, AlertSeverity, SystemSnapshot};
+use crate::models::{Alert, AlertSeverity, GpuInfo, SystemSnapshot};
 use crate::monitor::ContainerInfo;
 
 /// Shared state that the main loop writes and the HTTP server reads.
@@ -121,12 +121,6 @@ impl MetricDef {
         self.write_header(out);
         push_metric(out, self.name, &[], value);
     }
-
-    /// Write header + a single labeled value.
-    fn emit_labeled(&self, out: &mut String, labels: &[(&str, &str)], value: f64) {
-        self.write_header(out);
-        push_metric(out, self.name, labels, value);
-    }
 }
 
 // ── Static metric definitions ────────────────────────────────
@@ -324,16 +318,46 @@ fn render_system_metrics(out: &mut String, sys: &SystemSnapshot) {
         }
     }
 
-    // GPU
-    if let Some(ref gpu) = sys.gpu {
-        let gpu_label = [("gpu", &*gpu.name)];
-        M_GPU_UTIL.emit_labeled(out, &gpu_label, gpu.utilization as f64);
-        M_GPU_MEM_USED.emit_labeled(out, &gpu_label, gpu.memory_used as f64);
-        M_GPU_MEM_TOTAL.emit_labeled(out, &gpu_label, gpu.memory_total as f64);
-        M_GPU_TEMP.emit_labeled(out, &gpu_label, gpu.temperature as f64);
-        M_GPU_POWER.emit_labeled(out, &gpu_label, gpu.power_draw as f64);
-        if let Some(fan) = gpu.fan_speed {
-            M_GPU_FAN.emit_labeled(out, &gpu_label, fan as f64);
+    // GPUs: one header per family, one series per device
+    if !sys.gpus.is_empty() {
+        let labels: Vec<[(&str, &str); 3]> = sys
+            .gpus
+            .iter()
+            .map(|g| {
+                [
+                    ("gpu", &*g.name),
+                    ("id", &*g.id),
+                    ("vendor", g.vendor.as_str()),
+                ]
+            })
+            .collect();
+        type GpuValue = fn(&GpuInfo) -> Option<f64>;
+        let families: [(&MetricDef, GpuValue); 6] = [
+            (&M_GPU_UTIL, |g| Some(g.utilization as f64)),
+            (&M_GPU_MEM_USED, |g| {
+                (g.memory_total > 0).then_some(g.memory_used as f64)
+            }),
+            (&M_GPU_MEM_TOTAL, |g| {
+                (g.memory_total > 0).then_some(g.memory_total as f64)
+            }),
+            (&M_GPU_TEMP, |g| g.temperature.map(|t| t as f64)),
+            (&M_GPU_POWER, |g| g.power_draw.map(|w| w as f64)),
+            (&M_GPU_FAN, |g| g.fan_speed.map(|f| f as f64)),
+        ];
+        for (def, value) in families {
+            let series: Vec<_> = sys
+                .gpus
+                .iter()
+                .zip(&labels)
+                .filter_map(|(g, l)| value(g).map(|v| (l, v)))
+                .collect();
+            if series.is_empty() {
+                continue;
+            }
+            def.write_header(out);
+            for (l, v) in series {
+                push_metric(out, def.name, l, v);
+            }
         }
     }
 
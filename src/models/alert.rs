@@ -1,8 +1,9 @@
 use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// Severity levels for alerts - drives color coding and sort priority.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum AlertSeverity {
     Info,
     Warning,
@@ -22,11 +23,13 @@ impl fmt::Display for AlertSeverity {
 }
 
 /// Category of the alert for filtering and grouping.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum AlertCategory {
     HighCpu,
     HighMemory,
     HighDiskIo,
+    GpuHog,
+    NetworkHog,
     Zombie,
     Suspicious,
     SystemOverload,
@@ -47,6 +50,8 @@ impl fmt::Display for AlertCategory {
             AlertCategory::HighCpu => write!(f, "CPU"),
             AlertCategory::HighMemory => write!(f, "MEM"),
             AlertCategory::HighDiskIo => write!(f, "DISK"),
+            AlertCategory::GpuHog => write!(f, "GPU"),
+            AlertCategory::NetworkHog => write!(f, "NET"),
             AlertCategory::Zombie => write!(f, "ZOMBIE"),
             AlertCategory::Suspicious => write!(f, "SUSPECT"),
             AlertCategory::SystemOverload => write!(f, "OVERLOAD"),
@@ -64,7 +69,7 @@ impl fmt::Display for AlertCategory {
 }
 
 /// A single alert event generated by the detection engine.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub struct Alert {
     pub severity: AlertSeverity,
@@ -75,6 +80,9 @@ pub struct Alert {
     pub timestamp: DateTime<Local>,
     pub value: f64,
     pub threshold: f64,
+    /// Matched a silence or maintenance window: recorded but not notified.
+    #[serde(default)]
+    pub suppressed: bool,
 }
 
 impl Alert {
@@ -96,6 +104,7 @@ impl Alert {
             timestamp: Local::now(),
             value,
             threshold,
+            suppressed: false,
         }
     }
 
@@ -142,6 +151,8 @@ mod tests {
         assert_eq!(AlertCategory::HighCpu.to_string(), "CPU");
         assert_eq!(AlertCategory::HighMemory.to_string(), "MEM");
         assert_eq!(AlertCategory::HighDiskIo.to_string(), "DISK");
+        assert_eq!(AlertCategory::GpuHog.to_string(), "GPU");
+        assert_eq!(AlertCategory::NetworkHog.to_string(), "NET");
         assert_eq!(AlertCategory::Zombie.to_string(), "ZOMBIE");
         assert_eq!(AlertCategory::Suspicious.to_string(), "SUSPECT");
         assert_eq!(AlertCategory::SystemOverload.to_string(), "OVERLOAD");
@@ -0,0 +1,205 @@
+use serde::{Deserialize, Serialize};
+
+use super::format_bytes;
+
+/// What a cgroup represents, from its name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CgroupKind {
+    /// systemd slice (`system.slice`, `user-1000.slice`)
+    Slice,
+    /// systemd service (`nginx.service`)
+    Service,
+    /// systemd scope (`session-3.scope`, `init.scope`)
+    Scope,
+    /// Container scope (`docker-<id>.scope`, `/docker/<id>`, libpod, CRI)
+    Container,
+    #[default]
+    Other,
+}
+
+impl CgroupKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CgroupKind::Slice => "slice",
+            CgroupKind::Service => "service",
+            CgroupKind::Scope => "scope",
+            CgroupKind::Container => "container",
+            CgroupKind::Other => "cgroup",
+        }
+    }
+}
+
+/// A cgroup v2 group. Resource usage covers the group and everything below
+/// it, as the kernel accounts it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CgroupInfo {
+    /// Path under the cgroup root, e.g. `/system.slice/nginx.service`
+    pub path: String,
+    pub kind: CgroupKind,
+    /// CPU usage (% of one core) from `cpu.stat` `usage_usec`
+    pub cpu_usage: f32,
+    /// `memory.current` (bytes)
+    pub memory_bytes: u64,
+    /// `io.stat` `rbytes` per second, summed over devices
+    pub io_read_bytes_per_sec: u64,
+    /// `io.stat` `wbytes` per second, summed over devices
+    pub io_write_bytes_per_sec: u64,
+    /// `pids.current`: tasks in the group and below
+    pub pids: u64,
+    /// PIDs directly in this group
+    pub processes: Vec<u32>,
+}
+
+impl CgroupInfo {
+    /// Last path component (`nginx.service`).
+    pub fn name(&self) -> &str {
+        self.path.rsplit('/').next().unwrap_or(&self.path)
+    }
+
+    /// Path of the enclosing group (`None` for top-level groups).
+    pub fn parent(&self) -> Option<&str> {
+        let (parent, _) = self.path.rsplit_once('/')?;
+        (!parent.is_empty()).then_some(parent)
+    }
+
+    /// Container ID for container groups.
+    pub fn container_id(&self) -> Option<&str> {
+        container_id(self.name())
+    }
+
+    /// Name for display: containers as `container 1a2b3c4d5e6f`.
+    pub fn display_name(&self) -> String {
+        match self.container_id() {
+            Some(id) => format!("container {}", &id[..id.len().min(12)]),
+            None => self.name().to_string(),
+        }
+    }
+
+    pub fn io_bytes_per_sec(&self) -> u64 {
+        self.io_read_bytes_per_sec + self.io_write_bytes_per_sec
+    }
+
+    /// Classify a group by its path.
+    pub fn kind_of(path: &str) -> CgroupKind {
+        let name = path.rsplit('/').next().unwrap_or(path);
+        if container_id(name).is_some() {
+            CgroupKind::Container
+        } else if name.ends_with(".slice") {
+            CgroupKind::Slice
+        } else if name.ends_with(".service") {
+            CgroupKind::Service
+        } else if name.ends_with(".scope") {
+            CgroupKind::Scope
+        } else {
+            CgroupKind::Other
+        }
+    }
+}
+
+/// Resource caps for a Sentinel-managed cgroup; `None` means unlimited.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceLimits {
+    /// `cpu.max` as % of one CPU
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu_percent: Option<u32>,
+    /// `memory.max` (bytes)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_bytes: Option<u64>,
+    /// `io.max` `rbps` and `wbps` (bytes/s) on every disk
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub io_bytes_per_sec: Option<u64>,
+}
+
+impl ResourceLimits {
+    pub fn is_unlimited(&self) -> bool {
+        self.cpu_percent.is_none() && self.memory_bytes.is_none() && self.io_bytes_per_sec.is_none()
+    }
+
+    /// `CPU 50%, memory 2.0 GiB, I/O 10.0 MiB/s`, or `unlimited`.
+    pub fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(pct) = self.cpu_percent {
+            parts.push(format!("CPU {}%", pct));
+        }
+        if let Some(bytes) = self.memory_bytes {
+            parts.push(format!("memory {}", format_bytes(bytes)));
+        }
+        if let Some(rate) = self.io_bytes_per_sec {
+            parts.push(format!("I/O {}/s", format_bytes(rate)));
+        }
+        if parts.is_empty() {
+            "unlimited".to_string()
+        } else {
+            parts.join(", ")
+        }
+    }
+}
+
+/// Container ID in a cgroup name: `docker-<id>.scope` (systemd driver),
+/// `libpod-<id>.scope`, `cri-containerd-<id>.scope`, `crio-<id>.scope`, or
+/// a bare 64-hex-digit `<id>` (cgroupfs driver).
+fn container_id(name: &str) -> Option<&str> {
+    let id = match name.strip_suffix(".scope") {
+        Some(scope) => ["docker-", "libpod-", "cri-containerd-", "crio-"]
+            .iter()
+            .find_map(|prefix| scope.strip_prefix(prefix))?,
+        None => name,
+    };
+    (id.len() == 64 && id.bytes().all(|b| b.is_ascii_hexdigit())).then_some(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ID: &str = "4f3a1b2c9d8e7f6a5b4c3d2e1f0a9b8c7d6e5f4a3b2c1d0e9f8a7b6c5d4e3f2a";
+
+    #[test]
+    fn classifies_systemd_units_and_containers() {
+        let kind = CgroupInfo::kind_of;
+        assert_eq!(kind("/system.slice"), CgroupKind::Slice);
+        assert_eq!(kind("/system.slice/nginx.service"), CgroupKind::Service);
+        assert_eq!(
+            kind("/user.slice/user-1000.slice/session-3.scope"),
+            CgroupKind::Scope
+        );
+        assert_eq!(
+            kind(&format!("/system.slice/docker-{}.scope", ID)),
+            CgroupKind::Container
+        );
+        assert_eq!(kind(&format!("/docker/{}", ID)), CgroupKind::Container);
+        assert_eq!(kind("/docker"), CgroupKind::Other);
+        // Not a container ID
+        assert_eq!(kind("/system.slice/docker-abc.scope"), CgroupKind::Scope);
+    }
+
+    #[test]
+    fn describes_limits() {
+        assert_eq!(ResourceLimits::default().describe(), "unlimited");
+        let limits = ResourceLimits {
+            cpu_percent: Some(150),
+            memory_bytes: Some(2 << 30),
+            io_bytes_per_sec: None,
+        };
+        assert!(!limits.is_unlimited());
+        assert_eq!(limits.describe(), "CPU 150%, memory 2.0 GiB");
+    }
+
+    #[test]
+    fn names_and_parents() {
+        let cg = CgroupInfo {
+            path: format!("/machine.slice/libpod-{}.scope", ID),
+            ..CgroupInfo::default()
+        };
+        assert_eq!(cg.parent(), Some("/machine.slice"));
+        assert_eq!(cg.container_id(), Some(ID));
+        assert_eq!(cg.display_name(), "container 4f3a1b2c9d8e");
+
+        let top = CgroupInfo {
+            path: "/system.slice".to_string(),
+            ..CgroupInfo::default()
+        };
+        assert_eq!(top.name(), "system.slice");
+        assert_eq!(top.parent(), None);
+    }
+}
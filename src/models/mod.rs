@@ -1,7 +1,9 @@
 mod process;
 mod system;
 mod alert;
+mod cgroup;
 
 pub use process::*;
 pub use system::*;
 pub use alert::*;
+pub use cgroup::*;
@@ -1,8 +1,10 @@
 use std::fmt;
 
+use serde::{Deserialize, Serialize};
+
 /// Represents a single process snapshot with all relevant metrics.
 /// This is our core domain entity - immutable snapshot of process state.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub struct ProcessInfo {
     pub pid: u32,
@@ -18,9 +20,21 @@ pub struct ProcessInfo {
     pub start_time: u64,
     pub parent_pid: Option<u32>,
     pub thread_count: Option<u32>,
+    /// GPU utilization (%) on the busiest GPU the process uses
+    #[serde(default)]
+    pub gpu_usage: f32,
+    /// GPU device memory held by the process across all GPUs (bytes)
+    #[serde(default)]
+    pub gpu_memory_bytes: u64,
+    /// Network bytes received per second
+    #[serde(default)]
+    pub net_rx_bytes_per_sec: u64,
+    /// Network bytes sent per second
+    #[serde(default)]
+    pub net_tx_bytes_per_sec: u64,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ProcessStatus {
     Running,
     Sleeping,
@@ -58,6 +72,36 @@ impl ProcessInfo {
     pub fn disk_write_display(&self) -> String {
         format_bytes(self.disk_write_bytes)
     }
+
+    /// Whether the process holds GPU memory or used a GPU in the last sample
+    pub fn uses_gpu(&self) -> bool {
+        self.gpu_usage > 0.0 || self.gpu_memory_bytes > 0
+    }
+
+    /// GPU usage as `35% 1.2 GiB`, or `-` for processes not on a GPU
+    pub fn gpu_display(&self) -> String {
+        if !self.uses_gpu() {
+            return "-".to_string();
+        }
+        format!(
+            "{:.0}% {}",
+            self.gpu_usage,
+            format_bytes(self.gpu_memory_bytes)
+        )
+    }
+
+    /// Received + sent network bytes per second
+    pub fn net_bytes_per_sec(&self) -> u64 {
+        self.net_rx_bytes_per_sec + self.net_tx_bytes_per_sec
+    }
+
+    /// Network rate as `1.2 MiB/s`, or `-` for processes with no traffic
+    pub fn net_display(&self) -> String {
+        match self.net_bytes_per_sec() {
+            0 => "-".to_string(),
+            rate => format!("{}/s", format_bytes(rate)),
+        }
+    }
 }
 
 /// Formats bytes into human-readable string (KiB, MiB, GiB)
@@ -162,6 +206,10 @@ mod tests {
             start_time: 0,
             parent_pid: None,
             thread_count: None,
+            gpu_usage: 0.0,
+            gpu_memory_bytes: 0,
+            net_rx_bytes_per_sec: 0,
+            net_tx_bytes_per_sec: 0,
         }
     }
 
@@ -177,4 +225,27 @@ mod tests {
         assert_eq!(p.disk_read_display(), "1.0 MiB");
         assert_eq!(p.disk_write_display(), "2.0 KiB");
     }
+
+    #[test]
+    fn process_info_gpu_display() {
+        let mut p = make_process(1, "test", 0.0, 0);
+        assert!(!p.uses_gpu());
+        assert_eq!(p.gpu_display(), "-");
+
+        p.gpu_usage = 35.4;
+        p.gpu_memory_bytes = 1536 * 1024 * 1024;
+        assert!(p.uses_gpu());
+        assert_eq!(p.gpu_display(), "35% 1.5 GiB");
+    }
+
+    #[test]
+    fn process_info_net_display() {
+        let mut p = make_process(1, "test", 0.0, 0);
+        assert_eq!(p.net_display(), "-");
+
+        p.net_rx_bytes_per_sec = 1024 * 1024;
+        p.net_tx_bytes_per_sec = 512 * 1024;
+        assert_eq!(p.net_bytes_per_sec(), 1536 * 1024);
+        assert_eq!(p.net_display(), "1.5 MiB/s");
+    }
 }
@@ -1,6 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+use super::CgroupInfo;
+
 /// System-wide resource snapshot.
 /// Provides the big-picture view of machine health.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub struct SystemSnapshot {
     pub total_memory: u64,
@@ -23,14 +27,19 @@ pub struct SystemSnapshot {
     pub disks: Vec<DiskInfo>,
     /// CPU temperature (may be None if sensors unavailable)
     pub cpu_temp: Option<CpuTemperature>,
-    /// NVIDIA GPU info (may be None if no GPU or NVML unavailable)
-    pub gpu: Option<GpuInfo>,
+    /// GPUs: NVIDIA via NVML, AMD / Intel via DRM sysfs (empty if none found)
+    #[serde(default)]
+    pub gpus: Vec<GpuInfo>,
+    /// cgroup v2 groups that hold processes, parents before children
+    /// (empty without cgroup v2)
+    #[serde(default)]
+    pub cgroups: Vec<CgroupInfo>,
     /// Battery info (may be None if no battery / desktop)
     pub battery: Option<BatteryInfo>,
 }
 
 /// Network interface snapshot (rates since last refresh).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub struct NetworkInfo {
     pub name: String,
@@ -45,7 +54,7 @@ pub struct NetworkInfo {
 }
 
 /// Mounted filesystem snapshot.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub struct DiskInfo {
     pub mount_point: String,
@@ -60,7 +69,7 @@ pub struct DiskInfo {
 }
 
 /// CPU temperature readings.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub struct CpuTemperature {
     /// Overall/package temperature in Celsius (if available)
@@ -69,17 +78,41 @@ pub struct CpuTemperature {
     pub core_temps: Vec<f32>,
 }
 
-/// NVIDIA GPU snapshot.
-#[derive(Debug, Clone)]
+/// GPU vendor, which decides how a [`GpuInfo`] was collected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum GpuVendor {
+    #[default]
+    Nvidia,
+    Amd,
+    Intel,
+}
+
+impl GpuVendor {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GpuVendor::Nvidia => "nvidia",
+            GpuVendor::Amd => "amd",
+            GpuVendor::Intel => "intel",
+        }
+    }
+}
+
+/// GPU snapshot (one per device).
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub struct GpuInfo {
+    /// Stable device id: `nvidia0` for NVML index 0, `card1` for DRM cards
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub vendor: GpuVendor,
     pub name: String,
-    pub utilization: u32,       // 0-100%
-    pub memory_used: u64,       // bytes
-    pub memory_total: u64,      // bytes
-    pub temperature: u32,       // Celsius
-    pub power_draw: f32,        // Watts
-    pub fan_speed: Option<u32>, // 0-100%
+    pub utilization: u32,         // 0-100%
+    pub memory_used: u64,         // bytes (0 for integrated GPUs)
+    pub memory_total: u64,        // bytes (0 for integrated GPUs)
+    pub temperature: Option<u32>, // Celsius
+    pub power_draw: Option<f32>,  // Watts
+    pub fan_speed: Option<u32>,   // 0-100%
 }
 
 impl GpuInfo {
@@ -92,7 +125,7 @@ impl GpuInfo {
 }
 
 /// Battery status.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub struct BatteryInfo {
     pub percent: f32, // 0-100
@@ -100,7 +133,7 @@ pub struct BatteryInfo {
     pub time_remaining: Option<String>, // e.g. "2h 15m"
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum BatteryStatus {
     Charging,
     Discharging,
@@ -153,7 +186,8 @@ mod tests {
             networks: vec![],
             disks: vec![],
             cpu_temp: None,
-            gpu: None,
+            gpus: vec![],
+            cgroups: vec![],
             battery: None,
         }
     }
@@ -202,12 +236,14 @@ mod tests {
 
     fn make_gpu(used: u64, total: u64) -> GpuInfo {
         GpuInfo {
+            id: "nvidia0".to_string(),
+            vendor: GpuVendor::Nvidia,
             name: "Test GPU".to_string(),
             utilization: 50,
             memory_used: used,
             memory_total: total,
-            temperature: 65,
-            power_draw: 200.0,
+            temperature: Some(65),
+            power_draw: Some(200.0),
             fan_speed: Some(60),
         }
     }
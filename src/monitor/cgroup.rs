@@ -0,0 +1,249 @@
+//! cgroup v2 groups and their resource usage.
+//!
+//! Process membership comes from the `0::<path>` line of `/proc/<pid>/cgroup`;
+//! every group holding a process, and its ancestors, is read from
+//! `/sys/fs/cgroup/<path>/`:
+//!
+//! | File             | Field                                     |
+//! |------------------|-------------------------------------------|
+//! | `cpu.stat`       | `usage_usec` (delta → % of one core)      |
+//! | `memory.current` | memory                                    |
+//! | `io.stat`        | `rbytes` / `wbytes` (delta → bytes/s)     |
+//! | `pids.current`   | tasks                                     |
+//!
+//! The kernel accounts each file over the whole subtree, so a slice's usage
+//! includes its services. Hosts on the legacy v1 hierarchy report nothing.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use crate::models::CgroupInfo;
+use crate::utils::read_u64;
+
+/// (`usage_usec`, `rbytes`, `wbytes`) counters of one group.
+type Counters = (u64, u64, u64);
+
+/// Collects cgroup v2 usage.
+pub struct CgroupCollector {
+    cgroup_root: PathBuf,
+    proc_root: PathBuf,
+    /// When the previous sample was taken, for rates
+    prev_at: Option<Instant>,
+    /// Group path → counters at the previous sample
+    prev: HashMap<String, Counters>,
+}
+
+impl CgroupCollector {
+    pub fn new() -> Self {
+        Self::with_roots("/sys/fs/cgroup", "/proc")
+    }
+
+    /// Read from alternate cgroup and `/proc` trees (tests).
+    pub fn with_roots(cgroup_root: impl Into<PathBuf>, proc_root: impl Into<PathBuf>) -> Self {
+        Self {
+            cgroup_root: cgroup_root.into(),
+            proc_root: proc_root.into(),
+            prev_at: None,
+            prev: HashMap::new(),
+        }
+    }
+
+    /// Every group that holds a process, with its ancestors, parents first.
+    pub fn collect(&mut self) -> Vec<CgroupInfo> {
+        self.collect_at(Instant::now())
+    }
+
+    fn collect_at(&mut self, now: Instant) -> Vec<CgroupInfo> {
+        if !self.cgroup_root.join("cgroup.controllers").exists() {
+            return Vec::new();
+        }
+        let elapsed_secs = self
+            .prev_at
+            .map(|at| now.saturating_duration_since(at).as_secs_f64())
+            .filter(|s| *s > 0.0);
+        self.prev_at = Some(now);
+
+        // Keyed by path components so parents sort before their children
+        let mut groups: BTreeMap<Vec<String>, Vec<u32>> = BTreeMap::new();
+        for (pid, path) in read_memberships(&self.proc_root) {
+            let parts: Vec<String> = path
+                .split('/')
+                .filter(|p| !p.is_empty())
+                .map(str::to_string)
+                .collect();
+            if parts.is_empty() {
+                continue;
+            }
+            for depth in 1..parts.len() {
+                groups.entry(parts[..depth].to_vec()).or_default();
+            }
+            groups.entry(parts).or_default().push(pid);
+        }
+
+        let mut next = HashMap::new();
+        let cgroups = groups
+            .into_iter()
+            .map(|(parts, mut processes)| {
+                let path = format!("/{}", parts.join("/"));
+                let dir = self.cgroup_root.join(parts.join("/"));
+                let counters = read_counters(&dir);
+                let prev = self.prev.get(&path).copied();
+                next.insert(path.clone(), counters);
+                let rate = |cur: u64, prev: u64| match elapsed_secs {
+                    Some(secs) => cur.saturating_sub(prev) as f64 / secs,
+                    None => 0.0,
+                };
+                let (cpu, read, write) = match prev {
+                    Some((cpu, read, write)) => (
+                        // usage_usec per second of wall time → % of one core
+                        rate(counters.0, cpu) / 10_000.0,
+                        rate(counters.1, read),
+                        rate(counters.2, write),
+                    ),
+                    None => (0.0, 0.0, 0.0),
+                };
+                processes.sort_unstable();
+                CgroupInfo {
+                    kind: CgroupInfo::kind_of(&path),
+                    cpu_usage: cpu as f32,
+                    memory_bytes: read_u64(&dir.join("memory.current")).unwrap_or(0),
+                    io_read_bytes_per_sec: read.round() as u64,
+                    io_write_bytes_per_sec: write.round() as u64,
+                    pids: read_u64(&dir.join("pids.current")).unwrap_or(processes.len() as u64),
+                    processes,
+                    path,
+                }
+            })
+            .collect();
+        self.prev = next;
+        cgroups
+    }
+}
+
+/// (PID, cgroup v2 path) for every process under `proc_root`.
+fn read_memberships(proc_root: &Path) -> Vec<(u32, String)> {
+    let Ok(entries) = fs::read_dir(proc_root) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .filter_map(|e| {
+            let pid: u32 = e.file_name().to_str()?.parse().ok()?;
+            let text = fs::read_to_string(e.path().join("cgroup")).ok()?;
+            let path = text.lines().find_map(|l| l.strip_prefix("0::"))?;
+            Some((pid, path.trim().to_string()))
+        })
+        .collect()
+}
+
+/// `usage_usec` from `cpu.stat` and `rbytes` / `wbytes` summed over the
+/// devices in `io.stat`; missing files (controller disabled) read as zero.
+fn read_counters(dir: &Path) -> Counters {
+    let usage_usec = fs::read_to_string(dir.join("cpu.stat"))
+        .ok()
+        .and_then(|text| {
+            text.lines()
+                .find_map(|l| l.strip_prefix("usage_usec "))
+                .and_then(|v| v.trim().parse().ok())
+        })
+        .unwrap_or(0);
+    let (mut rbytes, mut wbytes) = (0, 0);
+    if let Ok(text) = fs::read_to_string(dir.join("io.stat")) {
+        // `8:0 rbytes=1 wbytes=2 rios=3 wios=4 dbytes=0 dios=0`
+        for field in text.split_whitespace() {
+            if let Some(v) = field.strip_prefix("rbytes=") {
+                rbytes += v.parse::<u64>().unwrap_or(0);
+            } else if let Some(v) = field.strip_prefix("wbytes=") {
+                wbytes += v.parse::<u64>().unwrap_or(0);
+            }
+        }
+    }
+    (usage_usec, rbytes, wbytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::CgroupKind;
+    use crate::test_support::write_tree;
+    use std::time::Duration;
+
+    /// Write nginx.service's CPU and I/O counters.
+    fn write_nginx_counters(root: &Path, usage_usec: u64, wbytes: u64) {
+        write_tree(
+            root,
+            &[
+                (
+                    "cg/system.slice/nginx.service/cpu.stat",
+                    &format!("usage_usec {}\nuser_usec 0\nsystem_usec 0", usage_usec),
+                ),
+                (
+                    "cg/system.slice/nginx.service/io.stat",
+                    &format!(
+                        "8:0 rbytes=100 wbytes={} rios=1 wios=1\n259:0 rbytes=0 wbytes=50",
+                        wbytes
+                    ),
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn aggregates_groups_with_ancestors() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write_tree(
+            root,
+            &[
+                ("cg/cgroup.controllers", "cpu io memory pids"),
+                ("cg/system.slice/memory.current", "3000"),
+                ("cg/system.slice/nginx.service/memory.current", "2048"),
+                ("cg/system.slice/nginx.service/pids.current", "5"),
+                ("proc/10/cgroup", "0::/system.slice/nginx.service"),
+                ("proc/11/cgroup", "0::/system.slice/nginx.service"),
+                // Hybrid hierarchy: only the v2 line counts
+                ("proc/20/cgroup", "12:cpu:/\n0::/init.scope"),
+                // Kernel threads sit in the root group
+                ("proc/2/cgroup", "0::/"),
+            ],
+        );
+        write_nginx_counters(root, 1_000_000, 1_000);
+
+        let mut c = CgroupCollector::with_roots(root.join("cg"), root.join("proc"));
+        let t0 = Instant::now();
+        let first = c.collect_at(t0);
+        let paths: Vec<&str> = first.iter().map(|g| g.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "/init.scope",
+                "/system.slice",
+                "/system.slice/nginx.service"
+            ]
+        );
+        assert_eq!(first[2].cpu_usage, 0.0);
+
+        write_nginx_counters(root, 1_500_000, 201_000);
+        let groups = c.collect_at(t0 + Duration::from_secs(2));
+
+        let slice = &groups[1];
+        assert_eq!(slice.kind, CgroupKind::Slice);
+        assert_eq!(slice.memory_bytes, 3000);
+        assert!(slice.processes.is_empty());
+
+        let nginx = &groups[2];
+        assert_eq!(nginx.kind, CgroupKind::Service);
+        assert_eq!(nginx.processes, [10, 11]);
+        assert_eq!(nginx.memory_bytes, 2048);
+        assert_eq!(nginx.pids, 5);
+        assert!((nginx.cpu_usage - 25.0).abs() < 0.01);
+        assert_eq!(nginx.io_write_bytes_per_sec, 100_000);
+        assert_eq!(nginx.io_read_bytes_per_sec, 0);
+
+        // No controllers file: not a cgroup v2 mount
+        let mut v1 = CgroupCollector::with_roots(root.join("proc"), root.join("proc"));
+        assert!(v1.collect().is_empty());
+    }
+}
@@ -1,15 +1,20 @@
 use std::collections::HashMap;
 
+use nvml_wrapper::enums::device::UsedGpuMemory;
 use sysinfo::{
     Disks, Networks, ProcessStatus as SysProcessStatus, ProcessesToUpdate, System, Users,
 };
 
 use crate::constants::*;
 use crate::models::{
-    BatteryInfo, BatteryStatus, CpuTemperature, DiskInfo, GpuInfo, NetworkInfo, ProcessInfo,
-    ProcessStatus, SystemSnapshot,
+    BatteryInfo, BatteryStatus, CpuTemperature, DiskInfo, GpuInfo, GpuVendor, NetworkInfo,
+    ProcessInfo, ProcessStatus, SystemSnapshot,
 };
 
+use super::cgroup::CgroupCollector;
+use super::gpu::{DrmGpuCollector, ProcessGpuUsage};
+use super::net::{ProcessNetCollector, ProcessNetUsage};
+
 /// Responsible for collecting system and process data.
 /// Single Responsibility: only gathers data, no analysis.
 pub struct SystemCollector {
@@ -19,6 +24,15 @@ pub struct SystemCollector {
     disks: Disks,
     /// NVML handle (None if NVML not available)
     nvml: Option<nvml_wrapper::Nvml>,
+    /// NVML device index → timestamp (µs) of the newest per-process
+    /// utilization sample read, so each query returns only new samples
+    nvml_last_seen: HashMap<u32, u64>,
+    /// AMD / Intel GPUs from DRM sysfs
+    drm: DrmGpuCollector,
+    /// Per-process network rates
+    net: ProcessNetCollector,
+    /// cgroup v2 groups
+    cgroups: CgroupCollector,
     /// Previous disk I/O counters for delta calculation
     prev_disk_io: HashMap<String, (u64, u64)>,
     /// Timestamp of last collection for rate calculation
@@ -45,6 +59,10 @@ impl SystemCollector {
             networks,
             disks,
             nvml,
+            nvml_last_seen: HashMap::new(),
+            drm: DrmGpuCollector::new(),
+            net: ProcessNetCollector::new(),
+            cgroups: CgroupCollector::new(),
             prev_disk_io: HashMap::new(),
             last_collect: std::time::Instant::now(),
         }
@@ -58,7 +76,12 @@ impl SystemCollector {
         self.disks.refresh();
 
         let system = self.collect_system();
-        let processes = self.collect_processes();
+        let mut gpu_usage = self.read_nvidia_process_usage();
+        for (&pid, &usage) in self.drm.processes() {
+            gpu_usage.entry(pid).or_default().merge(usage);
+        }
+        self.net.collect();
+        let processes = self.collect_processes(&gpu_usage, self.net.processes());
         self.last_collect = std::time::Instant::now();
         (system, processes)
     }
@@ -124,7 +147,8 @@ impl SystemCollector {
 
         // Collect sensor data
         let cpu_temp = read_cpu_temperature();
-        let gpu = self.read_gpu_info();
+        let mut gpus = self.read_nvidia_gpus();
+        gpus.extend(self.drm.collect());
         let battery = read_battery_info();
 
         SystemSnapshot {
@@ -149,44 +173,101 @@ impl SystemCollector {
             networks,
             disks,
             cpu_temp,
-            gpu,
+            gpus,
+            cgroups: self.cgroups.collect(),
             battery,
         }
     }
 
-    /// Read GPU info from NVML (NVIDIA only).
-    fn read_gpu_info(&self) -> Option<GpuInfo> {
-        let nvml = self.nvml.as_ref()?;
-        let device = nvml.device_by_index(0).ok()?;
-
-        let name = device.name().unwrap_or_else(|_| "NVIDIA GPU".to_string());
-        let utilization = device.utilization_rates().map(|u| u.gpu).unwrap_or(0);
-        let memory_info = device.memory_info().ok()?;
-        let temperature = device
-            .temperature(nvml_wrapper::enum_wrappers::device::TemperatureSensor::Gpu)
-            .unwrap_or(0);
-        let power_draw = device
-            .power_usage()
-            .map(|mw| mw as f32 / 1000.0) // milliwatts -> watts
-            .unwrap_or(0.0);
-        let fan_speed = device.fan_speed(0).ok();
-
-        Some(GpuInfo {
-            name,
-            utilization,
-            memory_used: memory_info.used,
-            memory_total: memory_info.total,
-            temperature,
-            power_draw,
-            fan_speed,
-        })
+    /// Read every NVIDIA GPU from NVML.
+    fn read_nvidia_gpus(&self) -> Vec<GpuInfo> {
+        let Some(nvml) = self.nvml.as_ref() else {
+            return Vec::new();
+        };
+        let count = nvml.device_count().unwrap_or(0);
+        (0..count)
+            .filter_map(|index| {
+                let device = nvml.device_by_index(index).ok()?;
+
+                let name = device.name().unwrap_or_else(|_| "NVIDIA GPU".to_string());
+                let utilization = device.utilization_rates().map(|u| u.gpu).unwrap_or(0);
+                let memory_info = device.memory_info().ok()?;
+                let temperature = device
+                    .temperature(nvml_wrapper::enum_wrappers::device::TemperatureSensor::Gpu)
+                    .ok();
+                let power_draw = device
+                    .power_usage()
+                    .map(|mw| mw as f32 / 1000.0) // milliwatts -> watts
+                    .ok();
+                let fan_speed = device.fan_speed(0).ok();
+
+                Some(GpuInfo {
+                    id: format!("nvidia{}", index),
+                    vendor: GpuVendor::Nvidia,
+                    name,
+                    utilization,
+                    memory_used: memory_info.used,
+                    memory_total: memory_info.total,
+                    temperature,
+                    power_draw,
+                    fan_speed,
+                })
+            })
+            .collect()
+    }
+
+    /// Per-process GPU memory and utilization from NVML, by PID.
+    fn read_nvidia_process_usage(&mut self) -> HashMap<u32, ProcessGpuUsage> {
+        let mut usage: HashMap<u32, ProcessGpuUsage> = HashMap::new();
+        let Some(nvml) = self.nvml.as_ref() else {
+            return usage;
+        };
+        for index in 0..nvml.device_count().unwrap_or(0) {
+            let Ok(device) = nvml.device_by_index(index) else {
+                continue;
+            };
+            let mut on_device: HashMap<u32, ProcessGpuUsage> = HashMap::new();
+            let running = device
+                .running_compute_processes()
+                .unwrap_or_default()
+                .into_iter()
+                .chain(device.running_graphics_processes().unwrap_or_default());
+            for proc_info in running {
+                if let UsedGpuMemory::Used(bytes) = proc_info.used_gpu_memory {
+                    // A process in both lists reports the same allocation twice
+                    let entry = on_device.entry(proc_info.pid).or_default();
+                    entry.memory_bytes = entry.memory_bytes.max(bytes);
+                }
+            }
+
+            let last_seen = self.nvml_last_seen.get(&index).copied();
+            if let Ok(samples) = device.process_utilization_stats(last_seen) {
+                let mut newest = last_seen.unwrap_or(0);
+                for sample in samples {
+                    let entry = on_device.entry(sample.pid).or_default();
+                    entry.usage = entry.usage.max(sample.sm_util as f32);
+                    newest = newest.max(sample.timestamp);
+                }
+                self.nvml_last_seen.insert(index, newest);
+            }
+
+            for (pid, u) in on_device {
+                usage.entry(pid).or_default().merge(u);
+            }
+        }
+        usage
     }
 
-    fn collect_processes(&self) -> Vec<ProcessInfo> {
+    fn collect_processes(
+        &self,
+        gpu_usage: &HashMap<u32, ProcessGpuUsage>,
+        net_usage: &HashMap<u32, ProcessNetUsage>,
+    ) -> Vec<ProcessInfo> {
         self.sys
             .processes()
             .iter()
             .map(|(pid, proc_info)| {
+                let net = net_usage.get(&pid.as_u32()).copied().unwrap_or_default();
                 let cmd_parts: Vec<String> = proc_info
                     .cmd()
                     .iter()
@@ -226,6 +307,10 @@ impl SystemCollector {
                     start_time: proc_info.start_time(),
                     parent_pid: proc_info.parent().map(|p| p.as_u32()),
                     thread_count: proc_info.tasks().map(|t| t.len() as u32),
+                    gpu_usage: gpu_usage.get(&pid.as_u32()).map_or(0.0, |u| u.usage),
+                    gpu_memory_bytes: gpu_usage.get(&pid.as_u32()).map_or(0, |u| u.memory_bytes),
+                    net_rx_bytes_per_sec: net.rx_bytes_per_sec,
+                    net_tx_bytes_per_sec: net.tx_bytes_per_sec,
                 }
             })
             .collect()
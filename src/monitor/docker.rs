@@ -108,6 +108,22 @@ impl DockerMonitor {
         result
     }
 
+    /// Freeze every process in a container (`docker pause`).
+    pub async fn pause_container(&self, id: &str) -> Result<(), String> {
+        let Some(client) = &self.client else {
+            return Err("Docker not available".to_string());
+        };
+        client.pause_container(id).await.map_err(|e| e.to_string())
+    }
+
+    /// Resume a paused container (`docker unpause`).
+    pub async fn unpause_container(&self, id: &str) -> Result<(), String> {
+        let Some(client) = &self.client else {
+            return Err("Docker not available".to_string());
+        };
+        client.unpause_container(id).await.map_err(|e| e.to_string())
+    }
+
     async fn get_container_stats(
         &self,
         client: &Docker,
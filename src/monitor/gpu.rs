@@ -0,0 +1,626 @@
+//! AMD and Intel GPU collection from DRM sysfs and fdinfo.
+//!
+//! NVIDIA cards are read through NVML; every other `/sys/class/drm/card<N>`
+//! is read from its `device/` directory:
+//!
+//! | Source                                            | Field       |
+//! |---------------------------------------------------|-------------|
+//! | `gpu_busy_percent` (amdgpu)                       | utilization |
+//! | `mem_info_vram_used` / `mem_info_vram_total`      | VRAM        |
+//! | `hwmon/hwmon*/temp1_input`                        | temperature |
+//! | `hwmon/hwmon*/power1_average`, `power1_input`     | power       |
+//! | `hwmon/hwmon*/energy1_input` (delta, Intel)       | power       |
+//! | `hwmon/hwmon*/pwm1`                               | fan         |
+//!
+//! i915 and xe expose no busy percentage, so Intel utilization is the
+//! busiest engine summed over every DRM client in `/proc/<pid>/fdinfo/<fd>`
+//! between two samples. The same fdinfo counters give per-process GPU
+//! utilization and VRAM for amdgpu, i915 and xe clients.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use crate::constants::{PCI_VENDOR_AMD, PCI_VENDOR_INTEL};
+use crate::models::{GpuInfo, GpuVendor};
+use crate::utils::{read_trimmed, read_u64};
+
+/// One open DRM file, parsed from `/proc/<pid>/fdinfo/<fd>`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DrmClient {
+    pub driver: String,
+    /// PCI address of the device (`0000:00:02.0`)
+    pub pdev: String,
+    pub client_id: u64,
+    /// Busy time per engine in ns (`drm-engine-<name>`: i915, amdgpu)
+    pub engine_ns: BTreeMap<String, u64>,
+    /// Engine instances (`drm-engine-capacity-<name>`; 1 when absent)
+    pub engine_capacity: BTreeMap<String, u64>,
+    /// Busy and total GPU cycles per engine class
+    /// (`drm-cycles-<name>` / `drm-total-cycles-<name>`: xe)
+    pub engine_cycles: BTreeMap<String, (u64, u64)>,
+    /// Resident bytes per memory region (`drm-resident-<region>`, or the
+    /// older amdgpu `drm-memory-<region>`)
+    pub memory: BTreeMap<String, u64>,
+}
+
+impl DrmClient {
+    /// Bytes resident in device memory (`vram`, `vram0`, i915 `local0`).
+    pub fn vram_bytes(&self) -> u64 {
+        self.memory
+            .iter()
+            .filter(|(region, _)| region.starts_with("vram") || region.starts_with("local"))
+            .map(|(_, bytes)| bytes)
+            .sum()
+    }
+}
+
+/// Parse a DRM fdinfo file; `None` if it is not a DRM client.
+pub fn parse_fdinfo(text: &str) -> Option<DrmClient> {
+    let mut client = DrmClient::default();
+    for line in text.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        let number = || value.split_whitespace().next()?.parse::<u64>().ok();
+        let bytes = || {
+            let mut parts = value.split_whitespace();
+            let n = parts.next()?.parse::<u64>().ok()?;
+            Some(match parts.next() {
+                Some("KiB") => n << 10,
+                Some("MiB") => n << 20,
+                Some("GiB") => n << 30,
+                _ => n,
+            })
+        };
+        if key == "drm-driver" {
+            client.driver = value.to_string();
+        } else if key == "drm-pdev" {
+            client.pdev = value.to_string();
+        } else if key == "drm-client-id" {
+            client.client_id = number().unwrap_or(0);
+        } else if let Some(engine) = key.strip_prefix("drm-engine-capacity-") {
+            if let Some(n) = number() {
+                client.engine_capacity.insert(engine.to_string(), n);
+            }
+        } else if let Some(engine) = key.strip_prefix("drm-engine-") {
+            if let Some(n) = number() {
+                client.engine_ns.insert(engine.to_string(), n);
+            }
+        } else if let Some(engine) = key.strip_prefix("drm-total-cycles-") {
+            if let Some(n) = number() {
+                client
+                    .engine_cycles
+                    .entry(engine.to_string())
+                    .or_default()
+                    .1 = n;
+            }
+        } else if let Some(engine) = key.strip_prefix("drm-cycles-") {
+            if let Some(n) = number() {
+                client
+                    .engine_cycles
+                    .entry(engine.to_string())
+                    .or_default()
+                    .0 = n;
+            }
+        } else if let Some(region) = key.strip_prefix("drm-resident-") {
+            if let Some(n) = bytes() {
+                client.memory.insert(region.to_string(), n);
+            }
+        } else if let Some(region) = key.strip_prefix("drm-memory-") {
+            if let Some(n) = bytes() {
+                client.memory.entry(region.to_string()).or_insert(n);
+            }
+        }
+    }
+    (!client.driver.is_empty()).then_some(client)
+}
+
+/// Every DRM client open under `proc_root`, with the pid holding it.
+///
+/// A client shared by several fds or processes (dup, fork) is listed once.
+pub fn read_drm_clients(proc_root: &Path) -> Vec<(u32, DrmClient)> {
+    let mut seen = HashSet::new();
+    let mut clients = Vec::new();
+    let Ok(entries) = fs::read_dir(proc_root) else {
+        return clients;
+    };
+    let mut pids: Vec<u32> = entries
+        .flatten()
+        .filter_map(|e| e.file_name().to_str()?.parse().ok())
+        .collect();
+    pids.sort_unstable();
+
+    for pid in pids {
+        let pid_dir = proc_root.join(pid.to_string());
+        let Ok(fds) = fs::read_dir(pid_dir.join("fd")) else {
+            continue;
+        };
+        for fd in fds.flatten() {
+            let is_dri = fs::read_link(fd.path())
+                .map(|target| target.starts_with("/dev/dri"))
+                .unwrap_or(false);
+            if !is_dri {
+                continue;
+            }
+            let Ok(text) = fs::read_to_string(pid_dir.join("fdinfo").join(fd.file_name())) else {
+                continue;
+            };
+            let Some(client) = parse_fdinfo(&text) else {
+                continue;
+            };
+            let key = (client.driver.clone(), client.pdev.clone(), client.client_id);
+            if seen.insert(key) {
+                clients.push((pid, client));
+            }
+        }
+    }
+    clients
+}
+
+/// GPU utilization and memory attributed to one process.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ProcessGpuUsage {
+    /// Busiest engine (%) on the busiest GPU
+    pub usage: f32,
+    /// Device memory held across all GPUs (bytes)
+    pub memory_bytes: u64,
+}
+
+impl ProcessGpuUsage {
+    /// Combine usage of the same process on another GPU.
+    pub fn merge(&mut self, other: ProcessGpuUsage) {
+        self.usage = self.usage.max(other.usage);
+        self.memory_bytes += other.memory_bytes;
+    }
+}
+
+/// (PCI address, client id, engine) — identifies one fdinfo counter.
+type CounterKey = (String, u64, String);
+
+/// Collects AMD and Intel GPUs from DRM sysfs, and per-process usage from
+/// DRM fdinfo.
+pub struct DrmGpuCollector {
+    sys_root: PathBuf,
+    proc_root: PathBuf,
+    /// When the previous sample was taken, for rates
+    prev_at: Option<Instant>,
+    /// card → `energy1_input` (µJ) at the previous sample
+    prev_energy: HashMap<String, u64>,
+    /// Engine busy ns at the previous sample
+    prev_ns: HashMap<CounterKey, u64>,
+    /// Engine (busy, total) cycles at the previous sample
+    prev_cycles: HashMap<CounterKey, (u64, u64)>,
+    processes: HashMap<u32, ProcessGpuUsage>,
+}
+
+/// An AMD or Intel card found under `class/drm`.
+struct DrmCard {
+    id: String,
+    device: PathBuf,
+    vendor: GpuVendor,
+    /// PCI address (`0000:03:00.0`), matched against fdinfo `drm-pdev`
+    pdev: String,
+}
+
+impl DrmGpuCollector {
+    pub fn new() -> Self {
+        Self::with_roots("/sys", "/proc")
+    }
+
+    /// Read from alternate `/sys` and `/proc` trees (tests).
+    pub fn with_roots(sys_root: impl Into<PathBuf>, proc_root: impl Into<PathBuf>) -> Self {
+        Self {
+            sys_root: sys_root.into(),
+            proc_root: proc_root.into(),
+            prev_at: None,
+            prev_energy: HashMap::new(),
+            prev_ns: HashMap::new(),
+            prev_cycles: HashMap::new(),
+            processes: HashMap::new(),
+        }
+    }
+
+    /// One [`GpuInfo`] per AMD or Intel card, in card order.
+    pub fn collect(&mut self) -> Vec<GpuInfo> {
+        self.collect_at(Instant::now())
+    }
+
+    /// Per-process usage from the last [`collect`](Self::collect), by PID.
+    pub fn processes(&self) -> &HashMap<u32, ProcessGpuUsage> {
+        &self.processes
+    }
+
+    fn collect_at(&mut self, now: Instant) -> Vec<GpuInfo> {
+        let cards: Vec<DrmCard> = drm_cards(&self.sys_root.join("class/drm"))
+            .into_iter()
+            .filter_map(|(id, path)| {
+                let device = path.join("device");
+                let vendor = match read_trimmed(&device.join("vendor")).as_deref() {
+                    Some(PCI_VENDOR_AMD) => GpuVendor::Amd,
+                    Some(PCI_VENDOR_INTEL) => GpuVendor::Intel,
+                    _ => return None,
+                };
+                let pdev = pci_slot(&device).unwrap_or_default();
+                Some(DrmCard {
+                    id,
+                    device,
+                    vendor,
+                    pdev,
+                })
+            })
+            .collect();
+        if cards.is_empty() {
+            self.processes.clear();
+            return Vec::new();
+        }
+
+        let elapsed_secs = self
+            .prev_at
+            .map(|at| now.saturating_duration_since(at).as_secs_f64())
+            .filter(|s| *s > 0.0);
+        self.prev_at = Some(now);
+
+        // (pdev, engine) → busy % summed over clients
+        let mut card_busy: HashMap<(String, String), f64> = HashMap::new();
+        // (pid, pdev, engine) → busy %
+        let mut proc_busy: HashMap<(u32, String, String), f64> = HashMap::new();
+        let mut proc_memory: HashMap<u32, u64> = HashMap::new();
+        let mut next_ns = HashMap::new();
+        let mut next_cycles = HashMap::new();
+
+        for (pid, client) in read_drm_clients(&self.proc_root) {
+            if !matches!(client.driver.as_str(), "amdgpu" | "i915" | "xe") {
+                continue;
+            }
+            let busy = client_busy(&client, elapsed_secs, &self.prev_ns, &self.prev_cycles);
+            for (engine, pct) in busy {
+                *card_busy
+                    .entry((client.pdev.clone(), engine.clone()))
+                    .or_default() += pct;
+                *proc_busy
+                    .entry((pid, client.pdev.clone(), engine))
+                    .or_default() += pct;
+            }
+            *proc_memory.entry(pid).or_default() += client.vram_bytes();
+            for (engine, &ns) in &client.engine_ns {
+                next_ns.insert((client.pdev.clone(), client.client_id, engine.clone()), ns);
+            }
+            for (engine, &cycles) in &client.engine_cycles {
+                next_cycles.insert(
+                    (client.pdev.clone(), client.client_id, engine.clone()),
+                    cycles,
+                );
+            }
+        }
+        self.prev_ns = next_ns;
+        self.prev_cycles = next_cycles;
+
+        let mut processes: HashMap<u32, ProcessGpuUsage> = proc_memory
+            .into_iter()
+            .map(|(pid, memory_bytes)| {
+                (
+                    pid,
+                    ProcessGpuUsage {
+                        usage: 0.0,
+                        memory_bytes,
+                    },
+                )
+            })
+            .collect();
+        for ((pid, _, _), pct) in proc_busy {
+            let entry = processes.entry(pid).or_default();
+            entry.usage = entry.usage.max(pct.min(100.0) as f32);
+        }
+        processes.retain(|_, u| u.usage > 0.0 || u.memory_bytes > 0);
+        self.processes = processes;
+
+        cards
+            .into_iter()
+            .map(|card| self.read_card(card, &card_busy, elapsed_secs))
+            .collect()
+    }
+
+    fn read_card(
+        &mut self,
+        card: DrmCard,
+        card_busy: &HashMap<(String, String), f64>,
+        elapsed_secs: Option<f64>,
+    ) -> GpuInfo {
+        let device = &card.device;
+        let utilization = match card.vendor {
+            GpuVendor::Intel => card_busy
+                .iter()
+                .filter(|((pdev, _), _)| card.pdev.is_empty() || *pdev == card.pdev)
+                .map(|(_, pct)| *pct)
+                .fold(0.0f64, f64::max)
+                .round() as u32,
+            _ => read_u64(&device.join("gpu_busy_percent")).unwrap_or(0) as u32,
+        };
+
+        let hwmon = first_hwmon(device);
+        let sensor = |file: &str| hwmon.as_ref().and_then(|h| read_u64(&h.join(file)));
+        let energy_uj = sensor("energy1_input");
+        let prev_energy = match energy_uj {
+            Some(e) => self.prev_energy.insert(card.id.clone(), e),
+            None => self.prev_energy.remove(&card.id),
+        };
+        let power_draw = sensor("power1_average")
+            .or_else(|| sensor("power1_input"))
+            .map(|uw| uw as f32 / 1_000_000.0)
+            .or_else(|| {
+                let delta = energy_uj?.checked_sub(prev_energy?)?;
+                Some((delta as f64 / 1_000_000.0 / elapsed_secs?) as f32)
+            });
+
+        let name = read_trimmed(&device.join("product_name")).unwrap_or_else(|| {
+            match card.vendor {
+                GpuVendor::Intel => "Intel Graphics",
+                _ => "AMD Radeon",
+            }
+            .to_string()
+        });
+
+        GpuInfo {
+            id: card.id,
+            vendor: card.vendor,
+            name,
+            utilization: utilization.min(100),
+            memory_used: read_u64(&device.join("mem_info_vram_used")).unwrap_or(0),
+            memory_total: read_u64(&device.join("mem_info_vram_total")).unwrap_or(0),
+            temperature: sensor("temp1_input").map(|mc| (mc / 1000) as u32),
+            power_draw,
+            fan_speed: sensor("pwm1").map(|pwm| (pwm.min(255) * 100 / 255) as u32),
+        }
+    }
+}
+
+/// Busy % per engine for one client since the previous sample (none on the
+/// first sample).
+fn client_busy(
+    client: &DrmClient,
+    elapsed_secs: Option<f64>,
+    prev_ns: &HashMap<CounterKey, u64>,
+    prev_cycles: &HashMap<CounterKey, (u64, u64)>,
+) -> Vec<(String, f64)> {
+    let Some(secs) = elapsed_secs else {
+        return Vec::new();
+    };
+    let key = |engine: &str| (client.pdev.clone(), client.client_id, engine.to_string());
+    let mut busy = Vec::new();
+    for (engine, &ns) in &client.engine_ns {
+        // A client opened since the last sample did all its work in between
+        let delta = ns.saturating_sub(prev_ns.get(&key(engine)).copied().unwrap_or(0));
+        let capacity = client
+            .engine_capacity
+            .get(engine)
+            .copied()
+            .unwrap_or(1)
+            .max(1);
+        busy.push((
+            engine.clone(),
+            delta as f64 / (secs * 1e9 * capacity as f64) * 100.0,
+        ));
+    }
+    for (engine, &(cycles, total)) in &client.engine_cycles {
+        if let Some(&(prev_cycles, prev_total)) = prev_cycles.get(&key(engine)) {
+            let total_delta = total.saturating_sub(prev_total);
+            if total_delta > 0 {
+                let delta = cycles.saturating_sub(prev_cycles);
+                busy.push((engine.clone(), delta as f64 / total_delta as f64 * 100.0));
+            }
+        }
+    }
+    busy
+}
+
+/// `card<N>` entries under `class/drm`, skipping connectors (`card0-DP-1`).
+fn drm_cards(drm: &Path) -> Vec<(String, PathBuf)> {
+    let mut cards: Vec<(u32, String, PathBuf)> = fs::read_dir(drm)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|e| {
+                    let name = e.file_name().into_string().ok()?;
+                    let index = name.strip_prefix("card")?.parse().ok()?;
+                    Some((index, name, e.path()))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    cards.sort_by_key(|(i, _, _)| *i);
+    cards
+        .into_iter()
+        .map(|(_, name, path)| (name, path))
+        .collect()
+}
+
+/// PCI address from `device/uevent` (`PCI_SLOT_NAME=0000:00:02.0`).
+fn pci_slot(device: &Path) -> Option<String> {
+    fs::read_to_string(device.join("uevent"))
+        .ok()?
+        .lines()
+        .find_map(|l| l.strip_prefix("PCI_SLOT_NAME="))
+        .map(str::to_string)
+}
+
+fn first_hwmon(device: &Path) -> Option<PathBuf> {
+    let mut dirs: Vec<PathBuf> = fs::read_dir(device.join("hwmon"))
+        .ok()?
+        .flatten()
+        .map(|e| e.path())
+        .collect();
+    dirs.sort();
+    dirs.into_iter().next()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::write_tree;
+    use std::time::Duration;
+
+    /// An Intel client holding render engine time `render_ns`.
+    fn i915_fdinfo(render_ns: u64) -> String {
+        format!(
+            "pos:\t0\nflags:\t02100002\ndrm-driver:\ti915\ndrm-pdev:\t0000:00:02.0\n\
+             drm-client-id:\t7\ndrm-engine-render:\t{} ns\ndrm-engine-video:\t0 ns\n\
+             drm-engine-capacity-video:\t2",
+            render_ns
+        )
+    }
+
+    #[test]
+    fn parse_fdinfo_reads_i915_and_xe_fields() {
+        let c = parse_fdinfo(&i915_fdinfo(1500)).unwrap();
+        assert_eq!(c.driver, "i915");
+        assert_eq!(c.pdev, "0000:00:02.0");
+        assert_eq!(c.client_id, 7);
+        assert_eq!(c.engine_ns["render"], 1500);
+        assert_eq!(c.engine_capacity["video"], 2);
+        assert!(!c.engine_ns.contains_key("capacity-video"));
+
+        let xe = parse_fdinfo(
+            "drm-driver:\txe\ndrm-client-id:\t3\ndrm-cycles-rcs:\t100\ndrm-total-cycles-rcs:\t400",
+        )
+        .unwrap();
+        assert_eq!(xe.engine_cycles["rcs"], (100, 400));
+
+        assert!(parse_fdinfo("pos:\t0\nflags:\t02\n").is_none());
+    }
+
+    #[test]
+    fn reads_amd_cards_and_skips_nvidia_and_connectors() {
+        let dir = tempfile::tempdir().unwrap();
+        write_tree(
+            dir.path(),
+            &[
+                ("class/drm/card0/device/vendor", "0x10de"),
+                ("class/drm/card1/device/vendor", "0x1002"),
+                ("class/drm/card1/device/product_name", "Radeon RX 7800 XT"),
+                ("class/drm/card1/device/gpu_busy_percent", "37"),
+                ("class/drm/card1/device/mem_info_vram_used", "2147483648"),
+                ("class/drm/card1/device/mem_info_vram_total", "17179869184"),
+                ("class/drm/card1/device/hwmon/hwmon3/temp1_input", "61000"),
+                (
+                    "class/drm/card1/device/hwmon/hwmon3/power1_average",
+                    "142000000",
+                ),
+                ("class/drm/card1/device/hwmon/hwmon3/pwm1", "102"),
+                ("class/drm/card1-DP-1/status", "connected"),
+            ],
+        );
+        let mut collector = DrmGpuCollector::with_roots(dir.path(), dir.path().join("proc"));
+        let gpus = collector.collect();
+
+        assert_eq!(gpus.len(), 1);
+        let gpu = &gpus[0];
+        assert_eq!((gpu.id.as_str(), gpu.vendor), ("card1", GpuVendor::Amd));
+        assert_eq!(gpu.name, "Radeon RX 7800 XT");
+        assert_eq!(gpu.utilization, 37);
+        assert_eq!(gpu.memory_used, 2 << 30);
+        assert_eq!(gpu.memory_total, 16 << 30);
+        assert_eq!(gpu.temperature, Some(61));
+        assert_eq!(gpu.power_draw, Some(142.0));
+        assert_eq!(gpu.fan_speed, Some(40));
+    }
+
+    #[test]
+    fn intel_utilization_and_power_come_from_deltas() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write_tree(
+            root,
+            &[
+                ("sys/class/drm/card0/device/vendor", "0x8086"),
+                (
+                    "sys/class/drm/card0/device/uevent",
+                    "DRIVER=i915\nPCI_SLOT_NAME=0000:00:02.0",
+                ),
+                (
+                    "sys/class/drm/card0/device/hwmon/hwmon5/energy1_input",
+                    "1000000",
+                ),
+                ("proc/4242/fdinfo/9", &i915_fdinfo(1_000_000_000)),
+                // Same client through a dup'd fd: counted once
+                ("proc/4242/fdinfo/10", &i915_fdinfo(1_000_000_000)),
+            ],
+        );
+        let fd_dir = root.join("proc/4242/fd");
+        fs::create_dir_all(&fd_dir).unwrap();
+        std::os::unix::fs::symlink("/dev/dri/renderD128", fd_dir.join("9")).unwrap();
+        std::os::unix::fs::symlink("/dev/dri/renderD128", fd_dir.join("10")).unwrap();
+        std::os::unix::fs::symlink("/dev/null", fd_dir.join("0")).unwrap();
+
+        let mut collector = DrmGpuCollector::with_roots(root.join("sys"), root.join("proc"));
+        let start = Instant::now();
+        let first = collector.collect_at(start);
+        assert_eq!(first[0].vendor, GpuVendor::Intel);
+        assert_eq!(first[0].name, "Intel Graphics");
+        assert_eq!(first[0].utilization, 0);
+        assert_eq!(first[0].power_draw, None);
+
+        // 500 ms of render time and 6 J over 2 s
+        write_tree(
+            root,
+            &[
+                (
+                    "sys/class/drm/card0/device/hwmon/hwmon5/energy1_input",
+                    "7000000",
+                ),
+                ("proc/4242/fdinfo/9", &i915_fdinfo(1_500_000_000)),
+                ("proc/4242/fdinfo/10", &i915_fdinfo(1_500_000_000)),
+            ],
+        );
+        let second = collector.collect_at(start + Duration::from_secs(2));
+        assert_eq!(second[0].utilization, 25);
+        assert_eq!(second[0].power_draw, Some(3.0));
+        assert_eq!(second[0].memory_total, 0);
+    }
+
+    #[test]
+    fn per_process_usage_from_amdgpu_fdinfo() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let fdinfo = |gfx_ns: u64| {
+            format!(
+                "drm-driver:\tamdgpu\ndrm-pdev:\t0000:03:00.0\ndrm-client-id:\t12\n\
+                 drm-engine-gfx:\t{} ns\ndrm-memory-vram:\t524288 KiB\n\
+                 drm-memory-gtt:\t2048 KiB",
+                gfx_ns
+            )
+        };
+        write_tree(
+            root,
+            &[
+                ("sys/class/drm/card0/device/vendor", "0x1002"),
+                (
+                    "sys/class/drm/card0/device/uevent",
+                    "DRIVER=amdgpu\nPCI_SLOT_NAME=0000:03:00.0",
+                ),
+                ("proc/77/fdinfo/5", &fdinfo(0)),
+            ],
+        );
+        let fd_dir = root.join("proc/77/fd");
+        fs::create_dir_all(&fd_dir).unwrap();
+        std::os::unix::fs::symlink("/dev/dri/renderD129", fd_dir.join("5")).unwrap();
+
+        let mut collector = DrmGpuCollector::with_roots(root.join("sys"), root.join("proc"));
+        let start = Instant::now();
+        collector.collect_at(start);
+        // No rate yet, but VRAM is attributed at once (GTT is not VRAM)
+        assert_eq!(
+            collector.processes()[&77],
+            ProcessGpuUsage {
+                usage: 0.0,
+                memory_bytes: 512 << 20,
+            }
+        );
+
+        write_tree(root, &[("proc/77/fdinfo/5", &fdinfo(600_000_000))]);
+        collector.collect_at(start + Duration::from_secs(1));
+        assert_eq!(collector.processes()[&77].usage, 60.0);
+    }
+}
@@ -0,0 +1,547 @@
+//! Deep inspection of one process for the detail popup.
+//!
+//! Everything comes from `/proc/<pid>/`:
+//!
+//! | File           | Section                                            |
+//! |----------------|----------------------------------------------------|
+//! | `smaps_rollup` | RSS / PSS / USS / shared / swap                    |
+//! | `io`           | read/write syscalls and bytes, storage bytes       |
+//! | `limits`       | resource limits (soft / hard)                      |
+//! | `cgroup`       | cgroup v2 path                                     |
+//! | `ns/*`         | namespaces, compared against Sentinel's own        |
+//! | `status`       | capability sets                                    |
+//! | `stat`         | scheduler policy, priority, nice, last CPU         |
+//! | `task/*/stat`  | per-thread state and CPU time                      |
+//!
+//! Files the caller may not read (other users' processes without root) leave
+//! their section empty.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// Memory breakdown from `smaps_rollup` (bytes).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MemoryBreakdown {
+    pub rss: u64,
+    /// Proportional set size: shared pages split between their users
+    pub pss: u64,
+    /// Unique set size: private pages, freed if the process exits
+    pub uss: u64,
+    pub shared: u64,
+    pub swap: u64,
+}
+
+/// I/O counters from `/proc/<pid>/io`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IoCounters {
+    /// Bytes passed to read()-like syscalls (includes page cache hits)
+    pub rchar: u64,
+    pub wchar: u64,
+    pub syscr: u64,
+    pub syscw: u64,
+    /// Bytes actually fetched from storage
+    pub read_bytes: u64,
+    pub write_bytes: u64,
+    pub cancelled_write_bytes: u64,
+}
+
+/// One row of `/proc/<pid>/limits`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResourceLimit {
+    pub name: String,
+    pub soft: String,
+    pub hard: String,
+    pub units: String,
+}
+
+/// One namespace the process is in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Namespace {
+    /// `net`, `pid`, `mnt`, ...
+    pub kind: String,
+    /// Link target, e.g. `net:[4026531840]`
+    pub id: String,
+    /// Whether Sentinel is in the same namespace
+    pub shared: bool,
+}
+
+/// Capability sets from `status` (bit masks).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Capabilities {
+    pub inheritable: u64,
+    pub permitted: u64,
+    pub effective: u64,
+    pub bounding: u64,
+    pub ambient: u64,
+}
+
+impl Capabilities {
+    /// Names of the effective capabilities (`cap_net_admin`, ...).
+    pub fn effective_names(&self) -> Vec<&'static str> {
+        CAPABILITY_NAMES
+            .iter()
+            .enumerate()
+            .filter(|(bit, _)| self.effective & (1 << bit) != 0)
+            .map(|(_, name)| *name)
+            .collect()
+    }
+}
+
+/// Scheduling fields from `stat`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scheduling {
+    pub policy: &'static str,
+    pub priority: i64,
+    pub nice: i64,
+    /// Real-time priority (1-99 for FIFO / RR, 0 otherwise)
+    pub rt_priority: u64,
+    /// CPU the process last ran on
+    pub processor: i64,
+}
+
+/// One thread with its CPU usage since the previous sample.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThreadInfo {
+    pub tid: u32,
+    pub name: String,
+    /// `R`, `S`, `D`, ...
+    pub state: char,
+    /// utime + stime (clock ticks)
+    pub cpu_ticks: u64,
+    /// % of one CPU since the previous sample (0 on the first)
+    pub cpu_usage: f32,
+}
+
+/// Everything the inspector read for one process.
+#[derive(Debug, Clone, Default)]
+pub struct ProcessInspection {
+    pub memory: Option<MemoryBreakdown>,
+    pub io: Option<IoCounters>,
+    pub limits: Vec<ResourceLimit>,
+    pub cgroup: Option<String>,
+    pub namespaces: Vec<Namespace>,
+    pub capabilities: Option<Capabilities>,
+    pub scheduling: Option<Scheduling>,
+    pub threads: Vec<ThreadInfo>,
+}
+
+/// Bit → name, per `include/uapi/linux/capability.h`.
+const CAPABILITY_NAMES: &[&str] = &[
+    "cap_chown",
+    "cap_dac_override",
+    "cap_dac_read_search",
+    "cap_fowner",
+    "cap_fsetid",
+    "cap_kill",
+    "cap_setgid",
+    "cap_setuid",
+    "cap_setpcap",
+    "cap_linux_immutable",
+    "cap_net_bind_service",
+    "cap_net_broadcast",
+    "cap_net_admin",
+    "cap_net_raw",
+    "cap_ipc_lock",
+    "cap_ipc_owner",
+    "cap_sys_module",
+    "cap_sys_rawio",
+    "cap_sys_chroot",
+    "cap_sys_ptrace",
+    "cap_sys_pacct",
+    "cap_sys_admin",
+    "cap_sys_boot",
+    "cap_sys_nice",
+    "cap_sys_resource",
+    "cap_sys_time",
+    "cap_sys_tty_config",
+    "cap_mknod",
+    "cap_lease",
+    "cap_audit_write",
+    "cap_audit_control",
+    "cap_setfcap",
+    "cap_mac_override",
+    "cap_mac_admin",
+    "cap_syslog",
+    "cap_wake_alarm",
+    "cap_block_suspend",
+    "cap_audit_read",
+    "cap_perfmon",
+    "cap_bpf",
+    "cap_checkpoint_restore",
+];
+
+/// Namespace kinds listed under `/proc/<pid>/ns`.
+const NAMESPACE_KINDS: &[&str] = &["cgroup", "ipc", "mnt", "net", "pid", "time", "user", "uts"];
+
+/// Reads process details from procfs.
+pub struct ProcessInspector {
+    proc_root: PathBuf,
+    ticks_per_sec: f64,
+}
+
+impl ProcessInspector {
+    pub fn new() -> Self {
+        let ticks = unsafe { libc::sysconf(libc::_SC_CLK_TCK) };
+        Self {
+            proc_root: PathBuf::from("/proc"),
+            ticks_per_sec: if ticks > 0 { ticks as f64 } else { 100.0 },
+        }
+    }
+
+    /// Read from an alternate procfs with 100 ticks/s (tests).
+    #[cfg(test)]
+    fn with_root(proc_root: impl Into<PathBuf>) -> Self {
+        Self {
+            proc_root: proc_root.into(),
+            ticks_per_sec: 100.0,
+        }
+    }
+
+    /// Read every section for `pid`. Threads carry no CPU usage yet; see
+    /// [`ProcessInspector::threads`].
+    pub fn inspect(&self, pid: u32) -> ProcessInspection {
+        let dir = self.proc_root.join(pid.to_string());
+        let read = |file: &str| fs::read_to_string(dir.join(file)).ok();
+        ProcessInspection {
+            memory: read("smaps_rollup").map(|t| parse_smaps_rollup(&t)),
+            io: read("io").map(|t| parse_io(&t)),
+            limits: read("limits").map(|t| parse_limits(&t)).unwrap_or_default(),
+            cgroup: read("cgroup").and_then(|t| {
+                t.lines()
+                    .find_map(|l| l.strip_prefix("0::"))
+                    .map(|p| p.trim().to_string())
+            }),
+            namespaces: self.namespaces(pid),
+            capabilities: read("status").map(|t| parse_capabilities(&t)),
+            scheduling: read("stat").and_then(|t| parse_scheduling(&t)),
+            threads: self.threads(pid, &[], 0.0),
+        }
+    }
+
+    /// Threads of `pid`, busiest first. CPU usage is the tick delta against
+    /// `prev` over `elapsed_secs`.
+    pub fn threads(&self, pid: u32, prev: &[ThreadInfo], elapsed_secs: f64) -> Vec<ThreadInfo> {
+        let Ok(entries) = fs::read_dir(self.proc_root.join(pid.to_string()).join("task")) else {
+            return Vec::new();
+        };
+        let prev: HashMap<u32, u64> = prev.iter().map(|t| (t.tid, t.cpu_ticks)).collect();
+        let mut threads: Vec<ThreadInfo> = entries
+            .flatten()
+            .filter_map(|e| {
+                let tid: u32 = e.file_name().to_str()?.parse().ok()?;
+                let stat = fs::read_to_string(e.path().join("stat")).ok()?;
+                let (name, rest) = split_stat(&stat)?;
+                let state = rest.first()?.chars().next()?;
+                let utime: u64 = rest.get(11)?.parse().ok()?;
+                let stime: u64 = rest.get(12)?.parse().ok()?;
+                let cpu_ticks = utime + stime;
+                let cpu_usage = match prev.get(&tid) {
+                    Some(&before) if elapsed_secs > 0.0 => {
+                        (cpu_ticks.saturating_sub(before) as f64
+                            / self.ticks_per_sec
+                            / elapsed_secs
+                            * 100.0) as f32
+                    }
+                    _ => 0.0,
+                };
+                Some(ThreadInfo {
+                    tid,
+                    name: name.to_string(),
+                    state,
+                    cpu_ticks,
+                    cpu_usage,
+                })
+            })
+            .collect();
+        threads.sort_by(|a, b| {
+            b.cpu_usage
+                .total_cmp(&a.cpu_usage)
+                .then(b.cpu_ticks.cmp(&a.cpu_ticks))
+                .then(a.tid.cmp(&b.tid))
+        });
+        threads
+    }
+
+    /// Namespaces of `pid`, flagged when Sentinel shares them.
+    fn namespaces(&self, pid: u32) -> Vec<Namespace> {
+        let ns_dir = self.proc_root.join(pid.to_string()).join("ns");
+        let own_dir = self.proc_root.join("self").join("ns");
+        NAMESPACE_KINDS
+            .iter()
+            .filter_map(|kind| {
+                let id = fs::read_link(ns_dir.join(kind)).ok()?;
+                let id = id.to_string_lossy().to_string();
+                let shared = fs::read_link(own_dir.join(kind))
+                    .map(|own| own.to_string_lossy() == id)
+                    .unwrap_or(false);
+                Some(Namespace {
+                    kind: kind.to_string(),
+                    id,
+                    shared,
+                })
+            })
+            .collect()
+    }
+}
+
+/// `(comm, fields after comm)` of a `stat` line; comm may hold spaces and ')'.
+fn split_stat(stat: &str) -> Option<(&str, Vec<&str>)> {
+    let open = stat.find('(')?;
+    let close = stat.rfind(')')?;
+    let name = stat.get(open + 1..close)?;
+    Some((name, stat[close + 1..].split_whitespace().collect()))
+}
+
+/// `Key:   123 kB` lines into bytes.
+fn parse_smaps_rollup(text: &str) -> MemoryBreakdown {
+    let kib: HashMap<&str, u64> = text
+        .lines()
+        .filter_map(|l| {
+            let (key, rest) = l.split_once(':')?;
+            let value = rest.split_whitespace().next()?.parse().ok()?;
+            Some((key.trim(), value))
+        })
+        .collect();
+    let get = |key: &str| kib.get(key).copied().unwrap_or(0) * 1024;
+    MemoryBreakdown {
+        rss: get("Rss"),
+        pss: get("Pss"),
+        uss: get("Private_Clean") + get("Private_Dirty") + get("Private_Hugetlb"),
+        shared: get("Shared_Clean") + get("Shared_Dirty") + get("Shared_Hugetlb"),
+        swap: get("Swap"),
+    }
+}
+
+fn parse_io(text: &str) -> IoCounters {
+    let values: HashMap<&str, u64> = text
+        .lines()
+        .filter_map(|l| {
+            let (key, value) = l.split_once(':')?;
+            Some((key.trim(), value.trim().parse().ok()?))
+        })
+        .collect();
+    let get = |key: &str| values.get(key).copied().unwrap_or(0);
+    IoCounters {
+        rchar: get("rchar"),
+        wchar: get("wchar"),
+        syscr: get("syscr"),
+        syscw: get("syscw"),
+        read_bytes: get("read_bytes"),
+        write_bytes: get("write_bytes"),
+        cancelled_write_bytes: get("cancelled_write_bytes"),
+    }
+}
+
+/// Fixed-width table; column starts come from the header line.
+fn parse_limits(text: &str) -> Vec<ResourceLimit> {
+    let mut lines = text.lines();
+    let Some(header) = lines.next() else {
+        return Vec::new();
+    };
+    let (Some(soft), Some(hard), Some(units)) = (
+        header.find("Soft Limit"),
+        header.find("Hard Limit"),
+        header.find("Units"),
+    ) else {
+        return Vec::new();
+    };
+    let col = |line: &str, from: usize, to: usize| {
+        line.get(from.min(line.len())..to.min(line.len()))
+            .unwrap_or("")
+            .trim()
+            .to_string()
+    };
+    lines
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| ResourceLimit {
+            name: col(l, 0, soft),
+            soft: col(l, soft, hard),
+            hard: col(l, hard, units),
+            units: col(l, units, l.len()),
+        })
+        .collect()
+}
+
+fn parse_capabilities(status: &str) -> Capabilities {
+    let mask = |key: &str| {
+        status
+            .lines()
+            .find_map(|l| l.strip_prefix(key))
+            .and_then(|v| u64::from_str_radix(v.trim(), 16).ok())
+            .unwrap_or(0)
+    };
+    Capabilities {
+        inheritable: mask("CapInh:"),
+        permitted: mask("CapPrm:"),
+        effective: mask("CapEff:"),
+        bounding: mask("CapBnd:"),
+        ambient: mask("CapAmb:"),
+    }
+}
+
+/// Fields 18 (priority), 19 (nice), 39 (processor), 40 (rt_priority) and
+/// 41 (policy) of `stat`.
+fn parse_scheduling(stat: &str) -> Option<Scheduling> {
+    let (_, rest) = split_stat(stat)?;
+    // `rest[0]` is field 3 (state)
+    let field = |n: usize| rest.get(n - 3).copied();
+    let policy = match field(41)?.parse::<u32>().ok()? {
+        0 => "SCHED_OTHER",
+        1 => "SCHED_FIFO",
+        2 => "SCHED_RR",
+        3 => "SCHED_BATCH",
+        5 => "SCHED_IDLE",
+        6 => "SCHED_DEADLINE",
+        _ => "unknown",
+    };
+    Some(Scheduling {
+        policy,
+        priority: field(18)?.parse().ok()?,
+        nice: field(19)?.parse().ok()?,
+        rt_priority: field(40)?.parse().ok()?,
+        processor: field(39)?.parse().ok()?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::write_tree;
+    use std::os::unix::fs::symlink;
+
+    /// A `stat` line with the given comm, state, utime/stime and tail fields.
+    fn stat_line(comm: &str, state: char, utime: u64, stime: u64) -> String {
+        // Fields 3..=41: state, ppid..., utime (14), stime (15), ..., priority (18),
+        // nice (19), ..., processor (39), rt_priority (40), policy (41)
+        let mut fields: Vec<String> = (3..=41).map(|_| "0".to_string()).collect();
+        fields[0] = state.to_string();
+        fields[14 - 3] = utime.to_string();
+        fields[15 - 3] = stime.to_string();
+        fields[18 - 3] = "20".to_string();
+        fields[19 - 3] = "5".to_string();
+        fields[39 - 3] = "3".to_string();
+        fields[41 - 3] = "3".to_string();
+        format!("42 ({}) {}", comm, fields.join(" "))
+    }
+
+    #[test]
+    fn inspects_proc_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write_tree(
+            root,
+            &[
+                (
+                    "42/smaps_rollup",
+                    "55d0-7ffd ---p 00000000 00:00 0 [rollup]\n\
+                     Rss:                4096 kB\n\
+                     Pss:                3072 kB\n\
+                     Shared_Clean:       1024 kB\n\
+                     Shared_Dirty:          0 kB\n\
+                     Private_Clean:       512 kB\n\
+                     Private_Dirty:      2560 kB\n\
+                     Swap:                128 kB",
+                ),
+                (
+                    "42/io",
+                    "rchar: 1000\nwchar: 2000\nsyscr: 10\nsyscw: 20\n\
+                     read_bytes: 4096\nwrite_bytes: 8192\ncancelled_write_bytes: 0",
+                ),
+                (
+                    "42/limits",
+                    "Limit                     Soft Limit           Hard Limit           Units     \n\
+                     Max cpu time              unlimited            unlimited            seconds   \n\
+                     Max open files            1024                 524288               files     ",
+                ),
+                ("42/cgroup", "0::/system.slice/app.service"),
+                (
+                    "42/status",
+                    "Name:\tapp\nCapInh:\t0000000000000000\nCapPrm:\t0000000000003000\n\
+                     CapEff:\t0000000000001400\nCapBnd:\t000001ffffffffff\nCapAmb:\t0000000000000000",
+                ),
+                ("42/stat", &stat_line("my (app)", 'S', 0, 0)),
+                ("42/task/42/stat", &stat_line("app", 'S', 100, 50)),
+                ("42/task/43/stat", &stat_line("worker", 'R', 300, 0)),
+            ],
+        );
+        fs::create_dir_all(root.join("42/ns")).unwrap();
+        fs::create_dir_all(root.join("self/ns")).unwrap();
+        symlink("net:[4026532000]", root.join("42/ns/net")).unwrap();
+        symlink("pid:[4026531836]", root.join("42/ns/pid")).unwrap();
+        symlink("net:[4026531840]", root.join("self/ns/net")).unwrap();
+        symlink("pid:[4026531836]", root.join("self/ns/pid")).unwrap();
+
+        let inspector = ProcessInspector::with_root(root);
+        let info = inspector.inspect(42);
+
+        let mem = info.memory.unwrap();
+        assert_eq!(mem.rss, 4096 * 1024);
+        assert_eq!(mem.pss, 3072 * 1024);
+        assert_eq!(mem.uss, 3072 * 1024);
+        assert_eq!(mem.shared, 1024 * 1024);
+        assert_eq!(mem.swap, 128 * 1024);
+
+        let io = info.io.unwrap();
+        assert_eq!((io.rchar, io.syscw, io.write_bytes), (1000, 20, 8192));
+
+        assert_eq!(info.limits.len(), 2);
+        assert_eq!(info.limits[1].name, "Max open files");
+        assert_eq!(info.limits[1].soft, "1024");
+        assert_eq!(info.limits[1].hard, "524288");
+        assert_eq!(info.limits[1].units, "files");
+
+        assert_eq!(info.cgroup.as_deref(), Some("/system.slice/app.service"));
+
+        let ns: Vec<(&str, bool)> = info
+            .namespaces
+            .iter()
+            .map(|n| (n.kind.as_str(), n.shared))
+            .collect();
+        assert_eq!(ns, [("net", false), ("pid", true)]);
+        assert_eq!(info.namespaces[0].id, "net:[4026532000]");
+
+        let caps = info.capabilities.unwrap();
+        assert_eq!(
+            caps.effective_names(),
+            ["cap_net_bind_service", "cap_net_admin"]
+        );
+        assert_eq!(caps.bounding.count_ones(), 41);
+
+        let sched = info.scheduling.unwrap();
+        assert_eq!(sched.policy, "SCHED_BATCH");
+        assert_eq!((sched.priority, sched.nice, sched.processor), (20, 5, 3));
+
+        // First sample: ordered by CPU time, no rate yet
+        let tids: Vec<u32> = info.threads.iter().map(|t| t.tid).collect();
+        assert_eq!(tids, [43, 42]);
+        assert_eq!(info.threads[0].name, "worker");
+        assert_eq!(info.threads[0].state, 'R');
+        assert_eq!(info.threads[0].cpu_usage, 0.0);
+
+        // 2 s later: app used 150 ticks (75%), worker 20 ticks (10%)
+        write_tree(
+            root,
+            &[
+                ("42/task/42/stat", &stat_line("app", 'R', 250, 50)),
+                ("42/task/43/stat", &stat_line("worker", 'S', 320, 0)),
+            ],
+        );
+        let threads = inspector.threads(42, &info.threads, 2.0);
+        assert_eq!(threads[0].tid, 42);
+        assert!((threads[0].cpu_usage - 75.0).abs() < 0.01);
+        assert!((threads[1].cpu_usage - 10.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn unreadable_process_leaves_sections_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let info = ProcessInspector::with_root(dir.path()).inspect(7);
+        assert!(info.memory.is_none());
+        assert!(info.io.is_none());
+        assert!(info.limits.is_empty());
+        assert!(info.namespaces.is_empty());
+        assert!(info.scheduling.is_none());
+        assert!(info.threads.is_empty());
+    }
+}
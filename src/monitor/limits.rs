@@ -0,0 +1,315 @@
+//! Resource limits applied from the process table (`C`).
+//!
+//! Each limited process (or process tree) gets its own cgroup v2 group under
+//! `/sys/fs/cgroup/sentinel-limits/`, named after the PID it was opened on:
+//!
+//! | File         | Setting                                                    |
+//! |--------------|------------------------------------------------------------|
+//! | `cpu.max`    | `<quota> 100000` (or `max`)                                |
+//! | `memory.max` | bytes (or `max`)                                           |
+//! | `io.max`     | `<maj:min> rbps=<n> wbps=<n>` per disk in `/sys/block`     |
+//!
+//! Limiting a process that is already in one of these groups updates that
+//! group, so applying "unlimited" lifts the limits again. The groups are
+//! read back for the process detail popup. Writing under `/sys/fs/cgroup`
+//! needs root (or a delegated subtree).
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::constants::{CGROUP_CPU_PERIOD_US, CGROUP_ROOT, LIMIT_CGROUP_NAME};
+use crate::models::{ProcessInfo, ResourceLimits};
+
+/// Block devices that take no `io.max` rules.
+const VIRTUAL_DISK_PREFIXES: &[&str] = &["loop", "ram", "zram"];
+
+/// Controllers the limit groups need.
+const CONTROLLERS: &[&str] = &["cpu", "memory", "io"];
+
+/// A limit group a process currently sits in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppliedLimit {
+    /// Path under the cgroup root, e.g. `/sentinel-limits/pid-4242`
+    pub group: String,
+    pub limits: ResourceLimits,
+}
+
+/// Creates, updates and reads the Sentinel-managed limit groups.
+pub struct CgroupLimiter {
+    cgroup_root: PathBuf,
+    proc_root: PathBuf,
+    block_root: PathBuf,
+}
+
+impl CgroupLimiter {
+    pub fn new() -> Self {
+        Self::with_roots(CGROUP_ROOT, "/proc", "/sys/block")
+    }
+
+    /// Use alternate cgroup, `/proc` and `/sys/block` trees (tests).
+    fn with_roots(
+        cgroup_root: impl Into<PathBuf>,
+        proc_root: impl Into<PathBuf>,
+        block_root: impl Into<PathBuf>,
+    ) -> Self {
+        Self {
+            cgroup_root: cgroup_root.into(),
+            proc_root: proc_root.into(),
+            block_root: block_root.into(),
+        }
+    }
+
+    /// Move `pids` into a limit group capped by `limits`. The group is the one
+    /// `pids[0]` already sits in, or a new `pid-<pids[0]>`. Returns the group path.
+    pub fn apply(&self, pids: &[u32], limits: &ResourceLimits) -> Result<String, String> {
+        let &pid = pids
+            .first()
+            .ok_or_else(|| "no process to limit".to_string())?;
+        if !self.cgroup_root.join("cgroup.controllers").exists() {
+            return Err("cgroup v2 is not mounted".to_string());
+        }
+        let group = match self.limit_group_of(pid) {
+            Some(group) => group,
+            None => format!("/{}/pid-{}", LIMIT_CGROUP_NAME, pid),
+        };
+        let parent = self.cgroup_root.join(LIMIT_CGROUP_NAME);
+        let dir = self.cgroup_root.join(group.trim_start_matches('/'));
+        fs::create_dir_all(&dir).map_err(|e| format!("create {}: {}", dir.display(), e))?;
+        // Controllers may already be delegated; a failure shows up on the limit files
+        for controller in CONTROLLERS {
+            for level in [&self.cgroup_root, &parent] {
+                let _ = fs::write(
+                    level.join("cgroup.subtree_control"),
+                    format!("+{}", controller),
+                );
+            }
+        }
+
+        let cpu = limits.cpu_percent.map(|pct| {
+            let quota = (pct as u64 * CGROUP_CPU_PERIOD_US / 100).max(1000);
+            format!("{} {}", quota, CGROUP_CPU_PERIOD_US)
+        });
+        write_limit(&dir, "cpu.max", cpu)?;
+        write_limit(
+            &dir,
+            "memory.max",
+            limits.memory_bytes.map(|b| b.to_string()),
+        )?;
+        self.write_io_max(&dir, limits.io_bytes_per_sec)?;
+
+        for &pid in pids {
+            match fs::write(dir.join("cgroup.procs"), pid.to_string()) {
+                // Exited since the last refresh
+                Err(e) if e.raw_os_error() == Some(libc::ESRCH) => {}
+                Err(e) => return Err(format!("move PID {}: {}", pid, e)),
+                Ok(()) => {}
+            }
+        }
+        Ok(group)
+    }
+
+    /// The limit group `pid` sits in, with its current settings.
+    pub fn limit_of(&self, pid: u32) -> Option<AppliedLimit> {
+        let group = self.limit_group_of(pid)?;
+        let dir = self.cgroup_root.join(group.trim_start_matches('/'));
+        let read = |file: &str| fs::read_to_string(dir.join(file)).ok();
+        let cpu_percent = read("cpu.max").and_then(|text| {
+            let mut fields = text.split_whitespace();
+            let quota: u64 = fields.next()?.parse().ok()?;
+            let period: u64 = fields.next()?.parse().ok()?;
+            Some((quota * 100 / period.max(1)) as u32)
+        });
+        let memory_bytes = read("memory.max").and_then(|text| text.trim().parse().ok());
+        // `8:0 rbps=10485760 wbps=10485760 riops=max wiops=max`
+        let io_bytes_per_sec = read("io.max").and_then(|text| {
+            text.split_whitespace()
+                .find_map(|f| f.strip_prefix("rbps="))
+                .and_then(|v| v.parse().ok())
+        });
+        Some(AppliedLimit {
+            group,
+            limits: ResourceLimits {
+                cpu_percent,
+                memory_bytes,
+                io_bytes_per_sec,
+            },
+        })
+    }
+
+    /// `pid`'s cgroup path if it is one of the limit groups.
+    fn limit_group_of(&self, pid: u32) -> Option<String> {
+        let text = fs::read_to_string(self.proc_root.join(pid.to_string()).join("cgroup")).ok()?;
+        let path = text.lines().find_map(|l| l.strip_prefix("0::"))?.trim();
+        let prefix = format!("/{}/", LIMIT_CGROUP_NAME);
+        path.starts_with(&prefix).then(|| path.to_string())
+    }
+
+    /// Write one `io.max` rule per disk. Fails only when a cap was asked for
+    /// and no disk took it.
+    fn write_io_max(&self, dir: &Path, bytes_per_sec: Option<u64>) -> Result<(), String> {
+        let value = bytes_per_sec.map_or("max".to_string(), |b| b.to_string());
+        let mut last_err = None;
+        let mut applied = 0;
+        for dev in self.disks() {
+            let rule = format!("{} rbps={} wbps={}", dev, value, value);
+            match fs::write(dir.join("io.max"), rule) {
+                Ok(()) => applied += 1,
+                Err(e) => last_err = Some(e),
+            }
+        }
+        match (bytes_per_sec, applied, last_err) {
+            (Some(_), 0, Some(e)) => Err(format!("set io.max: {}", e)),
+            (Some(_), 0, None) => Err("set io.max: no disks found".to_string()),
+            _ => Ok(()),
+        }
+    }
+
+    /// `maj:min` of every physical disk.
+    fn disks(&self) -> Vec<String> {
+        let Ok(entries) = fs::read_dir(&self.block_root) else {
+            return Vec::new();
+        };
+        let mut disks: Vec<String> = entries
+            .flatten()
+            .filter(|e| {
+                let name = e.file_name();
+                let name = name.to_string_lossy();
+                !VIRTUAL_DISK_PREFIXES.iter().any(|p| name.starts_with(p))
+            })
+            .filter_map(|e| {
+                let dev = fs::read_to_string(e.path().join("dev")).ok()?;
+                Some(dev.trim().to_string())
+            })
+            .collect();
+        disks.sort();
+        disks
+    }
+}
+
+/// Write a limit file: the value, or `max` (best-effort, the controller may be
+/// missing) when unlimited.
+fn write_limit(dir: &Path, file: &str, value: Option<String>) -> Result<(), String> {
+    match value {
+        Some(value) => fs::write(dir.join(file), value).map_err(|e| format!("set {}: {}", file, e)),
+        None => {
+            let _ = fs::write(dir.join(file), "max");
+            Ok(())
+        }
+    }
+}
+
+/// `pid` and all of its descendants in `processes`.
+pub fn process_tree(processes: &[ProcessInfo], pid: u32) -> Vec<u32> {
+    let mut tree = vec![pid];
+    let mut i = 0;
+    while i < tree.len() {
+        let parent = tree[i];
+        for p in processes {
+            if p.parent_pid == Some(parent) && !tree.contains(&p.pid) {
+                tree.push(p.pid);
+            }
+        }
+        i += 1;
+    }
+    tree
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ProcessStatus;
+    use crate::test_support::write_tree;
+
+    fn process(pid: u32, parent_pid: Option<u32>) -> ProcessInfo {
+        ProcessInfo {
+            pid,
+            name: format!("p{}", pid),
+            cmd: String::new(),
+            cpu_usage: 0.0,
+            memory_bytes: 0,
+            memory_percent: 0.0,
+            disk_read_bytes: 0,
+            disk_write_bytes: 0,
+            status: ProcessStatus::Running,
+            user: "test".to_string(),
+            start_time: 0,
+            parent_pid,
+            thread_count: None,
+            gpu_usage: 0.0,
+            gpu_memory_bytes: 0,
+            net_rx_bytes_per_sec: 0,
+            net_tx_bytes_per_sec: 0,
+        }
+    }
+
+    #[test]
+    fn applies_and_reads_back_limits() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write_tree(
+            root,
+            &[
+                ("cg/cgroup.controllers", "cpu io memory pids"),
+                ("proc/4242/cgroup", "0::/user.slice/app.scope"),
+                ("block/sda/dev", "8:0"),
+                ("block/loop0/dev", "7:0"),
+            ],
+        );
+        let limiter =
+            CgroupLimiter::with_roots(root.join("cg"), root.join("proc"), root.join("block"));
+        let limits = ResourceLimits {
+            cpu_percent: Some(50),
+            memory_bytes: Some(512 << 20),
+            io_bytes_per_sec: Some(10 << 20),
+        };
+        let group = limiter.apply(&[4242, 4243], &limits).unwrap();
+        assert_eq!(group, "/sentinel-limits/pid-4242");
+
+        let cg = root.join("cg/sentinel-limits/pid-4242");
+        let read = |file: &str| fs::read_to_string(cg.join(file)).unwrap();
+        assert_eq!(read("cpu.max"), "50000 100000");
+        assert_eq!(read("memory.max"), "536870912");
+        // Loop devices are skipped
+        assert_eq!(read("io.max"), "8:0 rbps=10485760 wbps=10485760");
+        // A plain file keeps only the last write; the kernel appends each PID
+        assert_eq!(read("cgroup.procs"), "4243");
+
+        // Not moved yet (the fake cgroup.procs does not update /proc)
+        assert_eq!(limiter.limit_of(4242), None);
+        write_tree(
+            root,
+            &[("proc/4242/cgroup", "0::/sentinel-limits/pid-4242")],
+        );
+        let applied = limiter.limit_of(4242).unwrap();
+        assert_eq!(applied.group, "/sentinel-limits/pid-4242");
+        assert_eq!(applied.limits, limits);
+
+        // Re-limiting updates the same group; "unlimited" lifts the caps
+        let group = limiter.apply(&[4242], &ResourceLimits::default()).unwrap();
+        assert_eq!(group, "/sentinel-limits/pid-4242");
+        assert_eq!(read("cpu.max"), "max");
+        assert_eq!(
+            limiter.limit_of(4242).unwrap().limits,
+            ResourceLimits::default()
+        );
+
+        // No cgroup v2
+        let v1 =
+            CgroupLimiter::with_roots(root.join("proc"), root.join("proc"), root.join("block"));
+        assert!(v1.apply(&[4242], &limits).is_err());
+    }
+
+    #[test]
+    fn process_tree_collects_descendants() {
+        let procs = vec![
+            process(1, None),
+            process(10, Some(1)),
+            process(11, Some(10)),
+            process(12, Some(10)),
+            process(13, Some(11)),
+            process(20, Some(1)),
+        ];
+        assert_eq!(process_tree(&procs, 10), [10, 11, 12, 13]);
+        assert_eq!(process_tree(&procs, 20), [20]);
+    }
+}
@@ -1,5 +1,10 @@
+pub mod cgroup;
 mod collector;
 pub mod docker;
+pub mod gpu;
+pub mod inspect;
+pub mod limits;
+pub mod net;
 
 pub use collector::SystemCollector;
 pub use docker::{ContainerInfo, DockerMonitor};
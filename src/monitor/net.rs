@@ -0,0 +1,497 @@
+//! Per-process network bandwidth.
+//!
+//! The kernel keeps no per-process byte counters, so traffic is attributed
+//! from two sources:
+//!
+//! - **Sockets**: a netlink `sock_diag` dump of the TCP sockets in Sentinel's
+//!   network namespace gives `tcpi_bytes_received` / `tcpi_bytes_acked` per
+//!   socket inode, and `/proc/<pid>/fd` maps inodes to processes. Without
+//!   root only the user's own processes can be mapped. UDP sockets carry no
+//!   byte counters and are not counted.
+//! - **Network namespaces**: processes in another namespace (containers) are
+//!   invisible to the dump, so the namespace's `/proc/<pid>/net/dev` totals
+//!   (excluding `lo`) are charged to its lowest PID, normally the
+//!   container's init.
+//!
+//! Rates are deltas between two samples; the first sample reports nothing.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+/// Network rates attributed to one process.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProcessNetUsage {
+    pub rx_bytes_per_sec: u64,
+    pub tx_bytes_per_sec: u64,
+}
+
+/// (received, sent) byte counters.
+type Counters = (u64, u64);
+
+/// Collects per-process network rates.
+pub struct ProcessNetCollector {
+    proc_root: PathBuf,
+    /// Query `sock_diag`; cleared once the netlink socket cannot be opened
+    sock_diag: bool,
+    /// When the previous sample was taken, for rates
+    prev_at: Option<Instant>,
+    /// Socket inode → counters at the previous sample
+    prev_sockets: HashMap<u64, Counters>,
+    /// Namespace inode → `net/dev` totals at the previous sample
+    prev_netns: HashMap<u64, Counters>,
+    processes: HashMap<u32, ProcessNetUsage>,
+}
+
+/// What one pass over `/proc` found.
+#[derive(Debug, Default)]
+struct ProcScan {
+    /// Socket inode → lowest PID holding it
+    socket_pids: HashMap<u64, u32>,
+    /// Namespace inode → lowest PID in it, for namespaces other than ours
+    netns_pids: BTreeMap<u64, u32>,
+}
+
+impl ProcessNetCollector {
+    pub fn new() -> Self {
+        let mut collector = Self::with_roots("/proc");
+        collector.sock_diag = true;
+        collector
+    }
+
+    /// Read from an alternate `/proc` tree, without `sock_diag` (tests).
+    pub fn with_roots(proc_root: impl Into<PathBuf>) -> Self {
+        Self {
+            proc_root: proc_root.into(),
+            sock_diag: false,
+            prev_at: None,
+            prev_sockets: HashMap::new(),
+            prev_netns: HashMap::new(),
+            processes: HashMap::new(),
+        }
+    }
+
+    /// Take a sample; per-process rates are then available from
+    /// [`processes`](Self::processes).
+    pub fn collect(&mut self) {
+        let sockets = if self.sock_diag {
+            dump_tcp_sockets().unwrap_or_else(|_| {
+                self.sock_diag = false;
+                Vec::new()
+            })
+        } else {
+            Vec::new()
+        };
+        self.collect_at(Instant::now(), &sockets);
+    }
+
+    /// Per-process rates from the last [`collect`](Self::collect), by PID.
+    pub fn processes(&self) -> &HashMap<u32, ProcessNetUsage> {
+        &self.processes
+    }
+
+    fn collect_at(&mut self, now: Instant, sockets: &[(u64, Counters)]) {
+        let elapsed_secs = self
+            .prev_at
+            .map(|at| now.saturating_duration_since(at).as_secs_f64())
+            .filter(|s| *s > 0.0);
+        self.prev_at = Some(now);
+
+        let scan = scan_proc(&self.proc_root, !sockets.is_empty());
+        let mut totals: HashMap<u32, Counters> = HashMap::new();
+
+        let mut next_sockets = HashMap::new();
+        for &(inode, counters) in sockets {
+            // A socket opened since the last sample did all its traffic in between
+            let prev = self.prev_sockets.get(&inode).copied().unwrap_or((0, 0));
+            next_sockets.insert(inode, counters);
+            if let Some(&pid) = scan.socket_pids.get(&inode) {
+                let total = totals.entry(pid).or_default();
+                total.0 += counters.0.saturating_sub(prev.0);
+                total.1 += counters.1.saturating_sub(prev.1);
+            }
+        }
+        self.prev_sockets = next_sockets;
+
+        let mut next_netns = HashMap::new();
+        for (&netns, &pid) in &scan.netns_pids {
+            let Some(counters) =
+                read_net_dev(&self.proc_root.join(pid.to_string()).join("net/dev"))
+            else {
+                continue;
+            };
+            next_netns.insert(netns, counters);
+            // Totals since the namespace was created; only deltas are traffic
+            if let Some(prev) = self.prev_netns.get(&netns) {
+                let total = totals.entry(pid).or_default();
+                total.0 += counters.0.saturating_sub(prev.0);
+                total.1 += counters.1.saturating_sub(prev.1);
+            }
+        }
+        self.prev_netns = next_netns;
+
+        self.processes = match elapsed_secs {
+            Some(secs) => totals
+                .into_iter()
+                .map(|(pid, (rx, tx))| {
+                    let usage = ProcessNetUsage {
+                        rx_bytes_per_sec: (rx as f64 / secs).round() as u64,
+                        tx_bytes_per_sec: (tx as f64 / secs).round() as u64,
+                    };
+                    (pid, usage)
+                })
+                .filter(|(_, u)| u.rx_bytes_per_sec > 0 || u.tx_bytes_per_sec > 0)
+                .collect(),
+            None => HashMap::new(),
+        };
+    }
+}
+
+/// Map sockets (when `sockets` is set) and foreign network namespaces to
+/// PIDs. Processes whose namespace cannot be read are skipped.
+fn scan_proc(proc_root: &Path, sockets: bool) -> ProcScan {
+    let mut scan = ProcScan::default();
+    let Some(own_netns) = netns_inode(&proc_root.join("self")) else {
+        return scan;
+    };
+    let Ok(entries) = fs::read_dir(proc_root) else {
+        return scan;
+    };
+    let mut pids: Vec<u32> = entries
+        .flatten()
+        .filter_map(|e| e.file_name().to_str()?.parse().ok())
+        .collect();
+    pids.sort_unstable();
+
+    for pid in pids {
+        let pid_dir = proc_root.join(pid.to_string());
+        let Some(netns) = netns_inode(&pid_dir) else {
+            continue;
+        };
+        if netns != own_netns {
+            scan.netns_pids.entry(netns).or_insert(pid);
+            continue;
+        }
+        if !sockets {
+            continue;
+        }
+        let Ok(fds) = fs::read_dir(pid_dir.join("fd")) else {
+            continue;
+        };
+        for fd in fds.flatten() {
+            if let Some(inode) = fs::read_link(fd.path())
+                .ok()
+                .and_then(|target| bracketed_inode(target.to_str()?, "socket:"))
+            {
+                scan.socket_pids.entry(inode).or_insert(pid);
+            }
+        }
+    }
+    scan
+}
+
+/// Inode of the network namespace a `/proc/<pid>` directory is in.
+fn netns_inode(pid_dir: &Path) -> Option<u64> {
+    let target = fs::read_link(pid_dir.join("ns/net")).ok()?;
+    bracketed_inode(target.to_str()?, "net:")
+}
+
+/// Parse `socket:[12345]` / `net:[4026531840]` links.
+fn bracketed_inode(link: &str, prefix: &str) -> Option<u64> {
+    link.strip_prefix(prefix)?
+        .strip_prefix('[')?
+        .strip_suffix(']')?
+        .parse()
+        .ok()
+}
+
+/// Received and sent bytes summed over every interface but `lo`.
+fn read_net_dev(path: &Path) -> Option<Counters> {
+    let text = fs::read_to_string(path).ok()?;
+    let mut totals = (0, 0);
+    // Two header lines, then `iface: rx_bytes rx_packets … tx_bytes …`
+    for line in text.lines().skip(2) {
+        let Some((iface, fields)) = line.split_once(':') else {
+            continue;
+        };
+        if iface.trim() == "lo" {
+            continue;
+        }
+        let fields: Vec<u64> = fields
+            .split_whitespace()
+            .filter_map(|f| f.parse().ok())
+            .collect();
+        if fields.len() >= 9 {
+            totals.0 += fields[0];
+            totals.1 += fields[8];
+        }
+    }
+    Some(totals)
+}
+
+// ── sock_diag ───────────────────────────────────────────────────
+
+const NLMSG_HDR_LEN: usize = 16;
+const NLMSG_ERROR: u16 = 2;
+const NLMSG_DONE: u16 = 3;
+const NLM_F_REQUEST: u16 = 0x1;
+const NLM_F_DUMP: u16 = 0x300;
+const SOCK_DIAG_BY_FAMILY: u16 = 20;
+/// `struct inet_diag_msg`; the socket inode is its last field
+const INET_DIAG_MSG_LEN: usize = 72;
+/// Attribute carrying `struct tcp_info`
+const INET_DIAG_INFO: u16 = 2;
+/// Offsets of `tcpi_bytes_acked` and `tcpi_bytes_received` in `tcp_info`
+const TCPI_BYTES_ACKED: usize = 120;
+const TCPI_BYTES_RECEIVED: usize = 128;
+
+/// `inet_diag_req_v2` dump of every TCP socket of `family`, asking for
+/// `tcp_info`.
+fn diag_request(family: u8) -> [u8; 72] {
+    let mut req = [0u8; 72];
+    req[0..4].copy_from_slice(&72u32.to_ne_bytes());
+    req[4..6].copy_from_slice(&SOCK_DIAG_BY_FAMILY.to_ne_bytes());
+    req[6..8].copy_from_slice(&(NLM_F_REQUEST | NLM_F_DUMP).to_ne_bytes());
+    req[16] = family;
+    req[17] = libc::IPPROTO_TCP as u8;
+    req[18] = 1 << (INET_DIAG_INFO - 1);
+    // Every TCP state
+    req[20..24].copy_from_slice(&u32::MAX.to_ne_bytes());
+    req
+}
+
+/// Socket inode → counters for every IPv4 and IPv6 TCP socket in Sentinel's
+/// network namespace.
+#[cfg(target_os = "linux")]
+fn dump_tcp_sockets() -> Result<Vec<(u64, Counters)>, String> {
+    use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+
+    // SAFETY: socket() has no memory preconditions; the result is checked
+    let fd = unsafe {
+        libc::socket(
+            libc::AF_NETLINK,
+            libc::SOCK_DGRAM | libc::SOCK_CLOEXEC,
+            libc::NETLINK_SOCK_DIAG,
+        )
+    };
+    if fd < 0 {
+        return Err(std::io::Error::last_os_error().to_string());
+    }
+    // SAFETY: fd is a freshly opened descriptor owned by nothing else
+    let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+
+    let mut sockets = Vec::new();
+    let mut buf = vec![0u8; 32 * 1024];
+    for family in [libc::AF_INET, libc::AF_INET6] {
+        let req = diag_request(family as u8);
+        // SAFETY: req is a live buffer of the given length
+        let sent = unsafe { libc::send(fd.as_raw_fd(), req.as_ptr().cast(), req.len(), 0) };
+        if sent < 0 {
+            return Err(std::io::Error::last_os_error().to_string());
+        }
+        loop {
+            // SAFETY: buf is a live, writable buffer of the given length
+            let n = unsafe { libc::recv(fd.as_raw_fd(), buf.as_mut_ptr().cast(), buf.len(), 0) };
+            if n < 0 {
+                return Err(std::io::Error::last_os_error().to_string());
+            }
+            if n == 0 || parse_diag_replies(&buf[..n as usize], &mut sockets)? {
+                break;
+            }
+        }
+    }
+    Ok(sockets)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn dump_tcp_sockets() -> Result<Vec<(u64, Counters)>, String> {
+    Err("sock_diag is Linux-only".to_string())
+}
+
+/// Parse one datagram of `sock_diag` replies into `out`; `Ok(true)` once the
+/// dump is complete.
+fn parse_diag_replies(buf: &[u8], out: &mut Vec<(u64, Counters)>) -> Result<bool, String> {
+    let mut offset = 0;
+    while offset + NLMSG_HDR_LEN <= buf.len() {
+        let len = read_u32(buf, offset).unwrap_or(0) as usize;
+        if len < NLMSG_HDR_LEN || offset + len > buf.len() {
+            break;
+        }
+        let body = &buf[offset + NLMSG_HDR_LEN..offset + len];
+        match read_u16(buf, offset + 4) {
+            Some(NLMSG_DONE) => return Ok(true),
+            Some(NLMSG_ERROR) => {
+                let errno = read_u32(body, 0).map_or(0, |e| e as i32);
+                return Err(std::io::Error::from_raw_os_error(-errno).to_string());
+            }
+            Some(SOCK_DIAG_BY_FAMILY) => out.extend(parse_diag_msg(body)),
+            _ => {}
+        }
+        offset += align4(len);
+    }
+    Ok(false)
+}
+
+/// Socket inode and counters from one `inet_diag_msg` with its attributes.
+fn parse_diag_msg(msg: &[u8]) -> Option<(u64, Counters)> {
+    let inode = read_u32(msg, INET_DIAG_MSG_LEN - 4)? as u64;
+    if inode == 0 {
+        return None;
+    }
+    let mut offset = INET_DIAG_MSG_LEN;
+    while offset + 4 <= msg.len() {
+        let len = read_u16(msg, offset)? as usize;
+        if len < 4 || offset + len > msg.len() {
+            break;
+        }
+        if read_u16(msg, offset + 2)? == INET_DIAG_INFO {
+            let info = &msg[offset + 4..offset + len];
+            let received = read_u64(info, TCPI_BYTES_RECEIVED)?;
+            let acked = read_u64(info, TCPI_BYTES_ACKED)?;
+            return Some((inode, (received, acked)));
+        }
+        offset += align4(len);
+    }
+    None
+}
+
+fn align4(len: usize) -> usize {
+    (len + 3) & !3
+}
+
+fn read_u16(buf: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_ne_bytes(
+        buf.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+fn read_u32(buf: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_ne_bytes(
+        buf.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+fn read_u64(buf: &[u8], offset: usize) -> Option<u64> {
+    Some(u64::from_ne_bytes(
+        buf.get(offset..offset + 8)?.try_into().ok()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::write_tree;
+    use std::os::unix::fs::symlink;
+    use std::time::Duration;
+
+    /// Point `link` (relative to `root`) at `target`.
+    fn link(root: &Path, link: &str, target: &str) {
+        let path = root.join(link);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        symlink(target, path).unwrap();
+    }
+
+    fn net_dev(rx: u64, tx: u64) -> String {
+        format!(
+            "Inter-|   Receive                            |  Transmit\n \
+             face |bytes    packets errs drop fifo frame compressed multicast|bytes\n    \
+             lo: 999 1 0 0 0 0 0 0 999 1 0 0 0 0 0 0\n  \
+             eth0: {} 10 0 0 0 0 0 0 {} 10 0 0 0 0 0 0",
+            rx, tx
+        )
+    }
+
+    /// A netlink message of `kind` with `body`.
+    fn nlmsg(kind: u16, body: &[u8]) -> Vec<u8> {
+        let mut msg = Vec::new();
+        msg.extend(((NLMSG_HDR_LEN + body.len()) as u32).to_ne_bytes());
+        msg.extend(kind.to_ne_bytes());
+        msg.extend([0u8; 10]);
+        msg.extend(body);
+        msg.resize(align4(msg.len()), 0);
+        msg
+    }
+
+    /// An `inet_diag_msg` for `inode` with a `tcp_info` attribute.
+    fn diag_msg(inode: u32, received: u64, acked: u64) -> Vec<u8> {
+        let mut body = vec![0u8; INET_DIAG_MSG_LEN];
+        body[INET_DIAG_MSG_LEN - 4..].copy_from_slice(&inode.to_ne_bytes());
+        let mut info = vec![0u8; 232];
+        info[TCPI_BYTES_ACKED..TCPI_BYTES_ACKED + 8].copy_from_slice(&acked.to_ne_bytes());
+        info[TCPI_BYTES_RECEIVED..TCPI_BYTES_RECEIVED + 8].copy_from_slice(&received.to_ne_bytes());
+        // An unrelated attribute first
+        body.extend(8u16.to_ne_bytes());
+        body.extend(1u16.to_ne_bytes());
+        body.extend([0u8; 4]);
+        body.extend(((4 + info.len()) as u16).to_ne_bytes());
+        body.extend(INET_DIAG_INFO.to_ne_bytes());
+        body.extend(info);
+        nlmsg(SOCK_DIAG_BY_FAMILY, &body)
+    }
+
+    #[test]
+    fn parses_sock_diag_replies() {
+        let mut buf = diag_msg(4242, 1000, 250);
+        buf.extend(diag_msg(0, 5, 5));
+        let mut out = Vec::new();
+        assert_eq!(parse_diag_replies(&buf, &mut out), Ok(false));
+        assert_eq!(out, vec![(4242, (1000, 250))]);
+
+        let done = nlmsg(NLMSG_DONE, &[0u8; 4]);
+        assert_eq!(parse_diag_replies(&done, &mut out), Ok(true));
+
+        let eperm = nlmsg(NLMSG_ERROR, &(-libc::EPERM).to_ne_bytes());
+        assert!(parse_diag_replies(&eperm, &mut out).is_err());
+
+        let req = diag_request(libc::AF_INET6 as u8);
+        assert_eq!(read_u32(&req, 0), Some(72));
+        assert_eq!(req[16], libc::AF_INET6 as u8);
+    }
+
+    #[test]
+    fn attributes_socket_and_namespace_traffic() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        link(root, "self/ns/net", "net:[100]");
+        // Two host processes sharing a socket, one with its own
+        link(root, "10/ns/net", "net:[100]");
+        link(root, "10/fd/3", "socket:[555]");
+        link(root, "11/ns/net", "net:[100]");
+        link(root, "11/fd/3", "socket:[555]");
+        link(root, "11/fd/4", "socket:[556]");
+        link(root, "11/fd/5", "/dev/null");
+        // A container: init and a worker in namespace 200
+        link(root, "20/ns/net", "net:[200]");
+        link(root, "21/ns/net", "net:[200]");
+        write_tree(root, &[("20/net/dev", &net_dev(10_000, 4_000))]);
+
+        let mut c = ProcessNetCollector::with_roots(root);
+        let t0 = Instant::now();
+        c.collect_at(t0, &[(555, (100, 100)), (556, (0, 0))]);
+        assert!(c.processes().is_empty());
+
+        write_tree(root, &[("20/net/dev", &net_dev(12_000, 5_000))]);
+        c.collect_at(
+            t0 + Duration::from_secs(2),
+            &[(555, (4_100, 1_100)), (556, (0, 600)), (557, (9, 9))],
+        );
+        let p = c.processes();
+        assert_eq!(
+            p[&10],
+            ProcessNetUsage {
+                rx_bytes_per_sec: 2_000,
+                tx_bytes_per_sec: 500,
+            }
+        );
+        assert_eq!(p[&11].tx_bytes_per_sec, 300);
+        assert_eq!(
+            p[&20],
+            ProcessNetUsage {
+                rx_bytes_per_sec: 1_000,
+                tx_bytes_per_sec: 500,
+            }
+        );
+        assert!(!p.contains_key(&21));
+    }
+}
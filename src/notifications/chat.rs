@@ -0,0 +1,740 @@
+//! Slack, Mattermost and Discord notifications via incoming webhooks.
+//!
+//! Each alert group is rendered as a rich message: a severity-colored
+//! attachment (Slack/Mattermost) or embed (Discord) with the group's
+//! explanation ([`explain_group`]), per-worker breakdown, suggested action
+//! ([`suggest_action`]) and a compact system context footer.
+//!
+//! | `format`     | Payload                                          |
+//! |--------------|--------------------------------------------------|
+//! | `slack`      | attachment with Block Kit `blocks`               |
+//! | `mattermost` | attachment with `title`/`text`/`fields` (Markdown) |
+//! | `discord`    | `embeds` with `fields` and footer                |
+//!
+//! Severity-filtered and rate-limited per (category, app) like Telegram.
+//! With `digest_secs > 0`, alerts are batched and flushed as one summary
+//! message, sharing the digest buffer used by the Telegram notifier.
+//! Delivery reuses the webhook retry policy.
+
+use serde_json::{json, Value};
+
+use crate::alerts::ActiveAlert;
+use crate::config::{ChatConfig, NotificationConfig};
+use crate::constants::{
+    CHAT_DIGEST_DETAIL_LINES, CHAT_DIGEST_MAX_ALERTS, CHAT_RATE_LIMIT_SECS, CHAT_RESOLVED_COLOR,
+    MAX_WORKER_DISPLAY,
+};
+use crate::models::{Alert, AlertCategory, AlertSeverity, ProcessInfo};
+
+use super::digest::Digest;
+use super::escalation::Escalation;
+use super::rate_limit::AlertRateLimiter;
+use super::telegram::{
+    category_display_name, explain_group, format_avg_value, format_threshold_for_category,
+    format_worker_value, group_alerts_by_app, parse_min_severity, severity_label, suggest_action,
+    AlertContext, GroupedAlert,
+};
+use super::webhook::Endpoint;
+
+/// Chat service payload flavor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatFormat {
+    Slack,
+    Mattermost,
+    Discord,
+}
+
+impl ChatFormat {
+    /// Parse a `format` config value (case-insensitive).
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.trim().to_lowercase().as_str() {
+            "slack" => Ok(Self::Slack),
+            "mattermost" => Ok(Self::Mattermost),
+            "discord" => Ok(Self::Discord),
+            other => Err(format!(
+                "unknown chat format '{}' (expected slack, mattermost or discord)",
+                other
+            )),
+        }
+    }
+
+    /// Lowercase name, as written in config.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Slack => "slack",
+            Self::Mattermost => "mattermost",
+            Self::Discord => "discord",
+        }
+    }
+}
+
+/// Attachment/embed color for a severity (`0xRRGGBB`).
+pub fn severity_color(severity: AlertSeverity) -> u32 {
+    match severity {
+        AlertSeverity::Danger => 0x8B0000,
+        AlertSeverity::Critical => 0xE01E5A,
+        AlertSeverity::Warning => 0xECB22E,
+        AlertSeverity::Info => 0x36C5F0,
+    }
+}
+
+// ── Message Model ───────────────────────────────────────────────
+
+/// Service-neutral message, rendered per [`ChatFormat`].
+#[derive(Debug, Clone)]
+pub struct ChatMessage {
+    pub color: u32,
+    pub title: String,
+    /// Main text: the group explanation or alert message.
+    pub body: String,
+    /// Short label/value pairs shown side by side.
+    pub fields: Vec<(String, String)>,
+    /// Per-worker breakdown lines (grouped alerts only).
+    pub workers: Vec<String>,
+    pub action: Option<String>,
+    pub footer: String,
+}
+
+impl ChatMessage {
+    /// Message for a (possibly single-worker) alert group.
+    pub fn from_group(
+        group: &GroupedAlert,
+        hostname: &str,
+        context: Option<&AlertContext>,
+    ) -> Self {
+        let mut fields = Vec::new();
+        let mut workers = Vec::new();
+
+        let body = if group.worker_count > 1 {
+            fields.push((
+                "Application".to_string(),
+                format!("{} ({} workers)", group.app_name, group.worker_count),
+            ));
+            fields.push((
+                "Avg / worker".to_string(),
+                format_avg_value(group.category, group.avg_percent),
+            ));
+            fields.push((
+                "Threshold".to_string(),
+                format_threshold_for_category(group.category, group.threshold),
+            ));
+
+            let mut details = group.worker_details.clone();
+            details.sort_by(|a, b| {
+                b.value
+                    .partial_cmp(&a.value)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+            for detail in details.iter().take(MAX_WORKER_DISPLAY) {
+                workers.push(format!(
+                    "PID {} \u{2014} {} \u{2014} {}",
+                    detail.pid,
+                    detail.name,
+                    format_worker_value(group.category, detail.value),
+                ));
+            }
+            if details.len() > MAX_WORKER_DISPLAY {
+                workers.push(format!(
+                    "... +{} more workers",
+                    details.len() - MAX_WORKER_DISPLAY
+                ));
+            }
+            explain_group(group)
+        } else {
+            let pid = group
+                .pids
+                .first()
+                .map(|p| p.to_string())
+                .unwrap_or_else(|| "?".to_string());
+            fields.push((
+                "Process".to_string(),
+                format!("{} (PID {})", group.app_name, pid),
+            ));
+            group.representative_message.clone()
+        };
+
+        Self {
+            color: severity_color(group.severity),
+            title: format!(
+                "{}: {}",
+                severity_label(group.severity),
+                category_display_name(group.category)
+            ),
+            body,
+            fields,
+            workers,
+            action: Some(suggest_action(group)),
+            footer: footer(hostname, context),
+        }
+    }
+
+    /// Message for an alert whose condition cleared.
+    pub fn resolved(active: &ActiveAlert, hostname: &str) -> Self {
+        let mut duration = active.duration_display();
+        if active.acknowledged {
+            duration.push_str(" (acknowledged)");
+        }
+        Self {
+            color: CHAT_RESOLVED_COLOR,
+            title: format!("RESOLVED: {}", category_display_name(active.key.category)),
+            body: active.alert.message.clone(),
+            fields: vec![
+                ("Process".to_string(), active.key.subject.clone()),
+                ("Firing for".to_string(), duration),
+            ],
+            workers: Vec::new(),
+            action: None,
+            footer: footer(hostname, None),
+        }
+    }
+
+    /// Message for an escalation step.
+    pub fn escalation(escalation: &Escalation, hostname: &str) -> Self {
+        let active = &escalation.alert;
+        Self {
+            color: severity_color(active.alert.severity),
+            title: format!(
+                "{}: {}",
+                escalation.headline(),
+                category_display_name(active.key.category)
+            ),
+            body: active.alert.message.clone(),
+            fields: vec![
+                ("Process".to_string(), active.key.subject.clone()),
+                (
+                    "Unacknowledged for".to_string(),
+                    escalation.firing_display(),
+                ),
+            ],
+            workers: Vec::new(),
+            action: None,
+            footer: footer(hostname, None),
+        }
+    }
+
+    /// Summary of buffered `(alert, one-line summary)` entries.
+    pub fn digest(entries: &[(Alert, String)], hostname: &str) -> Self {
+        let top = entries
+            .iter()
+            .map(|(a, _)| a.severity)
+            .max()
+            .unwrap_or(AlertSeverity::Info);
+
+        let counts: Vec<String> = [
+            AlertSeverity::Danger,
+            AlertSeverity::Critical,
+            AlertSeverity::Warning,
+            AlertSeverity::Info,
+        ]
+        .iter()
+        .filter_map(|&sev| {
+            let n = entries.iter().filter(|(a, _)| a.severity == sev).count();
+            (n > 0).then(|| format!("{} {}", n, severity_label(sev).to_lowercase()))
+        })
+        .collect();
+
+        let mut body = counts.join(" \u{00B7} ");
+        for (alert, line) in entries.iter().take(CHAT_DIGEST_DETAIL_LINES) {
+            body.push_str(&format!(
+                "\n\u{2022} {} {}",
+                severity_label(alert.severity),
+                line
+            ));
+        }
+        if entries.len() > CHAT_DIGEST_DETAIL_LINES {
+            body.push_str(&format!(
+                "\n... and {} more",
+                entries.len() - CHAT_DIGEST_DETAIL_LINES
+            ));
+        }
+
+        Self {
+            color: severity_color(top),
+            title: format!("Sentinel Digest \u{2014} {} alerts", entries.len()),
+            body,
+            fields: Vec::new(),
+            workers: Vec::new(),
+            action: None,
+            footer: footer(hostname, None),
+        }
+    }
+
+    /// Render as the JSON body for `format`.
+    pub fn render(&self, format: ChatFormat) -> Value {
+        match format {
+            ChatFormat::Slack => self.to_slack(),
+            ChatFormat::Mattermost => self.to_mattermost(),
+            ChatFormat::Discord => self.to_discord(),
+        }
+    }
+
+    /// Slack: a colored attachment holding Block Kit blocks.
+    fn to_slack(&self) -> Value {
+        let mut blocks = vec![
+            json!({
+                "type": "header",
+                "text": { "type": "plain_text", "text": self.title },
+            }),
+            json!({
+                "type": "section",
+                "text": { "type": "mrkdwn", "text": self.body },
+            }),
+        ];
+        if !self.fields.is_empty() {
+            let fields: Vec<Value> = self
+                .fields
+                .iter()
+                .map(|(k, v)| json!({ "type": "mrkdwn", "text": format!("*{}*\n{}", k, v) }))
+                .collect();
+            blocks.push(json!({ "type": "section", "fields": fields }));
+        }
+        if !self.workers.is_empty() {
+            blocks.push(json!({
+                "type": "section",
+                "text": {
+                    "type": "mrkdwn",
+                    "text": format!("*Workers*\n```{}```", self.workers.join("\n")),
+                },
+            }));
+        }
+        if let Some(ref action) = self.action {
+            blocks.push(json!({
+                "type": "section",
+                "text": { "type": "mrkdwn", "text": format!("*Suggested action*\n{}", action) },
+            }));
+        }
+        blocks.push(json!({
+            "type": "context",
+            "elements": [{ "type": "mrkdwn", "text": self.footer }],
+        }));
+
+        json!({
+            "text": self.title,
+            "attachments": [{
+                "color": format!("#{:06X}", self.color),
+                "fallback": format!("{}: {}", self.title, self.body),
+                "blocks": blocks,
+            }],
+        })
+    }
+
+    /// Mattermost: a colored legacy attachment (it does not render blocks).
+    fn to_mattermost(&self) -> Value {
+        let mut text = self.body.clone();
+        if !self.workers.is_empty() {
+            text.push_str(&format!(
+                "\n\n**Workers**\n```\n{}\n```",
+                self.workers.join("\n")
+            ));
+        }
+        if let Some(ref action) = self.action {
+            text.push_str(&format!("\n\n**Suggested action:** {}", action));
+        }
+        let fields: Vec<Value> = self
+            .fields
+            .iter()
+            .map(|(k, v)| json!({ "short": true, "title": k, "value": v }))
+            .collect();
+
+        json!({
+            "attachments": [{
+                "fallback": format!("{}: {}", self.title, self.body),
+                "color": format!("#{:06X}", self.color),
+                "title": self.title,
+                "text": text,
+                "fields": fields,
+                "footer": self.footer,
+            }],
+        })
+    }
+
+    /// Discord: one embed with inline fields.
+    fn to_discord(&self) -> Value {
+        let mut fields: Vec<Value> = self
+            .fields
+            .iter()
+            .map(|(k, v)| json!({ "name": k, "value": v, "inline": true }))
+            .collect();
+        if !self.workers.is_empty() {
+            fields.push(json!({
+                "name": "Workers",
+                "value": format!("```\n{}\n```", self.workers.join("\n")),
+                "inline": false,
+            }));
+        }
+        if let Some(ref action) = self.action {
+            fields.push(json!({ "name": "Suggested action", "value": action, "inline": false }));
+        }
+
+        json!({
+            "username": "Sentinel",
+            "embeds": [{
+                "title": self.title,
+                "description": self.body,
+                "color": self.color,
+                "fields": fields,
+                "footer": { "text": self.footer },
+                "timestamp": chrono::Local::now().to_rfc3339(),
+            }],
+        })
+    }
+}
+
+/// `host | CPU 45% | RAM 78% | 245 procs | time` footer line.
+fn footer(hostname: &str, context: Option<&AlertContext>) -> String {
+    let mut parts = vec![hostname.to_string()];
+    if let Some(ctx) = context {
+        if let Some(cpu) = ctx.cpu_pct {
+            parts.push(format!("CPU {:.0}%", cpu));
+        }
+        if let Some(mem) = ctx.mem_pct {
+            parts.push(format!("RAM {:.0}%", mem));
+        }
+        if let Some(procs) = ctx.process_count {
+            parts.push(format!("{} procs", procs));
+        }
+        if let Some(temp) = ctx.max_temp {
+            parts.push(format!("{:.0}\u{00B0}C", temp));
+        }
+    }
+    parts.push(chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string());
+    parts.join(" | ")
+}
+
+// ── Notifier ────────────────────────────────────────────────────
+
+/// Notifier for one chat incoming webhook.
+pub struct ChatNotifier {
+    name: String,
+    format: ChatFormat,
+    endpoint: Endpoint,
+    min_severity: AlertSeverity,
+    limiter: AlertRateLimiter,
+    /// Buffered `(alert, one-line summary)` entries in digest mode.
+    digest: Digest<(Alert, String)>,
+}
+
+impl ChatNotifier {
+    /// Build a notifier from one `[[notifications.chat]]` entry.
+    pub fn from_config(cfg: &ChatConfig) -> Result<Self, String> {
+        let format = ChatFormat::parse(&cfg.format)?;
+        let endpoint = Endpoint::new(&cfg.url, Vec::new(), None)?;
+        Ok(Self {
+            name: cfg
+                .name
+                .clone()
+                .unwrap_or_else(|| format.as_str().to_string()),
+            format,
+            endpoint,
+            min_severity: parse_min_severity(&cfg.min_severity),
+            limiter: AlertRateLimiter::new(CHAT_RATE_LIMIT_SECS),
+            digest: Digest::new(cfg.digest_secs, CHAT_DIGEST_MAX_ALERTS),
+        })
+    }
+
+    /// Notifiers for every valid configured chat webhook (`Config::load`
+    /// already warned about invalid ones).
+    pub fn all_from_config(config: &NotificationConfig) -> Vec<Self> {
+        config
+            .chat
+            .iter()
+            .filter_map(|c| Self::from_config(c).ok())
+            .collect()
+    }
+
+    /// Display name (the configured `name`, or the format).
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Check severity filter and rate limit for a (category, app) pair.
+    fn should_send_for_app(
+        &self,
+        severity: AlertSeverity,
+        category: AlertCategory,
+        app_name: &str,
+    ) -> bool {
+        severity >= self.min_severity && self.limiter.ready(category, app_name)
+    }
+
+    /// Send a single (ungrouped) alert if it passes the filters.
+    pub fn send_alert(&mut self, alert: &Alert, hostname: &str, context: Option<&AlertContext>) {
+        self.send_grouped_alerts(std::slice::from_ref(alert), &[], hostname, context);
+    }
+
+    /// Send alerts grouped by parent application, one message per group
+    /// (or buffer them for the next digest).
+    pub fn send_grouped_alerts(
+        &mut self,
+        alerts: &[Alert],
+        processes: &[ProcessInfo],
+        hostname: &str,
+        context: Option<&AlertContext>,
+    ) {
+        if alerts.is_empty() {
+            return;
+        }
+        for group in group_alerts_by_app(alerts, processes) {
+            if !self.should_send_for_app(group.severity, group.category, &group.app_name) {
+                continue;
+            }
+            self.limiter.mark_group_sent(&group);
+
+            if self.digest.is_enabled() {
+                let representative = Alert {
+                    severity: group.severity,
+                    category: group.category,
+                    process_name: group.app_name.clone(),
+                    pid: *group.pids.first().unwrap_or(&0),
+                    message: group.representative_message.clone(),
+                    timestamp: chrono::Local::now(),
+                    value: group.total_value,
+                    threshold: group.threshold,
+                    suppressed: false,
+                };
+                let line = format!(
+                    "{} \u{2014} {}: {}",
+                    category_display_name(group.category),
+                    group.app_name,
+                    group.representative_message
+                );
+                self.digest.push((representative, line));
+            } else {
+                let message = ChatMessage::from_group(&group, hostname, context);
+                self.spawn_post(message.render(self.format));
+            }
+        }
+    }
+
+    /// Send "RESOLVED" messages. Severity-filtered, not rate-limited; clears
+    /// the rate limit so a fresh incident is posted right away.
+    pub fn send_resolved(&mut self, resolved: &[ActiveAlert], hostname: &str) {
+        for active in resolved {
+            if active.alert.severity < self.min_severity {
+                continue;
+            }
+            self.limiter.clear(active.key.category, &active.key.subject);
+
+            if self.digest.is_enabled() {
+                let line = format!(
+                    "RESOLVED {} \u{2014} {} after {}",
+                    category_display_name(active.key.category),
+                    active.key.subject,
+                    active.duration_display()
+                );
+                self.digest.push((active.alert.clone(), line));
+            } else {
+                let message = ChatMessage::resolved(active, hostname);
+                self.spawn_post(message.render(self.format));
+            }
+        }
+    }
+
+    /// Post an escalation step now. Bypasses the severity filter, rate limit
+    /// and digest.
+    pub fn send_escalation(&self, escalation: &Escalation, hostname: &str) {
+        let message = ChatMessage::escalation(escalation, hostname);
+        self.spawn_post(message.render(self.format));
+    }
+
+    /// Flush the digest if its interval has elapsed. Call every tick.
+    pub fn tick_digest(&mut self, hostname: &str) {
+        if let Some(entries) = self.digest.take_due() {
+            let message = ChatMessage::digest(&entries, hostname);
+            self.spawn_post(message.render(self.format));
+        }
+    }
+
+    /// Send a test message now (bypasses filters). Returns the attempt count.
+    pub async fn send_test(&self, hostname: &str) -> Result<u32, String> {
+        let message = ChatMessage {
+            color: CHAT_RESOLVED_COLOR,
+            title: "Sentinel Test Message".to_string(),
+            body: format!(
+                "{} notifications are working.",
+                match self.format {
+                    ChatFormat::Slack => "Slack",
+                    ChatFormat::Mattermost => "Mattermost",
+                    ChatFormat::Discord => "Discord",
+                }
+            ),
+            fields: Vec::new(),
+            workers: Vec::new(),
+            action: None,
+            footer: footer(hostname, None),
+        };
+        self.endpoint
+            .deliver(message.render(self.format).to_string())
+            .await
+    }
+
+    /// Deliver in the background so the main loop never waits on HTTP.
+    fn spawn_post(&self, payload: Value) {
+        let endpoint = self.endpoint.clone();
+        let body = payload.to_string();
+        tokio::spawn(async move {
+            let _ = endpoint.deliver(body).await;
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alerts::{AlertKey, AlertTracker};
+    use crate::notifications::telegram::WorkerDetail;
+    use std::time::Instant;
+
+    fn make_config(format: &str, digest_secs: u64) -> ChatConfig {
+        ChatConfig {
+            name: None,
+            format: format.into(),
+            url: "http://127.0.0.1:9/hook".into(),
+            min_severity: "warning".into(),
+            digest_secs,
+        }
+    }
+
+    fn make_group(severity: AlertSeverity, workers: usize) -> GroupedAlert {
+        GroupedAlert {
+            app_name: "node".into(),
+            category: AlertCategory::HighCpu,
+            severity,
+            worker_count: workers,
+            total_value: 95.0 * workers as f64,
+            avg_percent: 95.0,
+            threshold: 90.0,
+            pids: (100..100 + workers as u32).collect(),
+            worker_details: (100..100 + workers as u32)
+                .map(|pid| WorkerDetail {
+                    pid,
+                    name: "node-V8Worker".into(),
+                    value: 95.0,
+                    percent: 95.0,
+                })
+                .collect(),
+            representative_message: "node using 95.0% CPU".into(),
+        }
+    }
+
+    #[test]
+    fn parses_formats_and_rejects_unknown() {
+        assert_eq!(ChatFormat::parse("Mattermost"), Ok(ChatFormat::Mattermost));
+        assert!(ChatNotifier::from_config(&make_config("teams", 0)).is_err());
+        assert!(ChatNotifier::from_config(&ChatConfig {
+            url: "hooks.slack.com/x".into(),
+            ..make_config("slack", 0)
+        })
+        .is_err());
+        assert_eq!(
+            ChatNotifier::from_config(&make_config("discord", 0))
+                .unwrap()
+                .name(),
+            "discord"
+        );
+    }
+
+    #[test]
+    fn severity_colors_are_distinct() {
+        let colors = [
+            AlertSeverity::Info,
+            AlertSeverity::Warning,
+            AlertSeverity::Critical,
+            AlertSeverity::Danger,
+        ]
+        .map(severity_color);
+        for (i, c) in colors.iter().enumerate() {
+            assert!(!colors[i + 1..].contains(c));
+            assert_ne!(*c, CHAT_RESOLVED_COLOR);
+        }
+    }
+
+    #[test]
+    fn grouped_message_carries_explanation_and_action() {
+        let group = make_group(AlertSeverity::Critical, 3);
+        let msg = ChatMessage::from_group(&group, "box", None);
+        assert_eq!(msg.title, "CRITICAL: High CPU Usage");
+        assert_eq!(msg.body, explain_group(&group));
+        assert_eq!(msg.action.as_deref(), Some(suggest_action(&group).as_str()));
+        assert_eq!(msg.workers.len(), 3);
+
+        let slack = msg.render(ChatFormat::Slack);
+        let attachment = &slack["attachments"][0];
+        assert_eq!(attachment["color"], "#E01E5A");
+        let blocks = attachment["blocks"].as_array().unwrap();
+        assert_eq!(blocks[0]["type"], "header");
+        assert!(blocks.iter().any(|b| b["text"]["text"]
+            .as_str()
+            .is_some_and(|t| t.contains("Suggested action"))));
+
+        let mattermost = msg.render(ChatFormat::Mattermost);
+        assert_eq!(mattermost["attachments"][0]["title"], msg.title);
+        assert!(mattermost["attachments"][0]["text"]
+            .as_str()
+            .unwrap()
+            .contains("PID 100"));
+
+        let discord = msg.render(ChatFormat::Discord);
+        let embed = &discord["embeds"][0];
+        assert_eq!(embed["color"], 0xE01E5A);
+        assert_eq!(embed["description"], msg.body);
+        assert_eq!(embed["fields"][0]["name"], "Application");
+    }
+
+    #[test]
+    fn single_alert_uses_message_and_resolved_is_green() {
+        let msg = ChatMessage::from_group(&make_group(AlertSeverity::Warning, 1), "box", None);
+        assert_eq!(msg.body, "node using 95.0% CPU");
+        assert!(msg.workers.is_empty());
+        assert_eq!(msg.fields[0].1, "node (PID 100)");
+
+        let alert = Alert::new(
+            AlertSeverity::Critical,
+            AlertCategory::HighCpu,
+            "node",
+            100,
+            "node using 95.0% CPU".to_string(),
+            95.0,
+            90.0,
+        );
+        let mut tracker = AlertTracker::new();
+        tracker.update(std::slice::from_ref(&alert), Instant::now());
+        tracker.acknowledge(&AlertKey::of(&alert));
+        let active = tracker.active().remove(0);
+        let resolved = ChatMessage::resolved(&active, "box");
+        assert_eq!(resolved.color, CHAT_RESOLVED_COLOR);
+        assert!(resolved.fields[1].1.ends_with("(acknowledged)"));
+    }
+
+    #[tokio::test]
+    async fn digest_mode_buffers_and_summarizes() {
+        let mut chat = ChatNotifier::from_config(&make_config("slack", 600)).unwrap();
+        let alerts: Vec<Alert> = ["node", "postgres", "chrome"]
+            .iter()
+            .map(|name| {
+                Alert::new(
+                    AlertSeverity::Critical,
+                    AlertCategory::HighMemory,
+                    name,
+                    1,
+                    format!("{} is large", name),
+                    1.0,
+                    0.5,
+                )
+            })
+            .collect();
+        chat.send_grouped_alerts(&alerts, &[], "box", None);
+        assert_eq!(chat.digest.len(), 3);
+
+        // Rate limit still applies while buffering
+        chat.send_grouped_alerts(&alerts, &[], "box", None);
+        assert_eq!(chat.digest.len(), 3);
+
+        let entries = chat.digest.take_due().unwrap();
+        let msg = ChatMessage::digest(&entries, "box");
+        assert_eq!(msg.title, "Sentinel Digest \u{2014} 3 alerts");
+        assert!(msg.body.starts_with("3 critical"));
+        assert!(msg.body.contains("postgres is large"));
+        assert_eq!(msg.color, severity_color(AlertSeverity::Critical));
+    }
+}
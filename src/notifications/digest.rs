@@ -0,0 +1,97 @@
+//! Digest batching shared by the Telegram and chat notifiers (#8).
+//!
+//! With a non-zero interval, notifications are buffered instead of sent and
+//! flushed as one summary message once the interval has elapsed. The buffer
+//! keeps only the newest `capacity` entries.
+
+use std::time::{Duration, Instant};
+
+/// Buffer of pending notifications plus its flush timer.
+pub struct Digest<T> {
+    interval: Duration,
+    capacity: usize,
+    buffer: Vec<T>,
+    last_flush: Option<Instant>,
+}
+
+impl<T> Digest<T> {
+    /// A digest flushing every `interval_secs` (0 = disabled), holding at
+    /// most `capacity` entries.
+    pub fn new(interval_secs: u64, capacity: usize) -> Self {
+        Self {
+            interval: Duration::from_secs(interval_secs),
+            capacity,
+            buffer: Vec::new(),
+            last_flush: None,
+        }
+    }
+
+    /// Whether notifications should be buffered rather than sent right away.
+    pub fn is_enabled(&self) -> bool {
+        self.interval.as_secs() > 0
+    }
+
+    /// Change the flush interval (0 disables digest mode).
+    pub fn set_interval(&mut self, secs: u64) {
+        self.interval = Duration::from_secs(secs);
+    }
+
+    /// Buffer an entry, dropping the oldest ones beyond capacity.
+    pub fn push(&mut self, entry: T) {
+        self.buffer.push(entry);
+        if self.buffer.len() > self.capacity {
+            self.buffer.drain(..self.buffer.len() - self.capacity);
+        }
+    }
+
+    /// Number of buffered entries.
+    #[cfg(test)]
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Drain the buffer if digest mode is on, something is buffered and the
+    /// interval has elapsed since the last flush (the first flush is
+    /// immediate).
+    pub fn take_due(&mut self) -> Option<Vec<T>> {
+        if !self.is_enabled() || self.buffer.is_empty() {
+            return None;
+        }
+        if self
+            .last_flush
+            .is_some_and(|last| last.elapsed() < self.interval)
+        {
+            return None;
+        }
+        self.last_flush = Some(Instant::now());
+        Some(self.buffer.drain(..).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disabled_digest_never_flushes() {
+        let mut digest = Digest::new(0, 5);
+        digest.push(1);
+        assert!(!digest.is_enabled());
+        assert!(digest.take_due().is_none());
+    }
+
+    #[test]
+    fn flushes_once_per_interval_and_caps_buffer() {
+        let mut digest = Digest::new(3600, 2);
+        for i in 0..4 {
+            digest.push(i);
+        }
+        assert_eq!(digest.len(), 2);
+        assert_eq!(digest.take_due(), Some(vec![2, 3]));
+
+        // Interval has not elapsed since the first flush
+        digest.push(4);
+        assert!(digest.take_due().is_none());
+        assert_eq!(digest.len(), 1);
+    }
+}
@@ -0,0 +1,564 @@
+//! Escalation chains shared by every notifier type.
+//!
+//! A policy from `[[notifications.escalations]]` matches firing alerts like a
+//! silence does (category, process glob) plus a minimum severity. While a
+//! matching alert keeps firing unacknowledged, each step notifies its channel
+//! once the alert has been firing for the step's delay:
+//!
+//! ```toml
+//! [[notifications.escalations]]
+//! name = "thermal"
+//! category = "ThermalCritical"
+//!
+//! [[notifications.escalations.steps]]
+//! delay = "10m"
+//! channel = "email"
+//!
+//! [[notifications.escalations.steps]]
+//! delay = "20m"
+//! channel = "telegram"
+//! recipients = ["123456789"]   # second contact
+//! ```
+//!
+//! Acknowledging (or silencing) an alert restarts its clock. Progress is
+//! persisted in the event store so a restart neither re-sends steps nor
+//! forgets how long the alert has been firing.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::time::Duration;
+
+use crate::alerts::rules::{format_duration, parse_duration, parse_severity};
+use crate::alerts::silence::{parse_category, AlertMatcher};
+use crate::alerts::{ActiveAlert, AlertKey};
+use crate::config::{EscalationPolicyConfig, NotificationConfig};
+use crate::constants::{ESCALATION_STATE_GRACE_SECS, ESCALATION_TOUCH_SECS};
+use crate::models::{Alert, AlertSeverity};
+use crate::store::{EscalationRow, EventStore};
+
+// ── Policies ──────────────────────────────────────────────────────
+
+/// Where an escalation step is sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EscalationChannel {
+    Telegram,
+    Email,
+    Webhook,
+    Chat,
+}
+
+impl EscalationChannel {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.trim().to_lowercase().as_str() {
+            "telegram" => Ok(Self::Telegram),
+            "email" => Ok(Self::Email),
+            "webhook" => Ok(Self::Webhook),
+            "chat" => Ok(Self::Chat),
+            other => Err(format!(
+                "unknown channel '{}' (expected telegram, email, webhook or chat)",
+                other
+            )),
+        }
+    }
+}
+
+impl fmt::Display for EscalationChannel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Telegram => write!(f, "telegram"),
+            Self::Email => write!(f, "email"),
+            Self::Webhook => write!(f, "webhook"),
+            Self::Chat => write!(f, "chat"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EscalationStep {
+    pub delay: Duration,
+    pub channel: EscalationChannel,
+    /// Empty = the channel's configured default.
+    pub recipients: Vec<String>,
+}
+
+/// A compiled escalation policy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EscalationPolicy {
+    pub name: String,
+    matcher: AlertMatcher,
+    min_severity: AlertSeverity,
+    /// Ordered by delay.
+    pub steps: Vec<EscalationStep>,
+}
+
+impl EscalationPolicy {
+    pub fn compile(cfg: &EscalationPolicyConfig) -> Result<Self, String> {
+        if cfg.name.trim().is_empty() {
+            return Err("policy name is empty".to_string());
+        }
+        if cfg.steps.is_empty() {
+            return Err("policy has no steps".to_string());
+        }
+        let mut steps = cfg
+            .steps
+            .iter()
+            .map(|s| {
+                Ok(EscalationStep {
+                    delay: parse_duration(&s.delay)?,
+                    channel: EscalationChannel::parse(&s.channel)?,
+                    recipients: s.recipients.clone(),
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        steps.sort_by_key(|s| s.delay);
+
+        Ok(Self {
+            name: cfg.name.trim().to_string(),
+            matcher: AlertMatcher::parse(cfg.category.as_deref(), cfg.process.as_deref(), None)?,
+            min_severity: parse_severity(&cfg.min_severity)?,
+            steps,
+        })
+    }
+
+    /// Compile every valid policy (invalid ones are reported by
+    /// `Config::load`).
+    pub fn all_from_config(config: &NotificationConfig) -> Vec<Self> {
+        config
+            .escalations
+            .iter()
+            .filter_map(|p| Self::compile(p).ok())
+            .collect()
+    }
+
+    pub fn matches(&self, alert: &Alert) -> bool {
+        alert.severity >= self.min_severity && self.matcher.matches(alert)
+    }
+
+    /// One-line summary, e.g. `thermal: THERMAL!, >= CRIT: 10m email -> 20m telegram`.
+    pub fn describe(&self) -> String {
+        let steps: Vec<String> = self
+            .steps
+            .iter()
+            .map(|s| format!("{} {}", format_duration(s.delay), s.channel))
+            .collect();
+        format!(
+            "{}: {}, >= {}: {}",
+            self.name,
+            self.matcher.describe(),
+            self.min_severity,
+            steps.join(" -> ")
+        )
+    }
+}
+
+// ── Escalator ─────────────────────────────────────────────────────
+
+/// A step that is due: notify `channel` about `alert`.
+#[derive(Debug, Clone)]
+pub struct Escalation {
+    pub policy: String,
+    /// 1-based step number.
+    pub step: usize,
+    pub steps_total: usize,
+    pub channel: EscalationChannel,
+    pub recipients: Vec<String>,
+    pub alert: ActiveAlert,
+    /// Firing duration (since the escalation clock started).
+    pub firing_for: Duration,
+}
+
+impl Escalation {
+    /// Short header, e.g. `ESCALATED (step 2/3, policy thermal)`.
+    pub fn headline(&self) -> String {
+        format!(
+            "ESCALATED (step {}/{}, policy {})",
+            self.step, self.steps_total, self.policy
+        )
+    }
+
+    /// Firing duration for messages (`10m`, `90s`).
+    pub fn firing_display(&self) -> String {
+        format_duration(Duration::from_secs(self.firing_for.as_secs()))
+    }
+}
+
+/// An escalation in progress, for the `escalations` listing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EscalationProgress {
+    pub policy: String,
+    pub key: AlertKey,
+    pub steps_fired: usize,
+    pub steps_total: usize,
+    pub started_ts: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct EscalationState {
+    started_ts: i64,
+    last_seen_ts: i64,
+    steps_fired: usize,
+    /// Seen firing since startup (restored rows wait for the alert to return).
+    live: bool,
+}
+
+/// Tracks escalation progress per (policy, alert).
+#[derive(Default)]
+pub struct Escalator {
+    policies: Vec<EscalationPolicy>,
+    states: HashMap<(String, AlertKey), EscalationState>,
+}
+
+impl Escalator {
+    pub fn new(config: &NotificationConfig) -> Self {
+        Self {
+            policies: EscalationPolicy::all_from_config(config),
+            states: HashMap::new(),
+        }
+    }
+
+    pub fn policies(&self) -> &[EscalationPolicy] {
+        &self.policies
+    }
+
+    /// Swap in reloaded policies, dropping progress of removed ones.
+    pub fn set_policies(&mut self, config: &NotificationConfig, store: Option<&EventStore>) {
+        self.policies = EscalationPolicy::all_from_config(config);
+        let names: HashSet<&str> = self.policies.iter().map(|p| p.name.as_str()).collect();
+        self.states.retain(|(policy, key), _| {
+            let keep = names.contains(policy.as_str());
+            if !keep {
+                if let Some(store) = store {
+                    let _ = store.remove_escalation(policy, &category_name(key), &key.subject);
+                }
+            }
+            keep
+        });
+    }
+
+    /// Restore progress persisted by a previous run. Rows older than
+    /// [`ESCALATION_STATE_GRACE_SECS`] are ignored.
+    pub fn restore(&mut self, rows: &[EscalationRow], now_ms: i64) {
+        let grace_ms = ESCALATION_STATE_GRACE_SECS as i64 * 1000;
+        for row in rows {
+            if now_ms - row.last_seen_ts > grace_ms {
+                continue;
+            }
+            let Ok(category) = parse_category(&row.category) else {
+                continue;
+            };
+            let key = AlertKey {
+                category,
+                subject: row.subject.clone(),
+            };
+            self.states.insert(
+                (row.policy.clone(), key),
+                EscalationState {
+                    started_ts: row.started_ts,
+                    last_seen_ts: row.last_seen_ts,
+                    steps_fired: row.steps_fired as usize,
+                    live: false,
+                },
+            );
+        }
+    }
+
+    /// Escalations in progress (at least one alert matched), oldest first.
+    pub fn in_progress(&self) -> Vec<EscalationProgress> {
+        let mut list: Vec<EscalationProgress> = self
+            .states
+            .iter()
+            .filter(|(_, state)| state.live)
+            .filter_map(|((policy, key), state)| {
+                let p = self.policies.iter().find(|p| &p.name == policy)?;
+                Some(EscalationProgress {
+                    policy: policy.clone(),
+                    key: key.clone(),
+                    steps_fired: state.steps_fired,
+                    steps_total: p.steps.len(),
+                    started_ts: state.started_ts,
+                })
+            })
+            .collect();
+        list.sort_by_key(|p| p.started_ts);
+        list
+    }
+
+    /// Advance every policy against the firing alerts and return the steps
+    /// that became due. Acknowledged or suppressed alerts hold their clock
+    /// at zero; alerts that stopped firing are forgotten.
+    pub fn evaluate(
+        &mut self,
+        active: &[ActiveAlert],
+        is_suppressed: impl Fn(&Alert) -> bool,
+        now_ms: i64,
+        store: Option<&EventStore>,
+    ) -> Vec<Escalation> {
+        let touch_ms = ESCALATION_TOUCH_SECS as i64 * 1000;
+        let mut due = Vec::new();
+        let mut seen = HashSet::new();
+
+        for policy in &self.policies {
+            for alert in active.iter().filter(|a| policy.matches(&a.alert)) {
+                let id = (policy.name.clone(), alert.key.clone());
+                let held = alert.acknowledged || is_suppressed(&alert.alert);
+                let since_ms = alert.since.timestamp_millis();
+                let mut dirty = false;
+
+                let state = self.states.entry(id.clone()).or_insert_with(|| {
+                    dirty = true;
+                    EscalationState {
+                        started_ts: if held { now_ms } else { since_ms },
+                        last_seen_ts: now_ms,
+                        steps_fired: 0,
+                        live: true,
+                    }
+                });
+                if !state.live {
+                    // Restored from a previous run: keep the earlier start
+                    state.live = true;
+                    state.started_ts = state.started_ts.min(since_ms);
+                    dirty = true;
+                }
+
+                if held {
+                    state.started_ts = now_ms;
+                    if state.steps_fired > 0 {
+                        state.steps_fired = 0;
+                        dirty = true;
+                    }
+                } else {
+                    let firing_for =
+                        Duration::from_millis((now_ms - state.started_ts).max(0) as u64);
+                    while let Some(step) = policy.steps.get(state.steps_fired) {
+                        if firing_for < step.delay {
+                            break;
+                        }
+                        state.steps_fired += 1;
+                        dirty = true;
+                        due.push(Escalation {
+                            policy: policy.name.clone(),
+                            step: state.steps_fired,
+                            steps_total: policy.steps.len(),
+                            channel: step.channel,
+                            recipients: step.recipients.clone(),
+                            alert: alert.clone(),
+                            firing_for,
+                        });
+                    }
+                }
+
+                if dirty || now_ms - state.last_seen_ts >= touch_ms {
+                    state.last_seen_ts = now_ms;
+                    if let Some(store) = store {
+                        let _ = store.upsert_escalation(&row_for(&id, state));
+                    }
+                }
+                seen.insert(id);
+            }
+        }
+
+        // Forget alerts that stopped firing; restored rows wait out the grace period
+        let grace_ms = ESCALATION_STATE_GRACE_SECS as i64 * 1000;
+        self.states.retain(|(policy, key), state| {
+            let keep = seen.contains(&(policy.clone(), key.clone()))
+                || (!state.live && now_ms - state.last_seen_ts <= grace_ms);
+            if !keep {
+                if let Some(store) = store {
+                    let _ = store.remove_escalation(policy, &category_name(key), &key.subject);
+                }
+            }
+            keep
+        });
+
+        due
+    }
+}
+
+fn category_name(key: &AlertKey) -> String {
+    format!("{:?}", key.category)
+}
+
+fn row_for((policy, key): &(String, AlertKey), state: &EscalationState) -> EscalationRow {
+    EscalationRow {
+        policy: policy.clone(),
+        category: category_name(key),
+        subject: key.subject.clone(),
+        started_ts: state.started_ts,
+        last_seen_ts: state.last_seen_ts,
+        steps_fired: state.steps_fired as u32,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alerts::AlertTracker;
+    use crate::config::EscalationStepConfig;
+    use crate::config::RuleValue;
+    use crate::models::AlertCategory;
+
+    const MIN: i64 = 60_000;
+
+    fn thermal_policy() -> EscalationPolicyConfig {
+        EscalationPolicyConfig {
+            name: "thermal".to_string(),
+            category: Some("ThermalCritical".to_string()),
+            process: None,
+            min_severity: "critical".to_string(),
+            steps: vec![
+                EscalationStepConfig {
+                    delay: RuleValue::Text("20m".to_string()),
+                    channel: "telegram".to_string(),
+                    recipients: vec!["42".to_string()],
+                },
+                EscalationStepConfig {
+                    delay: RuleValue::Text("10m".to_string()),
+                    channel: "email".to_string(),
+                    recipients: Vec::new(),
+                },
+            ],
+        }
+    }
+
+    fn escalator() -> Escalator {
+        Escalator::new(&NotificationConfig {
+            escalations: vec![thermal_policy()],
+            ..NotificationConfig::default()
+        })
+    }
+
+    /// A tracker with one critical thermal alert; returns it and its start (ms).
+    fn firing(severity: AlertSeverity) -> (AlertTracker, i64) {
+        let alert = Alert::new(
+            severity,
+            AlertCategory::ThermalCritical,
+            "CPU",
+            0,
+            "CPU at 95°C".to_string(),
+            95.0,
+            90.0,
+        );
+        let start = alert.timestamp.timestamp_millis();
+        let mut tracker = AlertTracker::new();
+        tracker.update(&[alert], std::time::Instant::now());
+        (tracker, start)
+    }
+
+    #[test]
+    fn compile_sorts_steps_and_rejects_bad_config() {
+        let policy = EscalationPolicy::compile(&thermal_policy()).unwrap();
+        assert_eq!(policy.steps[0].channel, EscalationChannel::Email);
+        assert_eq!(policy.steps[1].delay, Duration::from_secs(1200));
+        assert_eq!(
+            policy.describe(),
+            "thermal: THERMAL!, >= CRIT: 10m email -> 20m telegram"
+        );
+
+        let mut bad = thermal_policy();
+        bad.steps[0].channel = "pager".to_string();
+        assert!(EscalationPolicy::compile(&bad).is_err());
+        bad.steps.clear();
+        assert!(EscalationPolicy::compile(&bad).is_err());
+    }
+
+    #[test]
+    fn steps_fire_once_in_order_of_delay() {
+        let mut esc = escalator();
+        let (tracker, start) = firing(AlertSeverity::Critical);
+        let active = tracker.active();
+
+        assert!(esc
+            .evaluate(&active, |_| false, start + 5 * MIN, None)
+            .is_empty());
+        let due = esc.evaluate(&active, |_| false, start + 10 * MIN, None);
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].channel, EscalationChannel::Email);
+        assert_eq!(due[0].headline(), "ESCALATED (step 1/2, policy thermal)");
+        assert!(esc
+            .evaluate(&active, |_| false, start + 15 * MIN, None)
+            .is_empty());
+
+        let due = esc.evaluate(&active, |_| false, start + 21 * MIN, None);
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].recipients, vec!["42".to_string()]);
+        assert_eq!(esc.in_progress()[0].steps_fired, 2);
+
+        // Resolved: state is dropped
+        assert!(esc
+            .evaluate(&[], |_| false, start + 22 * MIN, None)
+            .is_empty());
+        assert!(esc.in_progress().is_empty());
+    }
+
+    #[test]
+    fn below_min_severity_never_escalates() {
+        let mut esc = escalator();
+        let (tracker, start) = firing(AlertSeverity::Warning);
+        assert!(esc
+            .evaluate(&tracker.active(), |_| false, start + 60 * MIN, None)
+            .is_empty());
+    }
+
+    #[test]
+    fn acknowledging_restarts_the_clock() {
+        let mut esc = escalator();
+        let (mut tracker, start) = firing(AlertSeverity::Critical);
+        let key = tracker.active()[0].key.clone();
+        tracker.acknowledge(&key);
+
+        assert!(esc
+            .evaluate(&tracker.active(), |_| false, start + 30 * MIN, None)
+            .is_empty());
+
+        // Severity rises: the tracker un-acks, and the clock counts from then
+        let danger = Alert {
+            severity: AlertSeverity::Danger,
+            ..tracker.active()[0].alert.clone()
+        };
+        tracker.update(&[danger], std::time::Instant::now());
+        let active = tracker.active();
+        assert!(esc
+            .evaluate(&active, |_| false, start + 35 * MIN, None)
+            .is_empty());
+        assert_eq!(
+            esc.evaluate(&active, |_| false, start + 40 * MIN, None)
+                .len(),
+            1
+        );
+
+        // Suppressed alerts hold too
+        assert!(esc
+            .evaluate(&active, |_| true, start + 90 * MIN, None)
+            .is_empty());
+    }
+
+    #[test]
+    fn progress_survives_restart_via_store() {
+        let store = EventStore::open(None).unwrap();
+        let (tracker, start) = firing(AlertSeverity::Critical);
+        let mut esc = escalator();
+        assert_eq!(
+            esc.evaluate(&tracker.active(), |_| false, start + 11 * MIN, Some(&store))
+                .len(),
+            1
+        );
+
+        // New process: the tracker restarts the alert with a fresh `since`
+        let mut esc = escalator();
+        esc.restore(&store.escalations().unwrap(), start + 12 * MIN);
+        let mut restarted = tracker.active();
+        restarted[0].since += chrono::Duration::minutes(12);
+
+        assert!(esc
+            .evaluate(&restarted, |_| false, start + 12 * MIN, Some(&store))
+            .is_empty());
+        let due = esc.evaluate(&restarted, |_| false, start + 20 * MIN, Some(&store));
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].step, 2);
+        assert_eq!(store.escalations().unwrap()[0].steps_fired, 2);
+
+        // Resolution removes the persisted row
+        esc.evaluate(&[], |_| false, start + 21 * MIN, Some(&store));
+        assert!(store.escalations().unwrap().is_empty());
+    }
+}
@@ -10,12 +10,29 @@
 //! Credentials are stored in `config.toml` (editable via Settings TUI).
 //! Rate-limited: max 1 message per (category, PID) per 5 minutes.
 //! Severity-filtered via `telegram_min_severity` config.
+//! With `telegram_commands = true`, the bot also answers `/status`, `/top`,
+//! `/alerts`, `/timeline`, `/port`, `/ack` and confirmed `/kill` from the
+//! configured chat (see `telegram_bot`).
+//!
+//! ## Webhooks
+//! POSTs JSON payloads to `[[notifications.webhooks]]` URLs, optionally
+//! HMAC-signed, retried with backoff. Same filtering and rate limits as Telegram.
+//!
+//! ## Slack / Mattermost / Discord
+//! Renders grouped alerts as colored attachments or embeds for
+//! `[[notifications.chat]]` incoming webhooks, with optional digest batching.
 //!
 //! IMPORTANT: Credentials (SMTP user, password, recipient) are ONLY
 //! stored in the .env file and NEVER committed to source control.
 //! Telegram bot tokens are stored in config.toml (outside the git repo).
 
+pub mod chat;
+pub mod digest;
+pub mod escalation;
+pub mod rate_limit;
 pub mod telegram;
+pub mod telegram_bot;
+pub mod webhook;
 
 use std::collections::HashMap;
 use std::time::Instant;
@@ -24,6 +41,7 @@ use lettre::message::header::ContentType;
 use lettre::transport::smtp::authentication::Credentials;
 use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
 
+use crate::alerts::ActiveAlert;
 use crate::constants::{DEFAULT_SMTP_PORT, DEFAULT_SMTP_SERVER, EMAIL_RATE_LIMIT_SECS};
 
 /// Event types for rate limiting.
@@ -33,10 +51,16 @@ pub enum NotifyEvent {
     ThermalCritical,
     /// Sustained emergency temperature.
     ThermalEmergency,
+    /// A thermal mitigation ladder step (0-based) was applied.
+    ThermalMitigation { step: usize },
     /// Shutdown imminent (grace period started).
     ShutdownImminent,
     /// System recovered from thermal emergency.
     Recovered,
+    /// Critical alerts stopped firing.
+    AlertResolved,
+    /// An escalation policy step reached email.
+    Escalation,
     /// Test email.
     Test,
 }
@@ -47,8 +71,11 @@ impl NotifyEvent {
         match self {
             NotifyEvent::ThermalCritical => "[Sentinel] CRITICAL: Temperature threshold exceeded",
             NotifyEvent::ThermalEmergency => "[Sentinel] EMERGENCY: Sustained high temperature",
+            NotifyEvent::ThermalMitigation { .. } => "[Sentinel] MITIGATION: Throttling workload to cool down",
             NotifyEvent::ShutdownImminent => "[Sentinel] SHUTDOWN IMMINENT: Auto-shutdown triggered",
             NotifyEvent::Recovered => "[Sentinel] RECOVERED: Temperature returned to normal",
+            NotifyEvent::AlertResolved => "[Sentinel] RESOLVED: Alert condition cleared",
+            NotifyEvent::Escalation => "[Sentinel] ESCALATION: Alert still unacknowledged",
             NotifyEvent::Test => "[Sentinel] Test email - notifications working",
         }
     }
@@ -257,6 +284,18 @@ pub fn thermal_alert_body(
              Auto-shutdown may be initiated if enabled.",
             sensor, temp, hostname, timestamp,
         ),
+        NotifyEvent::ThermalMitigation { step } => format!(
+            "Sentinel Thermal Mitigation\n\
+             ===========================\n\n\
+             Severity: EMERGENCY\n\
+             Sensor: {}\n\
+             Temperature: {:.1}°C\n\
+             Host: {}\n\
+             Time: {}\n\n\
+             Mitigation step {} was applied after sustained emergency temperatures.\n\
+             Further steps, then auto-shutdown, follow if the temperature keeps rising.",
+            sensor, temp, hostname, timestamp, step + 1,
+        ),
         NotifyEvent::ShutdownImminent => format!(
             "Sentinel AUTO-SHUTDOWN IMMINENT\n\
              ===============================\n\n\
@@ -282,6 +321,18 @@ pub fn thermal_alert_body(
              Temperature has returned to safe levels. The system is operating normally.",
             sensor, temp, hostname, timestamp,
         ),
+        NotifyEvent::AlertResolved => format!(
+            "Sentinel Alert Resolved\n\
+             Host: {}\n\
+             Time: {}",
+            hostname, timestamp,
+        ),
+        NotifyEvent::Escalation => format!(
+            "Sentinel Alert Escalation\n\
+             Host: {}\n\
+             Time: {}",
+            hostname, timestamp,
+        ),
         NotifyEvent::Test => format!(
             "Sentinel Test Email\n\
              Host: {}\n\
@@ -291,6 +342,55 @@ pub fn thermal_alert_body(
     }
 }
 
+/// Build a "RESOLVED" email body listing alerts whose condition cleared.
+pub fn resolved_alerts_body(resolved: &[ActiveAlert], hostname: &str) -> String {
+    let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
+    let mut body = format!(
+        "Sentinel Alert Resolved\n\
+         =======================\n\n\
+         Host: {}\n\
+         Time: {}\n\n\
+         The following alerts are no longer firing:\n\n",
+        hostname, timestamp,
+    );
+    for active in resolved {
+        body.push_str(&format!(
+            "- [{}] {}: {} (firing for {}{})\n",
+            active.alert.severity,
+            active.key.subject,
+            active.alert.message,
+            active.duration_display(),
+            if active.acknowledged { ", acknowledged" } else { "" },
+        ));
+    }
+    body
+}
+
+/// Build an escalation email body.
+pub fn escalation_body(escalation: &escalation::Escalation, hostname: &str) -> String {
+    let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
+    let active = &escalation.alert;
+    format!(
+        "Sentinel Alert Escalation\n\
+         =========================\n\n\
+         {}\n\n\
+         Severity: {}\n\
+         Process: {}\n\
+         Alert: {}\n\
+         Unacknowledged for: {}\n\
+         Host: {}\n\
+         Time: {}\n\n\
+         Acknowledge the alert in Sentinel (`ack`) to stop further escalation.",
+        escalation.headline(),
+        active.alert.severity,
+        active.key.subject,
+        active.alert.message,
+        escalation.firing_display(),
+        hostname,
+        timestamp,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -309,7 +409,9 @@ mod tests {
         assert!(NotifyEvent::ThermalCritical.subject().contains("CRITICAL"));
         assert!(NotifyEvent::ThermalEmergency.subject().contains("EMERGENCY"));
         assert!(NotifyEvent::ShutdownImminent.subject().contains("SHUTDOWN"));
+        assert!(NotifyEvent::ThermalMitigation { step: 0 }.subject().contains("MITIGATION"));
         assert!(NotifyEvent::Recovered.subject().contains("RECOVERED"));
+        assert!(NotifyEvent::AlertResolved.subject().contains("RESOLVED"));
         assert!(NotifyEvent::Test.subject().contains("Test"));
     }
 
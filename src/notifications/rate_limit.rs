@@ -0,0 +1,90 @@
+//! Per-app rate limiting shared by the Telegram, webhook and chat notifiers.
+//!
+//! At most one notification per (alert category, app name) per cooldown. A
+//! resolved alert clears the key it was notified under (its parent app for a
+//! grouped worker) so a fresh incident is notified right away.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use super::telegram::GroupedAlert;
+use crate::models::AlertCategory;
+
+/// Last send time per (category, app name).
+pub struct AlertRateLimiter {
+    cooldown: Duration,
+    last_sent: HashMap<(AlertCategory, String), Instant>,
+    /// App name each grouped alert subject was notified under.
+    group_of: HashMap<(AlertCategory, String), String>,
+}
+
+impl AlertRateLimiter {
+    /// A limiter allowing one notification per key every `cooldown_secs`.
+    pub fn new(cooldown_secs: u64) -> Self {
+        Self {
+            cooldown: Duration::from_secs(cooldown_secs),
+            last_sent: HashMap::new(),
+            group_of: HashMap::new(),
+        }
+    }
+
+    /// Whether `app_name`'s `category` alert is outside its cooldown.
+    pub fn ready(&self, category: AlertCategory, app_name: &str) -> bool {
+        match self.last_sent.get(&(category, app_name.to_string())) {
+            Some(last) => last.elapsed() >= self.cooldown,
+            None => true,
+        }
+    }
+
+    /// Start the cooldown (mark before spawning the send, so a notification
+    /// in flight is not duplicated).
+    pub fn mark_sent(&mut self, category: AlertCategory, app_name: &str) {
+        self.last_sent
+            .insert((category, app_name.to_string()), Instant::now());
+    }
+
+    /// [`mark_sent`](Self::mark_sent) for a group, remembering which app
+    /// each of its alert subjects was notified under.
+    pub fn mark_group_sent(&mut self, group: &GroupedAlert) {
+        for worker in &group.worker_details {
+            self.group_of.insert(
+                (group.category, worker.name.clone()),
+                group.app_name.clone(),
+            );
+        }
+        self.mark_sent(group.category, &group.app_name);
+    }
+
+    /// Forget the key `subject`'s alert was notified under, e.g. when it
+    /// resolves.
+    pub fn clear(&mut self, category: AlertCategory, subject: &str) {
+        let app_name = self
+            .group_of
+            .remove(&(category, subject.to_string()))
+            .unwrap_or_else(|| subject.to_string());
+        self.last_sent.remove(&(category, app_name));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cooldown_is_per_category_and_app() {
+        let mut limiter = AlertRateLimiter::new(300);
+        assert!(limiter.ready(AlertCategory::HighCpu, "node"));
+
+        limiter.mark_sent(AlertCategory::HighCpu, "node");
+        assert!(!limiter.ready(AlertCategory::HighCpu, "node"));
+        assert!(limiter.ready(AlertCategory::HighCpu, "python"));
+        assert!(limiter.ready(AlertCategory::HighMemory, "node"));
+
+        limiter.clear(AlertCategory::HighCpu, "node");
+        assert!(limiter.ready(AlertCategory::HighCpu, "node"));
+
+        let mut no_cooldown = AlertRateLimiter::new(0);
+        no_cooldown.mark_sent(AlertCategory::HighCpu, "node");
+        assert!(no_cooldown.ready(AlertCategory::HighCpu, "node"));
+    }
+}
@@ -13,8 +13,11 @@
 //! 3. Configure in Settings TUI > Notifications, or in config.toml.
 
 use std::collections::HashMap;
-use std::time::{Duration, Instant};
+use std::time::Duration;
 
+use serde::Serialize;
+
+use crate::alerts::ActiveAlert;
 use crate::config::NotificationConfig;
 use crate::constants::{
     DEFAULT_TELEGRAM_DIGEST_SECS, MAX_PARENT_WALK_DEPTH, MAX_WORKER_DISPLAY, TELEGRAM_API_BASE,
@@ -22,23 +25,23 @@ use crate::constants::{
 };
 use crate::models::{Alert, AlertCategory, AlertSeverity, ProcessInfo};
 
+use super::digest::Digest;
+use super::escalation::Escalation;
+use super::rate_limit::AlertRateLimiter;
+
 /// Telegram notification manager with rate limiting, severity filtering,
 /// and optional digest mode (#8).
 pub struct TelegramNotifier {
+    /// Bot API base URL ([`TELEGRAM_API_BASE`] outside tests).
+    api_base: String,
     bot_token: String,
     chat_id: String,
     min_severity: AlertSeverity,
     client: reqwest::Client,
-    /// Last send time per (category, app_name) for app-based rate limiting.
-    last_sent: HashMap<(AlertCategory, String), Instant>,
-    rate_limit: Duration,
-    /// Digest mode: if > 0, batch alerts and send a summary every N seconds.
-    digest_interval: Duration,
-    /// Buffered alerts waiting for the next digest flush.
-    digest_buffer: Vec<(Alert, String)>,
-    /// Last time a digest was flushed.
-    #[allow(dead_code)] // read by tick_digest
-    last_digest: Option<Instant>,
+    /// App-based rate limiting per (category, app_name).
+    limiter: AlertRateLimiter,
+    /// Digest mode: if enabled, batch alerts and send a summary periodically.
+    digest: Digest<(Alert, String)>,
 }
 
 impl TelegramNotifier {
@@ -71,6 +74,7 @@ impl TelegramNotifier {
             })?;
 
         Some(Self {
+            api_base: TELEGRAM_API_BASE.to_string(),
             bot_token: token,
             chat_id,
             min_severity: parse_min_severity(&config.telegram_min_severity),
@@ -78,11 +82,8 @@ impl TelegramNotifier {
                 .timeout(Duration::from_secs(10))
                 .build()
                 .unwrap_or_default(),
-            last_sent: HashMap::new(),
-            rate_limit: Duration::from_secs(TELEGRAM_RATE_LIMIT_SECS),
-            digest_interval: Duration::from_secs(DEFAULT_TELEGRAM_DIGEST_SECS),
-            digest_buffer: Vec::new(),
-            last_digest: None,
+            limiter: AlertRateLimiter::new(TELEGRAM_RATE_LIMIT_SECS),
+            digest: Digest::new(DEFAULT_TELEGRAM_DIGEST_SECS, TELEGRAM_DIGEST_MAX_ALERTS),
         })
     }
 
@@ -98,6 +99,18 @@ impl TelegramNotifier {
         &self.chat_id
     }
 
+    /// Get the Bot API base URL.
+    pub fn api_base(&self) -> &str {
+        &self.api_base
+    }
+
+    /// Point the notifier at another Bot API server (e.g. a local mock).
+    #[cfg(test)]
+    pub fn with_api_base(mut self, api_base: &str) -> Self {
+        self.api_base = api_base.trim_end_matches('/').to_string();
+        self
+    }
+
     /// Check whether an alert passes the severity filter and rate limit.
     fn should_send(&self, alert: &Alert) -> bool {
         self.should_send_for_app(alert, &alert.process_name)
@@ -110,18 +123,14 @@ impl TelegramNotifier {
             return false;
         }
         // Rate limit per (category, app_name)
-        let key = (alert.category, app_name.to_string());
-        match self.last_sent.get(&key) {
-            Some(last) if last.elapsed() < self.rate_limit => false,
-            _ => true,
-        }
+        self.limiter.ready(alert.category, app_name)
     }
 
     /// Set the digest interval. If > 0, alerts are batched and flushed
     /// periodically as a single summary message (#8).
     #[allow(dead_code)]
     pub fn set_digest_interval(&mut self, secs: u64) {
-        self.digest_interval = Duration::from_secs(secs);
+        self.digest.set_interval(secs);
     }
 
     /// Send an alert via Telegram if it passes filters.
@@ -147,21 +156,16 @@ impl TelegramNotifier {
         }
 
         // Mark sent before spawning (prevents duplicates during async flight)
-        self.last_sent
-            .insert((alert.category, alert.process_name.clone()), Instant::now());
+        self.limiter.mark_sent(alert.category, &alert.process_name);
 
         let text = match context {
             Some(ctx) => format_alert_with_context(alert, hostname, ctx),
             None => format_alert(alert, hostname),
         };
 
-        if self.digest_interval.as_secs() > 0 {
+        if self.digest.is_enabled() {
             // Digest mode: buffer the alert (#8)
-            self.digest_buffer.push((alert.clone(), text));
-            if self.digest_buffer.len() > TELEGRAM_DIGEST_MAX_ALERTS {
-                self.digest_buffer
-                    .drain(..self.digest_buffer.len() - TELEGRAM_DIGEST_MAX_ALERTS);
-            }
+            self.digest.push((alert.clone(), text));
         } else {
             // Immediate mode: send now
             self.spawn_send(text);
@@ -197,17 +201,15 @@ impl TelegramNotifier {
                 timestamp: chrono::Local::now(),
                 value: group.total_value,
                 threshold: group.threshold,
+                suppressed: false,
             };
 
             if !self.should_send_for_app(&representative, &group.app_name) {
                 continue;
             }
 
-            // Mark sent for this (category, app_name) pair
-            self.last_sent.insert(
-                (group.category, group.app_name.clone()),
-                Instant::now(),
-            );
+            // Mark sent for this (category, app_name) pair and its workers
+            self.limiter.mark_group_sent(group);
 
             let text = if group.worker_count > 1 {
                 let mut msg = format_grouped_alert(group, hostname);
@@ -222,61 +224,119 @@ impl TelegramNotifier {
                 }
             };
 
-            if self.digest_interval.as_secs() > 0 {
-                self.digest_buffer.push((representative, text));
-                if self.digest_buffer.len() > TELEGRAM_DIGEST_MAX_ALERTS {
-                    self.digest_buffer
-                        .drain(..self.digest_buffer.len() - TELEGRAM_DIGEST_MAX_ALERTS);
-                }
+            if self.digest.is_enabled() {
+                self.digest.push((representative, text));
             } else {
                 self.spawn_send(text);
             }
         }
     }
 
+    /// Send "RESOLVED" messages for alerts whose condition cleared.
+    ///
+    /// Severity-filtered like the original alert but not rate-limited (each
+    /// alert resolves once). Clears the rate limit for the resolved key so a
+    /// fresh incident is notified right away.
+    pub fn send_resolved(&mut self, resolved: &[ActiveAlert], hostname: &str) {
+        for active in resolved {
+            if active.alert.severity < self.min_severity {
+                continue;
+            }
+            self.limiter.clear(active.key.category, &active.key.subject);
+
+            let text = format_resolved(active, hostname);
+            if self.digest.is_enabled() {
+                self.digest.push((active.alert.clone(), text));
+            } else {
+                self.spawn_send(text);
+            }
+        }
+    }
+
+    /// Send an escalation step to `chat_ids` (empty = the configured chat).
+    /// Bypasses the severity filter, rate limit and digest.
+    pub fn send_escalation(&self, escalation: &Escalation, chat_ids: &[String], hostname: &str) {
+        let text = format_escalation(escalation, hostname);
+        let default = std::slice::from_ref(&self.chat_id);
+        let targets = if chat_ids.is_empty() { default } else { chat_ids };
+        for chat_id in targets {
+            self.spawn_api_call(
+                "sendMessage",
+                serde_json::json!({
+                    "chat_id": chat_id,
+                    "text": text,
+                    "parse_mode": "HTML",
+                }),
+            );
+        }
+    }
+
     /// Tick the digest timer. Call this periodically from the event loop.
     /// If the digest interval has elapsed and there are buffered alerts,
     /// sends a summary message (#8).
-    #[allow(dead_code)] // called from app.rs when digest mode is enabled
     pub fn tick_digest(&mut self, hostname: &str) {
-        if self.digest_interval.as_secs() == 0 || self.digest_buffer.is_empty() {
-            return;
+        if let Some(alerts) = self.digest.take_due() {
+            self.spawn_send(format_digest(&alerts, hostname));
         }
+    }
 
-        let should_flush = match self.last_digest {
-            Some(last) => last.elapsed() >= self.digest_interval,
-            None => true, // First flush
-        };
+    /// Spawn a background task to send a message (non-blocking).
+    fn spawn_send(&self, text: String) {
+        self.spawn_api_call(
+            "sendMessage",
+            serde_json::json!({
+                "chat_id": self.chat_id,
+                "text": text,
+                "parse_mode": "HTML",
+            }),
+        );
+    }
 
-        if !should_flush {
-            return;
+    /// Reply to a bot command in the configured chat, optionally with an
+    /// inline keyboard (`reply_markup`).
+    pub fn reply(&self, text: String, keyboard: Option<serde_json::Value>) {
+        let mut body = serde_json::json!({
+            "chat_id": self.chat_id,
+            "text": text,
+            "parse_mode": "HTML",
+        });
+        if let Some(keyboard) = keyboard {
+            body["reply_markup"] = keyboard;
         }
+        self.spawn_api_call("sendMessage", body);
+    }
 
-        self.last_digest = Some(Instant::now());
-        let alerts: Vec<(Alert, String)> = self.digest_buffer.drain(..).collect();
-        let text = format_digest(&alerts, hostname);
-        self.spawn_send(text);
+    /// Replace the text of an earlier message (dropping its inline keyboard).
+    pub fn edit_message(&self, message_id: i64, text: String) {
+        self.spawn_api_call(
+            "editMessageText",
+            serde_json::json!({
+                "chat_id": self.chat_id,
+                "message_id": message_id,
+                "text": text,
+                "parse_mode": "HTML",
+            }),
+        );
     }
 
-    /// Spawn a background task to send a message (non-blocking).
-    fn spawn_send(&self, text: String) {
-        let url = format!(
-            "{}/bot{}/sendMessage",
-            TELEGRAM_API_BASE, self.bot_token
+    /// Acknowledge an inline button press (shows a short toast).
+    pub fn answer_callback(&self, callback_id: &str, text: &str) {
+        self.spawn_api_call(
+            "answerCallbackQuery",
+            serde_json::json!({
+                "callback_query_id": callback_id,
+                "text": text,
+            }),
         );
-        let chat_id = self.chat_id.clone();
+    }
+
+    /// POST a Bot API method in the background (non-blocking).
+    fn spawn_api_call(&self, method: &str, body: serde_json::Value) {
+        let url = format!("{}/bot{}/{}", self.api_base, self.bot_token, method);
         let client = self.client.clone();
 
         tokio::spawn(async move {
-            let _ = client
-                .post(&url)
-                .json(&serde_json::json!({
-                    "chat_id": chat_id,
-                    "text": text,
-                    "parse_mode": "HTML",
-                }))
-                .send()
-                .await;
+            let _ = client.post(&url).json(&body).send().await;
         });
     }
 
@@ -300,10 +360,7 @@ impl TelegramNotifier {
 
     /// Low-level POST to Telegram's sendMessage endpoint.
     async fn send_message(&self, text: &str) -> Result<(), String> {
-        let url = format!(
-            "{}/bot{}/sendMessage",
-            TELEGRAM_API_BASE, self.bot_token
-        );
+        let url = format!("{}/bot{}/sendMessage", self.api_base, self.bot_token);
 
         let resp = self
             .client
@@ -341,7 +398,7 @@ pub fn parse_min_severity(s: &str) -> AlertSeverity {
 }
 
 /// System context snapshot for rich alert messages (#9).
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct AlertContext {
     /// Overall CPU usage percentage.
     pub cpu_pct: Option<f32>,
@@ -379,7 +436,7 @@ fn is_groupable_category(cat: AlertCategory) -> bool {
 ///
 /// Captures the individual metrics of each worker process so the grouped
 /// notification can show a per-PID breakdown rather than just aggregates.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct WorkerDetail {
     /// Process ID.
     pub pid: u32,
@@ -395,7 +452,7 @@ pub struct WorkerDetail {
 ///
 /// Instead of 8 messages for "node-V8Worker (PID 100)", "node-V8Worker (PID 101)", etc.,
 /// we send ONE message: "Node.js — 8 workers — Total: 3.9 GiB".
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct GroupedAlert {
     /// The resolved parent application name (e.g. "node", "firefox").
     pub app_name: String,
@@ -570,6 +627,8 @@ pub fn category_display_name(cat: AlertCategory) -> &'static str {
         AlertCategory::HighCpu => "High CPU Usage",
         AlertCategory::HighMemory => "High Memory Usage",
         AlertCategory::HighDiskIo => "High Disk I/O",
+        AlertCategory::GpuHog => "GPU Hog",
+        AlertCategory::NetworkHog => "Network Hog",
         AlertCategory::Zombie => "Zombie Processes",
         AlertCategory::Suspicious => "Suspicious Activity",
         AlertCategory::SystemOverload => "System Overload",
@@ -704,6 +763,16 @@ pub fn suggest_action(group: &GroupedAlert) -> String {
         (AlertCategory::HighDiskIo, _) => {
             "Review disk I/O patterns. Consider buffering writes or moving to faster storage.".to_string()
         }
+        // GPU
+        (AlertCategory::GpuHog, _) => {
+            "Check the process's batch size or model size, or move it to another GPU. \
+             `nvidia-smi` / `radeontop` / `intel_gpu_top` show per-engine load.".to_string()
+        }
+        // Network
+        (AlertCategory::NetworkHog, _) => {
+            "Check for large transfers, backups or sync jobs. `nethogs` or `ss -tip` show \
+             per-connection throughput; `tc` or the application's own limits can cap it.".to_string()
+        }
         // Zombie
         (AlertCategory::Zombie, _) => {
             "Zombie processes indicate the parent isn't calling wait(). \
@@ -869,7 +938,7 @@ fn severity_emoji(severity: AlertSeverity) -> &'static str {
 }
 
 /// Label for a severity level.
-fn severity_label(severity: AlertSeverity) -> &'static str {
+pub(crate) fn severity_label(severity: AlertSeverity) -> &'static str {
     match severity {
         AlertSeverity::Danger => "DANGER",
         AlertSeverity::Critical => "CRITICAL",
@@ -914,7 +983,7 @@ fn format_value_for_category(
 }
 
 /// Format a threshold value for display.
-fn format_threshold_for_category(category: AlertCategory, threshold: f64) -> String {
+pub(crate) fn format_threshold_for_category(category: AlertCategory, threshold: f64) -> String {
     match category {
         AlertCategory::HighMemory | AlertCategory::MemoryLeak => {
             format_bytes_f64(threshold)
@@ -926,7 +995,7 @@ fn format_threshold_for_category(category: AlertCategory, threshold: f64) -> Str
 }
 
 /// Format the average value for display in grouped alert summaries.
-fn format_avg_value(category: AlertCategory, avg: f64) -> String {
+pub(crate) fn format_avg_value(category: AlertCategory, avg: f64) -> String {
     match category {
         AlertCategory::HighMemory | AlertCategory::MemoryLeak => format_bytes_f64(avg),
         AlertCategory::HighCpu => format!("{:.1}%", avg),
@@ -936,7 +1005,7 @@ fn format_avg_value(category: AlertCategory, avg: f64) -> String {
 }
 
 /// Format a single worker's value for per-PID breakdown display.
-fn format_worker_value(category: AlertCategory, value: f64) -> String {
+pub(crate) fn format_worker_value(category: AlertCategory, value: f64) -> String {
     match category {
         AlertCategory::HighMemory | AlertCategory::MemoryLeak => format_bytes_f64(value),
         AlertCategory::HighCpu => format!("{:.1}%", value),
@@ -989,6 +1058,51 @@ pub fn format_alert(alert: &Alert, hostname: &str) -> String {
     )
 }
 
+/// Format a resolved alert as an HTML Telegram message.
+pub fn format_resolved(active: &ActiveAlert, hostname: &str) -> String {
+    let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
+
+    format!(
+        "\u{2705} <b>RESOLVED: {}</b>\n\n\
+         {}\n\n\
+         Process: <code>{}</code>\n\
+         Firing for: <code>{}</code>{}\n\
+         Host: <code>{}</code>\n\
+         Time: <code>{}</code>",
+        category_display_name(active.key.category),
+        active.alert.message,
+        active.key.subject,
+        active.duration_display(),
+        if active.acknowledged { " (acknowledged)" } else { "" },
+        hostname,
+        timestamp,
+    )
+}
+
+/// Format an escalation step as an HTML Telegram message.
+pub fn format_escalation(escalation: &Escalation, hostname: &str) -> String {
+    let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
+    let active = &escalation.alert;
+
+    format!(
+        "\u{1F4E2} <b>{}: {}</b>\n\n\
+         {}\n\n\
+         Severity: <b>{}</b>\n\
+         Process: <code>{}</code>\n\
+         Unacknowledged for: <code>{}</code>\n\
+         Host: <code>{}</code>\n\
+         Time: <code>{}</code>",
+        escalation.headline(),
+        category_display_name(active.key.category),
+        active.alert.message,
+        severity_label(active.alert.severity),
+        active.key.subject,
+        escalation.firing_display(),
+        hostname,
+        timestamp,
+    )
+}
+
 /// Format an alert with system context (#9).
 ///
 /// Appends a "System Status" section with CPU, memory, thermal, and security info.
@@ -1054,7 +1168,6 @@ fn append_context(text: &mut String, ctx: &AlertContext) {
 /// Format a digest summary of multiple alerts (#8).
 ///
 /// Groups alerts by severity and produces a single summary message.
-#[allow(dead_code)] // called by tick_digest
 pub fn format_digest(alerts: &[(Alert, String)], hostname: &str) -> String {
     let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
 
@@ -1120,6 +1233,7 @@ pub fn format_digest(alerts: &[(Alert, String)], hostname: &str) -> String {
 mod tests {
     use super::*;
     use chrono::Local;
+    use std::time::Instant;
 
     fn make_alert(severity: AlertSeverity, category: AlertCategory) -> Alert {
         Alert {
@@ -1131,6 +1245,7 @@ mod tests {
             timestamp: Local::now(),
             value: 92.3,
             threshold: 50.0,
+            suppressed: false,
         }
     }
 
@@ -1260,8 +1375,8 @@ mod tests {
 
         // Simulate a send (key is now (category, app_name))
         notifier
-            .last_sent
-            .insert((alert.category, alert.process_name.clone()), Instant::now());
+            .limiter
+            .mark_sent(alert.category, &alert.process_name);
 
         // Second should be rate-limited (same process_name)
         assert!(!notifier.should_send(&alert));
@@ -1289,6 +1404,21 @@ mod tests {
         assert!(text.contains("Test alert message"), "Should contain message");
     }
 
+    #[test]
+    fn format_resolved_contains_key_fields() {
+        let mut tracker = crate::alerts::AlertTracker::new();
+        let t0 = Instant::now();
+        tracker.update(&[make_alert(AlertSeverity::Critical, AlertCategory::HighCpu)], t0);
+        let resolved = tracker.update(&[], t0 + Duration::from_secs(60));
+        let text = format_resolved(&resolved[0], "my-machine");
+
+        assert!(text.contains("RESOLVED"));
+        assert!(text.contains("High CPU Usage"));
+        assert!(text.contains("test_proc"));
+        assert!(text.contains("Firing for"));
+        assert!(text.contains("my-machine"));
+    }
+
     #[test]
     fn format_alert_severity_emojis() {
         let warning = format_alert(
@@ -1486,7 +1616,7 @@ mod tests {
             ..NotificationConfig::default()
         };
         let mut notifier = TelegramNotifier::from_config(&config).unwrap();
-        notifier.digest_interval = Duration::from_secs(60); // Enable digest
+        notifier.digest.set_interval(60); // Enable digest
 
         // Buffer 30 alerts (cap is 20)
         for i in 0..30u32 {
@@ -1497,10 +1627,10 @@ mod tests {
         }
 
         assert!(
-            notifier.digest_buffer.len() <= TELEGRAM_DIGEST_MAX_ALERTS,
+            notifier.digest.len() <= TELEGRAM_DIGEST_MAX_ALERTS,
             "Buffer should be capped at {}, got {}",
             TELEGRAM_DIGEST_MAX_ALERTS,
-            notifier.digest_buffer.len(),
+            notifier.digest.len(),
         );
     }
 
@@ -1523,6 +1653,10 @@ mod tests {
             start_time: 0,
             parent_pid,
             thread_count: None,
+            gpu_usage: 0.0,
+            gpu_memory_bytes: 0,
+            net_rx_bytes_per_sec: 0,
+            net_tx_bytes_per_sec: 0,
         }
     }
 
@@ -1543,6 +1677,7 @@ mod tests {
             timestamp: Local::now(),
             value,
             threshold,
+            suppressed: false,
         }
     }
 
@@ -1831,7 +1966,7 @@ mod tests {
             ..NotificationConfig::default()
         };
         let mut notifier = TelegramNotifier::from_config(&config).unwrap();
-        notifier.digest_interval = Duration::from_secs(60); // buffer mode so we can inspect
+        notifier.digest.set_interval(60); // buffer mode so we can inspect
 
         let procs = vec![
             make_process(100, "node", None),
@@ -1845,11 +1980,41 @@ mod tests {
 
         // First call should produce a grouped message
         notifier.send_grouped_alerts(&alerts, &procs, "host", None);
-        assert_eq!(notifier.digest_buffer.len(), 1, "Should send 1 grouped message");
+        assert_eq!(notifier.digest.len(), 1, "Should send 1 grouped message");
 
         // Second call should be rate-limited
         notifier.send_grouped_alerts(&alerts, &procs, "host", None);
-        assert_eq!(notifier.digest_buffer.len(), 1, "Should still be 1 (rate-limited)");
+        assert_eq!(notifier.digest.len(), 1, "Should still be 1 (rate-limited)");
+    }
+
+    #[test]
+    fn resolved_worker_clears_its_parent_app_rate_limit() {
+        let config = NotificationConfig {
+            telegram_enabled: true,
+            telegram_bot_token: Some("token".into()),
+            telegram_chat_id: Some("chat".into()),
+            telegram_min_severity: "warning".into(),
+            ..NotificationConfig::default()
+        };
+        let mut notifier = TelegramNotifier::from_config(&config).unwrap();
+        notifier.digest.set_interval(60);
+
+        let procs = vec![
+            make_process(100, "node", None),
+            make_process(101, "node-V8Worker", Some(100)),
+        ];
+        let alert = make_alert_with_name(AlertSeverity::Warning, AlertCategory::HighCpu, "node-V8Worker", 101, 60.0, 50.0);
+        notifier.send_grouped_alerts(std::slice::from_ref(&alert), &procs, "host", None);
+        assert!(!notifier.limiter.ready(AlertCategory::HighCpu, "node"));
+
+        // The resolved alert is keyed by the worker, the limit by its parent
+        let mut tracker = crate::alerts::AlertTracker::new();
+        let t0 = Instant::now();
+        tracker.update(std::slice::from_ref(&alert), t0);
+        let resolved = tracker.update(&[], t0 + Duration::from_secs(60));
+        assert_eq!(resolved[0].key.subject, "node-V8Worker");
+        notifier.send_resolved(&resolved, "host");
+        assert!(notifier.limiter.ready(AlertCategory::HighCpu, "node"));
     }
 
     // ── avg_percent calculation ───────────────────────────────────
@@ -1899,7 +2064,8 @@ mod tests {
         // Ensure no category returns the old terse abbreviations
         let all_categories = [
             AlertCategory::HighCpu, AlertCategory::HighMemory,
-            AlertCategory::HighDiskIo, AlertCategory::Zombie,
+            AlertCategory::HighDiskIo, AlertCategory::GpuHog, AlertCategory::NetworkHog,
+            AlertCategory::Zombie,
             AlertCategory::Suspicious, AlertCategory::SystemOverload,
             AlertCategory::MemoryLeak, AlertCategory::SecurityThreat,
             AlertCategory::SecurityScore, AlertCategory::ThermalWarning,
@@ -0,0 +1,578 @@
+//! Two-way Telegram bot: query and act on the host from chat.
+//!
+//! [`BotListener`] long-polls the Bot API `getUpdates` method in a background
+//! task and forwards commands from the configured chat ID only; messages
+//! from any other chat are dropped. The app answers them from live state and
+//! the same `DiagnosticEngine` reports the command palette shows.
+//!
+//! | Command         | Reply                                   |
+//! |-----------------|-----------------------------------------|
+//! | `/status`       | System summary + resource contention    |
+//! | `/top`          | Top processes by CPU                    |
+//! | `/alerts`       | Firing alerts                           |
+//! | `/timeline [m]` | Timeline report (default 30 minutes)    |
+//! | `/port <n>`     | Port diagnosis                          |
+//! | `/ack <target>` | Acknowledge alerts (`all`, PID or name) |
+//! | `/kill <pid>`   | Asks for confirmation first             |
+//!
+//! `/kill` never acts directly: it replies with an inline keyboard, and
+//! SIGTERM is only sent when "Kill" is pressed within
+//! [`TELEGRAM_KILL_CONFIRM_SECS`] and the PID still belongs to the same
+//! process (see [`KillConfirmations`]).
+
+use std::time::{Duration, Instant};
+
+use serde_json::{json, Value};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use crate::constants::{
+    TELEGRAM_COMMAND_MAX_AGE_SECS, TELEGRAM_KILL_CONFIRM_SECS, TELEGRAM_POLL_RETRY_SECS,
+    TELEGRAM_POLL_TIMEOUT_SECS, TELEGRAM_REPLY_MAX_CHARS,
+};
+
+/// A command sent to the bot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BotCommand {
+    Status,
+    Top,
+    Alerts,
+    /// Look-back window in minutes.
+    Timeline(u64),
+    Port(u16),
+    /// `all`, a PID or a process name (as for the `ack` palette command).
+    Ack(String),
+    Kill(u32),
+    Help,
+    /// Known command with missing/invalid arguments; holds the usage line.
+    Usage(&'static str),
+    Unknown(String),
+}
+
+/// An inline keyboard button press.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CallbackAction {
+    ConfirmKill { pid: u32, nonce: u64 },
+    Cancel,
+}
+
+/// Something the app has to answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BotUpdate {
+    Command(BotCommand),
+    Callback {
+        /// `callback_query.id`, needed to answer the button press.
+        id: String,
+        /// The message carrying the keyboard (edited with the outcome).
+        message_id: i64,
+        action: CallbackAction,
+    },
+}
+
+/// Parse a chat message into a command. Returns `None` for plain text.
+///
+/// Accepts the `/cmd@BotName` form Telegram uses in group chats.
+pub fn parse_command(text: &str) -> Option<BotCommand> {
+    let mut parts = text.split_whitespace();
+    let first = parts.next()?.strip_prefix('/')?;
+    let name = first.split('@').next().unwrap_or(first).to_lowercase();
+    let arg = parts.next();
+
+    let command = match name.as_str() {
+        "status" => BotCommand::Status,
+        "top" => BotCommand::Top,
+        "alerts" => BotCommand::Alerts,
+        "timeline" => match arg {
+            None => BotCommand::Timeline(30),
+            Some(m) => match m.parse() {
+                Ok(minutes) => BotCommand::Timeline(minutes),
+                Err(_) => BotCommand::Usage("/timeline [minutes]"),
+            },
+        },
+        "port" => match arg.and_then(|p| p.parse().ok()) {
+            Some(port) => BotCommand::Port(port),
+            None => BotCommand::Usage("/port <number>"),
+        },
+        "ack" => match arg {
+            Some(target) => BotCommand::Ack(target.to_string()),
+            None => BotCommand::Usage("/ack <all|pid|name>"),
+        },
+        "kill" => match arg.and_then(|p| p.parse().ok()) {
+            Some(pid) => BotCommand::Kill(pid),
+            None => BotCommand::Usage("/kill <pid>"),
+        },
+        "help" | "start" => BotCommand::Help,
+        other => BotCommand::Unknown(other.to_string()),
+    };
+    Some(command)
+}
+
+/// Parse `callback_data` written by [`kill_keyboard`].
+pub fn parse_callback(data: &str) -> Option<CallbackAction> {
+    if data == "cancel" {
+        return Some(CallbackAction::Cancel);
+    }
+    let mut parts = data.strip_prefix("kill:")?.split(':');
+    let pid = parts.next()?.parse().ok()?;
+    let nonce = parts.next()?.parse().ok()?;
+    Some(CallbackAction::ConfirmKill { pid, nonce })
+}
+
+/// Inline keyboard asking to confirm a kill.
+pub fn kill_keyboard(pid: u32, nonce: u64) -> Value {
+    json!({
+        "inline_keyboard": [[
+            { "text": format!("Kill {} (SIGTERM)", pid), "callback_data": format!("kill:{}:{}", pid, nonce) },
+            { "text": "Cancel", "callback_data": "cancel" },
+        ]]
+    })
+}
+
+/// Whether a `chat` object is the configured chat (numeric ID or `@username`).
+fn is_configured_chat(chat: &Value, chat_id: &str) -> bool {
+    let id_matches = chat["id"].as_i64().map(|id| id.to_string()).as_deref() == Some(chat_id);
+    let name_matches = chat["username"]
+        .as_str()
+        .is_some_and(|u| chat_id.strip_prefix('@') == Some(u));
+    id_matches || name_matches
+}
+
+/// Extract bot updates from a `getUpdates` response.
+///
+/// Returns the updates for the configured chat and the next `offset`
+/// (last `update_id` + 1), which confirms everything seen, including
+/// ignored updates. Messages dated before `not_before` (unix seconds) are
+/// dropped.
+pub fn parse_updates(
+    response: &Value,
+    chat_id: &str,
+    not_before: i64,
+) -> (Vec<BotUpdate>, Option<i64>) {
+    let mut updates = Vec::new();
+    let mut next_offset = None;
+
+    for update in response["result"].as_array().into_iter().flatten() {
+        if let Some(id) = update["update_id"].as_i64() {
+            next_offset = Some(next_offset.map_or(id + 1, |o: i64| o.max(id + 1)));
+        }
+
+        if let Some(message) = update.get("message") {
+            if !is_configured_chat(&message["chat"], chat_id)
+                || message["date"].as_i64().unwrap_or(0) < not_before
+            {
+                continue;
+            }
+            if let Some(command) = message["text"].as_str().and_then(parse_command) {
+                updates.push(BotUpdate::Command(command));
+            }
+        } else if let Some(query) = update.get("callback_query") {
+            let message = &query["message"];
+            if !is_configured_chat(&message["chat"], chat_id) {
+                continue;
+            }
+            let (Some(id), Some(message_id), Some(action)) = (
+                query["id"].as_str(),
+                message["message_id"].as_i64(),
+                query["data"].as_str().and_then(parse_callback),
+            ) else {
+                continue;
+            };
+            updates.push(BotUpdate::Callback {
+                id: id.to_string(),
+                message_id,
+                action,
+            });
+        }
+    }
+    (updates, next_offset)
+}
+
+/// Escape text for Telegram's HTML parse mode.
+pub fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Render a plain-text report as a `<pre>` block, truncated to fit a message.
+pub fn pre(text: &str) -> String {
+    let truncated: String = if text.chars().count() > TELEGRAM_REPLY_MAX_CHARS {
+        let mut t: String = text.chars().take(TELEGRAM_REPLY_MAX_CHARS).collect();
+        t.push_str("\n... (truncated)");
+        t
+    } else {
+        text.to_string()
+    };
+    format!("<pre>{}</pre>", escape_html(&truncated))
+}
+
+// ── Kill confirmations ──────────────────────────────────────────
+
+/// A `/kill` waiting for its button press.
+#[derive(Debug, Clone)]
+struct PendingKill {
+    pid: u32,
+    name: String,
+    nonce: u64,
+    requested: Instant,
+}
+
+/// Outstanding `/kill` confirmations.
+///
+/// Each request gets a fresh nonce embedded in the button, so stale or
+/// replayed presses (after expiry, or for an earlier request) do nothing.
+pub struct KillConfirmations {
+    pending: Vec<PendingKill>,
+    ttl: Duration,
+    next_nonce: u64,
+}
+
+impl KillConfirmations {
+    pub fn new() -> Self {
+        // Seed from the clock so buttons from a previous run never match
+        let seed = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(1);
+        Self {
+            pending: Vec::new(),
+            ttl: Duration::from_secs(TELEGRAM_KILL_CONFIRM_SECS),
+            next_nonce: seed,
+        }
+    }
+
+    /// Record a kill request and return the nonce for its button.
+    pub fn request(&mut self, pid: u32, name: &str) -> u64 {
+        self.pending.retain(|p| p.requested.elapsed() < self.ttl);
+        self.next_nonce = self.next_nonce.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        self.pending.push(PendingKill {
+            pid,
+            name: name.to_string(),
+            nonce: self.next_nonce,
+            requested: Instant::now(),
+        });
+        self.next_nonce
+    }
+
+    /// Consume a confirmation. Returns the process name recorded at request
+    /// time if the press matches an unexpired request.
+    pub fn confirm(&mut self, pid: u32, nonce: u64) -> Option<String> {
+        let idx = self
+            .pending
+            .iter()
+            .position(|p| p.pid == pid && p.nonce == nonce)?;
+        let pending = self.pending.remove(idx);
+        (pending.requested.elapsed() < self.ttl).then_some(pending.name)
+    }
+}
+
+impl Default for KillConfirmations {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ── Listener ────────────────────────────────────────────────────
+
+/// Background `getUpdates` long-poll loop. Stops when dropped.
+pub struct BotListener {
+    rx: mpsc::UnboundedReceiver<BotUpdate>,
+    handle: JoinHandle<()>,
+}
+
+impl BotListener {
+    /// Start polling `{api_base}/bot{token}/getUpdates` for `chat_id`.
+    pub fn spawn(api_base: &str, token: &str, chat_id: &str) -> Self {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let url = format!("{}/bot{}/getUpdates", api_base, token);
+        let chat_id = chat_id.to_string();
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(TELEGRAM_POLL_TIMEOUT_SECS + 10))
+            .build()
+            .unwrap_or_default();
+        let not_before = chrono::Utc::now().timestamp() - TELEGRAM_COMMAND_MAX_AGE_SECS;
+
+        let handle = tokio::spawn(async move {
+            let mut offset: Option<i64> = None;
+            loop {
+                let response = client
+                    .post(&url)
+                    .json(&json!({
+                        "offset": offset,
+                        "timeout": TELEGRAM_POLL_TIMEOUT_SECS,
+                        "allowed_updates": ["message", "callback_query"],
+                    }))
+                    .send()
+                    .await;
+                let body = match response {
+                    Ok(resp) if resp.status().is_success() => resp.json::<Value>().await.ok(),
+                    _ => None,
+                };
+                let Some(body) = body else {
+                    tokio::time::sleep(Duration::from_secs(TELEGRAM_POLL_RETRY_SECS)).await;
+                    continue;
+                };
+
+                let (updates, next) = parse_updates(&body, &chat_id, not_before);
+                if next.is_some() {
+                    offset = next;
+                }
+                for update in updates {
+                    if tx.send(update).is_err() {
+                        return; // App side is gone
+                    }
+                }
+            }
+        });
+
+        Self { rx, handle }
+    }
+
+    /// Next pending update, if any (non-blocking).
+    pub fn try_recv(&mut self) -> Option<BotUpdate> {
+        self.rx.try_recv().ok()
+    }
+}
+
+impl Drop for BotListener {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::NotificationConfig;
+    use crate::notifications::telegram::TelegramNotifier;
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    fn message(update_id: i64, chat_id: i64, text: &str) -> Value {
+        json!({
+            "update_id": update_id,
+            "message": {
+                "message_id": update_id * 10,
+                "date": chrono::Utc::now().timestamp(),
+                "chat": { "id": chat_id },
+                "text": text,
+            }
+        })
+    }
+
+    #[test]
+    fn parses_commands_and_arguments() {
+        assert_eq!(parse_command("/status"), Some(BotCommand::Status));
+        assert_eq!(parse_command("/top@SentinelBot"), Some(BotCommand::Top));
+        assert_eq!(parse_command("/timeline"), Some(BotCommand::Timeline(30)));
+        assert_eq!(
+            parse_command("/timeline 90"),
+            Some(BotCommand::Timeline(90))
+        );
+        assert_eq!(parse_command("/port 8080"), Some(BotCommand::Port(8080)));
+        assert_eq!(
+            parse_command("/port http"),
+            Some(BotCommand::Usage("/port <number>"))
+        );
+        assert_eq!(
+            parse_command("/ack node"),
+            Some(BotCommand::Ack("node".into()))
+        );
+        assert_eq!(parse_command("/kill 4242"), Some(BotCommand::Kill(4242)));
+        assert_eq!(
+            parse_command("/reboot"),
+            Some(BotCommand::Unknown("reboot".into()))
+        );
+        assert_eq!(parse_command("hello"), None);
+    }
+
+    #[test]
+    fn callback_data_roundtrips() {
+        let keyboard = kill_keyboard(77, 12345);
+        let data = keyboard["inline_keyboard"][0][0]["callback_data"]
+            .as_str()
+            .unwrap();
+        assert_eq!(
+            parse_callback(data),
+            Some(CallbackAction::ConfirmKill {
+                pid: 77,
+                nonce: 12345
+            })
+        );
+        assert_eq!(parse_callback("cancel"), Some(CallbackAction::Cancel));
+        assert_eq!(parse_callback("kill:abc:1"), None);
+    }
+
+    #[test]
+    fn only_configured_chat_is_answered() {
+        let response = json!({
+            "ok": true,
+            "result": [
+                message(5, 111, "/status"),
+                message(6, 999, "/kill 1"),
+                {
+                    "update_id": 7,
+                    "callback_query": {
+                        "id": "cb1",
+                        "data": "kill:42:9",
+                        "message": { "message_id": 70, "chat": { "id": 111 } },
+                    }
+                },
+                {
+                    "update_id": 8,
+                    "callback_query": {
+                        "id": "cb2",
+                        "data": "kill:42:9",
+                        "message": { "message_id": 80, "chat": { "id": 999 } },
+                    }
+                },
+            ]
+        });
+        let (updates, offset) = parse_updates(&response, "111", 0);
+        assert_eq!(offset, Some(9));
+        assert_eq!(
+            updates,
+            vec![
+                BotUpdate::Command(BotCommand::Status),
+                BotUpdate::Callback {
+                    id: "cb1".into(),
+                    message_id: 70,
+                    action: CallbackAction::ConfirmKill { pid: 42, nonce: 9 },
+                },
+            ]
+        );
+
+        // Stale messages are skipped, but still confirmed via the offset
+        let (updates, offset) =
+            parse_updates(&response, "111", chrono::Utc::now().timestamp() + 60);
+        assert_eq!(updates.len(), 1);
+        assert_eq!(offset, Some(9));
+    }
+
+    #[test]
+    fn kill_confirmation_needs_matching_fresh_nonce() {
+        let mut confirmations = KillConfirmations::new();
+        let nonce = confirmations.request(42, "stress");
+        assert_eq!(confirmations.confirm(42, nonce + 1), None);
+        assert_eq!(confirmations.confirm(43, nonce), None);
+        assert_eq!(confirmations.confirm(42, nonce), Some("stress".into()));
+        // Single use
+        assert_eq!(confirmations.confirm(42, nonce), None);
+
+        confirmations.ttl = Duration::ZERO;
+        let nonce = confirmations.request(42, "stress");
+        assert_eq!(confirmations.confirm(42, nonce), None);
+    }
+
+    #[test]
+    fn pre_escapes_and_truncates() {
+        assert_eq!(pre("a < b & c"), "<pre>a &lt; b &amp; c</pre>");
+        let long = "x".repeat(TELEGRAM_REPLY_MAX_CHARS + 50);
+        assert!(pre(&long).contains("... (truncated)"));
+    }
+
+    /// Serve canned JSON bodies (one per connection), recording request lines
+    /// and bodies.
+    async fn mock_bot_api(responses: Vec<Value>) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let log = seen.clone();
+
+        tokio::spawn(async move {
+            for response in responses {
+                let (mut sock, _) = listener.accept().await.unwrap();
+                let mut buf = Vec::new();
+                let mut chunk = [0u8; 4096];
+                let request = loop {
+                    let n = sock.read(&mut chunk).await.unwrap();
+                    buf.extend_from_slice(&chunk[..n]);
+                    let text = String::from_utf8_lossy(&buf).to_string();
+                    if let Some(pos) = text.find("\r\n\r\n") {
+                        let len: usize = text[..pos]
+                            .to_lowercase()
+                            .lines()
+                            .find_map(|l| l.strip_prefix("content-length:").map(str::to_string))
+                            .map(|v| v.trim().parse().unwrap())
+                            .unwrap_or(0);
+                        if buf.len() >= pos + 4 + len {
+                            let line = text.lines().next().unwrap_or_default().to_string();
+                            break format!("{} {}", line, &text[pos + 4..pos + 4 + len]);
+                        }
+                    }
+                };
+                log.lock().unwrap().push(request);
+                let body = response.to_string();
+                let resp = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                sock.write_all(resp.as_bytes()).await.unwrap();
+            }
+        });
+        (base, seen)
+    }
+
+    #[tokio::test]
+    async fn listener_polls_mock_api_and_confirms_offset() {
+        let (base, seen) = mock_bot_api(vec![
+            json!({ "ok": true, "result": [message(41, 111, "/port 8080"), message(42, 5, "/top")] }),
+            json!({ "ok": true, "result": [message(43, 111, "/alerts")] }),
+        ])
+        .await;
+
+        let mut listener = BotListener::spawn(&base, "T0KEN", "111");
+        let mut received = Vec::new();
+        for _ in 0..200 {
+            while let Some(update) = listener.try_recv() {
+                received.push(update);
+            }
+            if received.len() == 2 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(
+            received,
+            vec![
+                BotUpdate::Command(BotCommand::Port(8080)),
+                BotUpdate::Command(BotCommand::Alerts),
+            ]
+        );
+
+        let seen = seen.lock().unwrap();
+        assert!(seen[0].starts_with("POST /botT0KEN/getUpdates"));
+        let second: Value =
+            serde_json::from_str(seen[1].split_once(" HTTP/1.1 ").unwrap().1).unwrap();
+        assert_eq!(second["offset"], 43);
+    }
+
+    #[tokio::test]
+    async fn kill_reply_carries_confirmation_keyboard() {
+        let (base, seen) = mock_bot_api(vec![json!({ "ok": true, "result": {} })]).await;
+        let config = NotificationConfig {
+            telegram_enabled: true,
+            telegram_bot_token: Some("T0KEN".into()),
+            telegram_chat_id: Some("111".into()),
+            ..NotificationConfig::default()
+        };
+        let tg = TelegramNotifier::from_config(&config)
+            .unwrap()
+            .with_api_base(&base);
+
+        tg.reply("Kill?".into(), Some(kill_keyboard(42, 7)));
+        for _ in 0..200 {
+            if !seen.lock().unwrap().is_empty() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        let seen = seen.lock().unwrap();
+        assert!(seen[0].starts_with("POST /botT0KEN/sendMessage"));
+        assert!(seen[0].contains("\"callback_data\":\"kill:42:7\""));
+        assert!(seen[0].contains("\"chat_id\":\"111\""));
+    }
+}
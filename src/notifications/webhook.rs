@@ -0,0 +1,477 @@
+//! Generic webhook notifications.
+//!
+//! POSTs a JSON payload for each alert (or group of worker alerts) to a
+//! configured URL:
+//!
+//! ```json
+//! {
+//!   "event": "alert",
+//!   "hostname": "build-01",
+//!   "timestamp": "2026-03-02T10:15:00+01:00",
+//!   "alert": { "severity": "Critical", "category": "HighCpu", ... },
+//!   "group": { "app_name": "node", "worker_count": 8, "worker_details": [...] },
+//!   "context": { "cpu_pct": 91.0, "mem_pct": 64.2, ... }
+//! }
+//! ```
+//!
+//! `event` is `alert`, `resolved`, `escalation` or `test`. With a `secret`, the raw body is
+//! signed with HMAC-SHA256 in `X-Sentinel-Signature-256: sha256=<hex>`.
+//!
+//! Severity-filtered and rate-limited per (category, app) like Telegram.
+//! Failed deliveries (network errors, 5xx, 429) are retried with exponential
+//! backoff in a background task.
+
+use std::time::Duration;
+
+use hmac::{Hmac, Mac};
+use reqwest::header::{HeaderName, HeaderValue, CONTENT_TYPE, USER_AGENT};
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use sha2::Sha256;
+
+use crate::alerts::ActiveAlert;
+use crate::config::{NotificationConfig, WebhookConfig};
+use crate::constants::{
+    WEBHOOK_MAX_ATTEMPTS, WEBHOOK_RATE_LIMIT_SECS, WEBHOOK_RETRY_BASE_MS, WEBHOOK_SIGNATURE_HEADER,
+    WEBHOOK_TIMEOUT_SECS,
+};
+use crate::models::{Alert, AlertCategory, AlertSeverity, ProcessInfo};
+
+use super::escalation::Escalation;
+use super::rate_limit::AlertRateLimiter;
+use super::telegram::{group_alerts_by_app, parse_min_severity, AlertContext, GroupedAlert};
+
+/// Where and how to deliver payloads. Cloned into each background send.
+///
+/// Also used by the Slack/Mattermost/Discord notifiers in `chat`.
+#[derive(Clone)]
+pub(super) struct Endpoint {
+    url: String,
+    headers: Vec<(HeaderName, HeaderValue)>,
+    secret: Option<String>,
+    client: reqwest::Client,
+    pub(super) retry_base: Duration,
+}
+
+impl Endpoint {
+    /// Validate the URL and build an endpoint with the default timeout and
+    /// retry policy.
+    pub(super) fn new(
+        url: &str,
+        headers: Vec<(HeaderName, HeaderValue)>,
+        secret: Option<String>,
+    ) -> Result<Self, String> {
+        let url = url.trim();
+        if !(url.starts_with("http://") || url.starts_with("https://")) {
+            return Err(format!(
+                "webhook URL must start with http:// or https://: '{}'",
+                url
+            ));
+        }
+        Ok(Self {
+            url: url.to_string(),
+            headers,
+            secret,
+            client: reqwest::Client::builder()
+                .timeout(Duration::from_secs(WEBHOOK_TIMEOUT_SECS))
+                .build()
+                .unwrap_or_default(),
+            retry_base: Duration::from_millis(WEBHOOK_RETRY_BASE_MS),
+        })
+    }
+
+    /// POST `body`, retrying transient failures. Returns the attempt count
+    /// on success.
+    pub(super) async fn deliver(&self, body: String) -> Result<u32, String> {
+        let mut delay = self.retry_base;
+        let mut last_error = String::new();
+
+        for attempt in 1..=WEBHOOK_MAX_ATTEMPTS {
+            let mut request = self
+                .client
+                .post(&self.url)
+                .header(CONTENT_TYPE, "application/json")
+                .header(USER_AGENT, concat!("sentinel/", env!("CARGO_PKG_VERSION")));
+            for (name, value) in &self.headers {
+                request = request.header(name, value);
+            }
+            if let Some(ref secret) = self.secret {
+                request = request.header(WEBHOOK_SIGNATURE_HEADER, sign(secret, &body));
+            }
+
+            match request.body(body.clone()).send().await {
+                Ok(resp) if resp.status().is_success() => return Ok(attempt),
+                Ok(resp) => {
+                    let status = resp.status();
+                    last_error = format!("webhook returned HTTP {}", status);
+                    // Client errors won't fix themselves
+                    if !status.is_server_error() && status != StatusCode::TOO_MANY_REQUESTS {
+                        return Err(last_error);
+                    }
+                }
+                Err(e) => last_error = format!("webhook request failed: {}", e),
+            }
+
+            if attempt < WEBHOOK_MAX_ATTEMPTS {
+                tokio::time::sleep(delay).await;
+                delay *= 2;
+            }
+        }
+        Err(format!(
+            "{} (gave up after {} attempts)",
+            last_error, WEBHOOK_MAX_ATTEMPTS
+        ))
+    }
+}
+
+/// Webhook notifier for one endpoint, with severity filtering and rate limiting.
+pub struct WebhookNotifier {
+    name: String,
+    endpoint: Endpoint,
+    min_severity: AlertSeverity,
+    limiter: AlertRateLimiter,
+}
+
+impl WebhookNotifier {
+    /// Build a notifier from one `[[notifications.webhooks]]` entry.
+    pub fn from_config(cfg: &WebhookConfig) -> Result<Self, String> {
+        let headers = cfg
+            .headers
+            .iter()
+            .map(|(k, v)| {
+                let name = HeaderName::from_bytes(k.as_bytes())
+                    .map_err(|_| format!("invalid webhook header name '{}'", k))?;
+                let value = HeaderValue::from_str(v)
+                    .map_err(|_| format!("invalid value for webhook header '{}'", k))?;
+                Ok((name, value))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        let endpoint = Endpoint::new(
+            &cfg.url,
+            headers,
+            cfg.secret.clone().filter(|s| !s.is_empty()),
+        )?;
+
+        Ok(Self {
+            name: cfg.name.clone().unwrap_or_else(|| endpoint.url.clone()),
+            endpoint,
+            min_severity: parse_min_severity(&cfg.min_severity),
+            limiter: AlertRateLimiter::new(WEBHOOK_RATE_LIMIT_SECS),
+        })
+    }
+
+    /// Notifiers for every valid configured webhook (`Config::load` already
+    /// warned about invalid ones).
+    pub fn all_from_config(config: &NotificationConfig) -> Vec<Self> {
+        config
+            .webhooks
+            .iter()
+            .filter_map(|w| Self::from_config(w).ok())
+            .collect()
+    }
+
+    /// Display name (the configured `name`, or the URL).
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Check severity filter and rate limit for a (category, app) pair.
+    fn should_send_for_app(
+        &self,
+        severity: AlertSeverity,
+        category: AlertCategory,
+        app_name: &str,
+    ) -> bool {
+        severity >= self.min_severity && self.limiter.ready(category, app_name)
+    }
+
+    /// Send a single (ungrouped) alert if it passes the filters.
+    pub fn send_alert(&mut self, alert: &Alert, hostname: &str, context: Option<&AlertContext>) {
+        if !self.should_send_for_app(alert.severity, alert.category, &alert.process_name) {
+            return;
+        }
+        self.limiter.mark_sent(alert.category, &alert.process_name);
+        self.spawn_post(alert_payload(alert, None, hostname, context));
+    }
+
+    /// Send alerts grouped by parent application, one POST per group.
+    pub fn send_grouped_alerts(
+        &mut self,
+        alerts: &[Alert],
+        processes: &[ProcessInfo],
+        hostname: &str,
+        context: Option<&AlertContext>,
+    ) {
+        if alerts.is_empty() {
+            return;
+        }
+        for group in group_alerts_by_app(alerts, processes) {
+            if !self.should_send_for_app(group.severity, group.category, &group.app_name) {
+                continue;
+            }
+            self.limiter.mark_group_sent(&group);
+
+            // The group's most severe alert stands for it in the payload
+            let Some(alert) = alerts
+                .iter()
+                .filter(|a| a.category == group.category && group.pids.contains(&a.pid))
+                .max_by_key(|a| a.severity)
+            else {
+                continue;
+            };
+            self.spawn_post(alert_payload(alert, Some(&group), hostname, context));
+        }
+    }
+
+    /// Send `resolved` events. Severity-filtered, not rate-limited; clears
+    /// the rate limit so a fresh incident is posted right away.
+    pub fn send_resolved(&mut self, resolved: &[ActiveAlert], hostname: &str) {
+        for active in resolved {
+            if active.alert.severity < self.min_severity {
+                continue;
+            }
+            self.limiter.clear(active.key.category, &active.key.subject);
+            self.spawn_post(resolved_payload(active, hostname));
+        }
+    }
+
+    /// Send an `escalation` event. Bypasses the severity filter and rate limit.
+    pub fn send_escalation(&self, escalation: &Escalation, hostname: &str) {
+        self.spawn_post(escalation_payload(escalation, hostname));
+    }
+
+    /// Send a test payload now (bypasses filters). Returns the attempt count.
+    pub async fn send_test(&self, hostname: &str) -> Result<u32, String> {
+        let payload = json!({
+            "event": "test",
+            "hostname": hostname,
+            "timestamp": chrono::Local::now().to_rfc3339(),
+            "message": "Sentinel webhook notifications are working.",
+        });
+        self.endpoint.deliver(payload.to_string()).await
+    }
+
+    /// Deliver in the background so the main loop never waits on HTTP.
+    fn spawn_post(&self, payload: Value) {
+        let endpoint = self.endpoint.clone();
+        let body = payload.to_string();
+        tokio::spawn(async move {
+            let _ = endpoint.deliver(body).await;
+        });
+    }
+}
+
+/// JSON payload for an alert (optionally with its worker group).
+pub fn alert_payload(
+    alert: &Alert,
+    group: Option<&GroupedAlert>,
+    hostname: &str,
+    context: Option<&AlertContext>,
+) -> Value {
+    json!({
+        "event": "alert",
+        "hostname": hostname,
+        "timestamp": chrono::Local::now().to_rfc3339(),
+        "alert": alert,
+        "group": group,
+        "context": context,
+    })
+}
+
+/// JSON payload for a resolved alert.
+pub fn resolved_payload(active: &ActiveAlert, hostname: &str) -> Value {
+    json!({
+        "event": "resolved",
+        "hostname": hostname,
+        "timestamp": chrono::Local::now().to_rfc3339(),
+        "alert": active.alert,
+        "since": active.since.to_rfc3339(),
+        "duration": active.duration_display(),
+        "acknowledged": active.acknowledged,
+    })
+}
+
+/// JSON payload for an escalation step.
+pub fn escalation_payload(escalation: &Escalation, hostname: &str) -> Value {
+    json!({
+        "event": "escalation",
+        "hostname": hostname,
+        "timestamp": chrono::Local::now().to_rfc3339(),
+        "policy": escalation.policy,
+        "step": escalation.step,
+        "steps_total": escalation.steps_total,
+        "alert": escalation.alert.alert,
+        "since": escalation.alert.since.to_rfc3339(),
+        "firing_secs": escalation.firing_for.as_secs(),
+    })
+}
+
+/// `sha256=<hex>` HMAC-SHA256 signature of `body` with `secret`.
+pub fn sign(secret: &str, body: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(body.as_bytes());
+    let digest = mac.finalize().into_bytes();
+    let hex: String = digest.iter().map(|b| format!("{:02x}", b)).collect();
+    format!("sha256={}", hex)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    fn make_config(url: &str) -> WebhookConfig {
+        WebhookConfig {
+            name: Some("test".into()),
+            url: url.into(),
+            headers: BTreeMap::from([("X-Team".to_string(), "ops".to_string())]),
+            secret: Some("s3cret".into()),
+            min_severity: "warning".into(),
+        }
+    }
+
+    fn make_alert(severity: AlertSeverity, name: &str) -> Alert {
+        Alert::new(
+            severity,
+            AlertCategory::HighCpu,
+            name,
+            42,
+            format!("{} using 95.0% CPU", name),
+            95.0,
+            90.0,
+        )
+    }
+
+    /// A received request: lowercased header block and body.
+    type Received = Arc<Mutex<Vec<(String, String)>>>;
+
+    /// Serve one response per connection, in order, recording each request.
+    async fn serve(statuses: Vec<u16>) -> (String, Received) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let received: Received = Arc::new(Mutex::new(Vec::new()));
+        let log = received.clone();
+
+        tokio::spawn(async move {
+            for status in statuses {
+                let (mut sock, _) = listener.accept().await.unwrap();
+                let mut buf = Vec::new();
+                let mut chunk = [0u8; 4096];
+                // Read headers, then Content-Length bytes of body
+                let (head, body) = loop {
+                    let n = sock.read(&mut chunk).await.unwrap();
+                    buf.extend_from_slice(&chunk[..n]);
+                    let text = String::from_utf8_lossy(&buf).to_string();
+                    if let Some(pos) = text.find("\r\n\r\n") {
+                        let head = text[..pos].to_lowercase();
+                        let len: usize = head
+                            .lines()
+                            .find_map(|l| l.strip_prefix("content-length:"))
+                            .map(|v| v.trim().parse().unwrap())
+                            .unwrap_or(0);
+                        if buf.len() >= pos + 4 + len {
+                            break (head, text[pos + 4..pos + 4 + len].to_string());
+                        }
+                    }
+                };
+                log.lock().unwrap().push((head, body));
+                let resp = format!(
+                    "HTTP/1.1 {} X\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                    status
+                );
+                sock.write_all(resp.as_bytes()).await.unwrap();
+            }
+        });
+        (url, received)
+    }
+
+    fn fast(mut notifier: WebhookNotifier) -> WebhookNotifier {
+        notifier.endpoint.retry_base = Duration::from_millis(5);
+        notifier
+    }
+
+    #[test]
+    fn sign_matches_rfc4231_vector() {
+        assert_eq!(
+            sign("Jefe", "what do ya want for nothing?"),
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn rejects_bad_url_and_headers() {
+        assert!(WebhookNotifier::from_config(&make_config("ftp://example.com")).is_err());
+        let mut cfg = make_config("https://example.com");
+        cfg.headers.insert("Bad Header".into(), "x".into());
+        assert!(WebhookNotifier::from_config(&cfg).is_err());
+    }
+
+    #[test]
+    fn severity_filter_and_rate_limit() {
+        let mut wh = WebhookNotifier::from_config(&make_config("http://127.0.0.1:9")).unwrap();
+        assert!(!wh.should_send_for_app(AlertSeverity::Info, AlertCategory::HighCpu, "node"));
+        assert!(wh.should_send_for_app(AlertSeverity::Warning, AlertCategory::HighCpu, "node"));
+        wh.limiter.mark_sent(AlertCategory::HighCpu, "node");
+        assert!(!wh.should_send_for_app(AlertSeverity::Critical, AlertCategory::HighCpu, "node"));
+        assert!(wh.should_send_for_app(AlertSeverity::Critical, AlertCategory::HighMemory, "node"));
+    }
+
+    #[test]
+    fn payload_carries_alert_group_and_context() {
+        let alert = make_alert(AlertSeverity::Critical, "node");
+        let groups = group_alerts_by_app(std::slice::from_ref(&alert), &[]);
+        let ctx = AlertContext {
+            cpu_pct: Some(91.0),
+            ..AlertContext::default()
+        };
+        let v = alert_payload(&alert, groups.first(), "box", Some(&ctx));
+        assert_eq!(v["event"], "alert");
+        assert_eq!(v["hostname"], "box");
+        assert_eq!(v["alert"]["process_name"], "node");
+        assert_eq!(v["group"]["app_name"], "node");
+        assert_eq!(v["context"]["cpu_pct"], 91.0);
+    }
+
+    #[tokio::test]
+    async fn retries_server_errors_and_signs_body() {
+        let (url, received) = serve(vec![500, 503, 200]).await;
+        let wh = fast(WebhookNotifier::from_config(&make_config(&url)).unwrap());
+
+        let attempts = wh.send_test("box").await.unwrap();
+        assert_eq!(attempts, 3);
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 3);
+        let (head, body) = &received[2];
+        assert!(head.starts_with("post /hook"));
+        assert!(head.contains("x-team: ops"));
+        let signature = format!(
+            "{}: {}",
+            WEBHOOK_SIGNATURE_HEADER.to_lowercase(),
+            sign("s3cret", body)
+        );
+        assert!(
+            head.contains(&signature),
+            "missing {} in {}",
+            signature,
+            head
+        );
+        let payload: Value = serde_json::from_str(body).unwrap();
+        assert_eq!(payload["event"], "test");
+    }
+
+    #[tokio::test]
+    async fn client_errors_are_not_retried() {
+        let (url, received) = serve(vec![400, 200]).await;
+        let wh = fast(WebhookNotifier::from_config(&make_config(&url)).unwrap());
+
+        let err = wh.send_test("box").await.unwrap_err();
+        assert!(err.contains("400"), "{}", err);
+        assert_eq!(received.lock().unwrap().len(), 1);
+    }
+}
@@ -124,6 +124,14 @@ pub trait Plugin: Send {
     fn security_alerts(&mut self) -> Vec<crate::models::Alert> {
         Vec::new()
     }
+
+    /// Return every security condition that currently holds, ignoring
+    /// cooldowns. Used by the alert lifecycle to tell when an alert from
+    /// `security_alerts` has resolved; plugins that emit security alerts
+    /// should override both.
+    fn firing_alerts(&self) -> Vec<crate::models::Alert> {
+        Vec::new()
+    }
 }
 
 #[cfg(test)]
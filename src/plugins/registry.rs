@@ -96,6 +96,12 @@ impl PluginRegistry {
         }
         alerts
     }
+
+    /// Collect currently-holding security conditions from all plugins
+    /// (see `Plugin::firing_alerts`).
+    pub fn collect_firing_alerts(&self) -> Vec<crate::models::Alert> {
+        self.plugins.iter().flat_map(|p| p.firing_alerts()).collect()
+    }
 }
 
 #[cfg(test)]
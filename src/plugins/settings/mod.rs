@@ -299,6 +299,18 @@ impl SettingsPlugin {
                             masked: true,
                         },
                     },
+                    SettingItem {
+                        key: "thermal.source".into(),
+                        label: "Source".into(),
+                        value: config.thermal.source.clone(),
+                        description: "auto = Linux sysfs, else LHM (applies on restart)".into(),
+                        kind: SettingKind::Cycle(vec![
+                            "auto".into(),
+                            "sysfs".into(),
+                            "lm-sensors".into(),
+                            "lhm".into(),
+                        ]),
+                    },
                     SettingItem {
                         key: "thermal.poll_interval_secs".into(),
                         label: "Poll Interval".into(),
@@ -390,6 +402,20 @@ impl SettingsPlugin {
                             integer: true,
                         },
                     },
+                    SettingItem {
+                        key: "thermal.fan_control_enabled".into(),
+                        label: "Fan Control".into(),
+                        value: format!("{}", config.thermal.fan_control_enabled),
+                        description: "Drive PWM from fan curves (also needs SENTINEL_FAN_CONTROL)".into(),
+                        kind: SettingKind::Toggle,
+                    },
+                    SettingItem {
+                        key: "thermal.shutdown_dry_run".into(),
+                        label: "Shutdown Dry Run".into(),
+                        value: format!("{}", config.thermal.shutdown_dry_run),
+                        description: "Report shutdown actions instead of running them".into(),
+                        kind: SettingKind::Toggle,
+                    },
                 ],
             ),
             (
@@ -594,6 +620,14 @@ impl SettingsPlugin {
                                 .collect(),
                         ),
                     },
+                    SettingItem {
+                        key: "notifications.telegram_commands".into(),
+                        label: "Bot Commands".into(),
+                        value: format!("{}", config.notifications.telegram_commands),
+                        description: "Answer /status, /top, /kill... from the configured chat"
+                            .into(),
+                        kind: SettingKind::Toggle,
+                    },
                 ],
             ),
         ]
@@ -802,6 +836,10 @@ impl SettingsPlugin {
                 true
             }
             // ── Thermal ──────────────────────────────────────────
+            "thermal.source" => {
+                config.thermal.source = value.to_string();
+                true
+            }
             "thermal.lhm_url" => {
                 if !value.is_empty() {
                     config.thermal.lhm_url = value.to_string();
@@ -884,6 +922,14 @@ impl SettingsPlugin {
                     false
                 }
             }
+            "thermal.fan_control_enabled" => {
+                config.thermal.fan_control_enabled = value == "true";
+                true
+            }
+            "thermal.shutdown_dry_run" => {
+                config.thermal.shutdown_dry_run = value == "true";
+                true
+            }
             // ── Alerts ───────────────────────────────────────────
             "cpu_warning_threshold" => {
                 if let Ok(v) = value.parse::<f32>() {
@@ -995,6 +1041,10 @@ impl SettingsPlugin {
                 config.notifications.telegram_min_severity = value.to_string();
                 true
             }
+            "notifications.telegram_commands" => {
+                config.notifications.telegram_commands = value == "true";
+                true
+            }
             _ => false,
         }
     }
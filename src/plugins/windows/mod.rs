@@ -536,6 +536,27 @@ impl Plugin for WindowsPlugin {
     }
 
     fn security_alerts(&mut self) -> Vec<Alert> {
+        let raw_alerts = self.firing_alerts();
+
+        // Apply cooldown dedup (same pattern as AlertDetector)
+        let now = Instant::now();
+        let cooldown = std::time::Duration::from_secs(ALERT_COOLDOWN_SECS);
+        raw_alerts
+            .into_iter()
+            .filter(|alert| {
+                let key = (alert.pid, alert.category);
+                match self.alert_cooldowns.get(&key) {
+                    Some(last_fired) if now.duration_since(*last_fired) < cooldown => false,
+                    _ => {
+                        self.alert_cooldowns.insert(key, now);
+                        true
+                    }
+                }
+            })
+            .collect()
+    }
+
+    fn firing_alerts(&self) -> Vec<Alert> {
         let snap = match &self.state.snapshot {
             Some(s) => s,
             None => return Vec::new(),
@@ -619,22 +640,7 @@ impl Plugin for WindowsPlugin {
             }
         }
 
-        // Apply cooldown dedup (same pattern as AlertDetector)
-        let now = Instant::now();
-        let cooldown = std::time::Duration::from_secs(ALERT_COOLDOWN_SECS);
         raw_alerts
-            .into_iter()
-            .filter(|alert| {
-                let key = (alert.pid, alert.category);
-                match self.alert_cooldowns.get(&key) {
-                    Some(last_fired) if now.duration_since(*last_fired) < cooldown => false,
-                    _ => {
-                        self.alert_cooldowns.insert(key, now);
-                        true
-                    }
-                }
-            })
-            .collect()
     }
 }
 
@@ -0,0 +1,368 @@
+//! Historical replay: rebuild what the dashboard, process table and alerts
+//! tab showed at a past moment from the event store.
+//!
+//! A frame covers one refresh: the system snapshot at or before the chosen
+//! time, plus the process snapshot and events recorded before the next one.
+//! The store keeps aggregate figures only, so per-core usage, networks,
+//! disks, temperatures and cgroups are left empty, the process table holds
+//! the recorded top processes, and only the first GPU is restored.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Local, TimeZone};
+
+use crate::alerts::rules::parse_severity;
+use crate::alerts::silence::parse_category;
+use crate::alerts::{ActiveAlert, AlertKey};
+use crate::constants::{HISTORY_CAPACITY, REPLAY_ALERT_WINDOW_MINUTES, REPLAY_PROCESS_LIMIT};
+use crate::models::{Alert, GpuInfo, ProcessInfo, ProcessStatus, SystemSnapshot};
+use crate::store::{EventKind, EventRow, EventStore, ProcessSnapshotRow, SystemSnapshotRow};
+
+/// Everything the TUI shows for one moment in the past.
+#[derive(Debug, Clone)]
+pub struct ReplayFrame {
+    /// Timestamp of the system snapshot (epoch ms)
+    pub ts: i64,
+    pub system: SystemSnapshot,
+    pub processes: Vec<ProcessInfo>,
+    /// Alerts raised in the `REPLAY_ALERT_WINDOW_MINUTES` before `ts`, newest first
+    pub alerts: Vec<Alert>,
+    /// Alerts still firing at `ts`, oldest first
+    pub active_alerts: Vec<ActiveAlert>,
+    /// Sparkline samples up to `ts`, oldest first
+    pub cpu_history: Vec<u64>,
+    pub mem_history: Vec<u64>,
+}
+
+/// Build the frame of the last system snapshot at or before `ts_ms`. `live`
+/// supplies what the store does not record (host name, OS, CPU count, GPU
+/// model). None when nothing had been recorded by then.
+pub fn load_frame(
+    store: &EventStore,
+    ts_ms: i64,
+    live: Option<&SystemSnapshot>,
+) -> Option<ReplayFrame> {
+    let history = store
+        .query_system_history_until(ts_ms, HISTORY_CAPACITY)
+        .ok()?;
+    let row = history.last()?;
+    // Process rows and events of a refresh are written just after its system row
+    let end = store
+        .next_system_snapshot_ts(row.ts)
+        .ok()
+        .flatten()
+        .unwrap_or(i64::MAX);
+    let processes: Vec<ProcessInfo> = store
+        .query_processes_before(end, REPLAY_PROCESS_LIMIT)
+        .unwrap_or_default()
+        .iter()
+        .map(|p| process_from_row(p, row.mem_total))
+        .collect();
+    let window_start = row.ts - REPLAY_ALERT_WINDOW_MINUTES * 60_000;
+    let events = store
+        .query_events_between(window_start, end)
+        .unwrap_or_default();
+    let (alerts, active_alerts) = replay_alerts(&events);
+
+    Some(ReplayFrame {
+        ts: row.ts,
+        system: system_from_row(row, processes.len(), live),
+        processes,
+        alerts,
+        active_alerts,
+        cpu_history: history.iter().map(|r| r.cpu_global as u64).collect(),
+        mem_history: history.iter().map(|r| memory_percent(r) as u64).collect(),
+    })
+}
+
+fn memory_percent(row: &SystemSnapshotRow) -> f32 {
+    if row.mem_total == 0 {
+        return 0.0;
+    }
+    (row.mem_used as f32 / row.mem_total as f32) * 100.0
+}
+
+fn system_from_row(
+    row: &SystemSnapshotRow,
+    process_count: usize,
+    live: Option<&SystemSnapshot>,
+) -> SystemSnapshot {
+    let gpus = match row.gpu_util {
+        Some(utilization) => {
+            let mut gpu = live
+                .and_then(|s| s.gpus.first())
+                .cloned()
+                .unwrap_or_else(|| GpuInfo {
+                    id: String::new(),
+                    vendor: Default::default(),
+                    name: "GPU".to_string(),
+                    utilization: 0,
+                    memory_used: 0,
+                    memory_total: 0,
+                    temperature: None,
+                    power_draw: None,
+                    fan_speed: None,
+                });
+            gpu.utilization = utilization;
+            gpu.memory_used = row.gpu_mem_used.unwrap_or(0);
+            gpu.temperature = row.gpu_temp;
+            gpu.power_draw = None;
+            gpu.fan_speed = None;
+            vec![gpu]
+        }
+        None => vec![],
+    };
+    let age_secs = (Local::now().timestamp_millis() - row.ts).max(0) as u64 / 1000;
+
+    SystemSnapshot {
+        total_memory: row.mem_total,
+        used_memory: row.mem_used,
+        total_swap: row.swap_total,
+        used_swap: row.swap_used,
+        cpu_count: live.map_or(0, |s| s.cpu_count),
+        cpu_usages: vec![],
+        global_cpu_usage: row.cpu_global,
+        // Wrong only across a reboot since `row.ts`
+        uptime: live.map_or(0, |s| s.uptime.saturating_sub(age_secs)),
+        hostname: live.map(|s| s.hostname.clone()).unwrap_or_default(),
+        os_name: live.map(|s| s.os_name.clone()).unwrap_or_default(),
+        load_avg_1: row.load_1,
+        load_avg_5: row.load_5,
+        load_avg_15: row.load_15,
+        total_processes: process_count,
+        networks: vec![],
+        disks: vec![],
+        cpu_temp: None,
+        gpus,
+        cgroups: vec![],
+        battery: None,
+    }
+}
+
+fn process_from_row(row: &ProcessSnapshotRow, mem_total: u64) -> ProcessInfo {
+    let memory_percent = if mem_total == 0 {
+        0.0
+    } else {
+        (row.mem_bytes as f32 / mem_total as f32) * 100.0
+    };
+    ProcessInfo {
+        pid: row.pid,
+        name: row.name.clone(),
+        cmd: String::new(),
+        cpu_usage: row.cpu,
+        memory_bytes: row.mem_bytes,
+        memory_percent,
+        disk_read_bytes: row.disk_read,
+        disk_write_bytes: row.disk_write,
+        status: parse_status(&row.status),
+        user: row.user.clone(),
+        start_time: 0,
+        parent_pid: None,
+        thread_count: None,
+        gpu_usage: 0.0,
+        gpu_memory_bytes: 0,
+        net_rx_bytes_per_sec: row.net_rx,
+        net_tx_bytes_per_sec: row.net_tx,
+    }
+}
+
+/// Inverse of `ProcessStatus`'s `Display`.
+fn parse_status(s: &str) -> ProcessStatus {
+    match s {
+        "Running" => ProcessStatus::Running,
+        "Sleeping" => ProcessStatus::Sleeping,
+        "Stopped" => ProcessStatus::Stopped,
+        "Zombie" => ProcessStatus::Zombie,
+        "Dead" => ProcessStatus::Dead,
+        _ => ProcessStatus::Unknown,
+    }
+}
+
+/// Walk `events` (oldest first): every `alert` joins the history and keeps
+/// its key firing until an `alert_resolved` for the same key.
+fn replay_alerts(events: &[EventRow]) -> (Vec<Alert>, Vec<ActiveAlert>) {
+    let mut alerts = Vec::new();
+    let mut firing: HashMap<AlertKey, ActiveAlert> = HashMap::new();
+    for event in events {
+        match EventKind::from_str(&event.kind) {
+            Some(EventKind::Alert) => {
+                let Some(alert) = alert_from_event(event) else {
+                    continue;
+                };
+                match firing.get_mut(&AlertKey::of(&alert)) {
+                    Some(active) if alert.severity >= active.alert.severity => {
+                        active.alert = alert.clone();
+                    }
+                    Some(_) => {}
+                    None => {
+                        let since = alert.timestamp;
+                        firing.insert(
+                            AlertKey::of(&alert),
+                            ActiveAlert::replayed(alert.clone(), since),
+                        );
+                    }
+                }
+                alerts.push(alert);
+            }
+            Some(EventKind::AlertResolved) => {
+                let category =
+                    event_detail(event).and_then(|d| parse_category(d["category"].as_str()?).ok());
+                if let (Some(category), Some(subject)) = (category, event.name.clone()) {
+                    firing.remove(&AlertKey { category, subject });
+                }
+            }
+            _ => {}
+        }
+    }
+    alerts.reverse();
+    let mut active: Vec<ActiveAlert> = firing.into_values().collect();
+    active.sort_by_key(|a| a.since);
+    (alerts, active)
+}
+
+fn event_detail(event: &EventRow) -> Option<serde_json::Value> {
+    serde_json::from_str(event.detail.as_deref()?).ok()
+}
+
+/// An `alert` event back into the `Alert` that was recorded.
+fn alert_from_event(event: &EventRow) -> Option<Alert> {
+    let detail = event_detail(event)?;
+    let category = parse_category(detail["category"].as_str()?).ok()?;
+    let severity = parse_severity(event.severity.as_deref()?).ok()?;
+    let mut alert = Alert::new(
+        severity,
+        category,
+        event.name.as_deref().unwrap_or_default(),
+        event.pid.unwrap_or(0),
+        detail["message"].as_str().unwrap_or_default().to_string(),
+        detail["value"].as_f64().unwrap_or(0.0),
+        detail["threshold"].as_f64().unwrap_or(0.0),
+    );
+    alert.timestamp = local_time(event.ts)?;
+    alert.suppressed = event.suppressed;
+    Some(alert)
+}
+
+fn local_time(ts_ms: i64) -> Option<DateTime<Local>> {
+    Local.timestamp_millis_opt(ts_ms).single()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{AlertCategory, AlertSeverity};
+
+    fn system(cpu: f32) -> SystemSnapshot {
+        SystemSnapshot {
+            total_memory: 1000,
+            used_memory: 250,
+            total_swap: 0,
+            used_swap: 0,
+            cpu_count: 4,
+            cpu_usages: vec![cpu; 4],
+            global_cpu_usage: cpu,
+            uptime: 3600,
+            hostname: "box".to_string(),
+            os_name: "Linux".to_string(),
+            load_avg_1: 1.0,
+            load_avg_5: 1.0,
+            load_avg_15: 1.0,
+            total_processes: 2,
+            networks: vec![],
+            disks: vec![],
+            cpu_temp: None,
+            gpus: vec![],
+            cgroups: vec![],
+            battery: None,
+        }
+    }
+
+    fn process(pid: u32, name: &str, cpu: f32) -> ProcessInfo {
+        ProcessInfo {
+            pid,
+            name: name.to_string(),
+            cmd: String::new(),
+            cpu_usage: cpu,
+            memory_bytes: 100,
+            memory_percent: 10.0,
+            disk_read_bytes: 0,
+            disk_write_bytes: 0,
+            status: ProcessStatus::Sleeping,
+            user: "test".to_string(),
+            start_time: 0,
+            parent_pid: None,
+            thread_count: None,
+            gpu_usage: 0.0,
+            gpu_memory_bytes: 0,
+            net_rx_bytes_per_sec: 0,
+            net_tx_bytes_per_sec: 0,
+        }
+    }
+
+    /// Record one refresh the way `App::tick_refresh` does.
+    fn record(
+        store: &mut EventStore,
+        cpu: f32,
+        processes: &[ProcessInfo],
+        event: (EventKind, &str),
+    ) {
+        store.insert_system_snapshot(&system(cpu)).unwrap();
+        store.insert_process_snapshots(processes).unwrap();
+        let (kind, severity) = event;
+        let detail = serde_json::json!({
+            "category": AlertCategory::HighCpu.to_string(),
+            "message": "build using 95.0% CPU",
+            "value": 95.0,
+            "threshold": 90.0,
+        })
+        .to_string();
+        store
+            .insert_event(kind, Some(42), Some("build"), Some(&detail), Some(severity))
+            .unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+    }
+
+    #[test]
+    fn frames_rebuild_dashboard_processes_and_alerts() {
+        let mut store = EventStore::open(None).unwrap();
+        record(
+            &mut store,
+            40.0,
+            &[process(42, "build", 95.0)],
+            (EventKind::Alert, "crit"),
+        );
+        record(
+            &mut store,
+            80.0,
+            &[process(42, "build", 20.0), process(7, "sshd", 0.5)],
+            (EventKind::AlertResolved, "crit"),
+        );
+        let (first, last) = store.system_snapshot_bounds().unwrap().unwrap();
+        let live = system(10.0);
+
+        let frame = load_frame(&store, first, Some(&live)).unwrap();
+        assert_eq!(frame.ts, first);
+        assert_eq!(frame.system.global_cpu_usage, 40.0);
+        assert_eq!(frame.system.hostname, "box");
+        assert_eq!(frame.processes.len(), 1);
+        assert_eq!(frame.processes[0].status, ProcessStatus::Sleeping);
+        assert_eq!(frame.alerts.len(), 1);
+        assert_eq!(frame.alerts[0].severity, AlertSeverity::Critical);
+        assert_eq!(frame.alerts[0].category, AlertCategory::HighCpu);
+        assert_eq!(frame.active_alerts.len(), 1);
+        assert_eq!(frame.active_alerts[0].key.subject, "build");
+
+        // Between snapshots shows the earlier one; the alert has resolved by the last
+        let frame = load_frame(&store, last - 1, None).unwrap();
+        assert_eq!(frame.ts, first);
+        let frame = load_frame(&store, last, None).unwrap();
+        assert_eq!(frame.system.global_cpu_usage, 80.0);
+        assert_eq!(frame.processes.len(), 2);
+        assert_eq!(frame.alerts.len(), 1);
+        assert!(frame.active_alerts.is_empty());
+        assert_eq!(frame.cpu_history, [40, 80]);
+        assert_eq!(frame.mem_history, [25, 25]);
+
+        assert!(load_frame(&store, first - 1, None).is_none());
+    }
+}
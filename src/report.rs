@@ -0,0 +1,216 @@
+//! `sentinel report` — run a `DiagnosticEngine` report without the TUI.
+//!
+//! Store-backed reports (`timeline`, `port`, `pid`, `anomaly`,
+//! `thermal-history`, `diff`) open the event
+//! store read-only, so they can run over SSH next to a live Sentinel or
+//! `sentinel daemon`. Live reports (`why`, `disk`) take a one-shot sample
+//! with `SystemCollector`.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Result};
+use clap::{Subcommand, ValueEnum};
+
+use crate::config::Config;
+use crate::diagnostics::{parse_time_range, DiagnosticEngine, DiagnosticReport};
+use crate::monitor::SystemCollector;
+use crate::store::EventStore;
+
+/// Which diagnostic to run (mirrors the command palette).
+#[derive(Subcommand, Debug, Clone, PartialEq)]
+pub enum ReportKind {
+    /// What happened recently: process starts/exits, alerts, port changes
+    Timeline {
+        /// Look-back window in minutes
+        #[arg(long, short = 'm', default_value_t = 30)]
+        minutes: u64,
+    },
+    /// Who owns / owned a port, and its recent history
+    Port {
+        /// Port number
+        port: u16,
+    },
+    /// History and current state of a process
+    Pid {
+        /// Process ID
+        pid: u32,
+    },
+    /// CPU/memory spikes and other anomalies in the event store
+    Anomaly {
+        /// Look-back window in minutes
+        #[arg(long, short = 'm', default_value_t = 30)]
+        minutes: u64,
+    },
+    /// Per-sensor min/avg/max temperatures and rate of rise
+    ThermalHistory {
+        /// Look-back window in minutes
+        #[arg(long, short = 'm', default_value_t = 60)]
+        minutes: u64,
+        /// Only this sensor (e.g. "CPU Package")
+        #[arg(long, short = 's')]
+        sensor: Option<String>,
+    },
+    /// Processes that appeared, disappeared, grew or changed CPU share
+    Diff {
+        /// Earlier time: now, an age (1h, 30m), 14:30 or 2024-05-01T14:30
+        #[arg(default_value = "1h")]
+        from: String,
+        /// Later time, same forms
+        #[arg(default_value = "now")]
+        to: String,
+    },
+    /// Filesystem usage (live sample)
+    Disk,
+    /// What is competing for resources right now (live sample)
+    Why,
+}
+
+/// Output format for `sentinel report`.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReportFormat {
+    /// Human-readable text (same as the command palette)
+    #[default]
+    Text,
+    /// JSON with findings and suggested actions
+    Json,
+}
+
+/// Run the report and print it to stdout.
+pub fn run(kind: &ReportKind, format: ReportFormat, db: Option<&Path>, config: &Config) -> Result<()> {
+    let report = build(kind, db, config)?;
+    match format {
+        ReportFormat::Text => println!("{}", report.to_text()),
+        ReportFormat::Json => println!("{}", report.to_json()),
+    }
+    Ok(())
+}
+
+/// Build the requested report.
+fn build(kind: &ReportKind, db: Option<&Path>, config: &Config) -> Result<DiagnosticReport> {
+    let report = match kind {
+        ReportKind::Timeline { minutes } => {
+            DiagnosticEngine::timeline_report(&open_store(db)?, *minutes)
+        }
+        ReportKind::Port { port } => DiagnosticEngine::port_diagnosis(&open_store(db)?, *port),
+        ReportKind::Pid { pid } => {
+            let store = open_store(db)?;
+            let (_, processes) = SystemCollector::new().collect();
+            let current = processes.iter().find(|p| p.pid == *pid);
+            DiagnosticEngine::process_analysis(&store, *pid, current)
+        }
+        ReportKind::Anomaly { minutes } => {
+            DiagnosticEngine::anomaly_scan(&open_store(db)?, *minutes)
+        }
+        ReportKind::ThermalHistory { minutes, sensor } => DiagnosticEngine::thermal_history(
+            &open_store(db)?,
+            *minutes,
+            sensor.as_deref(),
+        ),
+        ReportKind::Diff { from, to } => {
+            let (from, to) =
+                parse_time_range(from, to, chrono::Local::now()).map_err(anyhow::Error::msg)?;
+            DiagnosticEngine::process_diff(&open_store(db)?, from, to)
+        }
+        ReportKind::Disk => {
+            let (system, _) = SystemCollector::new().collect();
+            DiagnosticEngine::disk_analysis(&system)
+        }
+        ReportKind::Why => {
+            let (system, processes) = SystemCollector::new().collect();
+            DiagnosticEngine::resource_contention(
+                &system,
+                &processes,
+                &config.ignored_zombie_parents,
+            )
+        }
+    };
+    Ok(report)
+}
+
+/// Open the event store read-only (default path unless `--db` is given).
+fn open_store(db: Option<&Path>) -> Result<EventStore> {
+    let path: PathBuf = db.map(Path::to_path_buf).unwrap_or_else(EventStore::default_path);
+    if !path.exists() {
+        bail!(
+            "no event store at {} (run Sentinel or `sentinel daemon` first)",
+            path.display()
+        );
+    }
+    EventStore::open_read_only(&path)
+        .map_err(|e| anyhow::anyhow!("could not open event store {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::EventKind;
+
+    #[test]
+    fn timeline_reads_existing_store() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sentinel.db");
+        {
+            let store = EventStore::open(Some(&path)).unwrap();
+            store
+                .insert_event(EventKind::ProcessStart, Some(42), Some("worker"), None, None)
+                .unwrap();
+        }
+
+        let kind = ReportKind::Timeline { minutes: 10 };
+        let report = build(&kind, Some(&path), &Config::default()).unwrap();
+        assert!(report.to_text().contains("started"));
+    }
+
+    #[test]
+    fn thermal_history_reads_existing_store() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sentinel.db");
+        {
+            let mut store = EventStore::open(Some(&path)).unwrap();
+            let mut snap = crate::thermal::ThermalSnapshot::empty();
+            snap.cpu_package = Some(71.0);
+            store.insert_thermal_snapshot(&snap).unwrap();
+        }
+
+        let kind = ReportKind::ThermalHistory {
+            minutes: 10,
+            sensor: None,
+        };
+        let report = build(&kind, Some(&path), &Config::default()).unwrap();
+        assert!(report.to_text().contains("CPU Package"));
+    }
+
+    #[test]
+    fn diff_reads_existing_store() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sentinel.db");
+        EventStore::open(Some(&path)).unwrap();
+
+        let diff = |from: &str| ReportKind::Diff {
+            from: from.to_string(),
+            to: "now".to_string(),
+        };
+        let report = build(&diff("2h"), Some(&path), &Config::default()).unwrap();
+        assert!(report.to_text().contains("No process snapshots"));
+
+        let err = build(&diff("soon"), Some(&path), &Config::default()).unwrap_err();
+        assert!(err.to_string().contains("invalid time 'soon'"));
+
+        let reversed = ReportKind::Diff {
+            from: "now".to_string(),
+            to: "2h".to_string(),
+        };
+        let err = build(&reversed, Some(&path), &Config::default()).unwrap_err();
+        assert!(err.to_string().contains("is later than"));
+    }
+
+    #[test]
+    fn missing_store_is_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("missing.db");
+        let err = build(&ReportKind::Port { port: 8080 }, Some(&path), &Config::default())
+            .unwrap_err();
+        assert!(err.to_string().contains("no event store"));
+        assert!(!path.exists(), "read-only report must not create the database");
+    }
+}
@@ -1,7 +1,7 @@
 //! Persistent event store backed by SQLite.
 //!
-//! Records system snapshots, process snapshots, discrete events, and network
-//! socket state. Provides time-range queries for diagnostics, anomaly detection,
+//! Records system snapshots, process snapshots, thermal sensor readings,
+//! discrete events, and network socket state. Provides time-range queries for diagnostics, anomaly detection,
 //! and AI context enrichment.
 //!
 //! Design:
@@ -14,9 +14,11 @@ use std::collections::HashSet;
 use std::fmt;
 use std::path::{Path, PathBuf};
 
-use rusqlite::{params, Connection, Result as SqlResult};
+use rusqlite::{params, Connection, OpenFlags, Result as SqlResult};
 
+use crate::constants::ESCALATION_STATE_GRACE_SECS;
 use crate::models::{ProcessInfo, SystemSnapshot};
+use crate::thermal::ThermalSnapshot;
 
 // ── Constants ─────────────────────────────────────────────────────
 
@@ -29,9 +31,15 @@ const TOP_CPU_SNAPSHOT_COUNT: usize = 50;
 /// How many top processes (by memory) to snapshot each tick.
 const TOP_MEM_SNAPSHOT_COUNT: usize = 30;
 
+/// How many top processes (by network rate) to snapshot each tick.
+const TOP_NET_SNAPSHOT_COUNT: usize = 20;
+
 /// Cleanup runs every N inserts to avoid running every tick.
 const CLEANUP_INTERVAL: u64 = 300;
 
+/// Shortest span used when measuring a sensor's rate of rise (1 minute).
+const THERMAL_RISE_SPAN_MS: i64 = 60_000;
+
 // ── Event types ───────────────────────────────────────────────────
 
 /// Discrete event kinds tracked by the store.
@@ -43,6 +51,16 @@ pub enum EventKind {
     PortBind,
     PortRelease,
     Alert,
+    /// A firing alert's condition cleared.
+    AlertResolved,
+    /// A firing alert was acknowledged by a user.
+    AlertAck,
+    /// An escalation policy step notified its channel.
+    AlertEscalated,
+    /// A thermal mitigation step was applied or undone.
+    ThermalMitigation,
+    /// An auto-shutdown action ran (or was reported in dry-run mode).
+    ShutdownAction,
     CpuSpike,
     MemorySpike,
     OomKill,
@@ -56,6 +74,11 @@ impl fmt::Display for EventKind {
             EventKind::PortBind => write!(f, "port_bind"),
             EventKind::PortRelease => write!(f, "port_release"),
             EventKind::Alert => write!(f, "alert"),
+            EventKind::AlertResolved => write!(f, "alert_resolved"),
+            EventKind::AlertAck => write!(f, "alert_ack"),
+            EventKind::AlertEscalated => write!(f, "alert_escalated"),
+            EventKind::ThermalMitigation => write!(f, "thermal_mitigation"),
+            EventKind::ShutdownAction => write!(f, "shutdown_action"),
             EventKind::CpuSpike => write!(f, "cpu_spike"),
             EventKind::MemorySpike => write!(f, "memory_spike"),
             EventKind::OomKill => write!(f, "oom_kill"),
@@ -72,6 +95,11 @@ impl EventKind {
             "port_bind" => Some(EventKind::PortBind),
             "port_release" => Some(EventKind::PortRelease),
             "alert" => Some(EventKind::Alert),
+            "alert_resolved" => Some(EventKind::AlertResolved),
+            "alert_ack" => Some(EventKind::AlertAck),
+            "alert_escalated" => Some(EventKind::AlertEscalated),
+            "thermal_mitigation" => Some(EventKind::ThermalMitigation),
+            "shutdown_action" => Some(EventKind::ShutdownAction),
             "cpu_spike" => Some(EventKind::CpuSpike),
             "memory_spike" => Some(EventKind::MemorySpike),
             "oom_kill" => Some(EventKind::OomKill),
@@ -113,6 +141,10 @@ pub struct ProcessSnapshotRow {
     pub disk_write: u64,
     pub status: String,
     pub user: String,
+    /// Network bytes received per second
+    pub net_rx: u64,
+    /// Network bytes sent per second
+    pub net_tx: u64,
 }
 
 /// A discrete event row from the database.
@@ -125,6 +157,63 @@ pub struct EventRow {
     pub name: Option<String>,
     pub detail: Option<String>,
     pub severity: Option<String>,
+    /// Recorded while a silence or maintenance window matched.
+    pub suppressed: bool,
+}
+
+/// An alert silence row from the database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SilenceRow {
+    pub id: i64,
+    pub created_ts: i64,
+    pub expires_ts: i64,
+    pub category: Option<String>,
+    pub process: Option<String>,
+    pub severity: Option<String>,
+    pub reason: Option<String>,
+}
+
+/// Progress of one escalation policy for one firing alert.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EscalationRow {
+    pub policy: String,
+    /// Alert category, as its `Debug` name (`ThermalCritical`).
+    pub category: String,
+    pub subject: String,
+    /// When the escalation clock started (epoch ms).
+    pub started_ts: i64,
+    /// When the alert was last seen firing (epoch ms).
+    pub last_seen_ts: i64,
+    pub steps_fired: u32,
+}
+
+/// A thermal sensor reading row from the database.
+#[derive(Debug, Clone)]
+pub struct ThermalReadingRow {
+    pub ts: i64,
+    pub sensor: String,
+    /// `cpu`, `gpu`, `ssd`, `ram`, `board` (°C) or `fan` (RPM).
+    pub category: String,
+    pub value: f32,
+}
+
+/// Per-sensor summary of thermal readings over a window.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThermalSensorStats {
+    pub sensor: String,
+    pub category: String,
+    pub samples: usize,
+    pub min: f32,
+    pub avg: f32,
+    pub max: f32,
+    /// When the maximum was first reached (epoch ms).
+    pub peak_ts: i64,
+    /// Least-squares slope across the whole window, per minute.
+    pub trend_per_min: Option<f32>,
+    /// Steepest rise over any span of at least a minute, per minute.
+    pub max_rise_per_min: Option<f32>,
+    /// End of the steepest rise (epoch ms).
+    pub max_rise_ts: Option<i64>,
 }
 
 /// A network socket row from the database.
@@ -186,6 +275,25 @@ impl EventStore {
         Ok(store)
     }
 
+    /// Open an existing database read-only (for `sentinel report`).
+    ///
+    /// Never creates the file or touches the schema, so it is safe to run
+    /// alongside a live Sentinel that is writing to the same database.
+    pub fn open_read_only(path: &Path) -> SqlResult<Self> {
+        let conn = Connection::open_with_flags(
+            path,
+            OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+        )?;
+        Ok(Self {
+            conn,
+            retention_secs: DEFAULT_RETENTION_SECS,
+            insert_count: 0,
+            prev_pids: HashSet::new(),
+            prev_pid_names: std::collections::HashMap::new(),
+            prev_listeners: HashSet::new(),
+        })
+    }
+
     /// Default database path: `~/.local/share/sentinel/sentinel.db`
     pub fn default_path() -> PathBuf {
         crate::constants::home_dir()
@@ -235,7 +343,9 @@ impl EventStore {
                 disk_read   INTEGER,
                 disk_write  INTEGER,
                 status      TEXT,
-                user        TEXT
+                user        TEXT,
+                net_rx      INTEGER NOT NULL DEFAULT 0,
+                net_tx      INTEGER NOT NULL DEFAULT 0
             );
             CREATE INDEX IF NOT EXISTS idx_proc_ts ON process_snapshots(ts);
             CREATE INDEX IF NOT EXISTS idx_proc_pid_ts ON process_snapshots(pid, ts);
@@ -247,7 +357,8 @@ impl EventStore {
                 pid         INTEGER,
                 name        TEXT,
                 detail      TEXT,
-                severity    TEXT
+                severity    TEXT,
+                suppressed  INTEGER NOT NULL DEFAULT 0
             );
             CREATE INDEX IF NOT EXISTS idx_events_ts ON events(ts);
             CREATE INDEX IF NOT EXISTS idx_events_kind_ts ON events(kind, ts);
@@ -267,12 +378,65 @@ impl EventStore {
             CREATE INDEX IF NOT EXISTS idx_net_ts ON network_sockets(ts);
             CREATE INDEX IF NOT EXISTS idx_net_port ON network_sockets(local_port, ts);
 
+            CREATE TABLE IF NOT EXISTS thermal_readings (
+                id          INTEGER PRIMARY KEY,
+                ts          INTEGER NOT NULL,
+                sensor      TEXT NOT NULL,
+                category    TEXT NOT NULL,
+                value       REAL NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS idx_thermal_ts ON thermal_readings(ts);
+            CREATE INDEX IF NOT EXISTS idx_thermal_sensor_ts ON thermal_readings(sensor, ts);
+
             CREATE TABLE IF NOT EXISTS favorites (
                 coin_id     TEXT PRIMARY KEY,
                 added_at    INTEGER NOT NULL
+            );
+
+            CREATE TABLE IF NOT EXISTS silences (
+                id          INTEGER PRIMARY KEY,
+                created_ts  INTEGER NOT NULL,
+                expires_ts  INTEGER NOT NULL,
+                category    TEXT,
+                process     TEXT,
+                severity    TEXT,
+                reason      TEXT
+            );
+
+            CREATE TABLE IF NOT EXISTS escalations (
+                policy       TEXT NOT NULL,
+                category     TEXT NOT NULL,
+                subject      TEXT NOT NULL,
+                started_ts   INTEGER NOT NULL,
+                last_seen_ts INTEGER NOT NULL,
+                steps_fired  INTEGER NOT NULL,
+                PRIMARY KEY (policy, category, subject)
             );",
         )?;
 
+        // Databases created before suppression tracking lack the column
+        let has_suppressed: bool = self
+            .conn
+            .prepare("SELECT 1 FROM pragma_table_info('events') WHERE name = 'suppressed'")?
+            .exists([])?;
+        if !has_suppressed {
+            self.conn.execute_batch(
+                "ALTER TABLE events ADD COLUMN suppressed INTEGER NOT NULL DEFAULT 0;",
+            )?;
+        }
+
+        // ...and process snapshots before per-process network accounting
+        let has_net: bool = self
+            .conn
+            .prepare("SELECT 1 FROM pragma_table_info('process_snapshots') WHERE name = 'net_rx'")?
+            .exists([])?;
+        if !has_net {
+            self.conn.execute_batch(
+                "ALTER TABLE process_snapshots ADD COLUMN net_rx INTEGER NOT NULL DEFAULT 0;
+                 ALTER TABLE process_snapshots ADD COLUMN net_tx INTEGER NOT NULL DEFAULT 0;",
+            )?;
+        }
+
         Ok(())
     }
 
@@ -281,11 +445,12 @@ impl EventStore {
     /// Record a system-wide snapshot.
     pub fn insert_system_snapshot(&mut self, system: &SystemSnapshot) -> SqlResult<()> {
         let ts = now_epoch_ms();
-        let (gpu_util, gpu_mem, gpu_temp) = match &system.gpu {
+        // The snapshot table keeps one GPU column set; record the first device
+        let (gpu_util, gpu_mem, gpu_temp) = match system.gpus.first() {
             Some(g) => (
                 Some(g.utilization),
                 Some(g.memory_used),
-                Some(g.temperature),
+                g.temperature,
             ),
             None => (None, None, None),
         };
@@ -341,9 +506,70 @@ impl EventStore {
         rows.collect()
     }
 
+    /// The last `limit` system snapshots at or before `until_ms`, oldest first.
+    pub fn query_system_history_until(
+        &self,
+        until_ms: i64,
+        limit: usize,
+    ) -> SqlResult<Vec<SystemSnapshotRow>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT ts, cpu_global, mem_used, mem_total, swap_used, swap_total, load_1, load_5, load_15, gpu_util, gpu_mem_used, gpu_temp
+             FROM system_snapshots WHERE ts <= ?1 ORDER BY ts DESC LIMIT ?2",
+        )?;
+
+        let rows = stmt.query_map(params![until_ms, limit as i64], |row| {
+            Ok(SystemSnapshotRow {
+                ts: row.get(0)?,
+                cpu_global: row.get(1)?,
+                mem_used: row.get(2)?,
+                mem_total: row.get(3)?,
+                swap_used: row.get(4)?,
+                swap_total: row.get(5)?,
+                load_1: row.get(6)?,
+                load_5: row.get(7)?,
+                load_15: row.get(8)?,
+                gpu_util: row.get(9)?,
+                gpu_mem_used: row.get(10)?,
+                gpu_temp: row.get(11)?,
+            })
+        })?;
+
+        let mut rows: Vec<SystemSnapshotRow> = rows.collect::<SqlResult<_>>()?;
+        rows.reverse();
+        Ok(rows)
+    }
+
+    /// Timestamp of the first system snapshot after `after_ms`.
+    pub fn next_system_snapshot_ts(&self, after_ms: i64) -> SqlResult<Option<i64>> {
+        self.conn.query_row(
+            "SELECT MIN(ts) FROM system_snapshots WHERE ts > ?1",
+            params![after_ms],
+            |row| row.get(0),
+        )
+    }
+
+    /// Timestamp of the last system snapshot before `before_ms`.
+    pub fn prev_system_snapshot_ts(&self, before_ms: i64) -> SqlResult<Option<i64>> {
+        self.conn.query_row(
+            "SELECT MAX(ts) FROM system_snapshots WHERE ts < ?1",
+            params![before_ms],
+            |row| row.get(0),
+        )
+    }
+
+    /// Timestamps of the oldest and newest system snapshots.
+    pub fn system_snapshot_bounds(&self) -> SqlResult<Option<(i64, i64)>> {
+        let (first, last): (Option<i64>, Option<i64>) =
+            self.conn
+                .query_row("SELECT MIN(ts), MAX(ts) FROM system_snapshots", [], |row| {
+                    Ok((row.get(0)?, row.get(1)?))
+                })?;
+        Ok(first.zip(last))
+    }
+
     // ── Process snapshots ─────────────────────────────────────────
 
-    /// Record snapshots for top processes (by CPU and memory).
+    /// Record snapshots for top processes (by CPU, memory and network rate).
     ///
     /// Only stores the top N processes to keep the database manageable,
     /// not all 500+ system processes.
@@ -364,10 +590,18 @@ impl EventStore {
         by_mem.sort_by(|a, b| b.memory_bytes.cmp(&a.memory_bytes));
         by_mem.truncate(TOP_MEM_SNAPSHOT_COUNT);
 
+        // Collect top processes by network rate (only those with traffic)
+        let mut by_net: Vec<&ProcessInfo> = processes
+            .iter()
+            .filter(|p| p.net_bytes_per_sec() > 0)
+            .collect();
+        by_net.sort_by_key(|p| std::cmp::Reverse(p.net_bytes_per_sec()));
+        by_net.truncate(TOP_NET_SNAPSHOT_COUNT);
+
         // Merge into deduplicated set
         let mut seen_pids = HashSet::new();
         let mut to_insert: Vec<&ProcessInfo> = Vec::new();
-        for p in by_cpu.into_iter().chain(by_mem.into_iter()) {
+        for p in by_cpu.into_iter().chain(by_mem).chain(by_net) {
             if seen_pids.insert(p.pid) {
                 to_insert.push(p);
             }
@@ -377,8 +611,8 @@ impl EventStore {
         let tx = self.conn.transaction()?;
         {
             let mut stmt = tx.prepare(
-                "INSERT INTO process_snapshots (ts, pid, name, cpu, mem_bytes, disk_read, disk_write, status, user)
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                "INSERT INTO process_snapshots (ts, pid, name, cpu, mem_bytes, disk_read, disk_write, status, user, net_rx, net_tx)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
             )?;
             for p in &to_insert {
                 stmt.execute(params![
@@ -391,6 +625,8 @@ impl EventStore {
                     p.disk_write_bytes,
                     p.status.to_string(),
                     p.user,
+                    p.net_rx_bytes_per_sec,
+                    p.net_tx_bytes_per_sec,
                 ])?;
             }
         }
@@ -405,7 +641,7 @@ impl EventStore {
         since_ms: i64,
     ) -> SqlResult<Vec<ProcessSnapshotRow>> {
         let mut stmt = self.conn.prepare(
-            "SELECT ts, pid, name, cpu, mem_bytes, disk_read, disk_write, status, user
+            "SELECT ts, pid, name, cpu, mem_bytes, disk_read, disk_write, status, user, net_rx, net_tx
              FROM process_snapshots WHERE pid = ?1 AND ts >= ?2 ORDER BY ts ASC",
         )?;
 
@@ -420,6 +656,8 @@ impl EventStore {
                 disk_write: row.get(6)?,
                 status: row.get(7)?,
                 user: row.get(8)?,
+                net_rx: row.get(9)?,
+                net_tx: row.get(10)?,
             })
         })?;
 
@@ -433,7 +671,7 @@ impl EventStore {
         limit: usize,
     ) -> SqlResult<Vec<ProcessSnapshotRow>> {
         let mut stmt = self.conn.prepare(
-            "SELECT ts, pid, name, cpu, mem_bytes, disk_read, disk_write, status, user
+            "SELECT ts, pid, name, cpu, mem_bytes, disk_read, disk_write, status, user, net_rx, net_tx
              FROM process_snapshots
              WHERE ts = (SELECT ts FROM process_snapshots ORDER BY ABS(ts - ?1) LIMIT 1)
              ORDER BY cpu DESC LIMIT ?2",
@@ -450,12 +688,101 @@ impl EventStore {
                 disk_write: row.get(6)?,
                 status: row.get(7)?,
                 user: row.get(8)?,
+                net_rx: row.get(9)?,
+                net_tx: row.get(10)?,
             })
         })?;
 
         rows.collect()
     }
 
+    /// Query the last process snapshot taken before `before_ms`, by CPU.
+    pub fn query_processes_before(
+        &self,
+        before_ms: i64,
+        limit: usize,
+    ) -> SqlResult<Vec<ProcessSnapshotRow>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT ts, pid, name, cpu, mem_bytes, disk_read, disk_write, status, user, net_rx, net_tx
+             FROM process_snapshots
+             WHERE ts = (SELECT MAX(ts) FROM process_snapshots WHERE ts < ?1)
+             ORDER BY cpu DESC LIMIT ?2",
+        )?;
+
+        let rows = stmt.query_map(params![before_ms, limit as i64], |row| {
+            Ok(ProcessSnapshotRow {
+                ts: row.get(0)?,
+                pid: row.get(1)?,
+                name: row.get(2)?,
+                cpu: row.get(3)?,
+                mem_bytes: row.get(4)?,
+                disk_read: row.get(5)?,
+                disk_write: row.get(6)?,
+                status: row.get(7)?,
+                user: row.get(8)?,
+                net_rx: row.get(9)?,
+                net_tx: row.get(10)?,
+            })
+        })?;
+
+        rows.collect()
+    }
+
+    // ── Thermal readings ──────────────────────────────────────────
+
+    /// Record every sensor of a thermal snapshot.
+    pub fn insert_thermal_snapshot(&mut self, snapshot: &ThermalSnapshot) -> SqlResult<()> {
+        let ts = now_epoch_ms();
+        let readings = snapshot.readings();
+        if readings.is_empty() {
+            return Ok(());
+        }
+
+        let tx = self.conn.transaction()?;
+        {
+            let mut stmt = tx.prepare(
+                "INSERT INTO thermal_readings (ts, sensor, category, value) VALUES (?1, ?2, ?3, ?4)",
+            )?;
+            for (category, sensor, value) in &readings {
+                stmt.execute(params![ts, sensor, category, value])?;
+            }
+        }
+        tx.commit()?;
+
+        self.maybe_cleanup();
+        Ok(())
+    }
+
+    /// Query thermal readings, optionally for one sensor (case-insensitive).
+    /// Rows are grouped by category and sensor, oldest first within each.
+    pub fn query_thermal_history(
+        &self,
+        sensor: Option<&str>,
+        since_ms: i64,
+    ) -> SqlResult<Vec<ThermalReadingRow>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT ts, sensor, category, value FROM thermal_readings
+             WHERE ts >= ?1 AND (?2 IS NULL OR sensor = ?2 COLLATE NOCASE)
+             ORDER BY category, sensor, ts ASC",
+        )?;
+
+        let rows = stmt.query_map(params![since_ms, sensor], |row| {
+            Ok(ThermalReadingRow {
+                ts: row.get(0)?,
+                sensor: row.get(1)?,
+                category: row.get(2)?,
+                value: row.get(3)?,
+            })
+        })?;
+
+        rows.collect()
+    }
+
+    /// Per-sensor min/avg/max and rate of rise since `since_ms`.
+    pub fn thermal_stats(&self, since_ms: i64) -> SqlResult<Vec<ThermalSensorStats>> {
+        Ok(summarize_thermal(&self.query_thermal_history(None, since_ms)?))
+    }
+
     // ── Events ────────────────────────────────────────────────────
 
     /// Record a discrete event.
@@ -466,11 +793,35 @@ impl EventStore {
         name: Option<&str>,
         detail: Option<&str>,
         severity: Option<&str>,
+    ) -> SqlResult<()> {
+        self.insert_event_inner(kind, pid, name, detail, severity, false)
+    }
+
+    /// Record an event that a silence or maintenance window suppressed.
+    pub fn insert_suppressed_event(
+        &self,
+        kind: EventKind,
+        pid: Option<u32>,
+        name: Option<&str>,
+        detail: Option<&str>,
+        severity: Option<&str>,
+    ) -> SqlResult<()> {
+        self.insert_event_inner(kind, pid, name, detail, severity, true)
+    }
+
+    fn insert_event_inner(
+        &self,
+        kind: EventKind,
+        pid: Option<u32>,
+        name: Option<&str>,
+        detail: Option<&str>,
+        severity: Option<&str>,
+        suppressed: bool,
     ) -> SqlResult<()> {
         let ts = now_epoch_ms();
         self.conn.execute(
-            "INSERT INTO events (ts, kind, pid, name, detail, severity) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
-            params![ts, kind.to_string(), pid, name, detail, severity],
+            "INSERT INTO events (ts, kind, pid, name, detail, severity, suppressed) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![ts, kind.to_string(), pid, name, detail, severity, suppressed],
         )?;
         Ok(())
     }
@@ -478,7 +829,7 @@ impl EventStore {
     /// Query events since a given timestamp.
     pub fn query_events_since(&self, since_ms: i64) -> SqlResult<Vec<EventRow>> {
         let mut stmt = self.conn.prepare(
-            "SELECT ts, kind, pid, name, detail, severity FROM events WHERE ts >= ?1 ORDER BY ts DESC",
+            "SELECT ts, kind, pid, name, detail, severity, suppressed FROM events WHERE ts >= ?1 ORDER BY ts DESC",
         )?;
 
         let rows = stmt.query_map(params![since_ms], |row| {
@@ -489,6 +840,7 @@ impl EventStore {
                 name: row.get(3)?,
                 detail: row.get(4)?,
                 severity: row.get(5)?,
+                suppressed: row.get(6)?,
             })
         })?;
 
@@ -498,7 +850,7 @@ impl EventStore {
     /// Query events of a specific kind since a given timestamp.
     pub fn query_events_by_kind(&self, kind: EventKind, since_ms: i64) -> SqlResult<Vec<EventRow>> {
         let mut stmt = self.conn.prepare(
-            "SELECT ts, kind, pid, name, detail, severity FROM events WHERE kind = ?1 AND ts >= ?2 ORDER BY ts DESC",
+            "SELECT ts, kind, pid, name, detail, severity, suppressed FROM events WHERE kind = ?1 AND ts >= ?2 ORDER BY ts DESC",
         )?;
 
         let rows = stmt.query_map(params![kind.to_string(), since_ms], |row| {
@@ -509,6 +861,28 @@ impl EventStore {
                 name: row.get(3)?,
                 detail: row.get(4)?,
                 severity: row.get(5)?,
+                suppressed: row.get(6)?,
+            })
+        })?;
+
+        rows.collect()
+    }
+
+    /// Query events in `[from_ms, to_ms)`, oldest first.
+    pub fn query_events_between(&self, from_ms: i64, to_ms: i64) -> SqlResult<Vec<EventRow>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT ts, kind, pid, name, detail, severity, suppressed FROM events WHERE ts >= ?1 AND ts < ?2 ORDER BY ts ASC, id ASC",
+        )?;
+
+        let rows = stmt.query_map(params![from_ms, to_ms], |row| {
+            Ok(EventRow {
+                ts: row.get(0)?,
+                kind: row.get(1)?,
+                pid: row.get(2)?,
+                name: row.get(3)?,
+                detail: row.get(4)?,
+                severity: row.get(5)?,
+                suppressed: row.get(6)?,
             })
         })?;
 
@@ -623,6 +997,32 @@ impl EventStore {
         rows.collect()
     }
 
+    /// Query the sockets of one process (most recent snapshot), listeners first.
+    pub fn query_process_sockets(&self, pid: u32) -> SqlResult<Vec<SocketRow>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT ts, pid, name, protocol, local_addr, local_port, remote_addr, remote_port, state
+             FROM network_sockets
+             WHERE pid = ?1 AND ts = (SELECT MAX(ts) FROM network_sockets)
+             ORDER BY state != 'LISTEN', local_port ASC",
+        )?;
+
+        let rows = stmt.query_map(params![pid], |row| {
+            Ok(SocketRow {
+                ts: row.get(0)?,
+                pid: row.get(1)?,
+                name: row.get(2)?,
+                protocol: row.get(3)?,
+                local_addr: row.get(4)?,
+                local_port: row.get(5)?,
+                remote_addr: row.get(6)?,
+                remote_port: row.get(7)?,
+                state: row.get(8)?,
+            })
+        })?;
+
+        rows.collect()
+    }
+
     // ── Process lifecycle detection ───────────────────────────────
 
     /// Diff current PIDs against previous tick to detect process start/exit events.
@@ -785,6 +1185,109 @@ impl EventStore {
         Ok(())
     }
 
+    // ── Silences ──────────────────────────────────────────────────
+
+    /// Store a silence and return its ID.
+    pub fn insert_silence(
+        &self,
+        expires_ts: i64,
+        category: Option<&str>,
+        process: Option<&str>,
+        severity: Option<&str>,
+        reason: Option<&str>,
+    ) -> SqlResult<i64> {
+        self.conn.execute(
+            "INSERT INTO silences (created_ts, expires_ts, category, process, severity, reason)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![now_epoch_ms(), expires_ts, category, process, severity, reason],
+        )?;
+        Ok(self.conn.last_insert_rowid())
+    }
+
+    /// Silences that have not expired yet, oldest first.
+    pub fn active_silences(&self) -> SqlResult<Vec<SilenceRow>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, created_ts, expires_ts, category, process, severity, reason
+             FROM silences WHERE expires_ts > ?1 ORDER BY id ASC",
+        )?;
+        let rows = stmt.query_map(params![now_epoch_ms()], |row| {
+            Ok(SilenceRow {
+                id: row.get(0)?,
+                created_ts: row.get(1)?,
+                expires_ts: row.get(2)?,
+                category: row.get(3)?,
+                process: row.get(4)?,
+                severity: row.get(5)?,
+                reason: row.get(6)?,
+            })
+        })?;
+        rows.collect()
+    }
+
+    /// Delete a silence. Returns false if no such silence exists.
+    pub fn remove_silence(&self, id: i64) -> SqlResult<bool> {
+        let n = self
+            .conn
+            .execute("DELETE FROM silences WHERE id = ?1", params![id])?;
+        Ok(n > 0)
+    }
+
+    /// Delete every silence. Returns how many were removed.
+    pub fn clear_silences(&self) -> SqlResult<usize> {
+        self.conn.execute("DELETE FROM silences", [])
+    }
+
+    // ── Escalations ───────────────────────────────────────────────
+
+    /// Insert or update the progress of an escalation.
+    pub fn upsert_escalation(&self, row: &EscalationRow) -> SqlResult<()> {
+        self.conn.execute(
+            "INSERT INTO escalations (policy, category, subject, started_ts, last_seen_ts, steps_fired)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+             ON CONFLICT (policy, category, subject) DO UPDATE SET
+                 started_ts = excluded.started_ts,
+                 last_seen_ts = excluded.last_seen_ts,
+                 steps_fired = excluded.steps_fired",
+            params![
+                row.policy,
+                row.category,
+                row.subject,
+                row.started_ts,
+                row.last_seen_ts,
+                row.steps_fired
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// All stored escalation progress.
+    pub fn escalations(&self) -> SqlResult<Vec<EscalationRow>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT policy, category, subject, started_ts, last_seen_ts, steps_fired
+             FROM escalations ORDER BY started_ts ASC",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok(EscalationRow {
+                policy: row.get(0)?,
+                category: row.get(1)?,
+                subject: row.get(2)?,
+                started_ts: row.get(3)?,
+                last_seen_ts: row.get(4)?,
+                steps_fired: row.get(5)?,
+            })
+        })?;
+        rows.collect()
+    }
+
+    /// Forget an escalation (the alert resolved or its policy was removed).
+    pub fn remove_escalation(&self, policy: &str, category: &str, subject: &str) -> SqlResult<()> {
+        self.conn.execute(
+            "DELETE FROM escalations WHERE policy = ?1 AND category = ?2 AND subject = ?3",
+            params![policy, category, subject],
+        )?;
+        Ok(())
+    }
+
     // ── Retention / cleanup ───────────────────────────────────────
 
     /// Purge data older than the retention window.
@@ -802,6 +1305,16 @@ impl EventStore {
             .execute("DELETE FROM events WHERE ts < ?1", params![cutoff])?;
         self.conn
             .execute("DELETE FROM network_sockets WHERE ts < ?1", params![cutoff])?;
+        self.conn
+            .execute("DELETE FROM thermal_readings WHERE ts < ?1", params![cutoff])?;
+        self.conn.execute(
+            "DELETE FROM silences WHERE expires_ts < ?1",
+            params![now_epoch_ms()],
+        )?;
+        self.conn.execute(
+            "DELETE FROM escalations WHERE last_seen_ts < ?1",
+            params![now_epoch_ms() - (ESCALATION_STATE_GRACE_SECS as i64 * 1000)],
+        )?;
         Ok(())
     }
 
@@ -832,6 +1345,7 @@ impl EventStore {
             "process_snapshots",
             "events",
             "network_sockets",
+            "thermal_readings",
         ];
         let mut stats = Vec::new();
         for table in &tables {
@@ -846,6 +1360,75 @@ impl EventStore {
     }
 }
 
+// ── Thermal summaries ─────────────────────────────────────────────
+
+/// Summarize readings grouped by sensor (as returned by
+/// [`EventStore::query_thermal_history`]).
+fn summarize_thermal(rows: &[ThermalReadingRow]) -> Vec<ThermalSensorStats> {
+    rows.chunk_by(|a, b| a.sensor == b.sensor && a.category == b.category)
+        .map(summarize_sensor)
+        .collect()
+}
+
+fn summarize_sensor(rows: &[ThermalReadingRow]) -> ThermalSensorStats {
+    let n = rows.len();
+    let mut min = f32::MAX;
+    let mut max = f32::MIN;
+    let mut sum = 0.0f64;
+    let mut peak_ts = rows[0].ts;
+    for r in rows {
+        min = min.min(r.value);
+        if r.value > max {
+            max = r.value;
+            peak_ts = r.ts;
+        }
+        sum += r.value as f64;
+    }
+
+    // Least-squares slope in value per minute
+    let t0 = rows[0].ts;
+    let xs: Vec<f64> = rows.iter().map(|r| (r.ts - t0) as f64 / 60_000.0).collect();
+    let mean_x = xs.iter().sum::<f64>() / n as f64;
+    let mean_y = sum / n as f64;
+    let (mut sxy, mut sxx) = (0.0f64, 0.0f64);
+    for (x, r) in xs.iter().zip(rows) {
+        sxy += (x - mean_x) * (r.value as f64 - mean_y);
+        sxx += (x - mean_x).powi(2);
+    }
+    let trend_per_min = (sxx > 0.0).then(|| (sxy / sxx) as f32);
+
+    // Steepest rise between each sample and the first one a full span later
+    let mut best: Option<(f32, i64)> = None;
+    let mut j = 0;
+    for (i, start) in rows.iter().enumerate() {
+        j = j.max(i + 1);
+        while j < n && rows[j].ts - start.ts < THERMAL_RISE_SPAN_MS {
+            j += 1;
+        }
+        if j >= n {
+            break;
+        }
+        let end = &rows[j];
+        let rate = (end.value - start.value) / ((end.ts - start.ts) as f32 / 60_000.0);
+        if best.is_none_or(|(b, _)| rate > b) {
+            best = Some((rate, end.ts));
+        }
+    }
+
+    ThermalSensorStats {
+        sensor: rows[0].sensor.clone(),
+        category: rows[0].category.clone(),
+        samples: n,
+        min,
+        avg: mean_y as f32,
+        max,
+        peak_ts,
+        trend_per_min,
+        max_rise_per_min: best.map(|(rate, _)| rate),
+        max_rise_ts: best.map(|(_, ts)| ts),
+    }
+}
+
 // ── /proc/net/tcp parser ──────────────────────────────────────────
 
 /// A parsed network socket from /proc/net/tcp.
@@ -1071,7 +1654,7 @@ fn now_epoch_ms() -> i64 {
 
 /// Format a duration in milliseconds as a human-readable age string.
 #[allow(dead_code)]
-fn format_age_ms(ms: i64) -> String {
+pub(crate) fn format_age_ms(ms: i64) -> String {
     let secs = ms / 1000;
     if secs < 60 {
         format!("{}s ago", secs)
@@ -1104,6 +1687,10 @@ mod tests {
             start_time: 0,
             parent_pid: None,
             thread_count: None,
+            gpu_usage: 0.0,
+            gpu_memory_bytes: 0,
+            net_rx_bytes_per_sec: 0,
+            net_tx_bytes_per_sec: 0,
         }
     }
 
@@ -1126,7 +1713,8 @@ mod tests {
             networks: vec![],
             disks: vec![],
             cpu_temp: None,
-            gpu: None,
+            gpus: vec![],
+            cgroups: vec![],
             battery: None,
         }
     }
@@ -1143,7 +1731,7 @@ mod tests {
     fn table_stats_empty() {
         let store = EventStore::open(None).unwrap();
         let stats = store.table_stats().unwrap();
-        assert_eq!(stats.len(), 4);
+        assert_eq!(stats.len(), 5);
         for (_, count) in &stats {
             assert_eq!(*count, 0);
         }
@@ -1175,6 +1763,48 @@ mod tests {
         assert_eq!(rows.len(), 0);
     }
 
+    #[test]
+    fn system_snapshot_navigation() {
+        let store = EventStore::open(None).unwrap();
+        assert_eq!(store.system_snapshot_bounds().unwrap(), None);
+        for (ts, cpu) in [(1000, 10.0), (2000, 20.0), (3000, 30.0)] {
+            store
+                .conn
+                .execute(
+                    "INSERT INTO system_snapshots (ts, cpu_global, mem_used, mem_total, swap_used, swap_total, load_1, load_5, load_15)
+                     VALUES (?1, ?2, 0, 0, 0, 0, 0, 0, 0)",
+                    params![ts, cpu],
+                )
+                .unwrap();
+            // Recorded a few ms after the system row of the same refresh
+            store
+                .conn
+                .execute(
+                    "INSERT INTO process_snapshots (ts, pid, name, cpu, mem_bytes, disk_read, disk_write, status, user)
+                     VALUES (?1, 7, 'app', ?2, 0, 0, 0, 'Running', 'test')",
+                    params![ts + 5, cpu],
+                )
+                .unwrap();
+        }
+
+        assert_eq!(store.system_snapshot_bounds().unwrap(), Some((1000, 3000)));
+        assert_eq!(store.next_system_snapshot_ts(1000).unwrap(), Some(2000));
+        assert_eq!(store.next_system_snapshot_ts(3000).unwrap(), None);
+        assert_eq!(store.prev_system_snapshot_ts(2500).unwrap(), Some(2000));
+        assert_eq!(store.prev_system_snapshot_ts(1000).unwrap(), None);
+
+        let rows = store.query_system_history_until(2500, 10).unwrap();
+        let ts: Vec<i64> = rows.iter().map(|r| r.ts).collect();
+        assert_eq!(ts, [1000, 2000]);
+        let rows = store.query_system_history_until(3000, 1).unwrap();
+        assert_eq!(rows[0].ts, 3000);
+
+        let procs = store.query_processes_before(3000, 10).unwrap();
+        assert_eq!(procs.len(), 1);
+        assert_eq!(procs[0].ts, 2005);
+        assert!(store.query_processes_before(1000, 10).unwrap().is_empty());
+    }
+
     // ── Process snapshots ─────────────────────────────────────────
 
     #[test]
@@ -1207,6 +1837,108 @@ mod tests {
         assert_eq!(proc_count, 1); // Not 2
     }
 
+    #[test]
+    fn process_snapshots_record_network_hogs() {
+        let mut store = EventStore::open(None).unwrap();
+        // Idle by CPU and memory, but outside the top lists only by traffic
+        let mut procs: Vec<ProcessInfo> = (1..=TOP_CPU_SNAPSHOT_COUNT as u32 + 1)
+            .map(|pid| make_process(pid, "worker", 50.0, 1 << 30))
+            .collect();
+        let mut sync = make_process(999, "rsync", 0.0, 0);
+        sync.net_rx_bytes_per_sec = 300;
+        sync.net_tx_bytes_per_sec = 40 << 20;
+        procs.push(sync);
+        store.insert_process_snapshots(&procs).unwrap();
+
+        let rows = store.query_process_history(999, 0).unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!((rows[0].net_rx, rows[0].net_tx), (300, 40 << 20));
+    }
+
+    #[test]
+    fn process_snapshots_migrate_network_columns() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("old.db");
+        {
+            let conn = Connection::open(&path).unwrap();
+            conn.execute_batch(
+                "CREATE TABLE process_snapshots (
+                    id INTEGER PRIMARY KEY, ts INTEGER NOT NULL, pid INTEGER NOT NULL,
+                    name TEXT NOT NULL, cpu REAL, mem_bytes INTEGER, disk_read INTEGER,
+                    disk_write INTEGER, status TEXT, user TEXT
+                );
+                INSERT INTO process_snapshots (ts, pid, name, cpu, mem_bytes, disk_read, disk_write, status, user)
+                VALUES (1, 7, 'old', 1.0, 10, 0, 0, 'Running', 'root');",
+            )
+            .unwrap();
+        }
+        let store = EventStore::open(Some(&path)).unwrap();
+        let rows = store.query_process_history(7, 0).unwrap();
+        assert_eq!((rows[0].net_rx, rows[0].net_tx), (0, 0));
+    }
+
+    // ── Thermal readings ──────────────────────────────────────────
+
+    fn reading(ts: i64, sensor: &str, value: f32) -> ThermalReadingRow {
+        ThermalReadingRow {
+            ts,
+            sensor: sensor.to_string(),
+            category: "cpu".to_string(),
+            value,
+        }
+    }
+
+    #[test]
+    fn insert_and_query_thermal_snapshot() {
+        let mut store = EventStore::open(None).unwrap();
+        let mut snap = ThermalSnapshot::empty();
+        snap.cpu_package = Some(72.0);
+        snap.gpu_temp = Some(65.0);
+        snap.fan_rpms.push(crate::thermal::SensorReading {
+            name: "CPU Fan".to_string(),
+            value: 1400.0,
+        });
+        store.insert_thermal_snapshot(&snap).unwrap();
+
+        let rows = store.query_thermal_history(None, 0).unwrap();
+        assert_eq!(rows.len(), 3);
+
+        let cpu = store.query_thermal_history(Some("cpu package"), 0).unwrap();
+        assert_eq!(cpu.len(), 1);
+        assert_eq!(cpu[0].category, "cpu");
+        assert!((cpu[0].value - 72.0).abs() < 0.01);
+
+        let stats = store.thermal_stats(0).unwrap();
+        assert_eq!(stats.len(), 3);
+        assert!(stats.iter().all(|s| s.samples == 1 && s.trend_per_min.is_none()));
+    }
+
+    #[test]
+    fn thermal_summary_min_avg_max_and_rise() {
+        // 60 → 70 over the first minute, then flat
+        let rows = vec![
+            reading(0, "CPU Package", 60.0),
+            reading(30_000, "CPU Package", 62.0),
+            reading(60_000, "CPU Package", 70.0),
+            reading(120_000, "CPU Package", 70.0),
+            reading(0, "Core #1", 50.0),
+        ];
+        let stats = summarize_thermal(&rows);
+        assert_eq!(stats.len(), 2);
+
+        let pkg = &stats[0];
+        assert_eq!(pkg.samples, 4);
+        assert_eq!((pkg.min, pkg.max), (60.0, 70.0));
+        assert!((pkg.avg - 65.5).abs() < 0.01);
+        assert_eq!(pkg.peak_ts, 60_000);
+        assert!(pkg.trend_per_min.unwrap() > 0.0);
+        assert!((pkg.max_rise_per_min.unwrap() - 10.0).abs() < 0.01);
+        assert_eq!(pkg.max_rise_ts, Some(60_000));
+
+        // A single sample spans less than a minute: no rate
+        assert_eq!(stats[1].max_rise_per_min, None);
+    }
+
     // ── Events ────────────────────────────────────────────────────
 
     #[test]
@@ -1235,6 +1967,44 @@ mod tests {
         assert_eq!(events.len(), 2);
     }
 
+    #[test]
+    fn suppressed_events_are_marked() {
+        let store = EventStore::open(None).unwrap();
+        store
+            .insert_event(EventKind::Alert, Some(1), Some("a"), None, Some("warning"))
+            .unwrap();
+        store
+            .insert_suppressed_event(EventKind::Alert, Some(2), Some("b"), None, Some("warning"))
+            .unwrap();
+
+        let events = store.query_events_by_kind(EventKind::Alert, 0).unwrap();
+        let suppressed: Vec<_> = events.iter().filter(|e| e.suppressed).collect();
+        assert_eq!(suppressed.len(), 1);
+        assert_eq!(suppressed[0].name.as_deref(), Some("b"));
+    }
+
+    #[test]
+    fn old_events_table_gains_suppressed_column() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sentinel.db");
+        {
+            let conn = Connection::open(&path).unwrap();
+            conn.execute_batch(
+                "CREATE TABLE events (
+                    id INTEGER PRIMARY KEY, ts INTEGER NOT NULL, kind TEXT NOT NULL,
+                    pid INTEGER, name TEXT, detail TEXT, severity TEXT
+                );
+                INSERT INTO events (ts, kind) VALUES (1, 'alert');",
+            )
+            .unwrap();
+        }
+
+        let store = EventStore::open(Some(&path)).unwrap();
+        let events = store.query_events_since(0).unwrap();
+        assert_eq!(events.len(), 1);
+        assert!(!events[0].suppressed);
+    }
+
     #[test]
     fn query_events_by_kind() {
         let store = EventStore::open(None).unwrap();
@@ -1259,6 +2029,35 @@ mod tests {
         assert_eq!(exits.len(), 1);
     }
 
+    // ── Network sockets ───────────────────────────────────────────
+
+    #[test]
+    fn process_sockets_from_latest_snapshot() {
+        let store = EventStore::open(None).unwrap();
+        let insert = |ts: i64, pid: u32, port: u16, state: &str| {
+            store
+                .conn
+                .execute(
+                    "INSERT INTO network_sockets (ts, pid, name, protocol, local_addr, local_port, remote_addr, remote_port, state)
+                     VALUES (?1, ?2, 'app', 'tcp', '0.0.0.0', ?3, NULL, NULL, ?4)",
+                    params![ts, pid, port, state],
+                )
+                .unwrap();
+        };
+        insert(1000, 42, 9000, "LISTEN");
+        insert(2000, 42, 51000, "ESTABLISHED");
+        insert(2000, 42, 8080, "LISTEN");
+        insert(2000, 7, 22, "LISTEN");
+
+        let rows = store.query_process_sockets(42).unwrap();
+        let ports: Vec<(u16, &str)> = rows
+            .iter()
+            .map(|r| (r.local_port, r.state.as_str()))
+            .collect();
+        assert_eq!(ports, [(8080, "LISTEN"), (51000, "ESTABLISHED")]);
+        assert!(store.query_process_sockets(99).unwrap().is_empty());
+    }
+
     // ── Process lifecycle detection ───────────────────────────────
 
     #[test]
@@ -1315,6 +2114,20 @@ mod tests {
         assert_eq!(events.len(), 0);
     }
 
+    #[test]
+    fn cleanup_removes_old_thermal_readings() {
+        let mut store = EventStore::open(None).unwrap();
+        store.set_retention(0);
+        let mut snap = ThermalSnapshot::empty();
+        snap.cpu_package = Some(60.0);
+        store.insert_thermal_snapshot(&snap).unwrap();
+
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        store.cleanup().unwrap();
+
+        assert!(store.query_thermal_history(None, 0).unwrap().is_empty());
+    }
+
     // ── Event counts ──────────────────────────────────────────────
 
     #[test]
@@ -1345,6 +2158,8 @@ mod tests {
             EventKind::PortBind,
             EventKind::PortRelease,
             EventKind::Alert,
+            EventKind::AlertResolved,
+            EventKind::AlertAck,
             EventKind::CpuSpike,
             EventKind::MemorySpike,
             EventKind::OomKill,
@@ -1511,4 +2326,85 @@ mod tests {
         let favs = store.get_favorites().unwrap();
         assert!(favs.is_empty());
     }
+
+    // ── Silences ──────────────────────────────────────────────────
+
+    #[test]
+    fn silences_persist_until_expiry() {
+        let store = EventStore::open(None).unwrap();
+        let now = now_epoch_ms();
+        let id = store
+            .insert_silence(now + 60_000, Some("HighCpu"), Some("make*"), None, None)
+            .unwrap();
+        store
+            .insert_silence(now - 1, None, None, Some("warning"), None)
+            .unwrap();
+
+        let active = store.active_silences().unwrap();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].id, id);
+        assert_eq!(active[0].process.as_deref(), Some("make*"));
+
+        assert!(store.remove_silence(id).unwrap());
+        assert!(!store.remove_silence(id).unwrap());
+        assert!(store.active_silences().unwrap().is_empty());
+    }
+
+    #[test]
+    fn escalations_upsert_and_expire() {
+        let store = EventStore::open(None).unwrap();
+        let now = now_epoch_ms();
+        let mut row = EscalationRow {
+            policy: "thermal".to_string(),
+            category: "ThermalCritical".to_string(),
+            subject: "CPU".to_string(),
+            started_ts: now - 60_000,
+            last_seen_ts: now,
+            steps_fired: 1,
+        };
+        store.upsert_escalation(&row).unwrap();
+        row.steps_fired = 2;
+        store.upsert_escalation(&row).unwrap();
+        assert_eq!(store.escalations().unwrap(), vec![row.clone()]);
+
+        // Rows not touched within the grace period are purged
+        let stale = EscalationRow {
+            subject: "GPU".to_string(),
+            last_seen_ts: now - (ESCALATION_STATE_GRACE_SECS as i64 + 1) * 1000,
+            ..row.clone()
+        };
+        store.upsert_escalation(&stale).unwrap();
+        store.cleanup().unwrap();
+        assert_eq!(store.escalations().unwrap(), vec![row.clone()]);
+
+        store
+            .remove_escalation(&row.policy, &row.category, &row.subject)
+            .unwrap();
+        assert!(store.escalations().unwrap().is_empty());
+    }
+
+    #[test]
+    fn open_read_only_reads_but_refuses_writes() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sentinel.db");
+        {
+            let store = EventStore::open(Some(&path)).unwrap();
+            store
+                .insert_event(EventKind::Alert, Some(42), Some("worker"), None, Some("warning"))
+                .unwrap();
+        }
+
+        let ro = EventStore::open_read_only(&path).unwrap();
+        let events = ro.query_events_since(0).unwrap();
+        assert_eq!(events.len(), 1);
+        assert!(ro
+            .insert_event(EventKind::Alert, Some(1), Some("x"), None, None)
+            .is_err());
+    }
+
+    #[test]
+    fn open_read_only_missing_file_fails() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(EventStore::open_read_only(&dir.path().join("nope.db")).is_err());
+    }
 }
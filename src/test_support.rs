@@ -0,0 +1,13 @@
+//! Helpers shared by unit tests.
+
+use std::fs;
+use std::path::Path;
+
+/// Write `files` (relative path, content) under `root`.
+pub fn write_tree(root: &Path, files: &[(&str, &str)]) {
+    for (path, content) in files {
+        let path = root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, format!("{}\n", content)).unwrap();
+    }
+}
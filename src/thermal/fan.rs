@@ -0,0 +1,715 @@
+//! Opt-in fan control: temperature → duty curves written to sysfs `pwm*`.
+//!
+//! Each `[[thermal.fan_curves]]` entry drives one PWM output from one
+//! temperature. A rising temperature raises the duty right away; the duty is
+//! only lowered again once the temperature has fallen `hysteresis` degrees
+//! below the reading that set it, so fans don't hunt around a curve point.
+//! If the input can't be read (sensor gone, poll failed) the output is driven
+//! to full speed until it comes back.
+//!
+//! OFF by default — double-gated like auto-shutdown: `fan_control_enabled`
+//! in config + `SENTINEL_FAN_CONTROL=true` in .env. An output is switched to
+//! manual mode (`pwm*_enable = 1`) on the first write and handed back to its
+//! previous mode when the controller is dropped.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config::FanCurveConfig;
+use crate::constants::{
+    DEFAULT_FAN_CURVE, DEFAULT_FAN_HYSTERESIS_C, ENV_FAN_CONTROL, FAN_FALLBACK_DUTY_PCT,
+};
+use crate::utils::read_trimmed;
+
+use super::sysfs::{indexed_files, sorted_entries, SYSFS_ROOT};
+use super::{natural_sort_key, ThermalSnapshot};
+
+/// Sensor names the curve editor cycles through.
+pub const FAN_SENSOR_CHOICES: &[&str] = &["cpu", "gpu", "ssd", "ram", "board", "max"];
+
+/// Which temperature a curve follows.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FanSensor {
+    Cpu,
+    Gpu,
+    Storage,
+    Memory,
+    Board,
+    Max,
+    /// A single reading, matched by name (e.g. "Core 0", "SYSTIN").
+    Named(String),
+}
+
+impl FanSensor {
+    pub fn parse(s: &str) -> Self {
+        match s.trim().to_lowercase().as_str() {
+            "cpu" => Self::Cpu,
+            "gpu" => Self::Gpu,
+            "ssd" | "storage" => Self::Storage,
+            "ram" | "memory" => Self::Memory,
+            "board" | "motherboard" => Self::Board,
+            "max" => Self::Max,
+            _ => Self::Named(s.trim().to_string()),
+        }
+    }
+
+    /// Current reading, or `None` if the snapshot doesn't have it.
+    pub fn read(&self, snap: &ThermalSnapshot) -> Option<f32> {
+        let positive = |v: f32| (v > 0.0).then_some(v);
+        match self {
+            Self::Cpu => snap.cpu_package.or_else(|| positive(snap.max_cpu_temp)),
+            Self::Gpu => positive(snap.max_gpu_temp),
+            Self::Storage => positive(snap.max_ssd_temp),
+            Self::Memory => positive(snap.max_ram_temp),
+            Self::Board => positive(snap.max_motherboard_temp),
+            Self::Max => positive(snap.max_temp),
+            Self::Named(name) => snap
+                .cpu_cores
+                .iter()
+                .chain(&snap.ssd_temps)
+                .chain(&snap.ram_temps)
+                .chain(&snap.motherboard_temps)
+                .find(|r| r.name.eq_ignore_ascii_case(name))
+                .map(|r| r.value),
+        }
+    }
+}
+
+// ── Curves ────────────────────────────────────────────────────────
+
+/// A validated fan curve.
+#[derive(Debug, Clone)]
+pub struct FanCurve {
+    pub name: String,
+    /// PWM output as configured (`nct6798/pwm2`, `/sys/class/hwmon/hwmon3/pwm2`).
+    pub pwm: String,
+    pub sensor: FanSensor,
+    /// `(°C, duty %)` in strictly rising temperature order.
+    pub points: Vec<(f32, f32)>,
+    pub hysteresis: f32,
+}
+
+impl FanCurve {
+    /// Validate a config entry.
+    pub fn compile(config: &FanCurveConfig) -> Result<Self, String> {
+        if config.name.trim().is_empty() {
+            return Err("missing name".to_string());
+        }
+        let file = Path::new(&config.pwm)
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("");
+        let relative_ok = config.pwm.split('/').count() == 2;
+        if !is_pwm_file(file) || !(config.pwm.starts_with('/') || relative_ok) {
+            return Err(format!(
+                "pwm '{}' is not <chip>/pwm<N> or an absolute pwm path",
+                config.pwm
+            ));
+        }
+        if config.points.is_empty() {
+            return Err("no points".to_string());
+        }
+        let mut points: Vec<(f32, f32)> = Vec::with_capacity(config.points.len());
+        for &[temp, duty] in &config.points {
+            if !temp.is_finite() || !(0.0..=100.0).contains(&duty) {
+                return Err(format!("invalid point [{}, {}]", temp, duty));
+            }
+            if let Some(&(prev, _)) = points.last().filter(|&&(prev, _)| temp <= prev) {
+                return Err(format!(
+                    "point temperatures must rise ({} after {})",
+                    temp, prev
+                ));
+            }
+            points.push((temp, duty));
+        }
+        if config.hysteresis.is_nan() || config.hysteresis < 0.0 {
+            return Err(format!("invalid hysteresis {}", config.hysteresis));
+        }
+        Ok(Self {
+            name: config.name.trim().to_string(),
+            pwm: config.pwm.clone(),
+            sensor: FanSensor::parse(&config.sensor),
+            points,
+            hysteresis: config.hysteresis,
+        })
+    }
+
+    /// Duty (%) at `temp`: linear between points, flat past either end.
+    pub fn duty_at(&self, temp: f32) -> f32 {
+        let (first, last) = (self.points[0], self.points[self.points.len() - 1]);
+        if temp <= first.0 {
+            return first.1;
+        }
+        for pair in self.points.windows(2) {
+            let ((t0, d0), (t1, d1)) = (pair[0], pair[1]);
+            if temp <= t1 {
+                return d0 + (d1 - d0) * (temp - t0) / (t1 - t0);
+            }
+        }
+        last.1
+    }
+
+    /// Duty to apply at `temp`, given the applied `(duty, temp that set it)`,
+    /// or `None` to hold the current duty.
+    fn next_duty(&self, temp: f32, applied: Option<(f32, f32)>) -> Option<f32> {
+        let want = self.duty_at(temp);
+        match applied {
+            None => Some(want),
+            Some((duty, _)) if want > duty => Some(want),
+            Some((duty, anchor)) if want < duty && temp <= anchor - self.hysteresis => Some(want),
+            Some(_) => None,
+        }
+    }
+}
+
+// ── Controller ────────────────────────────────────────────────────
+
+/// One curve bound to its PWM output.
+#[derive(Debug)]
+pub struct FanChannel {
+    pub curve: FanCurve,
+    /// Resolved `pwm*` file (None until the output is found).
+    path: Option<PathBuf>,
+    /// `pwm*_enable` before we took over, restored on release.
+    saved_enable: Option<String>,
+    /// Whether the output has been switched to manual mode.
+    manual: bool,
+    /// Applied duty and the temperature that set it (hysteresis anchor).
+    applied: Option<(f32, f32)>,
+    /// Last written duty (%).
+    pub duty: Option<f32>,
+    /// Last input temperature.
+    pub temp: Option<f32>,
+    /// Running at full speed because the input can't be read.
+    pub fallback: bool,
+    /// Last write error, if any.
+    pub error: Option<String>,
+}
+
+impl FanChannel {
+    fn new(curve: FanCurve) -> Self {
+        Self {
+            curve,
+            path: None,
+            saved_enable: None,
+            manual: false,
+            applied: None,
+            duty: None,
+            temp: None,
+            fallback: false,
+            error: None,
+        }
+    }
+
+    /// Write `duty` (%), taking manual control of the output first.
+    fn write(&mut self, duty: f32) -> Result<(), String> {
+        let path = self
+            .path
+            .clone()
+            .ok_or_else(|| format!("{} not found", self.curve.pwm))?;
+        if !self.manual {
+            let enable = enable_path(&path);
+            if enable.exists() {
+                self.saved_enable = read_trimmed(&enable);
+                fs::write(&enable, "1").map_err(|e| format!("{}: {}", enable.display(), e))?;
+            }
+            self.manual = true;
+        }
+        let raw = duty_to_pwm(duty);
+        if self.duty.map(duty_to_pwm) != Some(raw) {
+            fs::write(&path, raw.to_string()).map_err(|e| format!("{}: {}", path.display(), e))?;
+        }
+        self.duty = Some(duty);
+        Ok(())
+    }
+
+    /// Hand the output back to its previous mode (full speed if it had none).
+    fn release(&mut self) {
+        if !self.manual {
+            return;
+        }
+        if let Some(ref path) = self.path {
+            let _ = match self.saved_enable.take() {
+                Some(mode) => fs::write(enable_path(path), mode),
+                None => fs::write(path, "255"),
+            };
+        }
+        self.manual = false;
+        self.applied = None;
+        self.duty = None;
+    }
+}
+
+/// Drives every configured curve's PWM output.
+pub struct FanController {
+    /// Whether fan control is enabled (config + .env double-gate).
+    enabled: bool,
+    root: PathBuf,
+    channels: Vec<FanChannel>,
+}
+
+impl Default for FanController {
+    fn default() -> Self {
+        Self::with_root(false, &[], SYSFS_ROOT)
+    }
+}
+
+impl FanController {
+    /// Create a controller from config + .env settings.
+    pub fn new(config_enabled: bool, curves: &[FanCurveConfig]) -> Self {
+        // Double-gate: config must enable it AND .env must have the flag
+        let env_enabled = std::env::var(ENV_FAN_CONTROL)
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+        Self::with_root(config_enabled && env_enabled, curves, SYSFS_ROOT)
+    }
+
+    fn with_root(enabled: bool, curves: &[FanCurveConfig], root: impl Into<PathBuf>) -> Self {
+        let mut controller = Self {
+            enabled,
+            root: root.into(),
+            channels: Vec::new(),
+        };
+        controller.set_curves(curves);
+        controller
+    }
+
+    /// Whether PWM outputs are actually written.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn channels(&self) -> &[FanChannel] {
+        &self.channels
+    }
+
+    /// Replace the curves (invalid entries are skipped; config load warns).
+    pub fn set_curves(&mut self, curves: &[FanCurveConfig]) {
+        self.release();
+        self.channels = curves
+            .iter()
+            .filter_map(|c| FanCurve::compile(c).ok())
+            .map(FanChannel::new)
+            .collect();
+    }
+
+    /// PWM outputs available under this controller's sysfs root.
+    pub fn outputs(&self) -> Vec<String> {
+        available_outputs(&self.root)
+    }
+
+    /// Apply every curve to a new reading (`None` = the poll failed).
+    /// Returns status messages for fallback changes and write errors.
+    pub fn tick(&mut self, snapshot: Option<&ThermalSnapshot>) -> Vec<String> {
+        if !self.enabled {
+            return Vec::new();
+        }
+        let mut messages = Vec::new();
+        for ch in &mut self.channels {
+            if ch.path.is_none() {
+                ch.path = resolve_pwm(&self.root, &ch.curve.pwm);
+            }
+            ch.temp = snapshot.and_then(|s| ch.curve.sensor.read(s));
+
+            let duty = match ch.temp {
+                Some(temp) => {
+                    if ch.fallback {
+                        ch.fallback = false;
+                        ch.applied = None;
+                        messages.push(format!(
+                            "Fan '{}': sensor back, following curve",
+                            ch.curve.name
+                        ));
+                    }
+                    match ch.curve.next_duty(temp, ch.applied) {
+                        Some(duty) => {
+                            ch.applied = Some((duty, temp));
+                            duty
+                        }
+                        None => continue,
+                    }
+                }
+                None => {
+                    if !ch.fallback {
+                        ch.fallback = true;
+                        messages.push(format!(
+                            "Fan '{}': sensor unavailable, {} at full speed",
+                            ch.curve.name, ch.curve.pwm
+                        ));
+                    }
+                    ch.applied = None;
+                    FAN_FALLBACK_DUTY_PCT
+                }
+            };
+
+            match ch.write(duty) {
+                Ok(()) => ch.error = None,
+                Err(e) => {
+                    // Retry on the next reading
+                    ch.applied = None;
+                    if ch.error.as_ref() != Some(&e) {
+                        messages.push(format!("Fan '{}': {}", ch.curve.name, e));
+                    }
+                    ch.error = Some(e);
+                }
+            }
+        }
+        messages
+    }
+
+    /// Hand every output back to its previous mode.
+    pub fn release(&mut self) {
+        for ch in &mut self.channels {
+            ch.release();
+        }
+    }
+}
+
+impl Drop for FanController {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+
+// ── Curve editor ──────────────────────────────────────────────────
+
+/// Working copy of the curves for the Thermal tab's curve editor.
+#[derive(Debug, Clone)]
+pub struct FanCurveEditor {
+    pub curves: Vec<FanCurveConfig>,
+    /// Selected curve.
+    pub curve: usize,
+    /// Selected point of that curve.
+    pub point: usize,
+    /// PWM outputs found in sysfs, cycled with `p`.
+    pub outputs: Vec<String>,
+}
+
+impl FanCurveEditor {
+    pub fn new(curves: &[FanCurveConfig], outputs: Vec<String>) -> Self {
+        Self {
+            curves: curves.to_vec(),
+            curve: 0,
+            point: 0,
+            outputs,
+        }
+    }
+
+    pub fn current(&self) -> Option<&FanCurveConfig> {
+        self.curves.get(self.curve)
+    }
+
+    fn current_mut(&mut self) -> Option<&mut FanCurveConfig> {
+        self.curves.get_mut(self.curve)
+    }
+
+    pub fn next_curve(&mut self) {
+        if !self.curves.is_empty() {
+            self.curve = (self.curve + 1) % self.curves.len();
+            self.point = 0;
+        }
+    }
+
+    pub fn select_point(&mut self, delta: isize) {
+        if let Some(len) = self.current().map(|c| c.points.len()) {
+            self.point = self
+                .point
+                .saturating_add_signed(delta)
+                .min(len.saturating_sub(1));
+        }
+    }
+
+    /// Move the selected point's temperature, staying between its neighbours.
+    pub fn nudge_temp(&mut self, delta: f32) {
+        let point = self.point;
+        if let Some(curve) = self.current_mut() {
+            let low = point
+                .checked_sub(1)
+                .map_or(0.0, |i| curve.points[i][0] + 1.0);
+            let high = curve.points.get(point + 1).map_or(150.0, |p| p[0] - 1.0);
+            if let Some(p) = curve.points.get_mut(point) {
+                p[0] = (p[0] + delta).clamp(low, high.max(low));
+            }
+        }
+    }
+
+    pub fn nudge_duty(&mut self, delta: f32) {
+        let point = self.point;
+        if let Some(p) = self.current_mut().and_then(|c| c.points.get_mut(point)) {
+            p[1] = (p[1] + delta).clamp(0.0, 100.0);
+        }
+    }
+
+    /// Insert a point after the selected one (midway to the next, or 5 °C past the end).
+    pub fn add_point(&mut self) {
+        let point = self.point;
+        let Some(curve) = self.current_mut() else {
+            return;
+        };
+        let Some(&[temp, duty]) = curve.points.get(point) else {
+            return;
+        };
+        let new = match curve.points.get(point + 1) {
+            Some(&[next_temp, next_duty]) if next_temp - temp >= 2.0 => [
+                ((temp + next_temp) / 2.0).round(),
+                ((duty + next_duty) / 2.0).round(),
+            ],
+            Some(_) => return,
+            None => [temp + 5.0, duty],
+        };
+        curve.points.insert(point + 1, new);
+        self.point = point + 1;
+    }
+
+    /// Remove the selected point (a curve keeps at least two).
+    pub fn remove_point(&mut self) {
+        let point = self.point;
+        if let Some(curve) = self.current_mut() {
+            if curve.points.len() > 2 && point < curve.points.len() {
+                curve.points.remove(point);
+            }
+        }
+        self.select_point(0);
+    }
+
+    /// Add a curve on the first output no other curve uses.
+    pub fn add_curve(&mut self) {
+        let pwm = self
+            .outputs
+            .iter()
+            .find(|o| !self.curves.iter().any(|c| &c.pwm == *o))
+            .or(self.outputs.first())
+            .cloned()
+            .unwrap_or_else(|| "hwmon0/pwm1".to_string());
+        self.curves.push(FanCurveConfig {
+            name: format!("fan{}", self.curves.len() + 1),
+            pwm,
+            sensor: FAN_SENSOR_CHOICES[0].to_string(),
+            points: DEFAULT_FAN_CURVE.to_vec(),
+            hysteresis: DEFAULT_FAN_HYSTERESIS_C,
+        });
+        self.curve = self.curves.len() - 1;
+        self.point = 0;
+    }
+
+    pub fn remove_curve(&mut self) {
+        if self.curve < self.curves.len() {
+            self.curves.remove(self.curve);
+            self.curve = self.curve.min(self.curves.len().saturating_sub(1));
+            self.point = 0;
+        }
+    }
+
+    /// Switch the selected curve to the next sensor choice.
+    pub fn cycle_sensor(&mut self) {
+        if let Some(curve) = self.current_mut() {
+            let next = FAN_SENSOR_CHOICES
+                .iter()
+                .position(|s| curve.sensor.eq_ignore_ascii_case(s))
+                .map_or(0, |i| (i + 1) % FAN_SENSOR_CHOICES.len());
+            curve.sensor = FAN_SENSOR_CHOICES[next].to_string();
+        }
+    }
+
+    /// Switch the selected curve to the next PWM output found in sysfs.
+    pub fn cycle_output(&mut self) {
+        let outputs = self.outputs.clone();
+        if outputs.is_empty() {
+            return;
+        }
+        if let Some(curve) = self.current_mut() {
+            let next = outputs
+                .iter()
+                .position(|o| *o == curve.pwm)
+                .map_or(0, |i| (i + 1) % outputs.len());
+            curve.pwm = outputs[next].clone();
+        }
+    }
+
+    /// Check every curve compiles.
+    pub fn validate(&self) -> Result<(), String> {
+        for curve in &self.curves {
+            FanCurve::compile(curve).map_err(|e| format!("curve '{}': {}", curve.name, e))?;
+        }
+        Ok(())
+    }
+}
+
+// ── sysfs helpers ─────────────────────────────────────────────────
+
+fn is_pwm_file(name: &str) -> bool {
+    name.strip_prefix("pwm")
+        .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+}
+
+fn enable_path(pwm: &Path) -> PathBuf {
+    let mut name = pwm.file_name().unwrap_or_default().to_os_string();
+    name.push("_enable");
+    pwm.with_file_name(name)
+}
+
+fn duty_to_pwm(duty: f32) -> u8 {
+    (duty.clamp(0.0, 100.0) / 100.0 * 255.0).round() as u8
+}
+
+/// Find the file behind `<chip>/pwm<N>` (chip = hwmon `name` or `hwmonN`).
+fn resolve_pwm(root: &Path, spec: &str) -> Option<PathBuf> {
+    let path = if spec.starts_with('/') {
+        PathBuf::from(spec)
+    } else {
+        let (chip, pwm) = spec.split_once('/')?;
+        sorted_entries(&root.join("class/hwmon"))
+            .into_iter()
+            .find(|dir| {
+                dir.file_name().is_some_and(|n| n == chip)
+                    || read_trimmed(&dir.join("name")).as_deref() == Some(chip)
+            })?
+            .join(pwm)
+    };
+    path.exists().then_some(path)
+}
+
+/// Every `<chip>/pwm<N>` output under `root`.
+fn available_outputs(root: &Path) -> Vec<String> {
+    let mut outputs = Vec::new();
+    for dir in sorted_entries(&root.join("class/hwmon")) {
+        let Some(chip) = read_trimmed(&dir.join("name")) else {
+            continue;
+        };
+        for (index, _) in indexed_files(&dir, "pwm", "") {
+            outputs.push(format!("{}/pwm{}", chip, index));
+        }
+    }
+    outputs.sort_by_key(|o| natural_sort_key(o));
+    outputs.dedup();
+    outputs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn curve_config(pwm: &str, sensor: &str) -> FanCurveConfig {
+        FanCurveConfig {
+            name: "case".to_string(),
+            pwm: pwm.to_string(),
+            sensor: sensor.to_string(),
+            points: vec![[40.0, 20.0], [60.0, 40.0], [80.0, 100.0]],
+            hysteresis: 3.0,
+        }
+    }
+
+    fn snapshot_with_cpu(temp: f32) -> ThermalSnapshot {
+        ThermalSnapshot {
+            cpu_package: Some(temp),
+            max_cpu_temp: temp,
+            max_temp: temp,
+            ..ThermalSnapshot::empty()
+        }
+    }
+
+    /// A fake hwmon chip with one PWM output in automatic mode.
+    fn fake_chip(root: &Path) -> PathBuf {
+        let chip = root.join("class/hwmon/hwmon2");
+        fs::create_dir_all(&chip).unwrap();
+        fs::write(chip.join("name"), "nct6798\n").unwrap();
+        fs::write(chip.join("pwm2"), "128\n").unwrap();
+        fs::write(chip.join("pwm2_enable"), "5\n").unwrap();
+        chip
+    }
+
+    fn read(path: PathBuf) -> String {
+        fs::read_to_string(path).unwrap().trim().to_string()
+    }
+
+    #[test]
+    fn duty_interpolates_and_clamps_to_the_ends() {
+        let curve = FanCurve::compile(&curve_config("nct6798/pwm2", "cpu")).unwrap();
+        assert_eq!(curve.duty_at(20.0), 20.0);
+        assert_eq!(curve.duty_at(50.0), 30.0);
+        assert_eq!(curve.duty_at(70.0), 70.0);
+        assert_eq!(curve.duty_at(95.0), 100.0);
+    }
+
+    #[test]
+    fn compile_rejects_bad_curves() {
+        let mut bad = curve_config("nct6798/fan2", "cpu");
+        assert!(FanCurve::compile(&bad).is_err());
+        bad.pwm = "nct6798/pwm2".to_string();
+        bad.points = vec![[60.0, 40.0], [50.0, 60.0]];
+        assert!(FanCurve::compile(&bad).is_err());
+        bad.points = vec![[60.0, 140.0]];
+        assert!(FanCurve::compile(&bad).is_err());
+        assert!(FanCurve::compile(&curve_config("/sys/class/hwmon/hwmon2/pwm2", "gpu")).is_ok());
+    }
+
+    #[test]
+    fn hysteresis_holds_duty_until_temperature_falls_far_enough() {
+        let curve = FanCurve::compile(&curve_config("nct6798/pwm2", "cpu")).unwrap();
+        let applied = Some((70.0, 70.0));
+        // Rising reacts immediately
+        assert_eq!(curve.next_duty(75.0, applied), Some(85.0));
+        // Small dips hold the current duty
+        assert_eq!(curve.next_duty(68.0, applied), None);
+        // Falling past the hysteresis band lowers it
+        assert_eq!(curve.next_duty(66.0, applied), Some(58.0));
+    }
+
+    #[test]
+    fn controller_writes_pwm_and_falls_back_to_full_speed() {
+        let dir = tempfile::tempdir().unwrap();
+        let chip = fake_chip(dir.path());
+        let mut fans =
+            FanController::with_root(true, &[curve_config("nct6798/pwm2", "cpu")], dir.path());
+
+        assert!(fans.tick(Some(&snapshot_with_cpu(50.0))).is_empty());
+        assert_eq!(read(chip.join("pwm2_enable")), "1");
+        assert_eq!(read(chip.join("pwm2")), "77"); // 30 %
+
+        // No reading: full speed, reported once
+        assert_eq!(fans.tick(None).len(), 1);
+        assert!(fans.tick(None).is_empty());
+        assert_eq!(read(chip.join("pwm2")), "255");
+        assert!(fans.channels()[0].fallback);
+
+        // Back on the curve, then released to the firmware's mode
+        assert_eq!(fans.tick(Some(&snapshot_with_cpu(50.0))).len(), 1);
+        assert_eq!(read(chip.join("pwm2")), "77");
+        drop(fans);
+        assert_eq!(read(chip.join("pwm2_enable")), "5");
+    }
+
+    #[test]
+    fn disabled_controller_never_writes() {
+        let dir = tempfile::tempdir().unwrap();
+        let chip = fake_chip(dir.path());
+        let mut fans =
+            FanController::with_root(false, &[curve_config("hwmon2/pwm2", "cpu")], dir.path());
+        fans.tick(None);
+        assert_eq!(read(chip.join("pwm2")), "128");
+        assert_eq!(read(chip.join("pwm2_enable")), "5");
+        assert_eq!(fans.outputs(), vec!["nct6798/pwm2".to_string()]);
+    }
+
+    #[test]
+    fn editor_keeps_points_ordered() {
+        let mut editor = FanCurveEditor::new(
+            &[curve_config("nct6798/pwm2", "cpu")],
+            vec!["nct6798/pwm2".into()],
+        );
+        editor.nudge_temp(50.0);
+        assert_eq!(editor.curves[0].points[0][0], 59.0);
+        editor.nudge_temp(-9.0);
+        editor.add_point();
+        assert_eq!(editor.point, 1);
+        assert_eq!(editor.curves[0].points[1], [55.0, 30.0]);
+        editor.nudge_duty(80.0);
+        assert_eq!(editor.curves[0].points[1][1], 100.0);
+        editor.remove_point();
+        editor.remove_point();
+        assert_eq!(editor.curves[0].points.len(), 2);
+        editor.cycle_sensor();
+        assert_eq!(editor.curves[0].sensor, "gpu");
+        assert!(editor.validate().is_ok());
+    }
+}
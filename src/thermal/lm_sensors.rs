@@ -0,0 +1,221 @@
+//! lm-sensors backend: `sensors -j` output mapped into a [`ThermalSnapshot`].
+//!
+//! `sensors -j` prints one object per chip (`coretemp-isa-0000`,
+//! `nvme-pci-0100`), each holding features keyed by their configured label
+//! (`Package id 0`, `CPUTIN`, `CPU Fan`) with subfeatures such as
+//! `temp1_input` or `fan2_input`. Labels, offsets and ignores from
+//! `/etc/sensors.d` are already applied by the time we see them.
+//!
+//! Each chip name becomes the hardware path and each feature a sensor, so
+//! classification, noise filtering and ordering are shared with the LHM
+//! parser.
+
+use std::path::Path;
+use std::time::Duration;
+
+use serde_json::{Map, Value};
+
+use crate::constants::{LM_SENSORS_TIMEOUT_SECS, SYSFS_MAX_PLAUSIBLE_TEMP_C};
+
+use super::{is_noise_sensor, natural_sort_key, snapshot_from_sensors};
+use super::{ParsedSensor, ThermalSnapshot};
+
+/// Read `sensors -j` output from `file`, or run `sensors -j` when `None`.
+pub async fn read_output(file: Option<&Path>) -> Option<String> {
+    if let Some(path) = file {
+        return tokio::fs::read_to_string(path).await.ok();
+    }
+    let output = tokio::time::timeout(
+        Duration::from_secs(LM_SENSORS_TIMEOUT_SECS),
+        tokio::process::Command::new("sensors")
+            .arg("-j")
+            .kill_on_drop(true)
+            .output(),
+    )
+    .await
+    .ok()?
+    .ok()?;
+    // sensors exits non-zero when a single chip fails but still prints the rest
+    String::from_utf8(output.stdout).ok()
+}
+
+/// Parse `sensors -j` JSON into a ThermalSnapshot. Returns `None` when no
+/// temperature or fan reading is found.
+pub fn parse_sensors_json(json_str: &str) -> Option<ThermalSnapshot> {
+    let root: Map<String, Value> = serde_json::from_str(json_str).ok()?;
+
+    let mut chips: Vec<(&String, &Map<String, Value>)> = root
+        .iter()
+        .filter_map(|(chip, features)| Some((chip, features.as_object()?)))
+        .collect();
+    chips.sort_by_key(|(chip, _)| natural_sort_key(chip));
+
+    let mut sensors = Vec::new();
+    for (chip, features) in chips {
+        // "Adapter" is a plain string; features are objects of subfeatures
+        let mut features: Vec<(&String, &Map<String, Value>)> = features
+            .iter()
+            .filter_map(|(label, sub)| Some((label, sub.as_object()?)))
+            .collect();
+        features.sort_by_key(|(label, _)| natural_sort_key(label));
+
+        for (label, subfeatures) in features {
+            if is_noise_sensor(label) {
+                continue;
+            }
+            let Some((kind, value)) = input_reading(subfeatures) else {
+                continue;
+            };
+            let category = match kind {
+                "temp" => {
+                    // Disconnected Super I/O inputs read 127 °C or -128 °C
+                    if value <= 0.0 || value >= SYSFS_MAX_PLAUSIBLE_TEMP_C {
+                        continue;
+                    }
+                    "Temperatures"
+                }
+                "fan" => {
+                    // Unpopulated headers read 0 RPM and keep their default label
+                    if value <= 0.0 && is_default_label(label, "fan") {
+                        continue;
+                    }
+                    "Fans"
+                }
+                _ => continue,
+            };
+            sensors.push(ParsedSensor {
+                hardware_path: vec![chip.clone()],
+                name: label.clone(),
+                value,
+                category: category.to_string(),
+            });
+        }
+    }
+
+    snapshot_from_sensors(&sensors)
+}
+
+/// The `<kind><n>_input` subfeature of a feature, e.g. `("temp", 45.0)`.
+fn input_reading(subfeatures: &Map<String, Value>) -> Option<(&'static str, f32)> {
+    subfeatures.iter().find_map(|(key, value)| {
+        let stem = key.strip_suffix("_input")?;
+        let kind = ["temp", "fan"]
+            .into_iter()
+            .find(|kind| is_default_label(stem, kind))?;
+        Some((kind, value.as_f64()? as f32))
+    })
+}
+
+/// Whether `label` is the unconfigured `<prefix><n>` name (`fan3`, `temp1`).
+fn is_default_label(label: &str, prefix: &str) -> bool {
+    label
+        .strip_prefix(prefix)
+        .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_sensors_json() -> &'static str {
+        r#"{
+            "coretemp-isa-0000": {
+                "Adapter": "ISA adapter",
+                "Package id 0": {"temp1_input": 61.000, "temp1_max": 100.000, "temp1_crit": 100.000},
+                "Core 10": {"temp12_input": 58.000, "temp12_max": 100.000},
+                "Core 2": {"temp4_input": 55.000, "temp4_max": 100.000},
+                "Core 0": {"temp2_input": 57.000, "temp2_max": 100.000}
+            },
+            "amdgpu-pci-0300": {
+                "Adapter": "PCI adapter",
+                "vddgfx": {"in0_input": 0.806},
+                "edge": {"temp1_input": 52.000, "temp1_crit": 100.000},
+                "junction": {"temp2_input": 64.000},
+                "PPT": {"power1_average": 37.000}
+            },
+            "nvme-pci-0100": {
+                "Adapter": "PCI adapter",
+                "Composite": {"temp1_input": 41.850, "temp1_max": 81.850},
+                "Sensor 1": {"temp2_input": 41.850},
+                "Temperature Critical": {"temp3_input": 84.850}
+            },
+            "nct6798-isa-0290": {
+                "Adapter": "ISA adapter",
+                "SYSTIN": {"temp1_input": 36.000},
+                "CPUTIN": {"temp2_input": 70.500},
+                "AUXTIN3": {"temp6_input": 127.000},
+                "CPU Fan": {"fan2_input": 1180.000, "fan2_min": 0.000},
+                "fan3": {"fan3_input": 0.000, "fan3_min": 0.000},
+                "fan10": {"fan10_input": 640.000},
+                "fan9": {"fan9_input": 610.000}
+            },
+            "spd5118-i2c-0-51": {
+                "Adapter": "SMBus I801 adapter at efa0",
+                "temp1": {"temp1_input": 44.250}
+            }
+        }"#
+    }
+
+    #[test]
+    fn parses_sensors_json_into_sections() {
+        let snap = parse_sensors_json(sample_sensors_json()).unwrap();
+
+        assert_eq!(snap.cpu_package, Some(61.0));
+        let cores: Vec<&str> = snap.cpu_cores.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(cores, vec!["Core 0", "Core 2", "Core 10"]);
+        assert_eq!(snap.max_cpu_temp, 61.0);
+
+        assert_eq!(snap.gpu_temp, Some(52.0));
+        assert_eq!(snap.gpu_hotspot, Some(64.0));
+        assert_eq!(snap.max_gpu_temp, 64.0);
+
+        assert_eq!(snap.ssd_temps.len(), 2);
+        assert_eq!(snap.ssd_temps[0].name, "nvme-pci-0100: Composite");
+        assert_eq!(snap.max_ssd_temp, 41.85);
+
+        assert_eq!(snap.ram_temps.len(), 1);
+        assert_eq!(snap.max_ram_temp, 44.25);
+
+        assert_eq!(snap.max_temp, 64.0);
+    }
+
+    #[test]
+    fn sensors_json_filters_disconnected_and_socket_proxy() {
+        let snap = parse_sensors_json(sample_sensors_json()).unwrap();
+
+        let board: Vec<&str> = snap
+            .motherboard_temps
+            .iter()
+            .map(|t| t.name.as_str())
+            .collect();
+        // 127 °C disconnected input is dropped
+        assert!(!board.contains(&"AUXTIN3"));
+        assert!(board.contains(&"SYSTIN"));
+        // CPUTIN is a socket proxy: listed, but not counted
+        assert!(board.contains(&"CPUTIN (socket)"));
+        assert_eq!(snap.max_motherboard_temp, 36.0);
+    }
+
+    #[test]
+    fn sensors_json_drops_threshold_features() {
+        let snap = parse_sensors_json(sample_sensors_json()).unwrap();
+        assert!(snap.ssd_temps.iter().all(|t| !t.name.contains("Critical")));
+        assert!(snap.max_ssd_temp < 50.0);
+    }
+
+    #[test]
+    fn sensors_json_fans_skip_unpopulated_headers() {
+        let snap = parse_sensors_json(sample_sensors_json()).unwrap();
+        let fans: Vec<&str> = snap.fan_rpms.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(fans, vec!["CPU Fan", "fan9", "fan10"]);
+    }
+
+    #[test]
+    fn sensors_json_without_readings_returns_none() {
+        assert!(parse_sensors_json("{}").is_none());
+        assert!(parse_sensors_json("not json").is_none());
+        assert!(
+            parse_sensors_json(r#"{"acpi_fan-acpi-0": {"Adapter": "ACPI interface"}}"#).is_none()
+        );
+    }
+}
@@ -0,0 +1,687 @@
+//! Graduated thermal mitigation: throttle the machine before shutting it down.
+//!
+//! Once an emergency temperature has been sustained, the shutdown state
+//! machine walks the `[[thermal.mitigation]]` ladder one step at a time. Each
+//! step is held for its `hold`; the next one only runs if the temperature is
+//! still at emergency, and the shutdown grace period only starts once the
+//! ladder is exhausted. Steps act on the top CPU consumers (or containers),
+//! never on `mitigation_protected` names, PID 1, kernel threads or Sentinel
+//! itself:
+//!
+//! - `renice`: raise the nice value (never lowers it)
+//! - `cpu_quota`: move into a cgroup v2 group capped by `cpu.max`
+//! - `sigstop`: SIGSTOP
+//! - `docker_pause`: `docker pause` the busiest running containers
+//!
+//! Everything applied is undone when the temperature recovers, when the
+//! shutdown is aborted, and when the [`Mitigator`] is dropped.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use tokio::task::JoinHandle;
+
+use crate::alerts::rules::{format_duration, glob_match, parse_duration};
+use crate::config::{MitigationStepConfig, ThermalConfig};
+use crate::constants::{
+    CGROUP_CPU_PERIOD_US, CGROUP_ROOT, DEFAULT_MITIGATION_HOLD_SECS, DEFAULT_MITIGATION_NICE,
+    DEFAULT_MITIGATION_QUOTA_PCT, MITIGATION_CGROUP_NAME, MITIGATION_MIN_CPU_PCT, NICE_MAX,
+    NICE_MIN,
+};
+use crate::models::{ProcessInfo, ProcessStatus};
+use crate::monitor::{ContainerInfo, DockerMonitor};
+
+/// What a mitigation step does to its targets.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MitigationAction {
+    Renice {
+        nice: i32,
+    },
+    /// Cap the throttle group at `percent` of one CPU.
+    CpuQuota {
+        percent: f32,
+    },
+    Stop,
+    DockerPause,
+}
+
+impl MitigationAction {
+    fn parse(cfg: &MitigationStepConfig) -> Result<Self, String> {
+        match cfg.action.trim().to_lowercase().as_str() {
+            "renice" | "nice" => {
+                let nice = cfg.nice.unwrap_or(DEFAULT_MITIGATION_NICE);
+                if !(NICE_MIN..=NICE_MAX).contains(&nice) {
+                    return Err(format!(
+                        "nice {} out of range {}..{}",
+                        nice, NICE_MIN, NICE_MAX
+                    ));
+                }
+                Ok(Self::Renice { nice })
+            }
+            "cpu_quota" | "quota" => {
+                let percent = cfg.quota.unwrap_or(DEFAULT_MITIGATION_QUOTA_PCT);
+                if percent <= 0.0 {
+                    return Err(format!("quota must be above 0% (got {})", percent));
+                }
+                Ok(Self::CpuQuota { percent })
+            }
+            "sigstop" | "stop" => Ok(Self::Stop),
+            "docker_pause" | "pause" => Ok(Self::DockerPause),
+            other => Err(format!(
+                "unknown action '{}' (expected renice, cpu_quota, sigstop or docker_pause)",
+                other
+            )),
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Renice { nice } => format!("renice {:+}", nice),
+            Self::CpuQuota { percent } => format!("cpu quota {:.0}%", percent),
+            Self::Stop => "SIGSTOP".to_string(),
+            Self::DockerPause => "docker pause".to_string(),
+        }
+    }
+}
+
+/// A compiled `[[thermal.mitigation]]` step.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MitigationStep {
+    pub action: MitigationAction,
+    /// Processes (containers for `docker_pause`) acted on.
+    pub top: usize,
+    /// Seconds at emergency after this step before escalating.
+    pub hold_secs: u64,
+}
+
+impl MitigationStep {
+    pub fn compile(cfg: &MitigationStepConfig) -> Result<Self, String> {
+        if cfg.top == 0 {
+            return Err("top must be at least 1".to_string());
+        }
+        let hold_secs = match &cfg.hold {
+            Some(v) => parse_duration(v)?.as_secs(),
+            None => DEFAULT_MITIGATION_HOLD_SECS,
+        };
+        Ok(Self {
+            action: MitigationAction::parse(cfg)?,
+            top: cfg.top,
+            hold_secs,
+        })
+    }
+
+    /// One-line summary, e.g. `SIGSTOP top 3, hold 2m`.
+    pub fn describe(&self) -> String {
+        format!(
+            "{} top {}, hold {}",
+            self.action.describe(),
+            self.top,
+            format_duration(std::time::Duration::from_secs(self.hold_secs))
+        )
+    }
+}
+
+/// Something a step changed, with what's needed to undo it.
+#[derive(Debug, Clone, PartialEq)]
+enum Applied {
+    Reniced {
+        pid: u32,
+        name: String,
+        nice: i32,
+    },
+    /// Moved out of `cgroup` into the throttle group.
+    Throttled {
+        pid: u32,
+        name: String,
+        cgroup: PathBuf,
+    },
+    Stopped {
+        pid: u32,
+        name: String,
+    },
+    Paused {
+        id: String,
+        name: String,
+    },
+}
+
+impl Applied {
+    /// Whether this already covers `pid` for `action` (so a later step picks someone else).
+    fn covers(&self, action: &MitigationAction, target: u32) -> bool {
+        match (self, action) {
+            (Self::Reniced { pid, .. }, MitigationAction::Renice { .. })
+            | (Self::Throttled { pid, .. }, MitigationAction::CpuQuota { .. })
+            | (Self::Stopped { pid, .. }, MitigationAction::Stop) => *pid == target,
+            _ => false,
+        }
+    }
+}
+
+/// Applies ladder steps and remembers how to undo them.
+pub struct Mitigator {
+    steps: Vec<MitigationStep>,
+    /// Lowercased name globs that are never targeted.
+    protected: Vec<String>,
+    cgroup_root: PathBuf,
+    proc_root: PathBuf,
+    applied: Vec<Applied>,
+    /// In-flight Docker pause/unpause calls.
+    pending: Vec<JoinHandle<()>>,
+}
+
+impl Default for Mitigator {
+    fn default() -> Self {
+        Self {
+            steps: Vec::new(),
+            protected: Vec::new(),
+            cgroup_root: PathBuf::from(CGROUP_ROOT),
+            proc_root: PathBuf::from("/proc"),
+            applied: Vec::new(),
+            pending: Vec::new(),
+        }
+    }
+}
+
+impl Mitigator {
+    /// Compile the valid steps (invalid ones are reported by `Config::load`).
+    pub fn from_config(config: &ThermalConfig) -> Self {
+        let mut mitigator = Self::default();
+        mitigator.reconfigure(config);
+        mitigator
+    }
+
+    /// Swap in the configured ladder and protected names (config reload).
+    /// Changes already in effect are kept so `restore` still undoes them.
+    pub fn reconfigure(&mut self, config: &ThermalConfig) {
+        self.steps = config
+            .mitigation
+            .iter()
+            .filter_map(|s| MitigationStep::compile(s).ok())
+            .collect();
+        self.protected = config
+            .mitigation_protected
+            .iter()
+            .map(|p| p.trim().to_lowercase())
+            .collect();
+    }
+
+    /// Use a different cgroup mount and procfs (tests).
+    #[cfg(test)]
+    fn with_roots(mut self, cgroup_root: &Path, proc_root: &Path) -> Self {
+        self.cgroup_root = cgroup_root.to_path_buf();
+        self.proc_root = proc_root.to_path_buf();
+        self
+    }
+
+    pub fn steps(&self) -> &[MitigationStep] {
+        &self.steps
+    }
+
+    /// Hold time of each step, for [`super::shutdown::ShutdownManager::with_mitigation`].
+    pub fn hold_secs(&self) -> Vec<u64> {
+        self.steps.iter().map(|s| s.hold_secs).collect()
+    }
+
+    /// Number of changes currently in effect.
+    pub fn active_count(&self) -> usize {
+        self.applied.len()
+    }
+
+    fn is_protected(&self, name: &str) -> bool {
+        let name = name.to_lowercase();
+        self.protected.iter().any(|p| glob_match(p, &name))
+    }
+
+    /// Run ladder step `step` and describe what it did.
+    pub fn apply(
+        &mut self,
+        step: usize,
+        processes: &[ProcessInfo],
+        containers: &[ContainerInfo],
+    ) -> String {
+        let Some(s) = self.steps.get(step).cloned() else {
+            return format!("no mitigation step {}", step + 1);
+        };
+        let header = format!(
+            "step {}/{} {}",
+            step + 1,
+            self.steps.len(),
+            s.action.describe()
+        );
+
+        let done = match s.action {
+            MitigationAction::DockerPause => self.pause_containers(containers, s.top),
+            _ => {
+                let targets: Vec<(u32, String)> = self
+                    .targets(processes, s.top, &s.action)
+                    .into_iter()
+                    .map(|p| (p.pid, p.name.clone()))
+                    .collect();
+                let mut done = Vec::new();
+                for (pid, name) in targets {
+                    match self.apply_to_process(&s.action, pid, &name) {
+                        Ok(Some(applied)) => {
+                            done.push(format!("{} ({})", name, pid));
+                            self.applied.push(applied);
+                        }
+                        Ok(None) => {}
+                        Err(e) => done.push(format!("{} ({}) failed: {}", name, pid, e)),
+                    }
+                }
+                done
+            }
+        };
+
+        if done.is_empty() {
+            format!("{}: no eligible targets", header)
+        } else {
+            format!("{}: {}", header, done.join(", "))
+        }
+    }
+
+    /// Busiest eligible processes for `action`, highest CPU first.
+    fn targets<'a>(
+        &self,
+        processes: &'a [ProcessInfo],
+        top: usize,
+        action: &MitigationAction,
+    ) -> Vec<&'a ProcessInfo> {
+        let own_pid = std::process::id();
+        let mut candidates: Vec<&ProcessInfo> = processes
+            .iter()
+            .filter(|p| {
+                p.pid > 1
+                    && p.pid != own_pid
+                    // Kernel threads have no command line
+                    && !p.cmd.is_empty()
+                    && p.cpu_usage >= MITIGATION_MIN_CPU_PCT
+                    && !matches!(
+                        p.status,
+                        ProcessStatus::Stopped | ProcessStatus::Zombie | ProcessStatus::Dead
+                    )
+                    && !self.is_protected(&p.name)
+                    && !self.applied.iter().any(|a| a.covers(action, p.pid))
+            })
+            .collect();
+        candidates.sort_by(|a, b| b.cpu_usage.total_cmp(&a.cpu_usage));
+        candidates.truncate(top);
+        candidates
+    }
+
+    /// Apply a process action. `Ok(None)` means there was nothing to do.
+    fn apply_to_process(
+        &self,
+        action: &MitigationAction,
+        pid: u32,
+        name: &str,
+    ) -> Result<Option<Applied>, String> {
+        let name = name.to_string();
+        match *action {
+            MitigationAction::Renice { nice } => {
+                let current = read_nice(&self.proc_root, pid).unwrap_or(0);
+                if current >= nice {
+                    return Ok(None);
+                }
+                set_nice(pid, nice)?;
+                Ok(Some(Applied::Reniced {
+                    pid,
+                    name,
+                    nice: current,
+                }))
+            }
+            MitigationAction::CpuQuota { percent } => {
+                let cgroup = read_cgroup(&self.cgroup_root, &self.proc_root, pid)
+                    .ok_or_else(|| "no cgroup v2 membership".to_string())?;
+                let group = self.ensure_throttle_group(percent)?;
+                move_to_cgroup(&group, pid)?;
+                Ok(Some(Applied::Throttled { pid, name, cgroup }))
+            }
+            MitigationAction::Stop => {
+                send_signal(pid, libc::SIGSTOP)?;
+                Ok(Some(Applied::Stopped { pid, name }))
+            }
+            MitigationAction::DockerPause => Ok(None),
+        }
+    }
+
+    fn throttle_group(&self) -> PathBuf {
+        self.cgroup_root.join(MITIGATION_CGROUP_NAME)
+    }
+
+    /// Create the throttle cgroup (if needed) and set its `cpu.max`.
+    fn ensure_throttle_group(&self, percent: f32) -> Result<PathBuf, String> {
+        let group = self.throttle_group();
+        fs::create_dir_all(&group).map_err(|e| format!("create {}: {}", group.display(), e))?;
+        // The cpu controller may already be delegated; a failure here shows up on cpu.max
+        let _ = fs::write(self.cgroup_root.join("cgroup.subtree_control"), "+cpu");
+        let quota = ((percent / 100.0) * CGROUP_CPU_PERIOD_US as f32).max(1000.0) as u64;
+        fs::write(
+            group.join("cpu.max"),
+            format!("{} {}", quota, CGROUP_CPU_PERIOD_US),
+        )
+        .map_err(|e| format!("set cpu.max: {}", e))?;
+        Ok(group)
+    }
+
+    /// Pause the busiest running containers (the Docker calls run in the background).
+    fn pause_containers(&mut self, containers: &[ContainerInfo], top: usize) -> Vec<String> {
+        let mut candidates: Vec<&ContainerInfo> = containers
+            .iter()
+            .filter(|c| {
+                c.state == "running"
+                    && !self.is_protected(&c.name)
+                    && !self
+                        .applied
+                        .iter()
+                        .any(|a| matches!(a, Applied::Paused { id, .. } if *id == c.id))
+            })
+            .collect();
+        candidates.sort_by(|a, b| b.cpu_percent.total_cmp(&a.cpu_percent));
+        candidates.truncate(top);
+
+        let mut done = Vec::new();
+        for c in candidates {
+            let id = c.id.clone();
+            self.pending.push(tokio::spawn(async move {
+                let _ = DockerMonitor::new().pause_container(&id).await;
+            }));
+            done.push(format!("{} (container)", c.name));
+            self.applied.push(Applied::Paused {
+                id: c.id.clone(),
+                name: c.name.clone(),
+            });
+        }
+        done
+    }
+
+    /// Undo every change in effect, newest first. `None` when nothing was applied.
+    pub fn restore(&mut self) -> Option<String> {
+        if self.applied.is_empty() {
+            return None;
+        }
+        let applied = std::mem::take(&mut self.applied);
+        let total = applied.len();
+        let mut failed = Vec::new();
+        for change in applied.into_iter().rev() {
+            if let Err(e) = self.revert(&change) {
+                failed.push(e);
+            }
+        }
+        // Empty once every throttled process has moved back
+        let _ = fs::remove_dir(self.throttle_group());
+
+        let restored = total - failed.len();
+        Some(if failed.is_empty() {
+            format!("restored {} mitigation(s)", restored)
+        } else {
+            format!(
+                "restored {} mitigation(s); failed: {}",
+                restored,
+                failed.join(", ")
+            )
+        })
+    }
+
+    fn revert(&mut self, change: &Applied) -> Result<(), String> {
+        let result = match change {
+            Applied::Reniced { pid, nice, .. } => set_nice(*pid, *nice),
+            Applied::Throttled { pid, cgroup, .. } => move_to_cgroup(cgroup, *pid),
+            Applied::Stopped { pid, .. } => send_signal(*pid, libc::SIGCONT),
+            Applied::Paused { id, .. } => {
+                let Ok(handle) = tokio::runtime::Handle::try_current() else {
+                    return Err(format!("{} (container): no runtime to unpause", id));
+                };
+                let id = id.clone();
+                self.pending.push(handle.spawn(async move {
+                    let _ = DockerMonitor::new().unpause_container(&id).await;
+                }));
+                Ok(())
+            }
+        };
+        result.map_err(|e| match change {
+            Applied::Reniced { pid, name, .. }
+            | Applied::Throttled { pid, name, .. }
+            | Applied::Stopped { pid, name } => format!("{} ({}): {}", name, pid, e),
+            Applied::Paused { name, .. } => format!("{} (container): {}", name, e),
+        })
+    }
+
+    /// Wait for in-flight Docker pause/unpause calls (call before exiting).
+    pub async fn settle(&mut self) {
+        for handle in self.pending.drain(..) {
+            let _ = handle.await;
+        }
+    }
+}
+
+impl Drop for Mitigator {
+    fn drop(&mut self) {
+        self.restore();
+    }
+}
+
+/// Nice value from `/proc/<pid>/stat` (field 19).
+fn read_nice(proc_root: &Path, pid: u32) -> Option<i32> {
+    let stat = fs::read_to_string(proc_root.join(pid.to_string()).join("stat")).ok()?;
+    // comm may contain spaces and parentheses; fields resume after the last ')'
+    let rest = &stat[stat.rfind(')')? + 1..];
+    rest.split_whitespace().nth(16)?.parse().ok()
+}
+
+/// The process's cgroup v2 directory under `cgroup_root`.
+fn read_cgroup(cgroup_root: &Path, proc_root: &Path, pid: u32) -> Option<PathBuf> {
+    let content = fs::read_to_string(proc_root.join(pid.to_string()).join("cgroup")).ok()?;
+    let path = content.lines().find_map(|l| l.strip_prefix("0::"))?;
+    Some(cgroup_root.join(path.trim().trim_start_matches('/')))
+}
+
+/// Treat "no such process" as success: a target that exited needs no undo.
+fn os_result(ok: bool) -> Result<(), String> {
+    if ok {
+        return Ok(());
+    }
+    let err = std::io::Error::last_os_error();
+    if err.raw_os_error() == Some(libc::ESRCH) {
+        Ok(())
+    } else {
+        Err(err.to_string())
+    }
+}
+
+fn set_nice(pid: u32, nice: i32) -> Result<(), String> {
+    os_result(unsafe { libc::setpriority(libc::PRIO_PROCESS, pid, nice) } == 0)
+}
+
+fn send_signal(pid: u32, signal: i32) -> Result<(), String> {
+    os_result(unsafe { libc::kill(pid as i32, signal) } == 0)
+}
+
+fn move_to_cgroup(cgroup: &Path, pid: u32) -> Result<(), String> {
+    match fs::write(cgroup.join("cgroup.procs"), pid.to_string()) {
+        Err(e) if e.raw_os_error() != Some(libc::ESRCH) => Err(e.to_string()),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RuleValue;
+
+    fn step(action: &str) -> MitigationStepConfig {
+        MitigationStepConfig {
+            action: action.to_string(),
+            top: 2,
+            hold: None,
+            nice: None,
+            quota: None,
+        }
+    }
+
+    fn proc_info(pid: u32, name: &str, cpu: f32) -> ProcessInfo {
+        ProcessInfo {
+            pid,
+            name: name.to_string(),
+            cmd: format!("/usr/bin/{}", name),
+            cpu_usage: cpu,
+            memory_bytes: 0,
+            memory_percent: 0.0,
+            disk_read_bytes: 0,
+            disk_write_bytes: 0,
+            status: ProcessStatus::Running,
+            user: "user".to_string(),
+            start_time: 0,
+            parent_pid: Some(1),
+            thread_count: None,
+            gpu_usage: 0.0,
+            gpu_memory_bytes: 0,
+            net_rx_bytes_per_sec: 0,
+            net_tx_bytes_per_sec: 0,
+        }
+    }
+
+    fn mitigator(steps: &[MitigationStepConfig]) -> Mitigator {
+        Mitigator::from_config(&ThermalConfig {
+            mitigation: steps.to_vec(),
+            ..ThermalConfig::default()
+        })
+    }
+
+    #[test]
+    fn compile_applies_defaults_and_rejects_bad_steps() {
+        let renice = MitigationStep::compile(&step("renice")).unwrap();
+        assert_eq!(
+            renice.action,
+            MitigationAction::Renice {
+                nice: DEFAULT_MITIGATION_NICE
+            }
+        );
+        assert_eq!(renice.hold_secs, DEFAULT_MITIGATION_HOLD_SECS);
+
+        let mut quota = step("cpu_quota");
+        quota.quota = Some(25.0);
+        quota.hold = Some(RuleValue::Text("2m".into()));
+        let quota = MitigationStep::compile(&quota).unwrap();
+        assert_eq!(quota.describe(), "cpu quota 25% top 2, hold 2m");
+
+        assert!(MitigationStep::compile(&step("hibernate")).is_err());
+        let mut bad = step("renice");
+        bad.nice = Some(40);
+        assert!(MitigationStep::compile(&bad).is_err());
+        let mut bad = step("sigstop");
+        bad.top = 0;
+        assert!(MitigationStep::compile(&bad).is_err());
+    }
+
+    #[test]
+    fn targets_skip_protected_idle_and_already_mitigated() {
+        let mut m = mitigator(&[step("sigstop")]);
+        let processes = vec![
+            proc_info(1, "init", 90.0),
+            proc_info(std::process::id(), "sentinel", 80.0),
+            proc_info(100, "sshd", 70.0),
+            proc_info(200, "ffmpeg", 60.0),
+            proc_info(300, "idle", 1.0),
+            proc_info(400, "node", 40.0),
+            proc_info(500, "cargo", 50.0),
+        ];
+        let picked: Vec<u32> = m
+            .targets(&processes, 2, &MitigationAction::Stop)
+            .iter()
+            .map(|p| p.pid)
+            .collect();
+        assert_eq!(picked, vec![200, 500]);
+
+        m.applied.push(Applied::Stopped {
+            pid: 200,
+            name: "ffmpeg".into(),
+        });
+        let picked: Vec<u32> = m
+            .targets(&processes, 2, &MitigationAction::Stop)
+            .iter()
+            .map(|p| p.pid)
+            .collect();
+        assert_eq!(picked, vec![500, 400]);
+        // Renice is a different action: ffmpeg is still eligible
+        assert_eq!(
+            m.targets(&processes, 1, &MitigationAction::Renice { nice: 19 })[0].pid,
+            200
+        );
+        m.applied.clear();
+    }
+
+    #[test]
+    fn cpu_quota_moves_into_throttle_group_and_back() {
+        let dir = tempfile::tempdir().unwrap();
+        let (cgroup_root, proc_root) = (dir.path().join("cgroup"), dir.path().join("proc"));
+        let original = cgroup_root.join("user.slice/app.scope");
+        fs::create_dir_all(&original).unwrap();
+        fs::create_dir_all(proc_root.join("4242")).unwrap();
+        fs::write(proc_root.join("4242/cgroup"), "0::/user.slice/app.scope\n").unwrap();
+
+        let mut quota = step("cpu_quota");
+        quota.quota = Some(25.0);
+        let mut m = mitigator(&[quota]).with_roots(&cgroup_root, &proc_root);
+
+        let summary = m.apply(0, &[proc_info(4242, "blender", 95.0)], &[]);
+        assert_eq!(summary, "step 1/1 cpu quota 25%: blender (4242)");
+        let group = cgroup_root.join(MITIGATION_CGROUP_NAME);
+        assert_eq!(
+            fs::read_to_string(group.join("cpu.max")).unwrap(),
+            "25000 100000"
+        );
+        assert_eq!(
+            fs::read_to_string(group.join("cgroup.procs")).unwrap(),
+            "4242"
+        );
+        assert_eq!(m.active_count(), 1);
+
+        // A reload drops the ladder but still undoes what it applied
+        m.reconfigure(&ThermalConfig::default());
+        assert!(m.steps().is_empty());
+        assert_eq!(m.active_count(), 1);
+
+        assert_eq!(m.restore().as_deref(), Some("restored 1 mitigation(s)"));
+        assert_eq!(
+            fs::read_to_string(original.join("cgroup.procs")).unwrap(),
+            "4242"
+        );
+        assert_eq!(m.active_count(), 0);
+        assert!(m.restore().is_none());
+    }
+
+    #[test]
+    fn sigstop_is_undone_on_restore() {
+        let mut child = std::process::Command::new("sleep")
+            .arg("30")
+            .spawn()
+            .unwrap();
+        let pid = child.id();
+        let state = || {
+            let stat = fs::read_to_string(format!("/proc/{}/stat", pid)).unwrap();
+            stat[stat.rfind(')').unwrap() + 2..].chars().next().unwrap()
+        };
+
+        let mut m = mitigator(&[step("sigstop")]);
+        let summary = m.apply(0, &[proc_info(pid, "sleep", 50.0)], &[]);
+        assert_eq!(summary, format!("step 1/1 SIGSTOP: sleep ({})", pid));
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert_eq!(state(), 'T');
+
+        m.restore();
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert_ne!(state(), 'T');
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+
+    #[test]
+    fn apply_without_targets_says_so() {
+        let mut m = mitigator(&[step("renice")]);
+        assert_eq!(
+            m.apply(0, &[proc_info(200, "sshd", 99.0)], &[]),
+            "step 1/1 renice +19: no eligible targets"
+        );
+        assert_eq!(m.apply(3, &[], &[]), "no mitigation step 4");
+    }
+}
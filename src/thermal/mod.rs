@@ -1,20 +1,28 @@
-//! Thermal monitoring via LibreHardwareMonitor HTTP JSON API.
+//! Thermal monitoring via LibreHardwareMonitor HTTP JSON API or Linux sysfs.
 //!
 //! LHM exposes a tree of hardware → sub-hardware → sensors at `/data.json`.
 //! This module polls that endpoint, parses the JSON tree, and produces a
 //! [`ThermalSnapshot`] with structured temperature, fan, and voltage data.
+//! On bare-metal Linux, [`sysfs`] builds the same snapshot from hwmon chips
+//! and thermal zones; [`source`] picks the backend.
 //!
 //! Graceful fallback: if LHM is unreachable or the JSON format changes,
 //! `poll()` returns `None` and the rest of Sentinel continues normally.
 
+pub mod fan;
+pub mod lm_sensors;
+pub mod mitigation;
 pub mod shutdown;
+pub mod source;
+pub mod sysfs;
+pub mod thresholds;
 
 use std::time::Instant;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// A single sensor reading (temperature, fan RPM, etc.).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SensorReading {
     /// Human-readable name (e.g. "CPU Core #1", "GPU Hot Spot").
     pub name: String,
@@ -23,10 +31,11 @@ pub struct SensorReading {
 }
 
 /// Complete thermal snapshot from one LHM poll.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ThermalSnapshot {
-    /// When this snapshot was captured.
+    /// When this snapshot was captured (local clock; reset on deserialize).
     #[allow(dead_code)]
+    #[serde(skip, default = "Instant::now")]
     pub timestamp: Instant,
     /// CPU package temperature (if available).
     pub cpu_package: Option<f32>,
@@ -40,6 +49,9 @@ pub struct ThermalSnapshot {
     pub ssd_temps: Vec<SensorReading>,
     /// Fan speeds in RPM.
     pub fan_rpms: Vec<SensorReading>,
+    /// Fan PWM duty cycles in percent (sysfs `pwm*`).
+    #[serde(default)]
+    pub fan_pwm: Vec<SensorReading>,
     /// Motherboard / chipset temperatures.
     pub motherboard_temps: Vec<SensorReading>,
     /// RAM / DIMM module temperatures (#14).
@@ -59,6 +71,55 @@ pub struct ThermalSnapshot {
 }
 
 impl ThermalSnapshot {
+    /// A snapshot with no readings, to be filled in by a backend.
+    pub fn empty() -> Self {
+        Self {
+            timestamp: Instant::now(),
+            cpu_package: None,
+            cpu_cores: Vec::new(),
+            gpu_temp: None,
+            gpu_hotspot: None,
+            ssd_temps: Vec::new(),
+            fan_rpms: Vec::new(),
+            fan_pwm: Vec::new(),
+            motherboard_temps: Vec::new(),
+            ram_temps: Vec::new(),
+            max_temp: 0.0,
+            max_cpu_temp: 0.0,
+            max_gpu_temp: 0.0,
+            max_ram_temp: 0.0,
+            max_ssd_temp: 0.0,
+            max_motherboard_temp: 0.0,
+        }
+    }
+
+    /// Flatten every reading into `(category, sensor, value)` triples.
+    ///
+    /// Categories are `cpu`, `gpu`, `ssd`, `ram`, `board` (°C) and `fan` (RPM).
+    pub fn readings(&self) -> Vec<(&'static str, String, f32)> {
+        let mut out = Vec::new();
+        if let Some(t) = self.cpu_package {
+            out.push(("cpu", "CPU Package".to_string(), t));
+        }
+        out.extend(self.cpu_cores.iter().map(|r| ("cpu", r.name.clone(), r.value)));
+        if let Some(t) = self.gpu_temp {
+            out.push(("gpu", "GPU Core".to_string(), t));
+        }
+        if let Some(t) = self.gpu_hotspot {
+            out.push(("gpu", "GPU Hot Spot".to_string(), t));
+        }
+        let groups = [
+            ("ssd", &self.ssd_temps),
+            ("ram", &self.ram_temps),
+            ("board", &self.motherboard_temps),
+            ("fan", &self.fan_rpms),
+        ];
+        for (category, readings) in groups {
+            out.extend(readings.iter().map(|r| (category, r.name.clone(), r.value)));
+        }
+        out
+    }
+
     /// Returns the highest temperature across all sensors.
     #[allow(dead_code)]
     pub fn overall_max(&self) -> f32 {
@@ -127,6 +188,16 @@ impl LhmClient {
         }
     }
 
+    /// The polled endpoint.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Whether requests carry basic auth.
+    pub fn has_auth(&self) -> bool {
+        self.auth.is_some()
+    }
+
     /// Poll LHM and return a thermal snapshot, or None if unreachable / parse error.
     pub async fn poll(&self) -> Option<ThermalSnapshot> {
         let mut req = self.client.get(&self.url);
@@ -234,29 +305,19 @@ pub fn parse_lhm_json(json_str: &str) -> Option<ThermalSnapshot> {
     let mut sensors = Vec::new();
     collect_sensors(&root, &mut Vec::new(), &mut String::new(), &mut sensors);
 
+    snapshot_from_sensors(&sensors)
+}
+
+/// Classify flattened sensors into a ThermalSnapshot (shared with the
+/// lm-sensors backend). Returns None when there are no sensors.
+fn snapshot_from_sensors(sensors: &[ParsedSensor]) -> Option<ThermalSnapshot> {
     if sensors.is_empty() {
         return None;
     }
 
-    let mut snapshot = ThermalSnapshot {
-        timestamp: Instant::now(),
-        cpu_package: None,
-        cpu_cores: Vec::new(),
-        gpu_temp: None,
-        gpu_hotspot: None,
-        ssd_temps: Vec::new(),
-        fan_rpms: Vec::new(),
-        motherboard_temps: Vec::new(),
-        ram_temps: Vec::new(),
-        max_temp: 0.0,
-        max_cpu_temp: 0.0,
-        max_gpu_temp: 0.0,
-        max_ram_temp: 0.0,
-        max_ssd_temp: 0.0,
-        max_motherboard_temp: 0.0,
-    };
-
-    for sensor in &sensors {
+    let mut snapshot = ThermalSnapshot::empty();
+
+    for sensor in sensors {
         let name_lower = sensor.name.to_lowercase();
         let path_str = sensor.hardware_path.join(" ").to_lowercase();
 
@@ -267,7 +328,12 @@ pub fn parse_lhm_json(json_str: &str) -> Option<ThermalSnapshot> {
                     if sensor.value > snapshot.max_temp {
                         snapshot.max_temp = sensor.value;
                     }
-                    if name_lower.contains("package") || name_lower.contains("cpu total") {
+                    // Tctl/Tdie: AMD die temperature as reported by k10temp
+                    if name_lower.contains("package")
+                        || name_lower.contains("cpu total")
+                        || name_lower == "tctl"
+                        || name_lower == "tdie"
+                    {
                         snapshot.cpu_package = Some(sensor.value);
                         if sensor.value > snapshot.max_cpu_temp {
                             snapshot.max_cpu_temp = sensor.value;
@@ -291,9 +357,16 @@ pub fn parse_lhm_json(json_str: &str) -> Option<ThermalSnapshot> {
                     if sensor.value > snapshot.max_temp {
                         snapshot.max_temp = sensor.value;
                     }
-                    if name_lower.contains("hot spot") || name_lower.contains("hotspot") {
+                    // amdgpu (lm-sensors) labels these "junction" and "edge"
+                    if name_lower.contains("hot spot")
+                        || name_lower.contains("hotspot")
+                        || name_lower == "junction"
+                    {
                         snapshot.gpu_hotspot = Some(sensor.value);
-                    } else if name_lower.contains("gpu") || name_lower.contains("temperature") {
+                    } else if name_lower.contains("gpu")
+                        || name_lower.contains("temperature")
+                        || name_lower == "edge"
+                    {
                         snapshot.gpu_temp = Some(sensor.value);
                     }
                     if sensor.value > snapshot.max_gpu_temp {
@@ -488,12 +561,18 @@ fn is_motherboard_cpu_sensor(name: &str) -> bool {
         || lower == "cpu temperature"
         || lower == "cpu (peci)"
         || lower == "cpu peci"
+        // Nuvoton's raw input name when lm-sensors has no label for it
+        || lower == "cputin"
 }
 
 // ── Hardware classification ──────────────────────────────────────
 
 fn is_cpu_hardware(path: &str) -> bool {
     path.contains("cpu")
+        // lm-sensors chip names
+        || path.contains("coretemp")
+        || path.contains("k10temp")
+        || path.contains("zenpower")
         || path.contains("intel core")
         || path.contains("amd ryzen")
         || path.contains("processor")
@@ -506,6 +585,7 @@ fn is_gpu_hardware(path: &str) -> bool {
         || path.contains("radeon")
         || path.contains("amd rx")
         || path.contains("intel arc")
+        || path.contains("nouveau")
 }
 
 fn is_storage_hardware(path: &str) -> bool {
@@ -517,6 +597,7 @@ fn is_storage_hardware(path: &str) -> bool {
         || path.contains("crucial")
         || path.contains("kingston")
         || path.contains("hynix")
+        || path.contains("drivetemp")
 }
 
 /// Returns true if the hardware path indicates a RAM/DIMM module.
@@ -528,6 +609,8 @@ fn is_memory_hardware(path: &str) -> bool {
         || path.contains("ddr4")
         || path.contains("ddr5")
         || path.contains("ram")
+        || path.contains("jc42")
+        || path.contains("spd5118")
         // Common RAM brand names in LHM hardware paths
         || path.contains("corsair")
         || path.contains("g.skill")
@@ -623,11 +706,14 @@ impl ThermalSnapshot {
         }
 
         // Fans
-        if !self.fan_rpms.is_empty() {
+        if !self.fan_rpms.is_empty() || !self.fan_pwm.is_empty() {
             lines.push("Fans:".to_string());
             for s in &self.fan_rpms {
                 lines.push(format!("  {}: {:.0} RPM", s.name, s.value));
             }
+            for s in &self.fan_pwm {
+                lines.push(format!("  {}: {:.0}%", s.name, s.value));
+            }
             lines.push(String::new());
         }
 
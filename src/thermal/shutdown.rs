@@ -1,10 +1,27 @@
 //! Auto-shutdown state machine for thermal emergencies.
 //!
-//! State flow: Normal → Counting(start) → GracePeriod(start) → Shutdown
-//! Cancels and returns to Normal if temperature drops below critical.
+//! State flow: Normal → Counting(start) → Mitigating(step 1..N) → GracePeriod(start) → Shutdown
+//! Each mitigation step is held for its hold time and only escalates while the
+//! temperature is still at emergency (see [`super::mitigation`]); with no
+//! steps configured, Counting goes straight to GracePeriod.
+//! Cancels and returns to Normal once no sensor is at critical. Levels come
+//! from [`ThermalThresholds`](super::thresholds::ThermalThresholds), so
+//! per-category and per-sensor overrides apply.
 //! OFF by default — double-gated: config + .env flag.
+//!
+//! What "shutdown" means is configurable: `[[thermal.shutdown_actions]]`
+//! runs a list of [`ShutdownStep`]s in order (scripts, `systemctl suspend`,
+//! stopping services, webhooks, poweroff), each with a timeout. Dry-run mode
+//! reports what each action would do without running it.
+
+use std::time::{Duration, Instant};
+
+use tokio::sync::mpsc::UnboundedSender;
 
-use std::time::Instant;
+use crate::alerts::rules::parse_duration;
+use crate::config::ShutdownActionConfig;
+use crate::constants::{DEFAULT_SHUTDOWN_ACTION_TIMEOUT_SECS, ENV_SHUTDOWN_DRY_RUN};
+use crate::thermal::thresholds::Level;
 
 /// Auto-shutdown state machine states.
 #[derive(Debug, Clone)]
@@ -13,6 +30,12 @@ pub enum ShutdownState {
     Normal,
     /// Temperature exceeded emergency threshold; counting sustained seconds.
     Counting { since: Instant, required_secs: u64 },
+    /// Mitigation ladder step `step` applied; holding before escalating.
+    Mitigating {
+        since: Instant,
+        step: usize,
+        hold_secs: u64,
+    },
     /// Sustained emergency confirmed; grace period before shutdown.
     GracePeriod { since: Instant, grace_secs: u64 },
     /// Shutdown command issued.
@@ -31,6 +54,7 @@ impl ShutdownState {
         match self {
             ShutdownState::Normal => "Normal",
             ShutdownState::Counting { .. } => "Thermal Warning - Counting",
+            ShutdownState::Mitigating { .. } => "THERMAL MITIGATION",
             ShutdownState::GracePeriod { .. } => "SHUTDOWN IMMINENT",
             ShutdownState::Shutdown => "SHUTTING DOWN",
         }
@@ -51,6 +75,12 @@ impl ShutdownState {
                 let elapsed = since.elapsed().as_secs();
                 Some(required_secs.saturating_sub(elapsed))
             }
+            ShutdownState::Mitigating {
+                since, hold_secs, ..
+            } => {
+                let elapsed = since.elapsed().as_secs();
+                Some(hold_secs.saturating_sub(elapsed))
+            }
             ShutdownState::GracePeriod { since, grace_secs } => {
                 let elapsed = since.elapsed().as_secs();
                 Some(grace_secs.saturating_sub(elapsed))
@@ -66,14 +96,12 @@ pub struct ShutdownManager {
     pub state: ShutdownState,
     /// Whether auto-shutdown is enabled (config + .env double-gate).
     enabled: bool,
-    /// Emergency temperature threshold.
-    emergency_threshold: f32,
-    /// Critical temperature threshold (below this = recovery).
-    critical_threshold: f32,
     /// Required sustained seconds at emergency before escalation.
     sustained_secs: u64,
     /// Grace period seconds before actual shutdown.
     grace_secs: u64,
+    /// Hold seconds of each mitigation ladder step (empty = no ladder).
+    mitigation_holds: Vec<u64>,
     /// Schedule start hour (0-23).
     schedule_start: u8,
     /// Schedule end hour (0-24).
@@ -84,8 +112,6 @@ impl ShutdownManager {
     /// Create a new shutdown manager from config + .env settings.
     pub fn new(
         config_enabled: bool,
-        emergency_threshold: f32,
-        critical_threshold: f32,
         sustained_secs: u64,
         grace_secs: u64,
         schedule_start: u8,
@@ -99,15 +125,26 @@ impl ShutdownManager {
         Self {
             state: ShutdownState::Normal,
             enabled: config_enabled && env_enabled,
-            emergency_threshold,
-            critical_threshold,
             sustained_secs,
             grace_secs,
+            mitigation_holds: Vec::new(),
             schedule_start,
             schedule_end,
         }
     }
 
+    /// Run a mitigation ladder with these per-step hold times before the grace period.
+    pub fn with_mitigation(mut self, holds: Vec<u64>) -> Self {
+        self.set_mitigation(holds);
+        self
+    }
+
+    /// Replace the ladder's hold times (config reload). A step in progress
+    /// escalates into the new ladder, or to the grace period if it is shorter.
+    pub fn set_mitigation(&mut self, holds: Vec<u64>) {
+        self.mitigation_holds = holds;
+    }
+
     /// Whether the shutdown manager is actively enabled.
     pub fn is_enabled(&self) -> bool {
         self.enabled
@@ -124,9 +161,9 @@ impl ShutdownManager {
         }
     }
 
-    /// Tick the state machine with the current max temperature.
+    /// Tick the state machine with the most severe level any sensor is at.
     /// Returns a `ShutdownEvent` describing what happened this tick.
-    pub fn tick(&mut self, max_temp: f32) -> ShutdownEvent {
+    pub fn tick(&mut self, level: Level) -> ShutdownEvent {
         if !self.enabled || !self.in_schedule() {
             // If disabled or outside schedule, reset to normal
             if self.state.is_active() {
@@ -138,7 +175,7 @@ impl ShutdownManager {
 
         match &self.state {
             ShutdownState::Normal => {
-                if max_temp >= self.emergency_threshold {
+                if level >= Level::Emergency {
                     self.state = ShutdownState::Counting {
                         since: Instant::now(),
                         required_secs: self.sustained_secs,
@@ -152,17 +189,13 @@ impl ShutdownManager {
                 since,
                 required_secs,
             } => {
-                if max_temp < self.critical_threshold {
+                if level < Level::Critical {
                     self.state = ShutdownState::Normal;
                     return ShutdownEvent::Recovered;
                 }
                 let elapsed = since.elapsed().as_secs();
                 if elapsed >= *required_secs {
-                    self.state = ShutdownState::GracePeriod {
-                        since: Instant::now(),
-                        grace_secs: self.grace_secs,
-                    };
-                    ShutdownEvent::GracePeriodStarted
+                    self.escalate(0)
                 } else {
                     ShutdownEvent::Counting {
                         elapsed_secs: elapsed,
@@ -170,8 +203,28 @@ impl ShutdownManager {
                     }
                 }
             }
+            ShutdownState::Mitigating {
+                since,
+                step,
+                hold_secs,
+            } => {
+                if level < Level::Critical {
+                    self.state = ShutdownState::Normal;
+                    return ShutdownEvent::Recovered;
+                }
+                let (step, elapsed) = (*step, since.elapsed().as_secs());
+                // Between critical and emergency the current step is holding the line
+                if elapsed >= *hold_secs && level >= Level::Emergency {
+                    self.escalate(step + 1)
+                } else {
+                    ShutdownEvent::MitigationHolding {
+                        step,
+                        remaining_secs: hold_secs.saturating_sub(elapsed),
+                    }
+                }
+            }
             ShutdownState::GracePeriod { since, grace_secs } => {
-                if max_temp < self.critical_threshold {
+                if level < Level::Critical {
                     self.state = ShutdownState::Normal;
                     return ShutdownEvent::Recovered;
                 }
@@ -185,7 +238,29 @@ impl ShutdownManager {
                     }
                 }
             }
-            ShutdownState::Shutdown => ShutdownEvent::ShutdownNow,
+            // Already issued; the app aborts back to Normal unless it is powering off
+            ShutdownState::Shutdown => ShutdownEvent::None,
+        }
+    }
+
+    /// Move to mitigation step `step`, or to the grace period once the ladder is exhausted.
+    fn escalate(&mut self, step: usize) -> ShutdownEvent {
+        match self.mitigation_holds.get(step) {
+            Some(&hold_secs) => {
+                self.state = ShutdownState::Mitigating {
+                    since: Instant::now(),
+                    step,
+                    hold_secs,
+                };
+                ShutdownEvent::Mitigate { step }
+            }
+            None => {
+                self.state = ShutdownState::GracePeriod {
+                    since: Instant::now(),
+                    grace_secs: self.grace_secs,
+                };
+                ShutdownEvent::GracePeriodStarted
+            }
         }
     }
 
@@ -212,11 +287,15 @@ pub enum ShutdownEvent {
         elapsed_secs: u64,
         required_secs: u64,
     },
+    /// Sustained emergency — apply mitigation ladder step `step` (0-based).
+    Mitigate { step: usize },
+    /// A mitigation step is in effect; escalates in `remaining_secs` if still at emergency.
+    MitigationHolding { step: usize, remaining_secs: u64 },
     /// Sustained emergency confirmed — grace period started.
     GracePeriodStarted,
     /// Grace period countdown.
     GracePeriodCountdown { remaining_secs: u64 },
-    /// Execute shutdown NOW.
+    /// Run the shutdown actions NOW (emitted once).
     ShutdownNow,
     /// Temperature dropped below critical — recovered.
     Recovered,
@@ -263,6 +342,218 @@ pub fn execute_shutdown() -> std::io::Result<()> {
     Ok(())
 }
 
+// ── Shutdown actions ──────────────────────────────────────────────
+
+/// One thing auto-shutdown does.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShutdownAction {
+    /// Platform shutdown ([`execute_shutdown`]).
+    Shutdown,
+    Poweroff,
+    Suspend,
+    /// `systemctl stop <unit>`.
+    StopService(String),
+    /// `sh -c <command>`.
+    Command(String),
+    /// POST a JSON notice to a URL.
+    Webhook(String),
+}
+
+impl ShutdownAction {
+    /// Whether the machine goes down once this action succeeds.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, ShutdownAction::Shutdown | ShutdownAction::Poweroff)
+    }
+}
+
+/// A compiled `[[thermal.shutdown_actions]]` entry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShutdownStep {
+    pub action: ShutdownAction,
+    pub timeout: Duration,
+}
+
+/// Facts passed to shutdown actions (env vars for commands, webhook payload).
+#[derive(Debug, Clone)]
+pub struct ShutdownContext {
+    pub hostname: String,
+    pub max_temp: f32,
+}
+
+/// Progress of a [`run_shutdown_actions`] run.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShutdownProgress {
+    /// One action finished: what it did, or why it failed.
+    Action(Result<String, String>),
+    /// Every action has run. `powering_off` when a shutdown or poweroff
+    /// succeeded for real; otherwise Sentinel carries on (suspend, dry run,
+    /// failure).
+    Finished { powering_off: bool },
+}
+
+impl ShutdownStep {
+    pub fn compile(cfg: &ShutdownActionConfig) -> Result<Self, String> {
+        let required = |field: &Option<String>, name: &str| {
+            field
+                .as_deref()
+                .map(str::trim)
+                .filter(|v| !v.is_empty())
+                .map(str::to_string)
+                .ok_or_else(|| format!("'{}' needs `{}`", cfg.action, name))
+        };
+        let action = match cfg.action.trim().to_lowercase().as_str() {
+            "shutdown" | "default" => ShutdownAction::Shutdown,
+            "poweroff" => ShutdownAction::Poweroff,
+            "suspend" => ShutdownAction::Suspend,
+            "stop_service" | "stop" => ShutdownAction::StopService(required(&cfg.service, "service")?),
+            "command" | "script" => ShutdownAction::Command(required(&cfg.command, "command")?),
+            "webhook" => {
+                let url = required(&cfg.url, "url")?;
+                if !url.starts_with("http://") && !url.starts_with("https://") {
+                    return Err(format!("webhook url must be http(s): {}", url));
+                }
+                ShutdownAction::Webhook(url)
+            }
+            other => {
+                return Err(format!(
+                    "unknown action '{}' (expected shutdown, poweroff, suspend, stop_service, command or webhook)",
+                    other
+                ))
+            }
+        };
+        let timeout = match &cfg.timeout {
+            Some(v) => parse_duration(v)?,
+            None => Duration::from_secs(DEFAULT_SHUTDOWN_ACTION_TIMEOUT_SECS),
+        };
+        Ok(Self { action, timeout })
+    }
+
+    /// Compile the configured actions (invalid ones are reported by
+    /// `Config::load`); with none configured, the platform shutdown.
+    pub fn all_from_config(actions: &[ShutdownActionConfig]) -> Vec<Self> {
+        let steps: Vec<Self> = actions
+            .iter()
+            .filter_map(|a| Self::compile(a).ok())
+            .collect();
+        if steps.is_empty() {
+            vec![Self {
+                action: ShutdownAction::Shutdown,
+                timeout: Duration::from_secs(DEFAULT_SHUTDOWN_ACTION_TIMEOUT_SECS),
+            }]
+        } else {
+            steps
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match &self.action {
+            ShutdownAction::Shutdown => "platform shutdown".to_string(),
+            ShutdownAction::Poweroff => "systemctl poweroff".to_string(),
+            ShutdownAction::Suspend => "systemctl suspend".to_string(),
+            ShutdownAction::StopService(unit) => format!("systemctl stop {}", unit),
+            ShutdownAction::Command(cmd) => format!("run `{}`", cmd),
+            ShutdownAction::Webhook(url) => format!("POST {}", url),
+        }
+    }
+
+    /// Run this action (or, in dry-run mode, only say what it would do).
+    pub async fn run(&self, ctx: &ShutdownContext, dry_run: bool) -> Result<String, String> {
+        if dry_run {
+            return Ok(format!("dry run: would {}", self.describe()));
+        }
+        let outcome = match &self.action {
+            ShutdownAction::Shutdown => {
+                execute_shutdown().map_err(|e| e.to_string())?;
+                return Ok("platform shutdown issued".to_string());
+            }
+            ShutdownAction::Poweroff => self.run_command("systemctl", &["poweroff"], ctx).await,
+            ShutdownAction::Suspend => self.run_command("systemctl", &["suspend"], ctx).await,
+            ShutdownAction::StopService(unit) => {
+                self.run_command("systemctl", &["stop", unit], ctx).await
+            }
+            ShutdownAction::Command(cmd) => self.run_command("sh", &["-c", cmd], ctx).await,
+            ShutdownAction::Webhook(url) => self.post_webhook(url, ctx).await,
+        };
+        outcome
+            .map(|()| format!("{}: ok", self.describe()))
+            .map_err(|e| format!("{}: {}", self.describe(), e))
+    }
+
+    async fn run_command(
+        &self,
+        program: &str,
+        args: &[&str],
+        ctx: &ShutdownContext,
+    ) -> Result<(), String> {
+        let child = tokio::process::Command::new(program)
+            .args(args)
+            .env("SENTINEL_HOSTNAME", &ctx.hostname)
+            .env("SENTINEL_MAX_TEMP", format!("{:.1}", ctx.max_temp))
+            .stdin(std::process::Stdio::null())
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .kill_on_drop(true)
+            .status();
+        let status = tokio::time::timeout(self.timeout, child)
+            .await
+            .map_err(|_| format!("timed out after {}s", self.timeout.as_secs_f32()))?
+            .map_err(|e| e.to_string())?;
+        if status.success() {
+            Ok(())
+        } else {
+            Err(format!("exited with {}", status))
+        }
+    }
+
+    async fn post_webhook(&self, url: &str, ctx: &ShutdownContext) -> Result<(), String> {
+        let client = reqwest::Client::builder()
+            .timeout(self.timeout)
+            .build()
+            .map_err(|e| e.to_string())?;
+        let payload = serde_json::json!({
+            "event": "thermal_shutdown",
+            "hostname": ctx.hostname,
+            "temperature": ctx.max_temp,
+        });
+        let resp = client
+            .post(url)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        if resp.status().is_success() {
+            Ok(())
+        } else {
+            Err(format!("HTTP {}", resp.status()))
+        }
+    }
+}
+
+/// Dry run from config, or forced on by `SENTINEL_SHUTDOWN_DRY_RUN` in .env.
+pub fn dry_run_enabled(config_dry_run: bool) -> bool {
+    config_dry_run
+        || std::env::var(ENV_SHUTDOWN_DRY_RUN)
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false)
+}
+
+/// Run `steps` in order, reporting each outcome on `tx`. A failed or
+/// timed-out action doesn't stop the ones after it.
+pub async fn run_shutdown_actions(
+    steps: Vec<ShutdownStep>,
+    ctx: ShutdownContext,
+    dry_run: bool,
+    tx: UnboundedSender<ShutdownProgress>,
+) {
+    let mut powering_off = false;
+    for step in &steps {
+        let result = step.run(&ctx, dry_run).await;
+        powering_off |= !dry_run && result.is_ok() && step.action.is_terminal();
+        let _ = tx.send(ShutdownProgress::Action(result));
+    }
+    let _ = tx.send(ShutdownProgress::Finished { powering_off });
+}
+
 use chrono::Timelike;
 
 #[cfg(test)]
@@ -273,10 +564,9 @@ mod tests {
         ShutdownManager {
             state: ShutdownState::Normal,
             enabled,
-            emergency_threshold: 100.0,
-            critical_threshold: 95.0,
             sustained_secs: 2,
             grace_secs: 2,
+            mitigation_holds: Vec::new(),
             schedule_start: 0,
             schedule_end: 24, // Always in schedule for testing
         }
@@ -285,36 +575,251 @@ mod tests {
     #[test]
     fn disabled_manager_does_nothing() {
         let mut mgr = make_manager(false);
-        assert_eq!(mgr.tick(110.0), ShutdownEvent::None);
+        assert_eq!(mgr.tick(Level::Emergency), ShutdownEvent::None);
         assert!(!mgr.state.is_active());
     }
 
     #[test]
     fn normal_stays_normal_below_emergency() {
         let mut mgr = make_manager(true);
-        assert_eq!(mgr.tick(90.0), ShutdownEvent::None);
+        assert_eq!(mgr.tick(Level::Normal), ShutdownEvent::None);
         assert!(!mgr.state.is_active());
     }
 
     #[test]
     fn normal_to_counting_at_emergency() {
         let mut mgr = make_manager(true);
-        assert_eq!(mgr.tick(100.0), ShutdownEvent::EmergencyStarted);
+        assert_eq!(mgr.tick(Level::Emergency), ShutdownEvent::EmergencyStarted);
         assert!(mgr.state.is_active());
     }
 
     #[test]
     fn counting_recovers_below_critical() {
         let mut mgr = make_manager(true);
-        mgr.tick(100.0); // Start counting
-        assert_eq!(mgr.tick(90.0), ShutdownEvent::Recovered); // Below critical
+        mgr.tick(Level::Emergency); // Start counting
+        assert_eq!(mgr.tick(Level::Normal), ShutdownEvent::Recovered); // Below critical
+        assert!(!mgr.state.is_active());
+    }
+
+    #[test]
+    fn sensor_emergency_override_starts_counting() {
+        use crate::config::{ThermalConfig, ThermalThresholdConfig};
+        use crate::thermal::thresholds::ThermalThresholds;
+        use crate::thermal::{SensorReading, ThermalSnapshot};
+
+        let thresholds = ThermalThresholds::from_config(&ThermalConfig {
+            emergency_threshold: 100.0,
+            thresholds: vec![ThermalThresholdConfig {
+                category: Some("ssd".to_string()),
+                sensor: None,
+                warning: None,
+                critical: Some(80.0),
+                emergency: Some(85.0),
+            }],
+            ..ThermalConfig::default()
+        });
+        let mut snap = ThermalSnapshot::empty();
+        snap.ssd_temps.push(SensorReading {
+            name: "WD Blue".to_string(),
+            value: 88.0,
+        });
+
+        // 88°C is far below the global emergency but past the SSD's own
+        let mut mgr = make_manager(true);
+        let level = thresholds.worst(&snap);
+        assert_eq!(mgr.tick(level), ShutdownEvent::EmergencyStarted);
+    }
+
+    /// Put the manager in `state` as if it had been there for `secs` seconds.
+    fn backdate(mgr: &mut ShutdownManager, secs: u64) {
+        let past = Instant::now() - std::time::Duration::from_secs(secs);
+        match &mut mgr.state {
+            ShutdownState::Counting { since, .. }
+            | ShutdownState::Mitigating { since, .. }
+            | ShutdownState::GracePeriod { since, .. } => *since = past,
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn ladder_steps_run_before_grace_period() {
+        let mut mgr = make_manager(true).with_mitigation(vec![10, 20]);
+        mgr.tick(Level::Emergency);
+        backdate(&mut mgr, 2);
+        assert_eq!(
+            mgr.tick(Level::Emergency),
+            ShutdownEvent::Mitigate { step: 0 }
+        );
+        assert_eq!(mgr.state.label(), "THERMAL MITIGATION");
+        assert!(matches!(
+            mgr.tick(Level::Emergency),
+            ShutdownEvent::MitigationHolding { step: 0, .. }
+        ));
+
+        backdate(&mut mgr, 10);
+        assert_eq!(
+            mgr.tick(Level::Emergency),
+            ShutdownEvent::Mitigate { step: 1 }
+        );
+        backdate(&mut mgr, 20);
+        assert_eq!(
+            mgr.tick(Level::Emergency),
+            ShutdownEvent::GracePeriodStarted
+        );
+    }
+
+    #[test]
+    fn ladder_holds_below_emergency_and_recovers_below_critical() {
+        let mut mgr = make_manager(true).with_mitigation(vec![10]);
+        mgr.tick(Level::Emergency);
+        backdate(&mut mgr, 2);
+        assert_eq!(
+            mgr.tick(Level::Emergency),
+            ShutdownEvent::Mitigate { step: 0 }
+        );
+
+        // Hold expired, but the step is keeping it under emergency: no escalation
+        backdate(&mut mgr, 60);
+        assert_eq!(
+            mgr.tick(Level::Critical),
+            ShutdownEvent::MitigationHolding {
+                step: 0,
+                remaining_secs: 0
+            }
+        );
+        assert_eq!(mgr.tick(Level::Normal), ShutdownEvent::Recovered);
         assert!(!mgr.state.is_active());
     }
 
+    #[test]
+    fn shutdown_now_is_emitted_once() {
+        let mut mgr = make_manager(true);
+        mgr.state = ShutdownState::GracePeriod {
+            since: Instant::now(),
+            grace_secs: 0,
+        };
+        assert_eq!(mgr.tick(Level::Emergency), ShutdownEvent::ShutdownNow);
+        assert_eq!(mgr.tick(Level::Emergency), ShutdownEvent::None);
+        assert!(mgr.abort());
+    }
+
+    fn action(action: &str) -> ShutdownActionConfig {
+        ShutdownActionConfig {
+            action: action.to_string(),
+            command: None,
+            service: None,
+            url: None,
+            timeout: None,
+        }
+    }
+
+    fn context() -> ShutdownContext {
+        ShutdownContext {
+            hostname: "ci-runner".to_string(),
+            max_temp: 106.5,
+        }
+    }
+
+    #[test]
+    fn compile_shutdown_actions() {
+        let mut stop = action("stop_service");
+        assert!(ShutdownStep::compile(&stop).is_err());
+        stop.service = Some("postgresql".into());
+        stop.timeout = Some(crate::config::RuleValue::Text("20s".into()));
+        let step = ShutdownStep::compile(&stop).unwrap();
+        assert_eq!(step.describe(), "systemctl stop postgresql");
+        assert_eq!(step.timeout, Duration::from_secs(20));
+
+        let mut hook = action("webhook");
+        hook.url = Some("ftp://example.com".into());
+        assert!(ShutdownStep::compile(&hook).is_err());
+        assert!(ShutdownStep::compile(&action("hibernate")).is_err());
+
+        // Nothing valid configured: the platform shutdown
+        let steps = ShutdownStep::all_from_config(&[action("reboot")]);
+        assert_eq!(steps.len(), 1);
+        assert_eq!(steps[0].action, ShutdownAction::Shutdown);
+    }
+
+    #[tokio::test]
+    async fn dry_run_reports_without_running() {
+        let dir = tempfile::tempdir().unwrap();
+        let marker = dir.path().join("ran");
+        let mut cmd = action("command");
+        cmd.command = Some(format!("touch {}", marker.display()));
+        let steps = ShutdownStep::all_from_config(&[cmd, action("poweroff")]);
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        run_shutdown_actions(steps, context(), true, tx).await;
+        assert_eq!(
+            rx.recv().await,
+            Some(ShutdownProgress::Action(Ok(format!(
+                "dry run: would run `touch {}`",
+                marker.display()
+            ))))
+        );
+        assert_eq!(
+            rx.recv().await,
+            Some(ShutdownProgress::Action(Ok(
+                "dry run: would systemctl poweroff".to_string()
+            )))
+        );
+        assert_eq!(
+            rx.recv().await,
+            Some(ShutdownProgress::Finished {
+                powering_off: false
+            })
+        );
+        assert!(!marker.exists());
+    }
+
+    #[tokio::test]
+    async fn commands_get_context_and_time_out() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("out");
+        let mut write = action("command");
+        write.command = Some(format!(
+            "echo \"$SENTINEL_HOSTNAME $SENTINEL_MAX_TEMP\" > {}",
+            out.display()
+        ));
+        let mut slow = action("command");
+        slow.command = Some("sleep 5".into());
+        slow.timeout = Some(crate::config::RuleValue::Number(0.2));
+        let mut failing = action("command");
+        failing.command = Some("exit 3".into());
+
+        let steps = ShutdownStep::all_from_config(&[slow, failing, write]);
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        run_shutdown_actions(steps, context(), false, tx).await;
+
+        let Some(ShutdownProgress::Action(Err(e))) = rx.recv().await else {
+            panic!("slow command should time out");
+        };
+        assert!(e.contains("timed out"), "{}", e);
+        let Some(ShutdownProgress::Action(Err(e))) = rx.recv().await else {
+            panic!("failing command should report its exit status");
+        };
+        assert!(e.contains("exited with"), "{}", e);
+        assert!(matches!(
+            rx.recv().await,
+            Some(ShutdownProgress::Action(Ok(_)))
+        ));
+        assert_eq!(
+            rx.recv().await,
+            Some(ShutdownProgress::Finished {
+                powering_off: false
+            })
+        );
+        assert_eq!(
+            std::fs::read_to_string(&out).unwrap().trim(),
+            "ci-runner 106.5"
+        );
+    }
+
     #[test]
     fn abort_resets_state() {
         let mut mgr = make_manager(true);
-        mgr.tick(100.0); // Start counting
+        mgr.tick(Level::Emergency); // Start counting
         assert!(mgr.abort());
         assert!(!mgr.state.is_active());
     }
@@ -0,0 +1,224 @@
+//! Thermal data sources behind a common [`ThermalSource`] trait.
+//!
+//! `[thermal] source` in config.toml selects the backend:
+//!
+//! - `lhm`: LibreHardwareMonitor over HTTP (Windows host, WSL2)
+//! - `sysfs`: Linux hwmon chips and thermal zones ([`super::sysfs`])
+//! - `lm-sensors`: `sensors -j` or a captured JSON file ([`super::lm_sensors`])
+//! - `auto` (default): sysfs when it finds sensors, LHM otherwise
+//!
+//! Every backend produces the same [`ThermalSnapshot`], so thermal alerts
+//! and auto-shutdown work the same on either.
+
+use std::path::PathBuf;
+
+use futures_util::future::BoxFuture;
+
+use crate::config::ThermalConfig;
+
+use super::lm_sensors;
+use super::sysfs::{self, SYSFS_ROOT};
+use super::{resolve_lhm_url, LhmAuth, LhmClient, ThermalSnapshot};
+
+/// A backend that can be polled for thermal snapshots.
+pub trait ThermalSource: Send + Sync {
+    /// Where readings come from, for the startup log.
+    fn describe(&self) -> String;
+
+    /// Take one reading, or `None` if the backend has no data right now.
+    fn poll(&self) -> BoxFuture<'_, Option<ThermalSnapshot>>;
+}
+
+impl ThermalSource for LhmClient {
+    fn describe(&self) -> String {
+        format!(
+            "LHM at {} (auth: {})",
+            self.url(),
+            if self.has_auth() { "yes" } else { "no" }
+        )
+    }
+
+    fn poll(&self) -> BoxFuture<'_, Option<ThermalSnapshot>> {
+        Box::pin(LhmClient::poll(self))
+    }
+}
+
+/// Linux sysfs backend (`/sys/class/hwmon`, `/sys/class/thermal`).
+pub struct SysfsSource {
+    root: PathBuf,
+}
+
+impl SysfsSource {
+    /// Read the sysfs tree mounted at `root` (normally `/sys`).
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+impl ThermalSource for SysfsSource {
+    fn describe(&self) -> String {
+        format!("sysfs at {}", self.root.display())
+    }
+
+    fn poll(&self) -> BoxFuture<'_, Option<ThermalSnapshot>> {
+        let root = self.root.clone();
+        // Some drivers (drivetemp, nvme) block briefly on read
+        Box::pin(async move {
+            tokio::task::spawn_blocking(move || sysfs::read_snapshot(&root))
+                .await
+                .ok()
+                .flatten()
+        })
+    }
+}
+
+/// lm-sensors backend: runs `sensors -j`, or reads a captured JSON file.
+pub struct LmSensorsSource {
+    file: Option<PathBuf>,
+}
+
+impl LmSensorsSource {
+    /// Parse `file` on every poll, or run `sensors -j` when `None`.
+    pub fn new(file: Option<PathBuf>) -> Self {
+        Self { file }
+    }
+}
+
+impl ThermalSource for LmSensorsSource {
+    fn describe(&self) -> String {
+        match &self.file {
+            Some(file) => format!("lm-sensors JSON at {}", file.display()),
+            None => "lm-sensors (sensors -j)".to_string(),
+        }
+    }
+
+    fn poll(&self) -> BoxFuture<'_, Option<ThermalSnapshot>> {
+        Box::pin(async move {
+            let json = lm_sensors::read_output(self.file.as_deref()).await?;
+            lm_sensors::parse_sensors_json(&json)
+        })
+    }
+}
+
+/// sysfs first, LHM when sysfs has no sensors (WSL2, Windows hosts).
+pub struct AutoSource {
+    sysfs: SysfsSource,
+    lhm: LhmClient,
+}
+
+impl ThermalSource for AutoSource {
+    fn describe(&self) -> String {
+        format!(
+            "{}, falling back to {}",
+            self.sysfs.describe(),
+            self.lhm.describe()
+        )
+    }
+
+    fn poll(&self) -> BoxFuture<'_, Option<ThermalSnapshot>> {
+        Box::pin(async move {
+            match ThermalSource::poll(&self.sysfs).await {
+                Some(snapshot) => Some(snapshot),
+                None => LhmClient::poll(&self.lhm).await,
+            }
+        })
+    }
+}
+
+/// Backend selected by `[thermal] source`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceKind {
+    Auto,
+    Lhm,
+    Sysfs,
+    LmSensors,
+}
+
+impl SourceKind {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.trim().to_lowercase().as_str() {
+            "auto" => Ok(Self::Auto),
+            "lhm" => Ok(Self::Lhm),
+            "sysfs" | "hwmon" => Ok(Self::Sysfs),
+            "lm-sensors" | "lm_sensors" | "sensors" => Ok(Self::LmSensors),
+            other => Err(format!(
+                "unknown thermal source '{}' (expected auto, lhm, sysfs or lm-sensors)",
+                other
+            )),
+        }
+    }
+}
+
+/// Build the configured backend (an invalid `source` falls back to auto).
+pub fn from_config(config: &ThermalConfig) -> Box<dyn ThermalSource> {
+    let lhm = || {
+        LhmClient::new(
+            &resolve_lhm_url(&config.lhm_url),
+            LhmAuth::from_config_or_env(config),
+        )
+    };
+    match SourceKind::parse(&config.source).unwrap_or(SourceKind::Auto) {
+        SourceKind::Lhm => Box::new(lhm()),
+        SourceKind::Sysfs => Box::new(SysfsSource::new(SYSFS_ROOT)),
+        SourceKind::LmSensors => Box::new(LmSensorsSource::new(
+            config.lm_sensors_file.as_ref().map(PathBuf::from),
+        )),
+        SourceKind::Auto => Box::new(AutoSource {
+            sysfs: SysfsSource::new(SYSFS_ROOT),
+            lhm: lhm(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_source_kinds() {
+        assert_eq!(SourceKind::parse("Auto"), Ok(SourceKind::Auto));
+        assert_eq!(SourceKind::parse("lhm"), Ok(SourceKind::Lhm));
+        assert_eq!(SourceKind::parse("hwmon"), Ok(SourceKind::Sysfs));
+        assert_eq!(SourceKind::parse("lm-sensors"), Ok(SourceKind::LmSensors));
+        assert!(SourceKind::parse("ipmi").is_err());
+    }
+
+    #[tokio::test]
+    async fn auto_prefers_sysfs_and_falls_back_to_lhm() {
+        let dir = tempfile::tempdir().unwrap();
+        let unreachable = || LhmClient::new("http://127.0.0.1:9/data.json", None);
+
+        // No sensors anywhere: both backends come up empty
+        let auto = AutoSource {
+            sysfs: SysfsSource::new(dir.path()),
+            lhm: unreachable(),
+        };
+        assert!(ThermalSource::poll(&auto).await.is_none());
+
+        let zone = dir.path().join("class/thermal/thermal_zone0");
+        std::fs::create_dir_all(&zone).unwrap();
+        std::fs::write(zone.join("type"), "x86_pkg_temp\n").unwrap();
+        std::fs::write(zone.join("temp"), "48000\n").unwrap();
+        let snapshot = ThermalSource::poll(&auto).await.expect("sysfs reading");
+        assert_eq!(snapshot.cpu_package, Some(48.0));
+    }
+
+    #[tokio::test]
+    async fn lm_sensors_reads_captured_json_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("sensors.json");
+        let source = LmSensorsSource::new(Some(file.clone()));
+        assert!(ThermalSource::poll(&source).await.is_none());
+
+        std::fs::write(
+            &file,
+            r#"{"k10temp-pci-00c3": {"Adapter": "PCI adapter", "Tctl": {"temp1_input": 67.5}}}"#,
+        )
+        .unwrap();
+        let snapshot = ThermalSource::poll(&source)
+            .await
+            .expect("captured reading");
+        assert_eq!(snapshot.cpu_package, Some(67.5));
+        assert!(source.describe().contains("sensors.json"));
+    }
+}
@@ -0,0 +1,413 @@
+//! Native Linux thermal backend reading sysfs.
+//!
+//! Walks every `/sys/class/hwmon/hwmon*` chip and classifies it by its
+//! `name` file:
+//!
+//! | Chip                                   | Snapshot field       |
+//! |----------------------------------------|----------------------|
+//! | `coretemp`, `k10temp`, `zenpower`, ... | CPU package / cores  |
+//! | `amdgpu`, `nouveau`, `radeon`          | GPU edge / junction  |
+//! | `nvme`, `drivetemp`                    | storage              |
+//! | `jc42`, `spd5118`                      | RAM                  |
+//! | anything else (`nct6775`, `acpitz`)    | motherboard          |
+//!
+//! `temp*_input` values are millidegrees, `fan*_input` RPM and `pwm*` a
+//! 0-255 duty cycle. `/sys/class/thermal/thermal_zone*` adds zones that no
+//! hwmon chip already reports (e.g. `x86_pkg_temp`).
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::constants::SYSFS_MAX_PLAUSIBLE_TEMP_C;
+use crate::utils::read_trimmed;
+
+use super::{is_motherboard_cpu_sensor, is_noise_sensor, natural_sort_key};
+use super::{SensorReading, ThermalSnapshot};
+
+/// Default sysfs mount point.
+pub const SYSFS_ROOT: &str = "/sys";
+
+/// Which snapshot section a chip feeds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ChipKind {
+    Cpu,
+    Gpu,
+    Storage,
+    Memory,
+    Other,
+}
+
+fn chip_kind(name: &str) -> ChipKind {
+    match name {
+        "coretemp" | "k10temp" | "k8temp" | "zenpower" | "cpu_thermal" | "soc_thermal" => {
+            ChipKind::Cpu
+        }
+        "amdgpu" | "radeon" | "nouveau" | "i915" | "xe" => ChipKind::Gpu,
+        "nvme" | "drivetemp" => ChipKind::Storage,
+        "jc42" | "spd5118" | "ee1004" => ChipKind::Memory,
+        _ => ChipKind::Other,
+    }
+}
+
+/// One temperature read from a chip or thermal zone.
+struct TempSensor {
+    kind: ChipKind,
+    /// Display name (chip or device prefixed where ambiguous).
+    name: String,
+    /// The sensor's own label (`Package id 0`, `CPUTIN`).
+    label: String,
+    value: f32,
+}
+
+/// Build a thermal snapshot from the sysfs tree under `root` (normally
+/// `/sys`). Returns `None` when no temperature sensor is found.
+pub fn read_snapshot(root: &Path) -> Option<ThermalSnapshot> {
+    let mut temps = Vec::new();
+    let mut snapshot = ThermalSnapshot::empty();
+    let mut chip_names = Vec::new();
+
+    for chip in sorted_entries(&root.join("class/hwmon")) {
+        let Some(name) = read_trimmed(&chip.join("name")) else {
+            continue;
+        };
+        read_chip(&chip, &name, &mut temps, &mut snapshot);
+        chip_names.push(name.replace('-', "_"));
+    }
+
+    // Thermal zones not already covered by a hwmon chip (acpitz shows up in both)
+    for zone in sorted_entries(&root.join("class/thermal")) {
+        let is_zone = zone
+            .file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(|n| n.starts_with("thermal_zone"));
+        if !is_zone {
+            continue;
+        }
+        let (Some(zone_type), Some(value)) = (
+            read_trimmed(&zone.join("type")),
+            read_millidegrees(&zone.join("temp")),
+        ) else {
+            continue;
+        };
+        if chip_names.contains(&zone_type.replace('-', "_")) {
+            continue;
+        }
+        let lower = zone_type.to_lowercase();
+        let kind = if lower.contains("x86_pkg") || lower.contains("cpu") || lower.contains("soc") {
+            ChipKind::Cpu
+        } else if lower.contains("gpu") {
+            ChipKind::Gpu
+        } else {
+            ChipKind::Other
+        };
+        let name = if kind == ChipKind::Cpu {
+            "Package".to_string()
+        } else {
+            zone_type
+        };
+        temps.push(TempSensor {
+            kind,
+            label: name.clone(),
+            name,
+            value,
+        });
+    }
+
+    if temps.is_empty() {
+        return None;
+    }
+    for t in temps {
+        add_temperature(&mut snapshot, t);
+    }
+    snapshot
+        .cpu_cores
+        .sort_by_key(|c| natural_sort_key(&c.name));
+    Some(snapshot)
+}
+
+/// Read one hwmon chip's temperatures, fans and PWM outputs.
+fn read_chip(chip: &Path, name: &str, temps: &mut Vec<TempSensor>, snapshot: &mut ThermalSnapshot) {
+    let kind = chip_kind(name);
+    let device = device_name(chip);
+
+    for (index, path) in indexed_files(chip, "temp", "_input") {
+        let Some(value) = read_millidegrees(&path) else {
+            continue;
+        };
+        let label = read_trimmed(&chip.join(format!("temp{}_label", index)));
+        if label.as_deref().is_some_and(is_noise_sensor) {
+            continue;
+        }
+        // An unlabeled CPU input (cpu_thermal, older k10temp) is the package
+        let label = match label {
+            Some(label) => label,
+            None if kind == ChipKind::Cpu => "Package".to_string(),
+            None => format!("Temp #{}", index),
+        };
+        let sensor_name = match kind {
+            ChipKind::Storage | ChipKind::Memory => {
+                format!("{} {}", device.as_deref().unwrap_or(name), label)
+            }
+            ChipKind::Other => format!("{} {}", name, label),
+            ChipKind::Cpu | ChipKind::Gpu => label.clone(),
+        };
+        temps.push(TempSensor {
+            kind,
+            name: sensor_name,
+            label,
+            value,
+        });
+    }
+
+    for (index, path) in indexed_files(chip, "fan", "_input") {
+        let Some(rpm) = read_trimmed(&path).and_then(|v| v.parse::<f32>().ok()) else {
+            continue;
+        };
+        let label = read_trimmed(&chip.join(format!("fan{}_label", index)));
+        // Unpopulated headers report 0 RPM and have no label
+        if rpm <= 0.0 && label.is_none() {
+            continue;
+        }
+        snapshot.fan_rpms.push(SensorReading {
+            name: label.unwrap_or_else(|| format!("{} Fan #{}", name, index)),
+            value: rpm,
+        });
+    }
+
+    for (index, path) in indexed_files(chip, "pwm", "") {
+        let Some(duty) = read_trimmed(&path).and_then(|v| v.parse::<f32>().ok()) else {
+            continue;
+        };
+        snapshot.fan_pwm.push(SensorReading {
+            name: format!("{} PWM #{}", name, index),
+            value: (duty / 255.0 * 100.0).clamp(0.0, 100.0),
+        });
+    }
+}
+
+/// Fold one temperature into the snapshot, updating the per-section maxima.
+fn add_temperature(snapshot: &mut ThermalSnapshot, t: TempSensor) {
+    let lower = t.label.to_lowercase();
+    let reading = SensorReading {
+        name: t.name.clone(),
+        value: t.value,
+    };
+    match t.kind {
+        ChipKind::Cpu => {
+            snapshot.max_cpu_temp = snapshot.max_cpu_temp.max(t.value);
+            // k10temp: Tdie is the real die temperature, Tctl may be offset
+            if lower.contains("package") || lower == "tdie" {
+                snapshot.cpu_package = Some(t.value);
+            } else if lower == "tctl" {
+                snapshot.cpu_package.get_or_insert(t.value);
+            } else if lower.contains("core") || lower.contains("ccd") {
+                snapshot.cpu_cores.push(reading);
+            }
+        }
+        ChipKind::Gpu => {
+            snapshot.max_gpu_temp = snapshot.max_gpu_temp.max(t.value);
+            if lower.contains("junction") || lower.contains("hotspot") {
+                snapshot.gpu_hotspot = Some(t.value);
+            } else if lower.contains("edge") || snapshot.gpu_temp.is_none() {
+                snapshot.gpu_temp = Some(t.value);
+            }
+        }
+        ChipKind::Storage => {
+            snapshot.max_ssd_temp = snapshot.max_ssd_temp.max(t.value);
+            snapshot.ssd_temps.push(reading);
+        }
+        ChipKind::Memory => {
+            snapshot.max_ram_temp = snapshot.max_ram_temp.max(t.value);
+            snapshot.ram_temps.push(reading);
+        }
+        ChipKind::Other => {
+            // Super I/O CPU inputs are a socket proxy: shown, not counted
+            if is_motherboard_cpu_sensor(&t.label) {
+                snapshot.motherboard_temps.push(SensorReading {
+                    name: format!("{} (socket)", t.name),
+                    value: t.value,
+                });
+                return;
+            }
+            snapshot.max_motherboard_temp = snapshot.max_motherboard_temp.max(t.value);
+            snapshot.motherboard_temps.push(reading);
+        }
+    }
+    snapshot.max_temp = snapshot.max_temp.max(t.value);
+}
+
+// ── sysfs helpers ─────────────────────────────────────────────────
+
+/// Directory entries sorted by name (`hwmon10` after `hwmon9`).
+pub(super) fn sorted_entries(dir: &Path) -> Vec<PathBuf> {
+    let mut entries: Vec<PathBuf> = fs::read_dir(dir)
+        .map(|rd| rd.flatten().map(|e| e.path()).collect())
+        .unwrap_or_default();
+    entries.sort_by_key(|p| natural_sort_key(&p.to_string_lossy()));
+    entries
+}
+
+/// Files named `<prefix><n><suffix>` in `dir`, sorted by `n`.
+pub(super) fn indexed_files(dir: &Path, prefix: &str, suffix: &str) -> Vec<(u32, PathBuf)> {
+    let mut files: Vec<(u32, PathBuf)> = fs::read_dir(dir)
+        .map(|rd| {
+            rd.flatten()
+                .filter_map(|e| {
+                    let name = e.file_name().into_string().ok()?;
+                    let index = name.strip_prefix(prefix)?.strip_suffix(suffix)?;
+                    Some((index.parse().ok()?, e.path()))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    files.sort_by_key(|(i, _)| *i);
+    files
+}
+
+/// Read a millidegree value, dropping disconnected inputs (Super I/O chips
+/// report -128 or 127 °C) and other implausible readings.
+fn read_millidegrees(path: &Path) -> Option<f32> {
+    let celsius = read_trimmed(path)?.parse::<f32>().ok()? / 1000.0;
+    (celsius > 0.0 && celsius < SYSFS_MAX_PLAUSIBLE_TEMP_C).then_some(celsius)
+}
+
+/// Block or controller name behind a chip (`nvme0`, `sda`), if any.
+fn device_name(chip: &Path) -> Option<String> {
+    let device = chip.join("device");
+    if let Some(block) = sorted_entries(&device.join("block")).first() {
+        return block.file_name()?.to_str().map(str::to_string);
+    }
+    // nvme: device -> .../nvme/nvme0
+    let target = fs::read_link(&device).ok()?;
+    let name = target.file_name()?.to_str()?;
+    name.starts_with("nvme").then(|| name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::write_tree;
+
+    fn fake_sysfs() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        write_tree(
+            dir.path(),
+            &[
+                ("class/hwmon/hwmon0/name", "coretemp"),
+                ("class/hwmon/hwmon0/temp1_label", "Package id 0"),
+                ("class/hwmon/hwmon0/temp1_input", "71000"),
+                ("class/hwmon/hwmon0/temp2_label", "Core 1"),
+                ("class/hwmon/hwmon0/temp2_input", "69000"),
+                ("class/hwmon/hwmon0/temp3_label", "Core 0"),
+                ("class/hwmon/hwmon0/temp3_input", "73000"),
+                ("class/hwmon/hwmon1/name", "amdgpu"),
+                ("class/hwmon/hwmon1/temp1_label", "edge"),
+                ("class/hwmon/hwmon1/temp1_input", "62000"),
+                ("class/hwmon/hwmon1/temp2_label", "junction"),
+                ("class/hwmon/hwmon1/temp2_input", "78000"),
+                ("class/hwmon/hwmon1/fan1_input", "1450"),
+                ("class/hwmon/hwmon1/pwm1", "128"),
+                ("class/hwmon/hwmon2/name", "nvme"),
+                ("class/hwmon/hwmon2/temp1_label", "Composite"),
+                ("class/hwmon/hwmon2/temp1_input", "44850"),
+                ("class/hwmon/hwmon3/name", "drivetemp"),
+                ("class/hwmon/hwmon3/device/block/sda/size", "0"),
+                ("class/hwmon/hwmon3/temp1_input", "35000"),
+                ("class/hwmon/hwmon4/name", "nct6775"),
+                ("class/hwmon/hwmon4/temp1_label", "SYSTIN"),
+                ("class/hwmon/hwmon4/temp1_input", "38000"),
+                ("class/hwmon/hwmon4/temp2_label", "CPUTIN"),
+                ("class/hwmon/hwmon4/temp2_input", "96000"),
+                ("class/hwmon/hwmon4/temp3_label", "AUXTIN0"),
+                ("class/hwmon/hwmon4/temp3_input", "-128000"),
+                ("class/hwmon/hwmon4/fan1_label", "CPU Fan"),
+                ("class/hwmon/hwmon4/fan1_input", "980"),
+                ("class/hwmon/hwmon4/fan2_input", "0"),
+                ("class/hwmon/hwmon5/name", "acpitz"),
+                ("class/hwmon/hwmon5/temp1_input", "27800"),
+                ("class/thermal/thermal_zone0/type", "acpitz"),
+                ("class/thermal/thermal_zone0/temp", "27800"),
+                ("class/thermal/thermal_zone1/type", "x86_pkg_temp"),
+                ("class/thermal/thermal_zone1/temp", "71000"),
+                ("class/thermal/cooling_device0/type", "Processor"),
+            ],
+        );
+        #[cfg(unix)]
+        {
+            fs::create_dir_all(dir.path().join("devices/nvme/nvme0")).unwrap();
+            std::os::unix::fs::symlink(
+                dir.path().join("devices/nvme/nvme0"),
+                dir.path().join("class/hwmon/hwmon2/device"),
+            )
+            .unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn fake_tree_produces_full_snapshot() {
+        let dir = fake_sysfs();
+        let snap = read_snapshot(dir.path()).expect("sensors present");
+
+        assert_eq!(snap.cpu_package, Some(71.0));
+        let cores: Vec<&str> = snap.cpu_cores.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(cores, vec!["Core 0", "Core 1"]);
+        assert_eq!(snap.max_cpu_temp, 73.0);
+
+        assert_eq!(snap.gpu_temp, Some(62.0));
+        assert_eq!(snap.gpu_hotspot, Some(78.0));
+        assert_eq!(snap.max_gpu_temp, 78.0);
+
+        let storage: Vec<&str> = snap.ssd_temps.iter().map(|s| s.name.as_str()).collect();
+        #[cfg(unix)]
+        assert_eq!(storage, vec!["nvme0 Composite", "sda Temp #1"]);
+        assert_eq!(snap.max_ssd_temp, 44.85);
+
+        // Disconnected AUXTIN dropped, CPUTIN shown as socket proxy but not counted
+        let mb: Vec<&str> = snap
+            .motherboard_temps
+            .iter()
+            .map(|s| s.name.as_str())
+            .collect();
+        assert_eq!(
+            mb,
+            vec![
+                "nct6775 SYSTIN",
+                "nct6775 CPUTIN (socket)",
+                "acpitz Temp #1"
+            ]
+        );
+        assert_eq!(snap.max_motherboard_temp, 38.0);
+        assert_eq!(snap.max_temp, 78.0);
+
+        // Unlabeled 0 RPM header skipped
+        let fans: Vec<&str> = snap.fan_rpms.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(fans, vec!["amdgpu Fan #1", "CPU Fan"]);
+        assert_eq!(snap.fan_pwm.len(), 1);
+        assert!((snap.fan_pwm[0].value - 50.2).abs() < 0.1);
+    }
+
+    #[test]
+    fn thermal_zones_fill_in_without_hwmon() {
+        let dir = tempfile::tempdir().unwrap();
+        write_tree(
+            dir.path(),
+            &[
+                ("class/thermal/thermal_zone0/type", "x86_pkg_temp"),
+                ("class/thermal/thermal_zone0/temp", "55000"),
+                ("class/thermal/thermal_zone1/type", "iwlwifi_1"),
+                ("class/thermal/thermal_zone1/temp", "41000"),
+            ],
+        );
+        let snap = read_snapshot(dir.path()).unwrap();
+        assert_eq!(snap.cpu_package, Some(55.0));
+        assert_eq!(snap.motherboard_temps[0].name, "iwlwifi_1");
+        assert_eq!(snap.max_temp, 55.0);
+    }
+
+    #[test]
+    fn empty_tree_returns_none() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(read_snapshot(dir.path()).is_none());
+    }
+}
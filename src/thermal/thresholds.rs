@@ -0,0 +1,335 @@
+//! Per-category and per-sensor thermal thresholds, and sensor aliases.
+//!
+//! `warning_threshold` / `critical_threshold` / `emergency_threshold` in
+//! `[thermal]` are the defaults. `[[thermal.thresholds]]` entries override
+//! them for a category (`cpu`, `gpu`, `ssd`, `ram`, `board`) or for sensors
+//! whose name or alias matches a glob; category entries apply first, then
+//! sensor entries, each in file order. `[thermal.sensor_aliases]` renames
+//! sensors in alerts, notifications and the Thermal tab.
+
+use std::collections::BTreeMap;
+
+use crate::alerts::rules::glob_match;
+use crate::config::{ThermalConfig, ThermalThresholdConfig};
+
+/// Temperature categories a threshold entry can target.
+pub const CATEGORIES: [&str; 5] = ["cpu", "gpu", "ssd", "ram", "board"];
+
+/// Warning / critical / emergency temperatures (°C) for one sensor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Levels {
+    pub warning: f32,
+    pub critical: f32,
+    pub emergency: f32,
+}
+
+/// How hot a reading is relative to its [`Levels`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Normal,
+    Warning,
+    Critical,
+    Emergency,
+}
+
+impl Levels {
+    pub fn classify(&self, temp: f32) -> Level {
+        if temp >= self.emergency {
+            Level::Emergency
+        } else if temp >= self.critical {
+            Level::Critical
+        } else if temp >= self.warning {
+            Level::Warning
+        } else {
+            Level::Normal
+        }
+    }
+}
+
+/// A compiled `[[thermal.thresholds]]` entry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThresholdOverride {
+    category: Option<String>,
+    /// Lowercased glob matched against the sensor name and its alias.
+    sensor: Option<String>,
+    warning: Option<f32>,
+    critical: Option<f32>,
+    emergency: Option<f32>,
+}
+
+impl ThresholdOverride {
+    pub fn compile(cfg: &ThermalThresholdConfig) -> Result<Self, String> {
+        let category = match &cfg.category {
+            Some(c) => {
+                let c = c.trim().to_lowercase();
+                if !CATEGORIES.contains(&c.as_str()) {
+                    return Err(format!(
+                        "unknown category '{}' (expected {})",
+                        c,
+                        CATEGORIES.join(", ")
+                    ));
+                }
+                Some(c)
+            }
+            None => None,
+        };
+        let sensor = cfg
+            .sensor
+            .as_deref()
+            .map(|s| s.trim().to_lowercase())
+            .filter(|s| !s.is_empty());
+        if category.is_none() && sensor.is_none() {
+            return Err("needs a category or a sensor".to_string());
+        }
+        if cfg.warning.is_none() && cfg.critical.is_none() && cfg.emergency.is_none() {
+            return Err("sets no warning, critical or emergency threshold".to_string());
+        }
+        let clamp = |v: Option<f32>| v.map(|v| v.clamp(30.0, 150.0));
+        Ok(Self {
+            category,
+            sensor,
+            warning: clamp(cfg.warning),
+            critical: clamp(cfg.critical),
+            emergency: clamp(cfg.emergency),
+        })
+    }
+
+    /// One-line summary, e.g. `ssd: warning 70°C, critical 80°C`.
+    pub fn describe(&self) -> String {
+        let target = match (&self.category, &self.sensor) {
+            (Some(c), Some(s)) => format!("{} '{}'", c, s),
+            (Some(c), None) => c.clone(),
+            (None, Some(s)) => format!("'{}'", s),
+            (None, None) => String::new(),
+        };
+        let levels: Vec<String> = [
+            ("warning", self.warning),
+            ("critical", self.critical),
+            ("emergency", self.emergency),
+        ]
+        .iter()
+        .filter_map(|(name, v)| v.map(|v| format!("{} {:.0}°C", name, v)))
+        .collect();
+        format!("{}: {}", target, levels.join(", "))
+    }
+
+    fn matches(&self, category: &str, name: &str, alias: Option<&str>) -> bool {
+        if self.category.as_deref().is_some_and(|c| c != category) {
+            return false;
+        }
+        self.sensor.as_deref().is_none_or(|p| {
+            glob_match(p, &name.to_lowercase())
+                || alias.is_some_and(|a| glob_match(p, &a.to_lowercase()))
+        })
+    }
+
+    fn apply(&self, levels: &mut Levels) {
+        if let Some(v) = self.warning {
+            levels.warning = v;
+        }
+        if let Some(v) = self.critical {
+            levels.critical = v;
+        }
+        if let Some(v) = self.emergency {
+            levels.emergency = v;
+        }
+    }
+}
+
+/// Resolves thresholds and display names for thermal sensors.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThermalThresholds {
+    defaults: Levels,
+    overrides: Vec<ThresholdOverride>,
+    /// Lowercased sensor name → alias.
+    aliases: BTreeMap<String, String>,
+}
+
+impl Default for ThermalThresholds {
+    fn default() -> Self {
+        Self::from_config(&ThermalConfig::default())
+    }
+}
+
+impl ThermalThresholds {
+    /// Compile the config; invalid entries are skipped (config load warns about them).
+    pub fn from_config(cfg: &ThermalConfig) -> Self {
+        Self {
+            defaults: Levels {
+                warning: cfg.warning_threshold,
+                critical: cfg.critical_threshold,
+                emergency: cfg.emergency_threshold,
+            },
+            overrides: cfg
+                .thresholds
+                .iter()
+                .filter_map(|t| ThresholdOverride::compile(t).ok())
+                .collect(),
+            aliases: cfg
+                .sensor_aliases
+                .iter()
+                .map(|(name, alias)| (name.trim().to_lowercase(), alias.clone()))
+                .collect(),
+        }
+    }
+
+    /// The global `[thermal]` thresholds.
+    pub fn defaults(&self) -> Levels {
+        self.defaults
+    }
+
+    pub fn overrides(&self) -> &[ThresholdOverride] {
+        &self.overrides
+    }
+
+    /// The user's alias for a sensor, if any (case-insensitive).
+    pub fn alias(&self, sensor: &str) -> Option<&str> {
+        self.aliases
+            .get(&sensor.trim().to_lowercase())
+            .map(String::as_str)
+    }
+
+    /// The alias if one is set, else the sensor name.
+    pub fn display_name<'a>(&'a self, sensor: &'a str) -> &'a str {
+        self.alias(sensor).unwrap_or(sensor)
+    }
+
+    /// Thresholds for one sensor of `category`.
+    pub fn levels(&self, category: &str, sensor: &str) -> Levels {
+        let alias = self.alias(sensor);
+        let mut levels = self.defaults;
+        let (by_category, by_sensor): (Vec<_>, Vec<_>) =
+            self.overrides.iter().partition(|o| o.sensor.is_none());
+        for o in by_category.into_iter().chain(by_sensor) {
+            if o.matches(category, sensor, alias) {
+                o.apply(&mut levels);
+            }
+        }
+        levels
+    }
+
+    /// The most severe level any temperature sensor of `snapshot` is at.
+    pub fn worst(&self, snapshot: &super::ThermalSnapshot) -> Level {
+        self.hottest(snapshot)
+            .map_or(Level::Normal, |(level, _)| level)
+    }
+
+    /// The most severe level any temperature sensor is at, with that
+    /// sensor's temperature (the hottest one on a tie).
+    pub fn hottest(&self, snapshot: &super::ThermalSnapshot) -> Option<(Level, f32)> {
+        snapshot
+            .readings()
+            .iter()
+            .filter(|(category, _, _)| *category != "fan")
+            .map(|(category, sensor, value)| {
+                (self.levels(category, sensor).classify(*value), *value)
+            })
+            .max_by(|a, b| a.0.cmp(&b.0).then(a.1.total_cmp(&b.1)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(category: Option<&str>, sensor: Option<&str>) -> ThermalThresholdConfig {
+        ThermalThresholdConfig {
+            category: category.map(String::from),
+            sensor: sensor.map(String::from),
+            warning: None,
+            critical: None,
+            emergency: None,
+        }
+    }
+
+    fn config() -> ThermalConfig {
+        let mut ssd = entry(Some("ssd"), None);
+        ssd.warning = Some(70.0);
+        ssd.critical = Some(80.0);
+        let mut scratch = entry(None, Some("scratch-*"));
+        scratch.critical = Some(75.0);
+        ThermalConfig {
+            // Sensor entries win even when listed before category entries
+            thresholds: vec![scratch, ssd],
+            sensor_aliases: BTreeMap::from([(
+                "Samsung SSD 980 PRO".to_string(),
+                "scratch-nvme".to_string(),
+            )]),
+            ..ThermalConfig::default()
+        }
+    }
+
+    #[test]
+    fn category_and_sensor_overrides_layer_on_defaults() {
+        let t = ThermalThresholds::from_config(&config());
+
+        let cpu = t.levels("cpu", "CPU Package");
+        assert_eq!(cpu, t.defaults());
+
+        let ssd = t.levels("ssd", "WD Blue");
+        assert_eq!((ssd.warning, ssd.critical), (70.0, 80.0));
+        assert_eq!(ssd.emergency, t.defaults().emergency);
+
+        // Matched through its alias
+        let scratch = t.levels("ssd", "samsung ssd 980 pro");
+        assert_eq!((scratch.warning, scratch.critical), (70.0, 75.0));
+        assert_eq!(scratch.classify(76.0), Level::Critical);
+        assert_eq!(scratch.classify(60.0), Level::Normal);
+    }
+
+    #[test]
+    fn aliases_rename_sensors() {
+        let t = ThermalThresholds::from_config(&config());
+        assert_eq!(t.display_name("Samsung SSD 980 PRO"), "scratch-nvme");
+        assert_eq!(t.display_name("CPU Package"), "CPU Package");
+    }
+
+    #[test]
+    fn compile_rejects_bad_entries() {
+        assert!(ThresholdOverride::compile(&entry(None, None)).is_err());
+        assert!(ThresholdOverride::compile(&entry(Some("ssd"), None)).is_err());
+        let mut bad = entry(Some("nvme"), None);
+        bad.warning = Some(70.0);
+        assert!(ThresholdOverride::compile(&bad).is_err());
+
+        let mut ok = entry(Some("SSD"), None);
+        ok.warning = Some(10.0);
+        let o = ThresholdOverride::compile(&ok).unwrap();
+        assert_eq!(o.describe(), "ssd: warning 30°C");
+    }
+
+    #[test]
+    fn worst_level_uses_each_sensors_thresholds() {
+        let t = ThermalThresholds::from_config(&config());
+        let mut snap = super::super::ThermalSnapshot::empty();
+        snap.cpu_package = Some(72.0);
+        assert_eq!(t.worst(&snap), Level::Normal);
+
+        snap.ssd_temps.push(super::super::SensorReading {
+            name: "Samsung SSD 980 PRO".to_string(),
+            value: 72.0,
+        });
+        assert_eq!(t.worst(&snap), Level::Warning);
+    }
+
+    #[test]
+    fn hottest_reports_the_sensor_past_its_own_emergency() {
+        let mut cfg = config();
+        cfg.emergency_threshold = 100.0;
+        let mut ssd = entry(Some("ssd"), None);
+        ssd.emergency = Some(85.0);
+        cfg.thresholds.push(ssd);
+        let t = ThermalThresholds::from_config(&cfg);
+
+        let mut snap = super::super::ThermalSnapshot::empty();
+        assert_eq!(t.hottest(&snap), None);
+        snap.cpu_package = Some(95.0);
+        snap.ssd_temps.push(super::super::SensorReading {
+            name: "WD Blue".to_string(),
+            value: 86.0,
+        });
+        // The cooler SSD is past its override; the CPU is under the global 100°C
+        assert_eq!(t.hottest(&snap), Some((Level::Emergency, 86.0)));
+        assert_eq!(t.worst(&snap), Level::Emergency);
+    }
+}
@@ -5,5 +5,5 @@ pub mod theme;
 mod widgets;
 
 pub use renderer::render_with_plugins;
-pub use state::{AppState, CommandResult, Tab, SIGNAL_LIST};
+pub use state::{AppState, CgroupRow, CommandResult, DetailTab, Tab, SIGNAL_LIST};
 pub use theme::Theme;
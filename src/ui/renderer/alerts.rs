@@ -1,4 +1,5 @@
-//! Alerts tab: full alert history with scrolling.
+//! Alerts tab: full alert history with scrolling and lifecycle status
+//! (FIRING / ACK / RESOLVED, or SILENCED by a silence / maintenance window).
 
 use ratatui::{
     layout::Rect,
@@ -8,7 +9,8 @@ use ratatui::{
     Frame,
 };
 
-use crate::models::AlertSeverity;
+use crate::alerts::AlertKey;
+use crate::models::{Alert, AlertSeverity};
 use crate::ui::state::AppState;
 
 use super::helpers::render_scrollbar;
@@ -17,7 +19,11 @@ pub fn render_alerts(frame: &mut Frame, area: Rect, state: &AppState) {
     let t = &state.theme;
     let block = Block::default()
         .title(Span::styled(
-            format!(" Alert History ({}) ", state.alerts.len()),
+            format!(
+                " Alert History ({}) — {} firing ",
+                state.shown_alerts().len(),
+                state.shown_active_alerts().len()
+            ),
             t.header_style(),
         ))
         .borders(Borders::ALL)
@@ -26,7 +32,7 @@ pub fn render_alerts(frame: &mut Frame, area: Rect, state: &AppState) {
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
-    if state.alerts.is_empty() {
+    if state.shown_alerts().is_empty() {
         let msg = Paragraph::new(vec![
             Line::raw(""),
             Line::from(vec![Span::styled(
@@ -51,11 +57,12 @@ pub fn render_alerts(frame: &mut Frame, area: Rect, state: &AppState) {
     let visible_count = inner.height as usize;
 
     let lines: Vec<Line> = state
-        .alerts
+        .shown_alerts()
         .iter()
         .skip(visible_start)
         .take(visible_count)
-        .map(|a| {
+        .enumerate()
+        .map(|(i, a)| {
             let severity_symbol = match a.severity {
                 AlertSeverity::Info => "i",
                 AlertSeverity::Warning => "!",
@@ -63,8 +70,16 @@ pub fn render_alerts(frame: &mut Frame, area: Rect, state: &AppState) {
                 AlertSeverity::Danger => "X",
             };
 
-            Line::from(vec![
+            let (status, status_style) = match lifecycle_status(state, a) {
+                _ if a.suppressed => ("SILENCED", Style::default().fg(t.text_dim)),
+                Status::Firing => ("FIRING", Style::default().fg(t.danger)),
+                Status::Acknowledged => ("ACK", Style::default().fg(t.warning)),
+                Status::Resolved => ("RESOLVED", Style::default().fg(t.success)),
+            };
+
+            let line = Line::from(vec![
                 Span::styled(format!(" {} ", severity_symbol), t.alert_style(a.severity)),
+                Span::styled(format!("{:<8} ", status), status_style),
                 Span::styled(
                     format!("{:>6} ", a.severity),
                     t.severity_badge_style(a.severity),
@@ -87,11 +102,38 @@ pub fn render_alerts(frame: &mut Frame, area: Rect, state: &AppState) {
                     format!("  ({})", a.age_display()),
                     Style::default().fg(t.text_muted),
                 ),
-            ])
+            ]);
+            // Top row is the selection for `a` (acknowledge)
+            if i == 0 {
+                line.style(t.table_row_selected())
+            } else {
+                line
+            }
         })
         .collect();
 
     frame.render_widget(Paragraph::new(lines), inner);
 
-    render_scrollbar(frame, inner, state.alerts.len(), state.alert_scroll);
+    render_scrollbar(frame, inner, state.shown_alerts().len(), state.alert_scroll);
+}
+
+enum Status {
+    Firing,
+    Acknowledged,
+    Resolved,
+}
+
+/// An alert line is firing if its key is still active and it belongs to the
+/// current firing episode; otherwise it has resolved.
+fn lifecycle_status(state: &AppState, alert: &Alert) -> Status {
+    let key = AlertKey::of(alert);
+    match state
+        .shown_active_alerts()
+        .iter()
+        .find(|a| a.key == key && alert.timestamp >= a.since)
+    {
+        Some(a) if a.acknowledged => Status::Acknowledged,
+        Some(_) => Status::Firing,
+        None => Status::Resolved,
+    }
 }
@@ -9,7 +9,8 @@ use ratatui::{
     Frame,
 };
 
-use crate::models::format_bytes;
+use crate::constants::MAX_DASHBOARD_GPU_ROWS;
+use crate::models::{format_bytes, GpuInfo};
 use crate::ui::state::{AppState, FocusedWidget};
 use crate::ui::widgets::{CpuMiniChart, GradientGauge};
 use crate::utils::loading_dots;
@@ -68,7 +69,8 @@ pub fn render_dashboard(frame: &mut Frame, area: Rect, state: &AppState) {
 
     // Normal dashboard layout
     let has_insight = state.ai_has_key;
-    let has_gpu = state.system.as_ref().and_then(|s| s.gpu.as_ref()).is_some();
+    let gpu_count = state.shown_system().map_or(0, |s| s.gpus.len());
+    let has_gpu = gpu_count > 0;
     let has_docker = state.docker_available && !state.containers.is_empty();
     let has_thermal = state.thermal.is_some();
 
@@ -81,7 +83,12 @@ pub fn render_dashboard(frame: &mut Frame, area: Rect, state: &AppState) {
     } else {
         0
     };
-    let gpu_height: u16 = if has_gpu { 5 } else { 0 };
+    // One GPU: util + VRAM + details; several: one line per device
+    let gpu_height: u16 = match gpu_count {
+        0 => 0,
+        1 => 5,
+        n => (n as u16).min(MAX_DASHBOARD_GPU_ROWS) + 2,
+    };
     // Thermal summary: compact 3-row widget (border + 1 line of temps)
     let thermal_height: u16 = if has_thermal { 3 } else { 0 };
     let docker_height: u16 = if has_docker {
@@ -163,7 +170,9 @@ fn render_system_gauges(frame: &mut Frame, area: Rect, state: &AppState) {
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
-    let Some(sys) = &state.system else { return };
+    let Some(sys) = state.shown_system() else {
+        return;
+    };
 
     let has_battery = sys.battery.is_some();
     let mut constraints = vec![
@@ -300,7 +309,9 @@ fn render_system_gauges(frame: &mut Frame, area: Rect, state: &AppState) {
 
 fn render_cpu_cores(frame: &mut Frame, area: Rect, state: &AppState) {
     let t = &state.theme;
-    let Some(sys) = &state.system else { return };
+    let Some(sys) = state.shown_system() else {
+        return;
+    };
 
     let title = if let Some(ref temp) = sys.cpu_temp {
         if let Some(pkg) = temp.package_temp {
@@ -386,7 +397,7 @@ fn render_sparklines(frame: &mut Frame, area: Rect, state: &AppState) {
         .split(inner);
 
     let cpu_data: Vec<u64> = state
-        .cpu_history
+        .shown_cpu_history()
         .iter()
         .copied()
         .rev()
@@ -414,7 +425,7 @@ fn render_sparklines(frame: &mut Frame, area: Rect, state: &AppState) {
     frame.render_widget(cpu_spark, halves[0]);
 
     let mem_data: Vec<u64> = state
-        .mem_history
+        .shown_mem_history()
         .iter()
         .copied()
         .rev()
@@ -446,18 +457,31 @@ fn render_sparklines(frame: &mut Frame, area: Rect, state: &AppState) {
 }
 
 fn render_gpu_panel(frame: &mut Frame, area: Rect, state: &AppState) {
-    let t = &state.theme;
-    let Some(sys) = &state.system else { return };
-    let Some(gpu) = &sys.gpu else { return };
-
-    let title = format!(
-        " GPU: {} ({}°C, {:.0}W) ",
-        truncate_str(&gpu.name, 24),
-        gpu.temperature,
-        gpu.power_draw
-    );
+    let Some(sys) = state.shown_system() else {
+        return;
+    };
+    match sys.gpus.as_slice() {
+        [] => {}
+        [gpu] => render_single_gpu(frame, area, state, gpu),
+        gpus => render_gpu_list(frame, area, state, gpus),
+    }
+}
 
-    let block = Block::default()
+/// `62°C, 45W` from whichever sensors the GPU exposes.
+fn gpu_sensor_summary(gpu: &GpuInfo) -> String {
+    let mut parts = Vec::new();
+    if let Some(temp) = gpu.temperature {
+        parts.push(format!("{}°C", temp));
+    }
+    if let Some(power) = gpu.power_draw {
+        parts.push(format!("{:.0}W", power));
+    }
+    parts.join(", ")
+}
+
+fn gpu_block<'a>(title: String, state: &AppState) -> Block<'a> {
+    let t = &state.theme;
+    Block::default()
         .title(Span::styled(
             title,
             Style::default()
@@ -465,7 +489,19 @@ fn render_gpu_panel(frame: &mut Frame, area: Rect, state: &AppState) {
                 .add_modifier(Modifier::BOLD),
         ))
         .borders(Borders::ALL)
-        .border_style(t.border_style());
+        .border_style(t.border_style())
+}
+
+fn render_single_gpu(frame: &mut Frame, area: Rect, state: &AppState, gpu: &GpuInfo) {
+    let t = &state.theme;
+    let sensors = gpu_sensor_summary(gpu);
+    let title = if sensors.is_empty() {
+        format!(" GPU: {} ", truncate_str(&gpu.name, 24))
+    } else {
+        format!(" GPU: {} ({}) ", truncate_str(&gpu.name, 24), sensors)
+    };
+
+    let block = gpu_block(title, state);
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
@@ -481,29 +517,39 @@ fn render_gpu_panel(frame: &mut Frame, area: Rect, state: &AppState) {
     let gpu_gauge = GradientGauge::new(gpu.utilization as f32, "GPU  ", t, &state.glyphs);
     frame.render_widget(gpu_gauge, rows[0]);
 
-    let vram_pct = gpu.memory_percent();
-    let vram_label = format!(
-        "VRAM {} / {}  ",
-        crate::models::format_bytes(gpu.memory_used),
-        crate::models::format_bytes(gpu.memory_total),
-    );
-    let vram_gauge = GradientGauge::new(vram_pct, &vram_label, t, &state.glyphs);
-    frame.render_widget(vram_gauge, rows[1]);
+    if gpu.memory_total > 0 {
+        let vram_pct = gpu.memory_percent();
+        let vram_label = format!(
+            "VRAM {} / {}  ",
+            crate::models::format_bytes(gpu.memory_used),
+            crate::models::format_bytes(gpu.memory_total),
+        );
+        let vram_gauge = GradientGauge::new(vram_pct, &vram_label, t, &state.glyphs);
+        frame.render_widget(vram_gauge, rows[1]);
+    } else {
+        let shared = Paragraph::new(Span::styled(
+            " VRAM: shared with system memory",
+            Style::default().fg(t.text_dim),
+        ));
+        frame.render_widget(shared, rows[1]);
+    }
 
     if rows[2].height >= 1 {
-        let temp_color = t.temp_color(gpu.temperature as f32);
-        let mut detail_spans = vec![
-            Span::styled(" Temp: ", Style::default().fg(t.text_dim)),
-            Span::styled(
-                format!("{}°C", gpu.temperature),
-                Style::default().fg(temp_color),
-            ),
-            Span::styled("  Power: ", Style::default().fg(t.text_dim)),
-            Span::styled(
-                format!("{:.0}W", gpu.power_draw),
+        let mut detail_spans = Vec::new();
+        if let Some(temp) = gpu.temperature {
+            detail_spans.push(Span::styled(" Temp: ", Style::default().fg(t.text_dim)));
+            detail_spans.push(Span::styled(
+                format!("{}°C", temp),
+                Style::default().fg(t.temp_color(temp as f32)),
+            ));
+        }
+        if let Some(power) = gpu.power_draw {
+            detail_spans.push(Span::styled("  Power: ", Style::default().fg(t.text_dim)));
+            detail_spans.push(Span::styled(
+                format!("{:.0}W", power),
                 Style::default().fg(t.text_primary),
-            ),
-        ];
+            ));
+        }
         if let Some(fan) = gpu.fan_speed {
             detail_spans.push(Span::styled("  Fan: ", Style::default().fg(t.text_dim)));
             detail_spans.push(Span::styled(
@@ -515,6 +561,59 @@ fn render_gpu_panel(frame: &mut Frame, area: Rect, state: &AppState) {
     }
 }
 
+/// One line per GPU: utilization gauge, then VRAM and sensors.
+fn render_gpu_list(frame: &mut Frame, area: Rect, state: &AppState, gpus: &[GpuInfo]) {
+    let t = &state.theme;
+    let block = gpu_block(format!(" GPUs ({}) ", gpus.len()), state);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let label_width = gpus.iter().map(|g| g.id.len()).max().unwrap_or(0);
+    for (i, gpu) in gpus.iter().enumerate().take(inner.height as usize) {
+        let row = Rect {
+            y: inner.y + i as u16,
+            height: 1,
+            ..inner
+        };
+        let halves = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+            .split(row);
+
+        let label = format!("{:<width$} ", gpu.id, width = label_width);
+        let gauge = GradientGauge::new(gpu.utilization as f32, &label, t, &state.glyphs);
+        frame.render_widget(gauge, halves[0]);
+
+        let mut spans = vec![Span::styled(
+            format!(" {}", truncate_str(&gpu.name, 18)),
+            Style::default().fg(t.text_primary),
+        )];
+        if gpu.memory_total > 0 {
+            spans.push(Span::styled(
+                format!(
+                    "  {}/{}",
+                    crate::models::format_bytes(gpu.memory_used),
+                    crate::models::format_bytes(gpu.memory_total),
+                ),
+                Style::default().fg(t.text_dim),
+            ));
+        }
+        if let Some(temp) = gpu.temperature {
+            spans.push(Span::styled(
+                format!("  {}°C", temp),
+                Style::default().fg(t.temp_color(temp as f32)),
+            ));
+        }
+        if let Some(power) = gpu.power_draw {
+            spans.push(Span::styled(
+                format!("  {:.0}W", power),
+                Style::default().fg(t.text_primary),
+            ));
+        }
+        frame.render_widget(Paragraph::new(Line::from(spans)), halves[1]);
+    }
+}
+
 fn render_network_panel(frame: &mut Frame, area: Rect, state: &AppState) {
     let t = &state.theme;
     let block = Block::default()
@@ -527,7 +626,9 @@ fn render_network_panel(frame: &mut Frame, area: Rect, state: &AppState) {
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
-    let Some(sys) = &state.system else { return };
+    let Some(sys) = state.shown_system() else {
+        return;
+    };
 
     if sys.networks.is_empty() {
         let msg = Paragraph::new(Line::from(vec![Span::styled(
@@ -639,7 +740,9 @@ fn render_disk_panel(frame: &mut Frame, area: Rect, state: &AppState) {
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
-    let Some(sys) = &state.system else { return };
+    let Some(sys) = state.shown_system() else {
+        return;
+    };
 
     if sys.disks.is_empty() {
         let msg = Paragraph::new(Line::from(vec![Span::styled(
@@ -950,7 +1053,7 @@ fn render_top_processes(frame: &mut Frame, area: Rect, state: &AppState) {
 
 fn render_recent_alerts(frame: &mut Frame, area: Rect, state: &AppState) {
     let t = &state.theme;
-    let alert_count = state.alerts.len();
+    let alert_count = state.shown_alerts().len();
     let title = format!(" Recent Alerts ({}) ", alert_count);
     let block = Block::default()
         .title(Span::styled(&title, t.header_style()))
@@ -964,7 +1067,7 @@ fn render_recent_alerts(frame: &mut Frame, area: Rect, state: &AppState) {
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
-    if state.alerts.is_empty() {
+    if state.shown_alerts().is_empty() {
         let msg = Paragraph::new(Line::from(vec![Span::styled(
             t!("alert.none_healthy").to_string(),
             Style::default().fg(t.success),
@@ -974,7 +1077,7 @@ fn render_recent_alerts(frame: &mut Frame, area: Rect, state: &AppState) {
     }
 
     let lines: Vec<Line> = state
-        .alerts
+        .shown_alerts()
         .iter()
         .take(inner.height as usize)
         .map(|a| {
@@ -112,6 +112,27 @@ pub fn render_header_with_plugins(
         ));
     }
 
+    // Replay indicator: the screen shows stored data
+    if let Some(ref replay) = state.replay {
+        let when = replay
+            .frame_time()
+            .map(|t| t.format("%m-%d %H:%M:%S").to_string())
+            .unwrap_or_default();
+        let mode = if replay.playing {
+            format!("{}x", replay.speed())
+        } else {
+            "paused".to_string()
+        };
+        tab_spans.push(Span::raw(" "));
+        tab_spans.push(Span::styled(
+            format!(" REPLAY {} {} ", when, mode),
+            Style::default()
+                .fg(t.bg_dark)
+                .bg(t.warning)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+
     let tab_line = Paragraph::new(Line::from(tab_spans)).block(
         Block::default()
             .borders(Borders::ALL)
@@ -120,7 +141,7 @@ pub fn render_header_with_plugins(
     frame.render_widget(tab_line, chunks[1]);
 
     // Quick system summary
-    let sys_text = if let Some(sys) = &state.system {
+    let sys_text = if let Some(sys) = state.shown_system() {
         t!(
             "summary.cpu_ram_procs",
             cpu = format!("{:.0}", sys.global_cpu_usage),
@@ -6,7 +6,7 @@
 //! - `processes`: Processes tab (flat + tree view)
 //! - `alerts`: Alerts tab
 //! - `ai_chat`: Ask AI tab (chat history + input)
-//! - `overlays`: Popup overlays (process detail, help, signal picker, renice)
+//! - `overlays`: Popup overlays (process detail, help, signal picker, renice, fan curves)
 //! - `helpers`: Shared rendering utilities
 
 mod ai_chat;
@@ -80,6 +80,14 @@ pub fn render_with_plugins(frame: &mut Frame, state: &AppState, plugins: Option<
         overlays::render_renice_dialog(frame, size, state);
     }
 
+    if state.show_limit_dialog {
+        overlays::render_limit_dialog(frame, size, state);
+    }
+
+    if state.fan_editor.is_some() {
+        overlays::render_fan_curve_editor(frame, size, state);
+    }
+
     if state.show_help {
         overlays::render_help_overlay_with_plugins(frame, size, state, plugins);
     }
@@ -1,16 +1,18 @@
 //! Popup overlays: process detail, help, signal picker, renice dialog,
-//! command palette, command result.
+//! fan curve editor, command palette, command result.
 
 use ratatui::{
     layout::Rect,
     style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, Paragraph},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
     Frame,
 };
 
+use crate::models::format_bytes;
 use crate::plugins::registry::PluginRegistry;
-use crate::ui::state::AppState;
+use crate::ui::state::{AppState, DetailTab, LimitField, ProcessDetail};
+use crate::ui::theme::Theme;
 
 use super::helpers::{centered_rect, render_scrollbar, truncate_str};
 
@@ -29,7 +31,7 @@ pub fn render_process_detail(frame: &mut Frame, area: Rect, state: &AppState) {
     let block = Block::default()
         .title(Span::styled(
             format!(
-                " Process {} - {} (Esc to close, ↑↓ scroll) ",
+                " Process {} - {} (Esc close, ←→ section, ↑↓ scroll) ",
                 detail.pid, detail.name
             ),
             t.header_style(),
@@ -39,14 +41,70 @@ pub fn render_process_detail(frame: &mut Frame, area: Rect, state: &AppState) {
     let inner = block.inner(popup_area);
     frame.render_widget(block, popup_area);
 
-    let mut lines: Vec<Line> = Vec::new();
-    let cmd_width = (inner.width as usize).saturating_sub(4);
+    // Section bar on the first row, the selected section below it
+    let mut tab_spans = vec![Span::raw(" ")];
+    for (i, tab) in DetailTab::ALL.iter().enumerate() {
+        let label = format!("{} {}", i + 1, tab.label());
+        if *tab == state.detail_tab {
+            tab_spans.push(Span::styled(label, t.tab_active_style()));
+        } else {
+            tab_spans.push(Span::styled(label, t.tab_inactive_style()));
+        }
+        tab_spans.push(Span::raw(" "));
+    }
+    let bar_area = Rect {
+        height: inner.height.min(1),
+        ..inner
+    };
+    frame.render_widget(Paragraph::new(Line::from(tab_spans)), bar_area);
+    let body = Rect {
+        y: inner.y + inner.height.min(2),
+        height: inner.height.saturating_sub(2),
+        ..inner
+    };
 
-    // Basic info section
-    lines.push(Line::from(Span::styled(
-        " Process Info",
-        Style::default().fg(t.accent).add_modifier(Modifier::BOLD),
-    )));
+    let width = (body.width as usize).saturating_sub(4);
+    let lines = if state.detail_loading && state.detail_tab != DetailTab::Overview {
+        vec![Line::from(Span::styled(
+            format!("  Loading{}", crate::utils::loading_dots(state.tick_count)),
+            Style::default()
+                .fg(t.text_muted)
+                .add_modifier(Modifier::ITALIC),
+        ))]
+    } else {
+        match state.detail_tab {
+            DetailTab::Overview => detail_overview(detail, width, t),
+            DetailTab::Memory => detail_memory_io(detail, t),
+            DetailTab::Limits => detail_limits(detail, t),
+            DetailTab::Isolation => detail_isolation(detail, width, t),
+            DetailTab::Sockets => detail_sockets(detail, t),
+            DetailTab::Threads => detail_threads(detail, t),
+            DetailTab::Files => detail_files(detail, width, t),
+            DetailTab::Environment => detail_environ(detail, width, t),
+        }
+    };
+
+    // Apply scrolling
+    let visible_height = body.height as usize;
+    let total_lines = lines.len();
+    let scroll = state
+        .detail_scroll
+        .min(total_lines.saturating_sub(visible_height));
+
+    let visible_lines: Vec<Line> = lines
+        .into_iter()
+        .skip(scroll)
+        .take(visible_height)
+        .collect();
+
+    frame.render_widget(Paragraph::new(visible_lines), body);
+
+    render_scrollbar(frame, body, total_lines, scroll);
+}
+
+/// Process info, resource usage, scheduling and the full command.
+fn detail_overview<'a>(detail: &'a ProcessDetail, width: usize, t: &Theme) -> Vec<Line<'a>> {
+    let mut lines = vec![detail_section(" Process Info", t)];
     lines.push(detail_line("PID:      ", &format!("{}", detail.pid), t));
     lines.push(detail_line("Name:     ", &detail.name, t));
     lines.push(detail_line("User:     ", &detail.user, t));
@@ -63,10 +121,7 @@ pub fn render_process_detail(frame: &mut Frame, area: Rect, state: &AppState) {
     lines.push(Line::raw(""));
 
     // Resource usage
-    lines.push(Line::from(Span::styled(
-        " Resource Usage",
-        Style::default().fg(t.accent).add_modifier(Modifier::BOLD),
-    )));
+    lines.push(detail_section(" Resource Usage", t));
     let cpu_color = t.usage_color(detail.cpu_usage);
     lines.push(Line::from(vec![
         Span::styled("  CPU:      ", Style::default().fg(t.text_dim)),
@@ -81,118 +136,339 @@ pub fn render_process_detail(frame: &mut Frame, area: Rect, state: &AppState) {
         Span::styled(
             format!(
                 "{} ({:.1}%)",
-                crate::models::format_bytes(detail.memory_bytes),
+                format_bytes(detail.memory_bytes),
                 detail.memory_percent
             ),
             Style::default().fg(mem_color),
         ),
     ]));
+    if let Some(ref applied) = detail.limit {
+        lines.push(Line::from(vec![
+            Span::styled("  Limit:    ", Style::default().fg(t.text_dim)),
+            Span::styled(applied.limits.describe(), Style::default().fg(t.warning)),
+            Span::styled(
+                format!(" ({})", applied.group),
+                Style::default().fg(t.text_muted),
+            ),
+        ]));
+    }
     lines.push(Line::raw(""));
 
+    // Scheduling
+    if let Some(ref sched) = detail.inspection.scheduling {
+        lines.push(detail_section(" Scheduling", t));
+        lines.push(detail_line("Policy:   ", sched.policy, t));
+        lines.push(detail_line(
+            "Priority: ",
+            &format!(
+                "{} (nice {}, real-time {})",
+                sched.priority, sched.nice, sched.rt_priority
+            ),
+            t,
+        ));
+        lines.push(detail_line(
+            "Last CPU: ",
+            &format!("{}", sched.processor),
+            t,
+        ));
+        lines.push(Line::raw(""));
+    }
+
     // Full command
-    lines.push(Line::from(Span::styled(
-        " Full Command",
-        Style::default().fg(t.accent).add_modifier(Modifier::BOLD),
-    )));
-    for line in textwrap::wrap(&detail.cmd, cmd_width) {
+    lines.push(detail_section(" Full Command", t));
+    for line in textwrap::wrap(&detail.cmd, width) {
         lines.push(Line::from(vec![
             Span::styled("  ", Style::default()),
             Span::styled(line.to_string(), Style::default().fg(t.text_primary)),
         ]));
     }
+    lines
+}
+
+/// smaps_rollup memory breakdown and /proc/<pid>/io counters.
+fn detail_memory_io<'a>(detail: &ProcessDetail, t: &Theme) -> Vec<Line<'a>> {
+    let mut lines = vec![detail_section(" Memory (smaps_rollup)", t)];
+    match detail.inspection.memory {
+        Some(ref mem) => {
+            lines.push(detail_line("RSS:      ", &format_bytes(mem.rss), t));
+            lines.push(detail_line("PSS:      ", &format_bytes(mem.pss), t));
+            lines.push(detail_line("USS:      ", &format_bytes(mem.uss), t));
+            lines.push(detail_line("Shared:   ", &format_bytes(mem.shared), t));
+            lines.push(detail_line("Swap:     ", &format_bytes(mem.swap), t));
+        }
+        None => lines.push(detail_unavailable(t)),
+    }
     lines.push(Line::raw(""));
 
-    // Open file descriptors (#22: show loading state while deferred)
-    if state.detail_loading {
-        lines.push(Line::from(Span::styled(
-            " Open File Descriptors",
-            Style::default().fg(t.accent).add_modifier(Modifier::BOLD),
-        )));
+    lines.push(detail_section(" I/O", t));
+    match detail.inspection.io {
+        Some(ref io) => {
+            lines.push(detail_line(
+                "Read:          ",
+                &format!("{} in {} syscalls", format_bytes(io.rchar), io.syscr),
+                t,
+            ));
+            lines.push(detail_line(
+                "Written:       ",
+                &format!("{} in {} syscalls", format_bytes(io.wchar), io.syscw),
+                t,
+            ));
+            lines.push(detail_line(
+                "Storage read:  ",
+                &format_bytes(io.read_bytes),
+                t,
+            ));
+            lines.push(detail_line(
+                "Storage write: ",
+                &format_bytes(io.write_bytes),
+                t,
+            ));
+            lines.push(detail_line(
+                "Cancelled:     ",
+                &format_bytes(io.cancelled_write_bytes),
+                t,
+            ));
+        }
+        None => lines.push(detail_unavailable(t)),
+    }
+    lines
+}
+
+/// /proc/<pid>/limits table.
+fn detail_limits<'a>(detail: &ProcessDetail, t: &Theme) -> Vec<Line<'a>> {
+    let mut lines = vec![detail_section(" Resource Limits", t)];
+    if detail.inspection.limits.is_empty() {
+        lines.push(detail_unavailable(t));
+        return lines;
+    }
+    lines.push(Line::from(Span::styled(
+        format!("  {:<26}{:<14}{:<14}{}", "LIMIT", "SOFT", "HARD", "UNITS"),
+        t.table_header_style(),
+    )));
+    for limit in &detail.inspection.limits {
         lines.push(Line::from(vec![
-            Span::styled("  ", Style::default()),
             Span::styled(
-                format!("  Loading{}", crate::utils::loading_dots(state.tick_count)),
-                Style::default()
-                    .fg(t.text_muted)
-                    .add_modifier(Modifier::ITALIC),
+                format!("  {:<26}", truncate_str(&limit.name, 25)),
+                Style::default().fg(t.text_dim),
             ),
+            Span::styled(
+                format!("{:<14}{:<14}", limit.soft, limit.hard),
+                Style::default().fg(t.text_primary),
+            ),
+            Span::styled(limit.units.clone(), Style::default().fg(t.text_muted)),
         ]));
-        lines.push(Line::raw(""));
+    }
+    lines
+}
 
-        lines.push(Line::from(Span::styled(
-            " Environment Variables",
-            Style::default().fg(t.accent).add_modifier(Modifier::BOLD),
-        )));
+/// cgroup, namespaces and capabilities.
+fn detail_isolation<'a>(detail: &ProcessDetail, width: usize, t: &Theme) -> Vec<Line<'a>> {
+    let info = &detail.inspection;
+    let mut lines = vec![detail_section(" cgroup", t)];
+    lines.push(detail_line(
+        "Path:     ",
+        info.cgroup.as_deref().unwrap_or("(unknown)"),
+        t,
+    ));
+    lines.push(Line::raw(""));
+
+    lines.push(detail_section(" Namespaces", t));
+    if info.namespaces.is_empty() {
+        lines.push(detail_unavailable(t));
+    }
+    for ns in &info.namespaces {
+        let (note, color) = if ns.shared {
+            ("shared with Sentinel", t.text_muted)
+        } else {
+            ("isolated", t.warning)
+        };
         lines.push(Line::from(vec![
-            Span::styled("  ", Style::default()),
+            Span::styled(format!("  {:<8}", ns.kind), Style::default().fg(t.text_dim)),
             Span::styled(
-                format!("  Loading{}", crate::utils::loading_dots(state.tick_count)),
-                Style::default()
-                    .fg(t.text_muted)
-                    .add_modifier(Modifier::ITALIC),
+                format!("{:<22}", ns.id),
+                Style::default().fg(t.text_primary),
             ),
+            Span::styled(note, Style::default().fg(color)),
         ]));
+    }
+    lines.push(Line::raw(""));
+
+    lines.push(detail_section(" Capabilities", t));
+    let Some(ref caps) = info.capabilities else {
+        lines.push(detail_unavailable(t));
+        return lines;
+    };
+    let names = caps.effective_names();
+    let effective = if names.is_empty() {
+        "none".to_string()
     } else {
+        names.join(" ")
+    };
+    lines.push(Line::from(Span::styled(
+        format!("  Effective ({}):", names.len()),
+        Style::default().fg(t.text_dim),
+    )));
+    for line in textwrap::wrap(&effective, width.saturating_sub(2)) {
         lines.push(Line::from(Span::styled(
-            format!(" Open File Descriptors ({})", detail.open_fds),
-            Style::default().fg(t.accent).add_modifier(Modifier::BOLD),
+            format!("    {}", line),
+            Style::default().fg(if names.is_empty() {
+                t.text_primary
+            } else {
+                t.warning
+            }),
         )));
-        for fd in &detail.fd_sample {
-            lines.push(Line::from(vec![
-                Span::styled("  ", Style::default()),
-                Span::styled(truncate_str(fd, cmd_width), Style::default().fg(t.text_dim)),
-            ]));
-        }
-        if detail.open_fds > detail.fd_sample.len() {
-            lines.push(Line::from(vec![
-                Span::styled("  ", Style::default()),
-                Span::styled(
-                    format!(
-                        "  ... and {} more",
-                        detail.open_fds - detail.fd_sample.len()
-                    ),
-                    Style::default().fg(t.text_muted),
-                ),
-            ]));
-        }
-        lines.push(Line::raw(""));
+    }
+    for (label, mask) in [
+        ("Permitted:   ", caps.permitted),
+        ("Inheritable: ", caps.inheritable),
+        ("Bounding:    ", caps.bounding),
+        ("Ambient:     ", caps.ambient),
+    ] {
+        lines.push(detail_line(
+            label,
+            &format!("{:016x} ({})", mask, mask.count_ones()),
+            t,
+        ));
+    }
+    lines
+}
 
-        // Environment variables
+/// The process's sockets from the latest stored snapshot.
+fn detail_sockets<'a>(detail: &ProcessDetail, t: &Theme) -> Vec<Line<'a>> {
+    let mut lines = vec![detail_section(
+        &format!(" Sockets ({})", detail.sockets.len()),
+        t,
+    )];
+    if detail.sockets.is_empty() {
         lines.push(Line::from(Span::styled(
-            format!(" Environment Variables ({})", detail.environ.len()),
-            Style::default().fg(t.accent).add_modifier(Modifier::BOLD),
+            "  No sockets in the latest snapshot",
+            Style::default().fg(t.text_muted),
         )));
-        for var in &detail.environ {
-            lines.push(Line::from(vec![
-                Span::styled("  ", Style::default()),
-                Span::styled(
-                    truncate_str(var, cmd_width),
-                    Style::default().fg(t.text_dim),
+        return lines;
+    }
+    lines.push(Line::from(Span::styled(
+        format!("  {:<6}{:<24}{:<24}{}", "PROTO", "LOCAL", "REMOTE", "STATE"),
+        t.table_header_style(),
+    )));
+    for s in &detail.sockets {
+        let remote = match (&s.remote_addr, s.remote_port) {
+            (Some(addr), Some(port)) => format!("{}:{}", addr, port),
+            _ => "*".to_string(),
+        };
+        let state_color = if s.state == "LISTEN" {
+            t.accent
+        } else {
+            t.text_primary
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("  {:<6}", s.protocol),
+                Style::default().fg(t.text_dim),
+            ),
+            Span::styled(
+                format!(
+                    "{:<24}{:<24}",
+                    truncate_str(&format!("{}:{}", s.local_addr, s.local_port), 23),
+                    truncate_str(&remote, 23)
                 ),
-            ]));
-        }
+                Style::default().fg(t.text_primary),
+            ),
+            Span::styled(s.state.clone(), Style::default().fg(state_color)),
+        ]));
     }
+    lines
+}
 
-    // Apply scrolling
-    let visible_height = inner.height as usize;
-    let total_lines = lines.len();
-    let scroll = state
-        .detail_scroll
-        .min(total_lines.saturating_sub(visible_height));
+/// Threads with CPU usage since the previous refresh.
+fn detail_threads<'a>(detail: &ProcessDetail, t: &Theme) -> Vec<Line<'a>> {
+    let threads = &detail.inspection.threads;
+    let mut lines = vec![detail_section(&format!(" Threads ({})", threads.len()), t)];
+    if threads.is_empty() {
+        lines.push(detail_unavailable(t));
+        return lines;
+    }
+    lines.push(Line::from(Span::styled(
+        format!("  {:>8}  {:<18}{:<7}{:>7}", "TID", "NAME", "STATE", "CPU %"),
+        t.table_header_style(),
+    )));
+    for thread in threads {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("  {:>8}  ", thread.tid),
+                Style::default().fg(t.text_dim),
+            ),
+            Span::styled(
+                format!("{:<18}{:<7}", truncate_str(&thread.name, 17), thread.state),
+                Style::default().fg(t.text_primary),
+            ),
+            Span::styled(
+                format!("{:>6.1}%", thread.cpu_usage),
+                Style::default().fg(t.usage_color(thread.cpu_usage)),
+            ),
+        ]));
+    }
+    lines
+}
 
-    let visible_lines: Vec<Line> = lines
-        .into_iter()
-        .skip(scroll)
-        .take(visible_height)
-        .collect();
+/// Open file descriptors (first `MAX_FD_SAMPLE`).
+fn detail_files<'a>(detail: &ProcessDetail, width: usize, t: &Theme) -> Vec<Line<'a>> {
+    let mut lines = vec![detail_section(
+        &format!(" Open File Descriptors ({})", detail.open_fds),
+        t,
+    )];
+    for fd in &detail.fd_sample {
+        lines.push(Line::from(vec![
+            Span::styled("  ", Style::default()),
+            Span::styled(truncate_str(fd, width), Style::default().fg(t.text_dim)),
+        ]));
+    }
+    if detail.open_fds > detail.fd_sample.len() {
+        lines.push(Line::from(vec![
+            Span::styled("  ", Style::default()),
+            Span::styled(
+                format!(
+                    "  ... and {} more",
+                    detail.open_fds - detail.fd_sample.len()
+                ),
+                Style::default().fg(t.text_muted),
+            ),
+        ]));
+    }
+    lines
+}
 
-    frame.render_widget(Paragraph::new(visible_lines), inner);
+fn detail_environ<'a>(detail: &ProcessDetail, width: usize, t: &Theme) -> Vec<Line<'a>> {
+    let mut lines = vec![detail_section(
+        &format!(" Environment Variables ({})", detail.environ.len()),
+        t,
+    )];
+    for var in &detail.environ {
+        lines.push(Line::from(vec![
+            Span::styled("  ", Style::default()),
+            Span::styled(truncate_str(var, width), Style::default().fg(t.text_dim)),
+        ]));
+    }
+    lines
+}
 
-    render_scrollbar(frame, inner, total_lines, scroll);
+/// Helper: a bold section title in the detail popup.
+fn detail_section<'a>(title: &str, t: &Theme) -> Line<'a> {
+    Line::from(Span::styled(
+        title.to_string(),
+        Style::default().fg(t.accent).add_modifier(Modifier::BOLD),
+    ))
+}
+
+/// Helper: placeholder for a /proc file that could not be read.
+fn detail_unavailable<'a>(t: &Theme) -> Line<'a> {
+    Line::from(Span::styled(
+        "  (not readable; try running as root)",
+        Style::default().fg(t.text_muted),
+    ))
 }
 
 /// Helper: create a simple "  label: value" detail line.
-fn detail_line<'a>(label: &str, value: &str, t: &crate::ui::theme::Theme) -> Line<'a> {
+fn detail_line<'a>(label: &str, value: &str, t: &Theme) -> Line<'a> {
     Line::from(vec![
         Span::styled(format!("  {}", label), Style::default().fg(t.text_dim)),
         Span::styled(value.to_string(), Style::default().fg(t.text_primary)),
@@ -267,14 +543,22 @@ pub fn render_help_overlay_with_plugins(
         entry("s", "Cycle sort column", t.accent),
         entry("r", "Reverse sort direction", t.accent),
         entry("/", "Filter by name/cmd/PID", t.accent),
-        entry("Enter", "Process detail popup", t.accent),
+        entry("Enter", "Process inspector", t.accent),
+        entry("Left/Right / 1-8", "Inspector: switch section", t.accent),
         entry("t", "Toggle process tree view", t.accent),
+        entry("c", "Toggle cgroup view (Enter expands)", t.accent),
         entry("k", "SIGTERM selected process", t.warning),
         entry("K (shift)", "SIGKILL selected process", t.danger),
         entry("x", "Signal picker (choose signal)", t.warning),
         entry("n", "Renice process", t.accent),
+        entry("C (shift)", "Limit CPU / memory / I/O (cgroup)", t.warning),
         entry("a", "Ask AI about process", t.ai_accent),
         Line::raw(""),
+        // ── Alerts ──
+        section("Alerts Tab"),
+        entry("a", "Acknowledge top alert", t.accent),
+        dim_line("FIRING / ACK / RESOLVED / SILENCED per alert"),
+        Line::raw(""),
         // ── Renice ──
         section("Renice Dialog"),
         entry("Left / Right", "Adjust nice (+/-5)", t.accent),
@@ -317,8 +601,17 @@ pub fn render_help_overlay_with_plugins(
         // ── Thermal ──
         section("Thermal Guardian"),
         entry("Ctrl+X", "ABORT thermal shutdown", t.danger),
-        dim_line("Auto-shutdown is OFF by default"),
-        dim_line("Requires config + .env double-gate"),
+        entry("f", "Edit fan curves", t.accent_secondary),
+        dim_line("Auto-shutdown and fan control are OFF by default"),
+        dim_line("Both require config + .env double-gate"),
+        Line::raw(""),
+        // ── Replay ──
+        section("Replay (:replay [time])"),
+        entry(", / .", "Previous / next snapshot", t.warning),
+        entry("< / >", "Back / forward 10 minutes", t.warning),
+        entry("Space", "Play / pause", t.warning),
+        entry("[ / ]", "Slower / faster playback", t.warning),
+        entry("Esc", "Back to live data", t.warning),
         Line::raw(""),
         // ── Appearance ──
         section("Appearance"),
@@ -341,12 +634,30 @@ pub fn render_help_overlay_with_plugins(
             t.accent_secondary,
         ),
         entry("events [min]", "Event timeline log", t.accent_secondary),
+        entry(
+            "ack <all|pid|name>",
+            "Acknowledge firing alerts",
+            t.accent_secondary,
+        ),
+        entry("silence", "List silences / windows", t.accent_secondary),
+        entry(
+            "silence <dur> [k=v]",
+            "Mute matching alerts",
+            t.accent_secondary,
+        ),
+        entry("unsilence <id|all>", "Remove a silence", t.accent_secondary),
+        entry("escalations", "Escalation chains", t.accent_secondary),
         entry(
             "port <number>",
             "Who's using this port?",
             t.accent_secondary,
         ),
         entry("pid <number>", "Deep process analysis", t.accent_secondary),
+        entry(
+            "replay [time|off]",
+            "Scrub back through history",
+            t.accent_secondary,
+        ),
         entry(
             "anomaly [min]",
             "Anomaly detection scan",
@@ -361,6 +672,8 @@ pub fn render_help_overlay_with_plugins(
             "Send test Telegram msg",
             t.accent_secondary,
         ),
+        entry("webhook-test", "Send test webhook payload", t.accent_secondary),
+        entry("chat-test", "Send test chat message", t.accent_secondary),
         entry("config", "Show configuration", t.accent_secondary),
         entry("stats", "Event store statistics", t.accent_secondary),
         entry("<any text>", "Ask AI (natural language)", t.ai_accent),
@@ -563,6 +876,312 @@ pub fn render_renice_dialog(frame: &mut Frame, area: Rect, state: &AppState) {
     frame.render_widget(Paragraph::new(lines), inner);
 }
 
+/// Render the cgroup limit dialog (Processes tab `C`).
+pub fn render_limit_dialog(frame: &mut Frame, area: Rect, state: &AppState) {
+    let t = &state.theme;
+    let popup_width = 56.min(area.width.saturating_sub(4));
+    let popup_height = 13.min(area.height.saturating_sub(4));
+    let popup_area = centered_rect(popup_width, popup_height, area);
+
+    frame.render_widget(Clear, popup_area);
+
+    let title = format!(
+        " Limit PID {} ({}) ",
+        state.limit_pid.unwrap_or(0),
+        truncate_str(&state.limit_name, 16),
+    );
+
+    let block = Block::default()
+        .title(Span::styled(title, t.header_style()))
+        .borders(Borders::ALL)
+        .border_style(t.border_highlight_style());
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let v = &state.limit_values;
+    let unlimited = || "unlimited".to_string();
+    let tree_label = if state.limit_tree {
+        format!("yes ({} processes)", state.limit_targets().len())
+    } else {
+        "no".to_string()
+    };
+    let rows = [
+        (
+            LimitField::Cpu,
+            "CPU (cpu.max)",
+            v.cpu_percent
+                .map_or_else(unlimited, |pct| format!("{}% of one CPU", pct)),
+        ),
+        (
+            LimitField::Memory,
+            "Memory (memory.max)",
+            v.memory_bytes
+                .map_or_else(unlimited, crate::models::format_bytes),
+        ),
+        (
+            LimitField::Io,
+            "Disk I/O (io.max)",
+            v.io_bytes_per_sec.map_or_else(unlimited, |rate| {
+                format!("{}/s", crate::models::format_bytes(rate))
+            }),
+        ),
+        (LimitField::Tree, "Whole process tree", tree_label),
+    ];
+
+    let g = &state.glyphs;
+    let mut lines = vec![Line::raw("")];
+    for (field, label, value) in rows {
+        let selected = field == state.limit_field;
+        let marker = if selected { g.pointer } else { " " };
+        let value_style = if selected {
+            Style::default().fg(t.accent).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(t.text_primary)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!(" {} ", marker), Style::default().fg(t.accent)),
+            Span::styled(format!("{:<21}", label), Style::default().fg(t.text_dim)),
+            Span::styled(value, value_style),
+        ]));
+    }
+    lines.push(Line::raw(""));
+    lines.push(Line::from(Span::styled(
+        if v.is_unlimited() {
+            "  Applying with no limits lifts existing ones"
+        } else {
+            "  Moves the process into its own cgroup v2 group"
+        },
+        Style::default().fg(t.text_muted),
+    )));
+    lines.push(Line::raw(""));
+    lines.push(Line::from(vec![
+        Span::styled(
+            "  ↑/↓ ",
+            Style::default().fg(t.accent).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled("Field  ", Style::default().fg(t.text_dim)),
+        Span::styled(
+            "←/→ ",
+            Style::default().fg(t.accent).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled("Adjust  ", Style::default().fg(t.text_dim)),
+        Span::styled(
+            "0 ",
+            Style::default().fg(t.accent).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled("Unlimited", Style::default().fg(t.text_dim)),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled(
+            "  Enter ",
+            Style::default().fg(t.accent).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled("Apply  ", Style::default().fg(t.text_dim)),
+        Span::styled(
+            "Esc ",
+            Style::default().fg(t.accent).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled("Cancel", Style::default().fg(t.text_dim)),
+    ]));
+
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+/// Render the fan curve editor (Thermal tab `f`).
+pub fn render_fan_curve_editor(frame: &mut Frame, area: Rect, state: &AppState) {
+    use crate::thermal::fan::FanCurve;
+
+    let Some(ref editor) = state.fan_editor else {
+        return;
+    };
+    let t = &state.theme;
+    let popup_width = 64.min(area.width.saturating_sub(4));
+    let popup_height = 22.min(area.height.saturating_sub(2));
+    let popup_area = centered_rect(popup_width, popup_height, area);
+
+    frame.render_widget(Clear, popup_area);
+
+    let title = match editor.current() {
+        Some(curve) => format!(
+            " Fan Curve {}/{}: {} ",
+            editor.curve + 1,
+            editor.curves.len(),
+            truncate_str(&curve.name, 20)
+        ),
+        None => " Fan Curves ".to_string(),
+    };
+    let block = Block::default()
+        .title(Span::styled(title, t.header_style()))
+        .borders(Borders::ALL)
+        .border_style(t.border_highlight_style());
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let dim = |s: String| Span::styled(s, Style::default().fg(t.text_dim));
+    let value = |s: String| Span::styled(s, Style::default().fg(t.text_primary).add_modifier(Modifier::BOLD));
+    let key = |s: &'static str| {
+        Span::styled(s, Style::default().fg(t.accent).add_modifier(Modifier::BOLD))
+    };
+
+    let mut lines = Vec::new();
+    match editor.current() {
+        None => {
+            lines.push(Line::raw(""));
+            lines.push(Line::from(dim("  No fan curves configured.".into())));
+            lines.push(Line::from(dim(
+                "  Press n to add one on the first PWM output found.".into(),
+            )));
+        }
+        Some(config) => {
+            lines.push(Line::from(vec![
+                dim("  Output: ".into()),
+                value(config.pwm.clone()),
+                dim("   Sensor: ".into()),
+                value(config.sensor.clone()),
+                dim("   Hysteresis: ".into()),
+                value(format!("{:.0}°C", config.hysteresis)),
+            ]));
+            let live = state
+                .fan_controller
+                .channels()
+                .iter()
+                .find(|ch| ch.curve.name == config.name);
+            let status = match live {
+                _ if !state.fan_controller.is_enabled() => {
+                    "Fan control disabled (double-gate: config + .env)".to_string()
+                }
+                Some(ch) if ch.fallback => "Sensor unavailable: full speed".to_string(),
+                Some(ch) if ch.error.is_some() => ch.error.clone().unwrap_or_default(),
+                Some(ch) => format!(
+                    "Now {} → {}",
+                    ch.temp.map_or("--".to_string(), |v| format!("{:.1}°C", v)),
+                    ch.duty.map_or("--".to_string(), |v| format!("{:.0}%", v)),
+                ),
+                None => "Not applied yet (Enter saves)".to_string(),
+            };
+            lines.push(Line::from(dim(format!("  {}", status))));
+            lines.push(Line::raw(""));
+
+            match FanCurve::compile(config) {
+                Ok(curve) => {
+                    let live_temp = live.and_then(|ch| ch.temp);
+                    let selected_temp = config.points.get(editor.point).map(|p| p[0]);
+                    lines.extend(fan_curve_graph(&curve, live_temp, selected_temp, inner.width, state));
+                }
+                Err(e) => lines.push(Line::from(Span::styled(
+                    format!("  Invalid curve: {}", e),
+                    Style::default().fg(t.danger),
+                ))),
+            }
+            lines.push(Line::raw(""));
+
+            let mut points = vec![dim("  Points: ".into())];
+            for (i, [temp, duty]) in config.points.iter().enumerate() {
+                let text = format!("{:.0}°C→{:.0}%", temp, duty);
+                points.push(if i == editor.point {
+                    Span::styled(
+                        format!("[{}]", text),
+                        Style::default().fg(t.accent).add_modifier(Modifier::BOLD),
+                    )
+                } else {
+                    dim(format!(" {} ", text))
+                });
+            }
+            lines.push(Line::from(points));
+        }
+    }
+
+    lines.push(Line::raw(""));
+    lines.push(Line::from(vec![
+        key("  ↑/↓ "),
+        dim("Point  ".into()),
+        key("←/→ "),
+        dim("Temp  ".into()),
+        key("+/- "),
+        dim("Duty  ".into()),
+        key("a/x "),
+        dim("Add/Del point".into()),
+    ]));
+    lines.push(Line::from(vec![
+        key("  Tab "),
+        dim("Curve  ".into()),
+        key("n/D "),
+        dim("New/Del curve  ".into()),
+        key("s "),
+        dim("Sensor  ".into()),
+        key("p "),
+        dim("Output".into()),
+    ]));
+    lines.push(Line::from(vec![
+        key("  Enter "),
+        dim("Save  ".into()),
+        key("Esc "),
+        dim("Cancel".into()),
+    ]));
+
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), inner);
+}
+
+/// Duty-vs-temperature bars for the curve editor, with the selected point
+/// and the live reading highlighted.
+fn fan_curve_graph<'a>(
+    curve: &crate::thermal::fan::FanCurve,
+    live_temp: Option<f32>,
+    selected_temp: Option<f32>,
+    width: u16,
+    state: &AppState,
+) -> Vec<Line<'a>> {
+    const ROWS: usize = 8;
+    const LABEL: &str = "      ";
+    let t = &state.theme;
+    let g = &state.glyphs;
+
+    let cols = (width as usize).saturating_sub(LABEL.len() + 4).max(10);
+    let lo = (curve.points[0].0 - 10.0).max(0.0).floor();
+    let hi = curve.points[curve.points.len() - 1].0 + 10.0;
+    let temp_at = |col: usize| lo + (hi - lo) * col as f32 / (cols - 1) as f32;
+    let col_of = |temp: f32| (((temp - lo) / (hi - lo)) * (cols - 1) as f32).round() as usize;
+    let selected_col = selected_temp.map(col_of);
+    let live_col = live_temp.map(|v| col_of(v.clamp(lo, hi)));
+
+    let mut lines = Vec::with_capacity(ROWS + 1);
+    for row in 0..ROWS {
+        let label = match row {
+            0 => "  100%",
+            _ if row == ROWS - 1 => "    0%",
+            _ => LABEL,
+        };
+        let mut spans = vec![Span::styled(
+            format!("{} ", label),
+            Style::default().fg(t.text_muted),
+        )];
+        let level = ROWS - 1 - row;
+        for col in 0..cols {
+            let eighths = (curve.duty_at(temp_at(col)) / 100.0 * (ROWS * 8) as f32).round() as usize;
+            let fill = eighths.saturating_sub(level * 8).min(8);
+            let ch = if fill == 0 { ' ' } else { g.bar_chars[fill - 1] };
+            let color = if Some(col) == live_col {
+                t.warning
+            } else if Some(col) == selected_col {
+                t.accent
+            } else {
+                t.text_dim
+            };
+            spans.push(Span::styled(ch.to_string(), Style::default().fg(color)));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    let lo_label = format!("{:.0}°C", lo);
+    let hi_label = format!("{:.0}°C", hi);
+    let gap = cols.saturating_sub(lo_label.chars().count() + hi_label.chars().count());
+    lines.push(Line::from(Span::styled(
+        format!("{} {}{}{}", LABEL, lo_label, " ".repeat(gap), hi_label),
+        Style::default().fg(t.text_muted),
+    )));
+    lines
+}
+
 /// Render the thermal shutdown overlay when shutdown state is active.
 pub fn render_shutdown_overlay(frame: &mut Frame, area: Rect, state: &AppState) {
     use crate::thermal::shutdown::ShutdownState;
@@ -576,7 +1195,7 @@ pub fn render_shutdown_overlay(frame: &mut Frame, area: Rect, state: &AppState)
 
     let border_color = match &state.shutdown_manager.state {
         ShutdownState::Shutdown | ShutdownState::GracePeriod { .. } => t.danger,
-        ShutdownState::Counting { .. } => t.warning,
+        ShutdownState::Counting { .. } | ShutdownState::Mitigating { .. } => t.warning,
         ShutdownState::Normal => return, // Don't render if normal
     };
 
@@ -605,9 +1224,18 @@ pub fn render_shutdown_overlay(frame: &mut Frame, area: Rect, state: &AppState)
     let bar_width = inner.width.saturating_sub(4) as usize;
     let max_secs = match &state.shutdown_manager.state {
         ShutdownState::Counting { required_secs, .. } => *required_secs,
+        ShutdownState::Mitigating { hold_secs, .. } => *hold_secs,
         ShutdownState::GracePeriod { grace_secs, .. } => *grace_secs,
         _ => 30,
     };
+    let countdown = match &state.shutdown_manager.state {
+        ShutdownState::Mitigating { step, .. } => format!(
+            "  Mitigation step {}: escalates in {}s if still hot",
+            step + 1,
+            remaining
+        ),
+        _ => format!("  Countdown: {}s remaining", remaining),
+    };
     let elapsed_ratio = if max_secs > 0 {
         1.0 - (remaining as f64 / max_secs as f64)
     } else {
@@ -632,10 +1260,7 @@ pub fn render_shutdown_overlay(frame: &mut Frame, area: Rect, state: &AppState)
                 .add_modifier(Modifier::BOLD),
         )),
         Line::raw(""),
-        Line::from(Span::styled(
-            format!("  Countdown: {}s remaining", remaining),
-            Style::default().fg(t.text_primary),
-        )),
+        Line::from(Span::styled(countdown, Style::default().fg(t.text_primary))),
         Line::from(Span::styled(
             format!("  {}", bar),
             Style::default().fg(border_color),
@@ -920,6 +1545,9 @@ fn render_action_confirm(frame: &mut Frame, area: Rect, state: &AppState) {
         crate::diagnostics::SuggestedAction::ReniceProcess { .. } => {
             (" Confirm: Renice Process ".to_string(), 0)
         }
+        crate::diagnostics::SuggestedAction::LimitProcess { .. } => {
+            (" Confirm: Limit Process ".to_string(), 0)
+        }
         crate::diagnostics::SuggestedAction::CleanDirectory { .. } => {
             (" Confirm: Clean Directory ".to_string(), 2)
         }
@@ -1,4 +1,4 @@
-//! Processes tab: flat table and tree view.
+//! Processes tab: flat table, tree view and cgroup view.
 
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
@@ -8,9 +8,10 @@ use ratatui::{
     Frame,
 };
 
-use crate::ui::state::{AppState, SortColumn, SortDirection};
+use crate::models::format_bytes;
+use crate::ui::state::{AppState, CgroupRow, SortColumn, SortDirection};
 
-use super::helpers::{render_scrollbar_bordered, status_badge, truncate_str};
+use super::helpers::{format_rate, render_scrollbar_bordered, status_badge, truncate_str};
 
 pub fn render_processes(frame: &mut Frame, area: Rect, state: &AppState) {
     let t = &state.theme;
@@ -28,7 +29,13 @@ pub fn render_processes(frame: &mut Frame, area: Rect, state: &AppState) {
         format!("Filter: {}_", state.filter_text)
     };
 
-    let tree_indicator = if state.tree_view { " [TREE] │" } else { "" };
+    let tree_indicator = if state.cgroup_view {
+        " [CGROUP] │"
+    } else if state.tree_view {
+        " [TREE] │"
+    } else {
+        ""
+    };
     let filtered = state.filtered_processes();
     let info = format!(
         " {} processes shown │{} Sort: {:?} {:?} │ {} ",
@@ -62,13 +69,17 @@ pub fn render_processes(frame: &mut Frame, area: Rect, state: &AppState) {
         }
     };
 
-    let title = if state.tree_view {
+    let title = if state.cgroup_view {
+        t!("title.process_cgroups").to_string()
+    } else if state.tree_view {
         t!("title.process_tree").to_string()
     } else {
         t!("title.process_list").to_string()
     };
 
-    if state.tree_view {
+    if state.cgroup_view {
+        render_cgroup_view(frame, chunks[1], state, &title);
+    } else if state.tree_view {
         render_tree_view(frame, chunks[1], state, &title);
     } else {
         render_flat_view(frame, chunks[1], state, &title, sort_indicator);
@@ -148,6 +159,119 @@ fn render_tree_view(frame: &mut Frame, area: Rect, state: &AppState, title: &str
     render_scrollbar_bordered(frame, area, total, state.selected_process);
 }
 
+fn render_cgroup_view(frame: &mut Frame, area: Rect, state: &AppState, title: &str) {
+    let t = &state.theme;
+    let g = &state.glyphs;
+    let rows_data = state.cgroup_rows();
+    let block = Block::default()
+        .title(Span::styled(title, t.header_style()))
+        .borders(Borders::ALL)
+        .border_style(t.border_style());
+
+    if rows_data.is_empty() {
+        let msg = if state.shown_system().is_some_and(|s| s.cgroups.is_empty()) {
+            "  No cgroup v2 hierarchy found (is /sys/fs/cgroup mounted as cgroup2?)"
+        } else {
+            "  No matching cgroups"
+        };
+        let p = Paragraph::new(Span::styled(msg, Style::default().fg(t.text_dim))).block(block);
+        frame.render_widget(p, area);
+        return;
+    }
+
+    let header = Row::new(vec![
+        Cell::from("PID").style(t.table_header_style()),
+        Cell::from("CGROUP / NAME").style(t.table_header_style()),
+        Cell::from("KIND").style(t.table_header_style()),
+        Cell::from("CPU %").style(t.table_header_style()),
+        Cell::from("MEMORY").style(t.table_header_style()),
+        Cell::from("IO").style(t.table_header_style()),
+        Cell::from("PIDS").style(t.table_header_style()),
+    ])
+    .height(1);
+
+    let rows: Vec<Row> = rows_data
+        .iter()
+        .enumerate()
+        .map(|(i, (prefix, row))| {
+            let style = if i == state.selected_process {
+                t.table_row_selected()
+            } else {
+                t.table_row_normal()
+            };
+            match row {
+                CgroupRow::Group(cg) => {
+                    let expanded =
+                        !state.filter_text.is_empty() || state.cgroup_expanded.contains(&cg.path);
+                    let marker = if expanded { g.sort_desc } else { g.pointer };
+                    let name = format!("{}{} {}", prefix, marker, state.cgroup_label(cg));
+                    let io = cg.io_bytes_per_sec();
+                    Row::new(vec![
+                        Cell::from(""),
+                        Cell::from(truncate_str(&name, 48))
+                            .style(Style::default().fg(t.text_primary)),
+                        Cell::from(cg.kind.as_str()).style(Style::default().fg(t.text_dim)),
+                        Cell::from(format!("{:.1}", cg.cpu_usage))
+                            .style(Style::default().fg(t.usage_color(cg.cpu_usage))),
+                        Cell::from(format_bytes(cg.memory_bytes)),
+                        Cell::from(if io > 0 {
+                            format_rate(io)
+                        } else {
+                            "-".to_string()
+                        }),
+                        Cell::from(format!("{}", cg.pids)),
+                    ])
+                    .style(style)
+                }
+                CgroupRow::Process(p) => {
+                    let name = format!("{}{}", prefix, p.name);
+                    let io = p.disk_read_bytes + p.disk_write_bytes;
+                    Row::new(vec![
+                        Cell::from(format!("{}", p.pid)).style(Style::default().fg(t.text_dim)),
+                        Cell::from(truncate_str(&name, 48))
+                            .style(Style::default().fg(t.text_primary)),
+                        Cell::from(status_badge(&p.status, t)),
+                        Cell::from(format!("{:.1}", p.cpu_usage))
+                            .style(Style::default().fg(t.usage_color(p.cpu_usage))),
+                        Cell::from(p.memory_display()),
+                        Cell::from(if io > 0 {
+                            format_rate(io)
+                        } else {
+                            "-".to_string()
+                        }),
+                        Cell::from(p.thread_count.map_or("-".to_string(), |n| n.to_string())),
+                    ])
+                    .style(style)
+                }
+            }
+        })
+        .collect();
+
+    let total = rows_data.len();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(8),
+            Constraint::Min(30),
+            Constraint::Length(10),
+            Constraint::Length(8),
+            Constraint::Length(12),
+            Constraint::Length(12),
+            Constraint::Length(6),
+        ],
+    )
+    .header(header)
+    .block(block)
+    .row_highlight_style(t.table_row_selected());
+
+    let mut table_state = TableState::default();
+    table_state.select(Some(state.selected_process));
+    frame.render_stateful_widget(table, area, &mut table_state);
+
+    render_scrollbar_bordered(frame, area, total, state.selected_process);
+}
+
 fn render_flat_view(
     frame: &mut Frame,
     area: Rect,
@@ -157,8 +281,11 @@ fn render_flat_view(
 ) {
     let t = &state.theme;
     let filtered = state.filtered_processes();
+    // GPU column only on machines with a GPU
+    let show_gpu = state.shown_system().is_some_and(|s| !s.gpus.is_empty())
+        || filtered.iter().any(|p| p.uses_gpu());
 
-    let header = Row::new(vec![
+    let mut header_cells = vec![
         Cell::from(format!("PID{}", sort_indicator(SortColumn::Pid))).style(t.table_header_style()),
         Cell::from(format!("NAME{}", sort_indicator(SortColumn::Name)))
             .style(t.table_header_style()),
@@ -169,12 +296,20 @@ fn render_flat_view(
         Cell::from("MEM %").style(t.table_header_style()),
         Cell::from("DISK R").style(t.table_header_style()),
         Cell::from("DISK W").style(t.table_header_style()),
+        Cell::from(format!("NET{}", sort_indicator(SortColumn::Net))).style(t.table_header_style()),
         Cell::from(format!("STATUS{}", sort_indicator(SortColumn::Status)))
             .style(t.table_header_style()),
         Cell::from("USER").style(t.table_header_style()),
         Cell::from("CMD").style(t.table_header_style()),
-    ])
-    .height(1);
+    ];
+    if show_gpu {
+        header_cells.insert(
+            5,
+            Cell::from(format!("GPU{}", sort_indicator(SortColumn::Gpu)))
+                .style(t.table_header_style()),
+        );
+    }
+    let header = Row::new(header_cells).height(1);
 
     let rows: Vec<Row> = filtered
         .iter()
@@ -182,13 +317,18 @@ fn render_flat_view(
         .map(|(i, p)| {
             let cpu_color = t.usage_color(p.cpu_usage);
             let mem_color = t.usage_color(p.memory_percent);
+            let net_color = if p.net_bytes_per_sec() > 0 {
+                t.info
+            } else {
+                t.text_dim
+            };
             let style = if i == state.selected_process {
                 t.table_row_selected()
             } else {
                 t.table_row_normal()
             };
 
-            Row::new(vec![
+            let mut cells = vec![
                 Cell::from(format!("{}", p.pid)).style(Style::default().fg(t.text_dim)),
                 Cell::from(truncate_str(&p.name, 22)),
                 Cell::from(format!("{:.1}", p.cpu_usage)).style(Style::default().fg(cpu_color)),
@@ -197,39 +337,54 @@ fn render_flat_view(
                     .style(Style::default().fg(mem_color)),
                 Cell::from(p.disk_read_display()).style(Style::default().fg(t.text_dim)),
                 Cell::from(p.disk_write_display()).style(Style::default().fg(t.text_dim)),
+                Cell::from(p.net_display()).style(Style::default().fg(net_color)),
                 Cell::from(status_badge(&p.status, t)),
                 Cell::from(truncate_str(&p.user, 10)).style(Style::default().fg(t.text_dim)),
                 Cell::from(truncate_str(&p.cmd, 40)).style(Style::default().fg(t.text_muted)),
-            ])
-            .style(style)
+            ];
+            if show_gpu {
+                let gpu_color = if p.uses_gpu() {
+                    t.usage_color(p.gpu_usage)
+                } else {
+                    t.text_dim
+                };
+                cells.insert(
+                    5,
+                    Cell::from(p.gpu_display()).style(Style::default().fg(gpu_color)),
+                );
+            }
+            Row::new(cells).style(style)
         })
         .collect();
 
     let total = filtered.len();
 
-    let table = Table::new(
-        rows,
-        [
-            Constraint::Length(8),
-            Constraint::Length(24),
-            Constraint::Length(8),
-            Constraint::Length(12),
-            Constraint::Length(7),
-            Constraint::Length(10),
-            Constraint::Length(10),
-            Constraint::Length(10),
-            Constraint::Length(12),
-            Constraint::Min(20),
-        ],
-    )
-    .header(header)
-    .block(
-        Block::default()
-            .title(Span::styled(title, t.header_style()))
-            .borders(Borders::ALL)
-            .border_style(t.border_style()),
-    )
-    .row_highlight_style(t.table_row_selected());
+    let mut widths = vec![
+        Constraint::Length(8),
+        Constraint::Length(24),
+        Constraint::Length(8),
+        Constraint::Length(12),
+        Constraint::Length(7),
+        Constraint::Length(10),
+        Constraint::Length(10),
+        Constraint::Length(12),
+        Constraint::Length(10),
+        Constraint::Length(12),
+        Constraint::Min(20),
+    ];
+    if show_gpu {
+        widths.insert(5, Constraint::Length(14));
+    }
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(
+            Block::default()
+                .title(Span::styled(title, t.header_style()))
+                .borders(Borders::ALL)
+                .border_style(t.border_style()),
+        )
+        .row_highlight_style(t.table_row_selected());
 
     let mut table_state = TableState::default();
     table_state.select(Some(state.selected_process));
@@ -10,7 +10,7 @@ use ratatui::{
     Frame,
 };
 
-use crate::constants::STATUS_MESSAGE_TIMEOUT_SECS;
+use crate::constants::{REPLAY_JUMP_MINUTES, STATUS_MESSAGE_TIMEOUT_SECS};
 use crate::plugins::registry::PluginRegistry;
 use crate::ui::state::{AppState, Tab};
 
@@ -49,6 +49,21 @@ pub fn render_status_bar_with_plugins(
 
     spans.push(sep());
 
+    // ── Replay hints ─────────────────────────────────────────
+    if state.replay.is_some() {
+        spans.push(badge(",/.", t.warning));
+        spans.push(dim(" Step "));
+        spans.push(badge("</>", t.warning));
+        spans.push(dim(&format!(" {}m ", REPLAY_JUMP_MINUTES)));
+        spans.push(badge("Space", t.warning));
+        spans.push(dim(" Play "));
+        spans.push(badge("[/]", t.warning));
+        spans.push(dim(" Speed "));
+        spans.push(badge("Esc", t.warning));
+        spans.push(dim(" Live "));
+        spans.push(sep());
+    }
+
     // ── Tab-specific hints ───────────────────────────────────
     match state.active_tab {
         Tab::Dashboard => {
@@ -82,6 +97,8 @@ pub fn render_status_bar_with_plugins(
         Tab::Alerts => {
             spans.push(badge(g.nav_up_down, t.accent));
             spans.push(dim(" Scroll "));
+            spans.push(badge("a", t.accent));
+            spans.push(dim(" Ack "));
         }
         Tab::AskAi => {
             spans.push(badge("Enter", t.ai_accent));
@@ -92,6 +109,8 @@ pub fn render_status_bar_with_plugins(
         Tab::Thermal => {
             spans.push(badge("r", t.accent_secondary));
             spans.push(dim(" Reload "));
+            spans.push(badge("f", t.accent_secondary));
+            spans.push(dim(" Fan curves "));
             spans.push(badge(":", t.accent_secondary));
             spans.push(dim(" thermal "));
             spans.push(badge("Ctrl+X", t.warning));
@@ -145,7 +164,7 @@ pub fn render_status_bar_with_plugins(
     }
 
     // ── System health indicator (right side) ─────────────────
-    if let Some(sys) = &state.system {
+    if let Some(sys) = state.shown_system() {
         let health_color = if sys.memory_percent() > 90.0 || sys.global_cpu_usage > 90.0 {
             t.danger
         } else if sys.memory_percent() > 75.0 || sys.global_cpu_usage > 75.0 {
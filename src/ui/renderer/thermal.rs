@@ -12,6 +12,7 @@ use ratatui::{
     Frame,
 };
 
+use crate::thermal::thresholds::{Level, Levels};
 use crate::ui::state::AppState;
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -22,7 +23,7 @@ use crate::ui::state::AppState;
 pub fn render_thermal_panel(frame: &mut Frame, area: Rect, state: &AppState) {
     let t = &state.theme;
     let block = Block::default()
-        .title(Span::styled(" Thermal Monitor (LHM) ", t.header_style()))
+        .title(Span::styled(" Thermal Monitor ", t.header_style()))
         .borders(Borders::ALL)
         .border_style(t.border_style());
     let inner = block.inner(area);
@@ -36,16 +37,11 @@ pub fn render_thermal_panel(frame: &mut Frame, area: Rect, state: &AppState) {
     if snap.cpu_package.is_some() || !snap.cpu_cores.is_empty() {
         lines.push(section_header("CPU ", t));
         if let Some(pkg) = snap.cpu_package {
-            lines.push(make_temp_line("  Package", pkg, 20, t, Some(&state.glyphs)));
+            lines.push(sensor_line(state, "cpu", "CPU Package", "Package", pkg, 20));
         }
         for core in &snap.cpu_cores {
-            let label = format!("  {}", core.name);
-            lines.push(make_temp_line(
-                &label,
-                core.value,
-                20,
-                t,
-                Some(&state.glyphs),
+            lines.push(sensor_line(
+                state, "cpu", &core.name, &core.name, core.value, 20,
             ));
         }
     }
@@ -54,15 +50,16 @@ pub fn render_thermal_panel(frame: &mut Frame, area: Rect, state: &AppState) {
     if snap.gpu_temp.is_some() || snap.gpu_hotspot.is_some() {
         lines.push(section_header("GPU ", t));
         if let Some(temp) = snap.gpu_temp {
-            lines.push(make_temp_line("  Core", temp, 20, t, Some(&state.glyphs)));
+            lines.push(sensor_line(state, "gpu", "GPU Core", "Core", temp, 20));
         }
         if let Some(temp) = snap.gpu_hotspot {
-            lines.push(make_temp_line(
-                "  Hot Spot",
+            lines.push(sensor_line(
+                state,
+                "gpu",
+                "GPU Hot Spot",
+                "Hot Spot",
                 temp,
                 20,
-                t,
-                Some(&state.glyphs),
             ));
         }
     }
@@ -71,8 +68,7 @@ pub fn render_thermal_panel(frame: &mut Frame, area: Rect, state: &AppState) {
     if !snap.ssd_temps.is_empty() {
         lines.push(section_header("Storage ", t));
         for s in snap.ssd_temps.iter().take(4) {
-            let label = format!("  {}", s.name);
-            lines.push(make_temp_line(&label, s.value, 20, t, Some(&state.glyphs)));
+            lines.push(sensor_line(state, "ssd", &s.name, &s.name, s.value, 20));
         }
     }
 
@@ -80,8 +76,7 @@ pub fn render_thermal_panel(frame: &mut Frame, area: Rect, state: &AppState) {
     if !snap.ram_temps.is_empty() {
         lines.push(section_header("Memory ", t));
         for s in snap.ram_temps.iter().take(4) {
-            let label = format!("  {}", s.name);
-            lines.push(make_temp_line(&label, s.value, 20, t, Some(&state.glyphs)));
+            lines.push(sensor_line(state, "ram", &s.name, &s.name, s.value, 20));
         }
     }
 
@@ -89,8 +84,7 @@ pub fn render_thermal_panel(frame: &mut Frame, area: Rect, state: &AppState) {
     if !snap.motherboard_temps.is_empty() {
         lines.push(section_header("Board ", t));
         for s in snap.motherboard_temps.iter().take(4) {
-            let label = format!("  {}", s.name);
-            lines.push(make_temp_line(&label, s.value, 20, t, Some(&state.glyphs)));
+            lines.push(sensor_line(state, "board", &s.name, &s.name, s.value, 20));
         }
     }
 
@@ -98,7 +92,11 @@ pub fn render_thermal_panel(frame: &mut Frame, area: Rect, state: &AppState) {
     if !snap.fan_rpms.is_empty() {
         lines.push(section_header("Fans ", t));
         for s in snap.fan_rpms.iter().take(4) {
-            lines.push(make_fan_line(&s.name, s.value, t));
+            lines.push(make_fan_line(
+                state.thermal_thresholds.display_name(&s.name),
+                s.value,
+                t,
+            ));
         }
     }
 
@@ -296,22 +294,18 @@ pub fn render_thermal_tab(frame: &mut Frame, area: Rect, state: &AppState) {
 
         let mut lines = Vec::new();
         if let Some(pkg) = snap.cpu_package {
-            lines.push(make_temp_line(
-                "  Package",
+            lines.push(sensor_line(
+                state,
+                "cpu",
+                "CPU Package",
+                "Package",
                 pkg,
                 bar_width,
-                t,
-                Some(&state.glyphs),
             ));
         }
         for core in &snap.cpu_cores {
-            let label = format!("  {}", core.name);
-            lines.push(make_temp_line(
-                &label,
-                core.value,
-                bar_width,
-                t,
-                Some(&state.glyphs),
+            lines.push(sensor_line(
+                state, "cpu", &core.name, &core.name, core.value, bar_width,
             ));
         }
         frame.render_widget(Paragraph::new(lines), inner);
@@ -336,21 +330,18 @@ pub fn render_thermal_tab(frame: &mut Frame, area: Rect, state: &AppState) {
 
         let mut lines = Vec::new();
         if let Some(temp) = snap.gpu_temp {
-            lines.push(make_temp_line(
-                "  Core",
-                temp,
-                bar_width,
-                t,
-                Some(&state.glyphs),
+            lines.push(sensor_line(
+                state, "gpu", "GPU Core", "Core", temp, bar_width,
             ));
         }
         if let Some(temp) = snap.gpu_hotspot {
-            lines.push(make_temp_line(
-                "  Hot Spot",
+            lines.push(sensor_line(
+                state,
+                "gpu",
+                "GPU Hot Spot",
+                "Hot Spot",
                 temp,
                 bar_width,
-                t,
-                Some(&state.glyphs),
             ));
         }
         frame.render_widget(Paragraph::new(lines), inner);
@@ -372,15 +363,7 @@ pub fn render_thermal_tab(frame: &mut Frame, area: Rect, state: &AppState) {
         let lines: Vec<Line> = snap
             .ssd_temps
             .iter()
-            .map(|s| {
-                make_temp_line(
-                    &format!("  {}", s.name),
-                    s.value,
-                    bar_width,
-                    t,
-                    Some(&state.glyphs),
-                )
-            })
+            .map(|s| sensor_line(state, "ssd", &s.name, &s.name, s.value, bar_width))
             .collect();
         frame.render_widget(Paragraph::new(lines), inner);
     }
@@ -401,15 +384,7 @@ pub fn render_thermal_tab(frame: &mut Frame, area: Rect, state: &AppState) {
         let lines: Vec<Line> = snap
             .ram_temps
             .iter()
-            .map(|s| {
-                make_temp_line(
-                    &format!("  {}", s.name),
-                    s.value,
-                    bar_width,
-                    t,
-                    Some(&state.glyphs),
-                )
-            })
+            .map(|s| sensor_line(state, "ram", &s.name, &s.name, s.value, bar_width))
             .collect();
         frame.render_widget(Paragraph::new(lines), inner);
     }
@@ -430,15 +405,7 @@ pub fn render_thermal_tab(frame: &mut Frame, area: Rect, state: &AppState) {
         let lines: Vec<Line> = snap
             .motherboard_temps
             .iter()
-            .map(|s| {
-                make_temp_line(
-                    &format!("  {}", s.name),
-                    s.value,
-                    bar_width,
-                    t,
-                    Some(&state.glyphs),
-                )
-            })
+            .map(|s| sensor_line(state, "board", &s.name, &s.name, s.value, bar_width))
             .collect();
         frame.render_widget(Paragraph::new(lines), inner);
     }
@@ -462,19 +429,24 @@ pub fn render_thermal_tab(frame: &mut Frame, area: Rect, state: &AppState) {
         let lines: Vec<Line> = snap
             .fan_rpms
             .iter()
-            .map(|s| make_fan_line(&s.name, s.value, t))
+            .map(|s| make_fan_line(state.thermal_thresholds.display_name(&s.name), s.value, t))
             .collect();
         frame.render_widget(Paragraph::new(lines), inner);
     }
 
-    // ── Right column: sparkline + summary + config + shutdown status ──
+    // ── Right column: sparkline + summary + config + fans + shutdown status ──
+    let fan_rows = match state.fan_controller.channels().len() {
+        0 => 0,
+        n => n as u16 + 2,
+    };
     let right_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(8),  // Temperature sparkline
-            Constraint::Length(10), // Thermal summary (#21)
-            Constraint::Length(10), // Thresholds & config
-            Constraint::Min(3),     // Shutdown status
+            Constraint::Length(8),        // Temperature sparkline
+            Constraint::Length(10),       // Thermal summary (#21)
+            Constraint::Length(10),       // Thresholds & config
+            Constraint::Length(fan_rows), // Fan control
+            Constraint::Min(3),           // Shutdown status
         ])
         .split(columns[1]);
 
@@ -487,8 +459,13 @@ pub fn render_thermal_tab(frame: &mut Frame, area: Rect, state: &AppState) {
     // Thresholds / config
     render_thresholds(frame, right_chunks[2], state);
 
+    // Fan control
+    if fan_rows > 0 {
+        render_fan_control(frame, right_chunks[3], state);
+    }
+
     // Shutdown status
-    render_shutdown_status(frame, right_chunks[3], state);
+    render_shutdown_status(frame, right_chunks[4], state);
 }
 
 /// Render when no thermal data is available.
@@ -512,7 +489,7 @@ fn render_no_data(frame: &mut Frame, area: Rect, state: &AppState) {
         )),
         Line::from(""),
         Line::from(Span::styled(
-            "  LibreHardwareMonitor is not reachable or not running.",
+            "  No Linux hwmon sensors, and LibreHardwareMonitor is not reachable.",
             Style::default().fg(t.text_dim),
         )),
         Line::from(""),
@@ -618,14 +595,12 @@ fn render_thermal_summary(frame: &mut Frame, area: Rect, state: &AppState) {
         None => return,
     };
 
-    let overall_status = if snap.max_temp >= crate::constants::DEFAULT_THERMAL_EMERGENCY_C {
-        ("EMERGENCY", Color::Red)
-    } else if snap.max_temp >= crate::constants::DEFAULT_THERMAL_CRITICAL_C {
-        ("CRITICAL", Color::Rgb(255, 140, 0))
-    } else if snap.max_temp >= crate::constants::DEFAULT_THERMAL_WARNING_C {
-        ("WARNING", Color::Yellow)
-    } else {
-        ("NORMAL", Color::Green)
+    // Each sensor against its own thresholds
+    let overall_status = match state.thermal_thresholds.worst(snap) {
+        Level::Emergency => ("EMERGENCY", Color::Red),
+        Level::Critical => ("CRITICAL", Color::Rgb(255, 140, 0)),
+        Level::Warning => ("WARNING", Color::Yellow),
+        Level::Normal => ("NORMAL", Color::Green),
     };
 
     let lines = vec![
@@ -710,11 +685,13 @@ fn render_thresholds(frame: &mut Frame, area: Rect, state: &AppState) {
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
-    let lines = vec![
+    let thresholds = &state.thermal_thresholds;
+    let levels = thresholds.defaults();
+    let mut lines = vec![
         Line::from(vec![
             Span::styled("  Warning:    ", Style::default().fg(t.text_dim)),
             Span::styled(
-                format!("{:.0}°C", crate::constants::DEFAULT_THERMAL_WARNING_C),
+                format!("{:.0}°C", levels.warning),
                 Style::default().fg(Color::Yellow),
             ),
             Span::styled("  (alert generated)", Style::default().fg(t.text_muted)),
@@ -722,7 +699,7 @@ fn render_thresholds(frame: &mut Frame, area: Rect, state: &AppState) {
         Line::from(vec![
             Span::styled("  Critical:   ", Style::default().fg(t.text_dim)),
             Span::styled(
-                format!("{:.0}°C", crate::constants::DEFAULT_THERMAL_CRITICAL_C),
+                format!("{:.0}°C", levels.critical),
                 Style::default().fg(Color::Rgb(255, 140, 0)),
             ),
             Span::styled(
@@ -733,11 +710,19 @@ fn render_thresholds(frame: &mut Frame, area: Rect, state: &AppState) {
         Line::from(vec![
             Span::styled("  Emergency:  ", Style::default().fg(t.text_dim)),
             Span::styled(
-                format!("{:.0}°C", crate::constants::DEFAULT_THERMAL_EMERGENCY_C),
+                format!("{:.0}°C", levels.emergency),
                 Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
             ),
             Span::styled("  (shutdown if enabled)", Style::default().fg(t.text_muted)),
         ]),
+    ];
+    lines.extend(thresholds.overrides().iter().map(|o| {
+        Line::from(Span::styled(
+            format!("  Override {}", o.describe()),
+            Style::default().fg(t.text_primary),
+        ))
+    }));
+    lines.extend([
         Line::from(""),
         Line::from(Span::styled(
             "  Configure in [thermal] section of config.toml",
@@ -751,7 +736,63 @@ fn render_thresholds(frame: &mut Frame, area: Rect, state: &AppState) {
             "  Run ':thermal' for live status, ':email-test' to test",
             Style::default().fg(t.text_muted),
         )),
-    ];
+    ]);
+
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+/// Render fan curve status: one line per curve.
+fn render_fan_control(frame: &mut Frame, area: Rect, state: &AppState) {
+    let t = &state.theme;
+    let fans = &state.fan_controller;
+
+    let block = Block::default()
+        .title(Span::styled(
+            if fans.is_enabled() {
+                " Fan Control (f: edit) "
+            } else {
+                " Fan Control — disabled (f: edit) "
+            },
+            Style::default()
+                .fg(if fans.is_enabled() {
+                    t.accent
+                } else {
+                    t.text_dim
+                })
+                .add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .border_style(t.border_style());
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let lines: Vec<Line> = fans
+        .channels()
+        .iter()
+        .map(|ch| {
+            let (status, color) = if ch.fallback {
+                ("sensor lost: 100%".to_string(), t.danger)
+            } else if let Some(ref e) = ch.error {
+                (e.clone(), t.danger)
+            } else {
+                (
+                    format!(
+                        "{} → {}",
+                        ch.temp.map_or("--".to_string(), |v| format!("{:.0}°C", v)),
+                        ch.duty.map_or("--".to_string(), |v| format!("{:.0}%", v)),
+                    ),
+                    t.text_primary,
+                )
+            };
+            Line::from(vec![
+                Span::styled(
+                    format!("  {:<10} {:<14} ", ch.curve.name, ch.curve.pwm),
+                    Style::default().fg(t.text_dim),
+                ),
+                Span::styled(status, Style::default().fg(color)),
+            ])
+        })
+        .collect();
 
     frame.render_widget(Paragraph::new(lines), inner);
 }
@@ -832,20 +873,42 @@ fn section_header<'a>(label: &str, t: &crate::ui::theme::Theme) -> Line<'a> {
     )])
 }
 
+/// Temperature line for one sensor: labelled with its alias (else `short`)
+/// and colored against its own thresholds.
+fn sensor_line<'a>(
+    state: &AppState,
+    category: &str,
+    sensor: &str,
+    short: &str,
+    temp: f32,
+    bar_width: usize,
+) -> Line<'a> {
+    let thresholds = &state.thermal_thresholds;
+    make_temp_line(
+        &format!("  {}", thresholds.alias(sensor).unwrap_or(short)),
+        temp,
+        thresholds.levels(category, sensor),
+        bar_width,
+        &state.theme,
+        Some(&state.glyphs),
+    )
+}
+
 /// Create a temperature line with a color-coded value and visual bar.
 fn make_temp_line<'a>(
     label: &str,
     temp: f32,
+    levels: Levels,
     bar_width: usize,
     t: &crate::ui::theme::Theme,
     g: Option<&crate::ui::glyphs::Glyphs>,
 ) -> Line<'a> {
-    let color = temp_color(temp);
+    let color = level_color(temp, levels);
     let bar = match g {
         Some(glyphs) => temp_bar_with_chars(temp, bar_width, glyphs.filled, glyphs.shade_light),
         None => temp_bar(temp, bar_width),
     };
-    let flashing = temp >= crate::constants::TEMP_CRITICAL_C;
+    let flashing = temp >= levels.critical;
 
     let mut style = Style::default().fg(color);
     if flashing {
@@ -891,6 +954,19 @@ fn temp_color(temp: f32) -> Color {
     }
 }
 
+/// Color for a temperature against its thresholds: red from critical,
+/// orange from warning, yellow within `TEMP_APPROACH_MARGIN_C` of warning.
+fn level_color(temp: f32, levels: Levels) -> Color {
+    match levels.classify(temp) {
+        Level::Emergency | Level::Critical => Color::Red,
+        Level::Warning => Color::Rgb(255, 140, 0), // Orange
+        Level::Normal if temp >= levels.warning - crate::constants::TEMP_APPROACH_MARGIN_C => {
+            Color::Yellow
+        }
+        Level::Normal => Color::Green,
+    }
+}
+
 /// Visual temperature bar using block characters.
 /// When `glyphs` is provided, uses the glyph set for the bar characters.
 fn temp_bar(temp: f32, max_width: usize) -> String {
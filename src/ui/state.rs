@@ -1,12 +1,21 @@
 use std::cmp::Ordering;
-use std::collections::VecDeque;
+use std::collections::{HashSet, VecDeque};
 
+use chrono::{DateTime, Local, TimeZone};
+
+use crate::alerts::ActiveAlert;
 use crate::ai::Conversation;
 use crate::constants::*;
 use crate::diagnostics::SuggestedAction;
-use crate::models::{Alert, ProcessInfo, SystemSnapshot};
+use crate::models::{Alert, CgroupInfo, ProcessInfo, ResourceLimits, SystemSnapshot};
+use crate::monitor::inspect::{ProcessInspection, ProcessInspector};
+use crate::monitor::limits::{process_tree, AppliedLimit, CgroupLimiter};
 use crate::monitor::ContainerInfo;
+use crate::replay::ReplayFrame;
+use crate::store::SocketRow;
+use crate::thermal::fan::{FanController, FanCurveEditor};
 use crate::thermal::shutdown::ShutdownManager;
+use crate::thermal::thresholds::ThermalThresholds;
 use crate::thermal::ThermalSnapshot;
 
 use super::glyphs::Glyphs;
@@ -46,6 +55,9 @@ impl CommandResult {
                         SuggestedAction::ReniceProcess { pid, name, nice } => {
                             format!("Set nice {} for PID {} ({})", nice, pid, name)
                         }
+                        SuggestedAction::LimitProcess { pid, name, limits } => {
+                            format!("Limit PID {} ({}) to {}", pid, name, limits.describe())
+                        }
                         SuggestedAction::FreePort { port, pid, name } => {
                             format!("Kill PID {} ({}) to free port {}", pid, name, port)
                         }
@@ -171,6 +183,91 @@ pub struct ProcessDetail {
     pub open_fds: usize,
     pub fd_sample: Vec<String>, // First N file descriptors
     pub environ: Vec<String>,   // Environment variables
+    /// Sentinel-managed cgroup limit the process sits in
+    pub limit: Option<AppliedLimit>,
+    /// smaps_rollup, io, limits, namespaces, capabilities, threads, ...
+    pub inspection: ProcessInspection,
+    /// The process's sockets from the latest stored snapshot
+    pub sockets: Vec<SocketRow>,
+    /// When the threads were last sampled, for per-thread CPU
+    pub threads_sampled_at: Option<std::time::Instant>,
+}
+
+/// Sections of the process detail popup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DetailTab {
+    Overview,
+    Memory,
+    Limits,
+    Isolation,
+    Sockets,
+    Threads,
+    Files,
+    Environment,
+}
+
+impl DetailTab {
+    pub const ALL: [DetailTab; 8] = [
+        DetailTab::Overview,
+        DetailTab::Memory,
+        DetailTab::Limits,
+        DetailTab::Isolation,
+        DetailTab::Sockets,
+        DetailTab::Threads,
+        DetailTab::Files,
+        DetailTab::Environment,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            DetailTab::Overview => "Overview",
+            DetailTab::Memory => "Mem/IO",
+            DetailTab::Limits => "Limits",
+            DetailTab::Isolation => "Isolation",
+            DetailTab::Sockets => "Sockets",
+            DetailTab::Threads => "Threads",
+            DetailTab::Files => "Files",
+            DetailTab::Environment => "Env",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let i = Self::ALL.iter().position(|t| *t == self).unwrap_or(0);
+        Self::ALL[(i + 1) % Self::ALL.len()]
+    }
+
+    pub fn prev(self) -> Self {
+        let i = Self::ALL.iter().position(|t| *t == self).unwrap_or(0);
+        Self::ALL[(i + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
+/// Rows of the limit dialog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LimitField {
+    Cpu,
+    Memory,
+    Io,
+    Tree,
+}
+
+impl LimitField {
+    pub const ALL: [LimitField; 4] = [
+        LimitField::Cpu,
+        LimitField::Memory,
+        LimitField::Io,
+        LimitField::Tree,
+    ];
+
+    pub fn next(self) -> Self {
+        let i = Self::ALL.iter().position(|f| *f == self).unwrap_or(0);
+        Self::ALL[(i + 1) % Self::ALL.len()]
+    }
+
+    pub fn prev(self) -> Self {
+        let i = Self::ALL.iter().position(|f| *f == self).unwrap_or(0);
+        Self::ALL[(i + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
 }
 
 /// Which tab is currently active in the UI.
@@ -255,7 +352,9 @@ pub enum SortColumn {
     Name,
     Cpu,
     Memory,
+    Gpu,
     DiskIo,
+    Net,
     Status,
 }
 
@@ -265,12 +364,85 @@ pub enum SortDirection {
     Desc,
 }
 
+/// A row of the cgroup view: a group or a process directly in one.
+#[derive(Debug, Clone, Copy)]
+pub enum CgroupRow<'a> {
+    Group(&'a CgroupInfo),
+    Process(&'a ProcessInfo),
+}
+
+/// The replayed frame on screen. Renderers read it through the `shown_*`
+/// accessors; the live fields keep updating underneath.
+#[derive(Debug)]
+struct ReplayView {
+    system: SystemSnapshot,
+    processes: Vec<ProcessInfo>,
+    alerts: Vec<Alert>,
+    active_alerts: Vec<ActiveAlert>,
+    cpu_history: VecDeque<u64>,
+    mem_history: VecDeque<u64>,
+}
+
+/// Position and playback of a historical replay (`:replay`).
+#[derive(Debug)]
+pub struct ReplayState {
+    /// Timestamp of the frame on screen (epoch ms)
+    pub frame_ts: i64,
+    /// Playback position; runs ahead of `frame_ts` between snapshots
+    pub cursor_ms: i64,
+    pub playing: bool,
+    /// Index into `REPLAY_SPEEDS`
+    pub speed_idx: usize,
+    last_advance: std::time::Instant,
+    view: ReplayView,
+}
+
+impl ReplayState {
+    /// Replayed seconds per wall-clock second.
+    pub fn speed(&self) -> u32 {
+        REPLAY_SPEEDS[self.speed_idx]
+    }
+
+    /// Local time of the frame on screen.
+    pub fn frame_time(&self) -> Option<DateTime<Local>> {
+        Local.timestamp_millis_opt(self.frame_ts).single()
+    }
+
+    pub fn toggle_playing(&mut self) {
+        self.playing = !self.playing;
+        self.cursor_ms = self.frame_ts;
+        self.last_advance = std::time::Instant::now();
+    }
+
+    pub fn faster(&mut self) {
+        self.speed_idx = (self.speed_idx + 1).min(REPLAY_SPEEDS.len() - 1);
+    }
+
+    pub fn slower(&mut self) {
+        self.speed_idx = self.speed_idx.saturating_sub(1);
+    }
+
+    /// While playing, move the cursor by the wall time since the last frame
+    /// times the speed. Returns the new cursor once a frame is due.
+    pub fn advance(&mut self, now: std::time::Instant) -> Option<i64> {
+        let elapsed = now.duration_since(self.last_advance);
+        if !self.playing || elapsed.as_millis() < REPLAY_FRAME_MS as u128 {
+            return None;
+        }
+        self.last_advance = now;
+        self.cursor_ms += elapsed.as_millis() as i64 * self.speed() as i64;
+        Some(self.cursor_ms)
+    }
+}
+
 /// Central application state - the single source of truth.
 pub struct AppState {
     pub active_tab: Tab,
     pub system: Option<SystemSnapshot>,
     pub processes: Vec<ProcessInfo>,
     pub alerts: Vec<Alert>,
+    /// Currently firing alerts (lifecycle view: firing / acknowledged).
+    pub active_alerts: Vec<ActiveAlert>,
     pub sort_column: SortColumn,
     pub sort_direction: SortDirection,
 
@@ -288,6 +460,7 @@ pub struct AppState {
     // ── Process detail popup ────────────────────────────────────
     pub show_process_detail: bool,
     pub process_detail: Option<ProcessDetail>,
+    pub detail_tab: DetailTab,
     pub detail_scroll: usize,
     /// Whether the detail popup is still loading heavy data (FDs, environ) (#22).
     pub detail_loading: bool,
@@ -297,10 +470,19 @@ pub struct AppState {
     // ── Process tree view ────────────────────────────────────────
     pub tree_view: bool,
 
+    // ── cgroup view ──────────────────────────────────────────────
+    pub cgroup_view: bool,
+    /// Paths of the groups expanded in the cgroup view
+    pub cgroup_expanded: HashSet<String>,
+
     // ── History ring buffers for sparklines (3600 points = 1 hr at 1s tick) ──
     pub cpu_history: VecDeque<u64>,
     pub mem_history: VecDeque<u64>,
 
+    // ── Historical replay ────────────────────────────────────────
+    /// Set while the screen shows stored data instead of live data.
+    pub replay: Option<ReplayState>,
+
     // ── AI Chat State ──────────────────────────────────────────
     pub ai_input: String,
     pub ai_conversation: Conversation,
@@ -335,6 +517,16 @@ pub struct AppState {
     pub renice_pid: Option<u32>,
     pub renice_name: String,
 
+    // ── Limit dialog ─────────────────────────────────────────
+    pub show_limit_dialog: bool,
+    pub limit_pid: Option<u32>,
+    pub limit_name: String,
+    /// Row being edited (see [`LimitField`])
+    pub limit_field: LimitField,
+    pub limit_values: ResourceLimits,
+    /// Also move the process's descendants
+    pub limit_tree: bool,
+
     // ── Zoomable history ─────────────────────────────────────
     pub history_window: HistoryWindow,
 
@@ -352,12 +544,18 @@ pub struct AppState {
     pub cjk_supported: bool,
 
     // ── Thermal monitoring ─────────────────────────────────────
-    /// Latest thermal snapshot from LHM (None if LHM unavailable).
+    /// Latest thermal snapshot (None if no thermal source has data).
     pub thermal: Option<ThermalSnapshot>,
     /// Temperature history ring buffer for sparklines (CPU package temp).
     pub temp_history: VecDeque<f32>,
     /// Auto-shutdown state machine.
     pub shutdown_manager: ShutdownManager,
+    /// Fan curve controller (PWM writes double-gated like auto-shutdown).
+    pub fan_controller: FanController,
+    /// Per-sensor thresholds and aliases, for colors and labels.
+    pub thermal_thresholds: ThermalThresholds,
+    /// Fan curve editor overlay (Thermal tab `f`), editing a working copy.
+    pub fan_editor: Option<FanCurveEditor>,
 
     // ── Event ticker (recent events from store for dashboard) ──
     pub recent_events: Vec<String>,
@@ -409,6 +607,7 @@ impl AppState {
             system: None,
             processes: Vec::new(),
             alerts: Vec::new(),
+            active_alerts: Vec::new(),
             sort_column: SortColumn::Cpu,
             sort_direction: SortDirection::Desc,
             alert_scroll: 0,
@@ -421,12 +620,16 @@ impl AppState {
             status_message: None,
             show_process_detail: false,
             process_detail: None,
+            detail_tab: DetailTab::Overview,
             detail_scroll: 0,
             detail_loading: false,
             detail_pending_pid: None,
             tree_view: false,
+            cgroup_view: false,
+            cgroup_expanded: HashSet::new(),
             cpu_history: VecDeque::with_capacity(HISTORY_CAPACITY),
             mem_history: VecDeque::with_capacity(HISTORY_CAPACITY),
+            replay: None,
             // AI
             ai_input: String::new(),
             ai_conversation: Conversation::new(MAX_CONVERSATION_HISTORY),
@@ -454,6 +657,12 @@ impl AppState {
             renice_value: 0,
             renice_pid: None,
             renice_name: String::new(),
+            show_limit_dialog: false,
+            limit_pid: None,
+            limit_name: String::new(),
+            limit_field: LimitField::Cpu,
+            limit_values: ResourceLimits::default(),
+            limit_tree: false,
             // History
             history_window: HistoryWindow::FiveMin,
             // Focus
@@ -469,6 +678,9 @@ impl AppState {
             thermal: None,
             temp_history: VecDeque::with_capacity(THERMAL_HISTORY_CAPACITY),
             shutdown_manager,
+            fan_controller: FanController::default(),
+            thermal_thresholds: ThermalThresholds::default(),
+            fan_editor: None,
             // Event ticker
             recent_events: Vec::new(),
             // Security dashboard
@@ -525,10 +737,29 @@ impl AppState {
 
     /// Get the PID and name of the currently selected process.
     pub fn selected_process_info(&self) -> Option<(u32, String)> {
-        let filtered = self.filtered_processes();
-        filtered
-            .get(self.selected_process)
-            .map(|p| (p.pid, p.name.clone()))
+        self.selected_row_process().map(|p| (p.pid, p.name.clone()))
+    }
+
+    /// The process on the selected row (`None` on a cgroup row).
+    pub fn selected_row_process(&self) -> Option<&ProcessInfo> {
+        if self.cgroup_view {
+            return match self.cgroup_rows().get(self.selected_process) {
+                Some((_, CgroupRow::Process(p))) => Some(*p),
+                _ => None,
+            };
+        }
+        self.filtered_processes().get(self.selected_process).copied()
+    }
+
+    /// Number of rows in the current process view.
+    pub fn process_row_count(&self) -> usize {
+        if self.cgroup_view {
+            self.cgroup_rows().len()
+        } else if self.tree_view {
+            self.tree_processes().len()
+        } else {
+            self.filtered_processes().len()
+        }
     }
 
     /// Open the signal picker for the currently selected process.
@@ -563,6 +794,76 @@ impl AppState {
         self.renice_pid = None;
     }
 
+    /// Open the limit dialog for the currently selected process, starting
+    /// from the limits it already has.
+    pub fn open_limit_dialog(&mut self) {
+        if let Some((pid, name)) = self.selected_process_info() {
+            self.limit_values = CgroupLimiter::new()
+                .limit_of(pid)
+                .map(|applied| applied.limits)
+                .unwrap_or_default();
+            self.limit_pid = Some(pid);
+            self.limit_name = name;
+            self.limit_field = LimitField::Cpu;
+            self.limit_tree = false;
+            self.show_limit_dialog = true;
+        }
+    }
+
+    /// Close the limit dialog.
+    pub fn close_limit_dialog(&mut self) {
+        self.show_limit_dialog = false;
+        self.limit_pid = None;
+    }
+
+    /// Raise or lower the selected limit by one step; lowering the first
+    /// step makes it unlimited. On the tree row, toggles the tree flag.
+    pub fn adjust_limit(&mut self, up: bool) {
+        const MIB: u64 = 1024 * 1024;
+        let step = |value: Option<u64>, step: u64| {
+            let cur = value.unwrap_or(0);
+            let next = if up {
+                cur / step * step + step
+            } else {
+                cur.div_ceil(step).saturating_sub(1) * step
+            };
+            (next > 0).then_some(next)
+        };
+        let v = &mut self.limit_values;
+        match self.limit_field {
+            LimitField::Cpu => {
+                v.cpu_percent = step(v.cpu_percent.map(u64::from), LIMIT_CPU_STEP_PCT as u64)
+                    .map(|pct| pct as u32);
+            }
+            LimitField::Memory => {
+                v.memory_bytes = step(v.memory_bytes, LIMIT_MEMORY_STEP_MIB * MIB);
+            }
+            LimitField::Io => {
+                v.io_bytes_per_sec = step(v.io_bytes_per_sec, LIMIT_IO_STEP_MIB * MIB);
+            }
+            LimitField::Tree => self.limit_tree = !self.limit_tree,
+        }
+    }
+
+    /// Make the selected limit unlimited.
+    pub fn clear_limit(&mut self) {
+        match self.limit_field {
+            LimitField::Cpu => self.limit_values.cpu_percent = None,
+            LimitField::Memory => self.limit_values.memory_bytes = None,
+            LimitField::Io => self.limit_values.io_bytes_per_sec = None,
+            LimitField::Tree => self.limit_tree = false,
+        }
+    }
+
+    /// PIDs the dialog applies to: the process, plus its descendants in tree mode.
+    pub fn limit_targets(&self) -> Vec<u32> {
+        match self.limit_pid {
+            Some(pid) if self.limit_tree => process_tree(&self.processes, pid),
+            Some(pid) => vec![pid],
+            None => Vec::new(),
+        }
+    }
+
     /// Toggle focus/expand on a dashboard widget (or unfocus).
     pub fn toggle_focus(&mut self) {
         if self.focused_widget.is_some() {
@@ -622,6 +923,95 @@ impl AppState {
         self.tick_count += 1;
     }
 
+    // ── Historical replay ─────────────────────────────────────────
+
+    /// Put a replayed frame on screen, entering replay mode if not already
+    /// in it. `cursor_ms` is the requested time.
+    pub fn show_replay_frame(&mut self, frame: ReplayFrame, cursor_ms: i64) {
+        let frame_ts = frame.ts;
+        let mut view = ReplayView {
+            system: frame.system,
+            processes: frame.processes,
+            alerts: frame.alerts,
+            active_alerts: frame.active_alerts,
+            cpu_history: frame.cpu_history.into(),
+            mem_history: frame.mem_history.into(),
+        };
+        self.sort_processes(&mut view.processes);
+        view.alerts.truncate(self.max_alerts);
+
+        match self.replay {
+            Some(ref mut replay) => {
+                replay.view = view;
+                replay.frame_ts = frame_ts;
+                replay.cursor_ms = cursor_ms.max(frame_ts);
+            }
+            None => {
+                self.replay = Some(ReplayState {
+                    frame_ts,
+                    cursor_ms: cursor_ms.max(frame_ts),
+                    playing: false,
+                    speed_idx: REPLAY_DEFAULT_SPEED_IDX,
+                    last_advance: std::time::Instant::now(),
+                    view,
+                });
+            }
+        }
+    }
+
+    /// Return to live data. False if not replaying.
+    pub fn stop_replay(&mut self) -> bool {
+        self.replay.take().is_some()
+    }
+
+    /// System data on screen: the replayed frame, else the live snapshot.
+    pub fn shown_system(&self) -> Option<&SystemSnapshot> {
+        match self.replay {
+            Some(ref replay) => Some(&replay.view.system),
+            None => self.system.as_ref(),
+        }
+    }
+
+    /// Processes on screen (sorted).
+    pub fn shown_processes(&self) -> &[ProcessInfo] {
+        match self.replay {
+            Some(ref replay) => &replay.view.processes,
+            None => &self.processes,
+        }
+    }
+
+    /// Alerts on screen, newest first.
+    pub fn shown_alerts(&self) -> &[Alert] {
+        match self.replay {
+            Some(ref replay) => &replay.view.alerts,
+            None => &self.alerts,
+        }
+    }
+
+    /// Active alert lifecycles on screen.
+    pub fn shown_active_alerts(&self) -> &[ActiveAlert] {
+        match self.replay {
+            Some(ref replay) => &replay.view.active_alerts,
+            None => &self.active_alerts,
+        }
+    }
+
+    /// CPU history sparkline on screen.
+    pub fn shown_cpu_history(&self) -> &VecDeque<u64> {
+        match self.replay {
+            Some(ref replay) => &replay.view.cpu_history,
+            None => &self.cpu_history,
+        }
+    }
+
+    /// Memory history sparkline on screen.
+    pub fn shown_mem_history(&self) -> &VecDeque<u64> {
+        match self.replay {
+            Some(ref replay) => &replay.view.mem_history,
+            None => &self.mem_history,
+        }
+    }
+
     fn sort_processes(&self, procs: &mut Vec<ProcessInfo>) {
         let dir = self.sort_direction;
         match self.sort_column {
@@ -640,11 +1030,19 @@ impl AppState {
             SortColumn::Memory => {
                 procs.sort_by(|a, b| apply_direction(a.memory_bytes.cmp(&b.memory_bytes), dir))
             }
+            SortColumn::Gpu => procs.sort_by(|a, b| {
+                let key_a = (a.gpu_memory_bytes, a.gpu_usage);
+                let key_b = (b.gpu_memory_bytes, b.gpu_usage);
+                apply_direction(key_a.partial_cmp(&key_b).unwrap_or(Ordering::Equal), dir)
+            }),
             SortColumn::DiskIo => procs.sort_by(|a, b| {
                 let total_a = a.disk_read_bytes + a.disk_write_bytes;
                 let total_b = b.disk_read_bytes + b.disk_write_bytes;
                 apply_direction(total_a.cmp(&total_b), dir)
             }),
+            SortColumn::Net => procs.sort_by(|a, b| {
+                apply_direction(a.net_bytes_per_sec().cmp(&b.net_bytes_per_sec()), dir)
+            }),
             SortColumn::Status => procs.sort_by(|a, b| {
                 apply_direction(a.status.to_string().cmp(&b.status.to_string()), dir)
             }),
@@ -686,8 +1084,10 @@ impl AppState {
             SortColumn::Pid => SortColumn::Name,
             SortColumn::Name => SortColumn::Cpu,
             SortColumn::Cpu => SortColumn::Memory,
-            SortColumn::Memory => SortColumn::DiskIo,
-            SortColumn::DiskIo => SortColumn::Status,
+            SortColumn::Memory => SortColumn::Gpu,
+            SortColumn::Gpu => SortColumn::DiskIo,
+            SortColumn::DiskIo => SortColumn::Net,
+            SortColumn::Net => SortColumn::Status,
             SortColumn::Status => SortColumn::Pid,
         };
     }
@@ -733,13 +1133,12 @@ impl AppState {
                 self.ai_insight_scroll += 1;
             }
             Tab::Processes => {
-                let filtered = self.filtered_processes();
-                if self.selected_process < filtered.len().saturating_sub(1) {
+                if self.selected_process < self.process_row_count().saturating_sub(1) {
                     self.selected_process += 1;
                 }
             }
             Tab::Alerts => {
-                if self.alert_scroll < self.alerts.len().saturating_sub(1) {
+                if self.alert_scroll < self.shown_alerts().len().saturating_sub(1) {
                     self.alert_scroll += 1;
                 }
             }
@@ -770,11 +1169,11 @@ impl AppState {
     pub fn page_down(&mut self) {
         match self.active_tab {
             Tab::Processes => {
-                let max = self.filtered_processes().len().saturating_sub(1);
+                let max = self.process_row_count().saturating_sub(1);
                 self.selected_process = (self.selected_process + PAGE_SIZE).min(max);
             }
             Tab::Alerts => {
-                let max = self.alerts.len().saturating_sub(1);
+                let max = self.shown_alerts().len().saturating_sub(1);
                 self.alert_scroll = (self.alert_scroll + PAGE_SIZE).min(max);
             }
             Tab::AskAi => {
@@ -786,10 +1185,10 @@ impl AppState {
 
     pub fn filtered_processes(&self) -> Vec<&ProcessInfo> {
         if self.filter_text.is_empty() {
-            self.processes.iter().collect()
+            self.shown_processes().iter().collect()
         } else {
             let filter = self.filter_text.to_lowercase();
-            self.processes
+            self.shown_processes()
                 .iter()
                 .filter(|p| {
                     p.name.to_lowercase().contains(&filter)
@@ -824,10 +1223,15 @@ impl AppState {
             open_fds: 0,
             fd_sample: Vec::new(),
             environ: Vec::new(),
+            limit: None,
+            inspection: ProcessInspection::default(),
+            sockets: Vec::new(),
+            threads_sampled_at: None,
         });
         self.show_process_detail = true;
         self.detail_loading = true;
         self.detail_pending_pid = Some(pid);
+        self.detail_tab = DetailTab::Overview;
         self.detail_scroll = 0;
     }
 
@@ -892,21 +1296,52 @@ impl AppState {
             Err(_) => vec!["(permission denied)".to_string()],
         };
 
+        let limit = CgroupLimiter::new().limit_of(pid);
+        let inspection = ProcessInspector::new().inspect(pid);
+
         // Update the detail with the loaded data
         if let Some(ref mut detail) = self.process_detail {
             if detail.pid == pid {
                 detail.open_fds = open_fds;
                 detail.fd_sample = fd_sample;
                 detail.environ = environ;
+                detail.limit = limit;
+                detail.inspection = inspection;
+                detail.threads_sampled_at = Some(std::time::Instant::now());
             }
         }
 
         self.detail_loading = false;
     }
 
+    /// Re-sample the open detail popup's threads for per-thread CPU.
+    /// Called after each data refresh.
+    pub fn refresh_detail_threads(&mut self) {
+        if !self.show_process_detail || self.detail_loading {
+            return;
+        }
+        let Some(ref mut detail) = self.process_detail else {
+            return;
+        };
+        let now = std::time::Instant::now();
+        let elapsed = detail
+            .threads_sampled_at
+            .map_or(0.0, |at| now.duration_since(at).as_secs_f64());
+        detail.inspection.threads =
+            ProcessInspector::new().threads(detail.pid, &detail.inspection.threads, elapsed);
+        detail.threads_sampled_at = Some(now);
+    }
+
+    /// Switch the detail popup to another section, back at the top.
+    pub fn set_detail_tab(&mut self, tab: DetailTab) {
+        self.detail_tab = tab;
+        self.detail_scroll = 0;
+    }
+
     pub fn close_process_detail(&mut self) {
         self.show_process_detail = false;
         self.process_detail = None;
+        self.detail_tab = DetailTab::Overview;
         self.detail_scroll = 0;
         self.detail_loading = false;
         self.detail_pending_pid = None;
@@ -918,7 +1353,7 @@ impl AppState {
         use std::collections::HashMap;
 
         let processes = if self.filter_text.is_empty() {
-            self.processes.iter().collect::<Vec<_>>()
+            self.shown_processes().iter().collect::<Vec<_>>()
         } else {
             self.filtered_processes().into_iter().collect()
         };
@@ -1035,8 +1470,171 @@ impl AppState {
         result
     }
 
+    /// Switch between the cgroup view and the process list.
+    pub fn toggle_cgroup_view(&mut self) {
+        self.cgroup_view = !self.cgroup_view;
+        if self.cgroup_view {
+            self.tree_view = false;
+        }
+        self.selected_process = 0;
+    }
+
+    /// Expand or collapse the selected group. Returns false on a process row.
+    pub fn toggle_selected_cgroup(&mut self) -> bool {
+        let path = match self.cgroup_rows().get(self.selected_process) {
+            Some((_, CgroupRow::Group(cg))) => cg.path.clone(),
+            _ => return false,
+        };
+        if !self.cgroup_expanded.remove(&path) {
+            self.cgroup_expanded.insert(path);
+        }
+        true
+    }
+
+    /// Label for a group: containers by their Docker name when known.
+    pub fn cgroup_label(&self, cg: &CgroupInfo) -> String {
+        let name = cg.container_id().and_then(|id| {
+            self.containers
+                .iter()
+                .find(|c| !c.id.is_empty() && id.starts_with(&c.id))
+        });
+        match name {
+            Some(c) => format!("container {}", c.name),
+            None => cg.display_name(),
+        }
+    }
+
+    /// Flatten the cgroup hierarchy for display, in tree-walk order.
+    /// Top-level groups are always shown; a group's subgroups and processes
+    /// only when it is expanded, or while a filter is active. Siblings are
+    /// sorted by CPU, subgroups before processes.
+    pub fn cgroup_rows(&self) -> Vec<(String, CgroupRow<'_>)> {
+        use std::collections::HashMap;
+
+        let Some(system) = self.shown_system() else {
+            return Vec::new();
+        };
+        let filter = self.filter_text.to_lowercase();
+        let by_pid: HashMap<u32, &ProcessInfo> = if filter.is_empty() {
+            self.shown_processes().iter().map(|p| (p.pid, p)).collect()
+        } else {
+            self.filtered_processes()
+                .into_iter()
+                .map(|p| (p.pid, p))
+                .collect()
+        };
+
+        let mut children: HashMap<&str, Vec<&CgroupInfo>> = HashMap::new();
+        for cg in &system.cgroups {
+            children.entry(cg.parent().unwrap_or("")).or_default().push(cg);
+        }
+        for groups in children.values_mut() {
+            groups.sort_by(|a, b| {
+                b.cpu_usage
+                    .partial_cmp(&a.cpu_usage)
+                    .unwrap_or(Ordering::Equal)
+            });
+        }
+
+        // While filtering, keep groups that match or hold a match
+        let mut visible: HashSet<&str> = HashSet::new();
+        for cg in &system.cgroups {
+            let hit = filter.is_empty()
+                || self.cgroup_label(cg).to_lowercase().contains(&filter)
+                || cg.processes.iter().any(|pid| by_pid.contains_key(pid));
+            if hit {
+                let mut path = cg.path.as_str();
+                visible.insert(path);
+                while let Some((parent, _)) = path.rsplit_once('/') {
+                    if parent.is_empty() {
+                        break;
+                    }
+                    visible.insert(parent);
+                    path = parent;
+                }
+            }
+        }
+
+        struct Walk<'s, 'a> {
+            state: &'s AppState,
+            children: HashMap<&'a str, Vec<&'a CgroupInfo>>,
+            visible: HashSet<&'a str>,
+            by_pid: HashMap<u32, &'a ProcessInfo>,
+            rows: Vec<(String, CgroupRow<'a>)>,
+        }
+
+        impl<'a> Walk<'_, 'a> {
+            fn group(&mut self, cg: &'a CgroupInfo, connector: String, prefix: &str) {
+                self.rows.push((connector, CgroupRow::Group(cg)));
+                let open = !self.state.filter_text.is_empty()
+                    || self.state.cgroup_expanded.contains(&cg.path);
+                if !open {
+                    return;
+                }
+                let groups: Vec<&'a CgroupInfo> = self
+                    .children
+                    .get(cg.path.as_str())
+                    .map(|g| {
+                        g.iter()
+                            .filter(|c| self.visible.contains(c.path.as_str()))
+                            .copied()
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                let mut procs: Vec<&'a ProcessInfo> = cg
+                    .processes
+                    .iter()
+                    .filter_map(|pid| self.by_pid.get(pid).copied())
+                    .collect();
+                procs.sort_by(|a, b| {
+                    b.cpu_usage
+                        .partial_cmp(&a.cpu_usage)
+                        .unwrap_or(Ordering::Equal)
+                });
+
+                let g = &self.state.glyphs;
+                let (branch, last, pipe, space) =
+                    (g.tree_branch, g.tree_last, g.tree_pipe, g.tree_space);
+                let count = groups.len() + procs.len();
+                for (i, child) in groups.into_iter().enumerate() {
+                    let is_last = i + 1 == count;
+                    let connector = format!("{}{}", prefix, if is_last { last } else { branch });
+                    let next = format!("{}{}", prefix, if is_last { space } else { pipe });
+                    self.group(child, connector, &next);
+                }
+                let offset = count - procs.len();
+                for (i, p) in procs.into_iter().enumerate() {
+                    let is_last = offset + i + 1 == count;
+                    let connector = format!("{}{}", prefix, if is_last { last } else { branch });
+                    self.rows.push((connector, CgroupRow::Process(p)));
+                }
+            }
+        }
+
+        let roots: Vec<&CgroupInfo> = children
+            .get("")
+            .map(|g| {
+                g.iter()
+                    .filter(|c| visible.contains(c.path.as_str()))
+                    .copied()
+                    .collect()
+            })
+            .unwrap_or_default();
+        let mut walk = Walk {
+            state: self,
+            children,
+            visible,
+            by_pid,
+            rows: Vec::new(),
+        };
+        for cg in roots {
+            walk.group(cg, String::new(), "");
+        }
+        walk.rows
+    }
+
     pub fn danger_alert_count(&self) -> usize {
-        self.alerts
+        self.shown_alerts()
             .iter()
             .filter(|a| {
                 a.severity == crate::models::AlertSeverity::Danger
@@ -1132,7 +1730,7 @@ mod tests {
 
     fn make_state() -> AppState {
         rust_i18n::set_locale("en");
-        let shutdown_mgr = ShutdownManager::new(false, 100.0, 95.0, 30, 30, 0, 24);
+        let shutdown_mgr = ShutdownManager::new(false, 30, 30, 0, 24);
         let glyphs = Glyphs::new(crate::ui::glyphs::GlyphMode::Unicode);
         AppState::new(
             100,
@@ -1159,6 +1757,10 @@ mod tests {
             start_time: 0,
             parent_pid: None,
             thread_count: None,
+            gpu_usage: 0.0,
+            gpu_memory_bytes: 0,
+            net_rx_bytes_per_sec: 0,
+            net_tx_bytes_per_sec: 0,
         }
     }
 
@@ -1182,6 +1784,10 @@ mod tests {
             start_time: 0,
             parent_pid: parent,
             thread_count: None,
+            gpu_usage: 0.0,
+            gpu_memory_bytes: 0,
+            net_rx_bytes_per_sec: 0,
+            net_tx_bytes_per_sec: 0,
         }
     }
 
@@ -1309,8 +1915,12 @@ mod tests {
         s.cycle_sort();
         assert_eq!(s.sort_column, SortColumn::Memory);
         s.cycle_sort();
+        assert_eq!(s.sort_column, SortColumn::Gpu);
+        s.cycle_sort();
         assert_eq!(s.sort_column, SortColumn::DiskIo);
         s.cycle_sort();
+        assert_eq!(s.sort_column, SortColumn::Net);
+        s.cycle_sort();
         assert_eq!(s.sort_column, SortColumn::Status);
         s.cycle_sort();
         assert_eq!(s.sort_column, SortColumn::Pid);
@@ -1431,6 +2041,39 @@ mod tests {
         assert_eq!(procs[0].name, "big");
     }
 
+    #[test]
+    fn sort_by_gpu_memory_then_usage() {
+        let mut s = make_state();
+        s.sort_column = SortColumn::Gpu;
+        s.sort_direction = SortDirection::Desc;
+        let mut idle = make_process(1, "idle", 0.0, 0);
+        idle.gpu_memory_bytes = 4 << 30;
+        let mut busy = make_process(2, "busy", 0.0, 0);
+        busy.gpu_memory_bytes = 4 << 30;
+        busy.gpu_usage = 80.0;
+        let mut procs = vec![make_process(3, "cpu-only", 90.0, 0), idle, busy];
+        s.sort_processes(&mut procs);
+        assert_eq!(procs[0].name, "busy");
+        assert_eq!(procs[1].name, "idle");
+        assert_eq!(procs[2].name, "cpu-only");
+    }
+
+    #[test]
+    fn sort_by_network_rate() {
+        let mut s = make_state();
+        s.sort_column = SortColumn::Net;
+        s.sort_direction = SortDirection::Desc;
+        let mut upload = make_process(1, "upload", 0.0, 0);
+        upload.net_tx_bytes_per_sec = 8 << 20;
+        let mut download = make_process(2, "download", 0.0, 0);
+        download.net_rx_bytes_per_sec = 2 << 20;
+        let mut procs = vec![make_process(3, "quiet", 50.0, 0), download, upload];
+        s.sort_processes(&mut procs);
+        assert_eq!(procs[0].name, "upload");
+        assert_eq!(procs[1].name, "download");
+        assert_eq!(procs[2].name, "quiet");
+    }
+
     #[test]
     fn sort_by_pid() {
         let mut s = make_state();
@@ -1699,6 +2342,48 @@ mod tests {
         assert_eq!(s.renice_pid, None);
     }
 
+    // ── Limit dialog ──────────────────────────────────────────────
+
+    #[test]
+    fn limit_dialog_steps_and_targets() {
+        let mut s = make_state();
+        s.processes = vec![
+            make_process_with_parent(4_000_001, "make", 0.0, None),
+            make_process_with_parent(4_000_002, "cc1", 0.0, Some(4_000_001)),
+        ];
+        s.open_limit_dialog();
+        assert!(s.show_limit_dialog);
+        assert_eq!(s.limit_values, ResourceLimits::default());
+        assert_eq!(s.limit_targets(), [4_000_001]);
+
+        s.adjust_limit(true);
+        s.adjust_limit(true);
+        assert_eq!(s.limit_values.cpu_percent, Some(2 * LIMIT_CPU_STEP_PCT));
+        s.adjust_limit(false);
+        s.adjust_limit(false);
+        assert_eq!(s.limit_values.cpu_percent, None);
+
+        // Off-step values snap to the grid
+        s.limit_field = s.limit_field.next();
+        s.limit_values.memory_bytes = Some(300 << 20);
+        s.adjust_limit(true);
+        assert_eq!(s.limit_values.memory_bytes, Some(512 << 20));
+        s.limit_values.memory_bytes = Some(300 << 20);
+        s.adjust_limit(false);
+        assert_eq!(s.limit_values.memory_bytes, Some(256 << 20));
+        s.clear_limit();
+        assert_eq!(s.limit_values.memory_bytes, None);
+
+        s.limit_field = LimitField::Cpu.prev();
+        assert_eq!(s.limit_field, LimitField::Tree);
+        s.adjust_limit(true);
+        assert_eq!(s.limit_targets(), [4_000_001, 4_000_002]);
+
+        s.close_limit_dialog();
+        assert!(!s.show_limit_dialog);
+        assert!(s.limit_targets().is_empty());
+    }
+
     // ── Focus cycling ─────────────────────────────────────────────
 
     #[test]
@@ -1757,6 +2442,112 @@ mod tests {
         assert_eq!(tree[1].1.pid, 2);
     }
 
+    // ── cgroup view ───────────────────────────────────────────────
+
+    fn cgroup_state() -> AppState {
+        let mut s = make_state();
+        let group = |path: &str, cpu: f32, processes: Vec<u32>| CgroupInfo {
+            path: path.to_string(),
+            kind: CgroupInfo::kind_of(path),
+            cpu_usage: cpu,
+            processes,
+            ..CgroupInfo::default()
+        };
+        s.system = Some(SystemSnapshot {
+            global_cpu_usage: 30.0,
+            cpu_usages: vec![30.0],
+            cpu_count: 1,
+            total_memory: 0,
+            used_memory: 0,
+            total_swap: 0,
+            used_swap: 0,
+            load_avg_1: 0.0,
+            load_avg_5: 0.0,
+            load_avg_15: 0.0,
+            uptime: 0,
+            hostname: "test".to_string(),
+            os_name: "Linux".to_string(),
+            total_processes: 4,
+            networks: Vec::new(),
+            disks: Vec::new(),
+            cpu_temp: None,
+            gpus: vec![],
+            cgroups: vec![
+                group("/init.scope", 0.1, vec![1]),
+                group("/system.slice", 30.0, vec![]),
+                group("/system.slice/cron.service", 1.0, vec![20]),
+                group("/system.slice/nginx.service", 25.0, vec![10, 11]),
+            ],
+            battery: None,
+        });
+        s.processes = vec![
+            make_process(1, "systemd", 0.1, 0),
+            make_process(10, "nginx", 5.0, 0),
+            make_process(11, "nginx-worker", 20.0, 0),
+            make_process(20, "cron", 1.0, 0),
+        ];
+        s.toggle_cgroup_view();
+        s
+    }
+
+    fn row_names(s: &AppState) -> Vec<String> {
+        s.cgroup_rows()
+            .iter()
+            .map(|(prefix, row)| match row {
+                CgroupRow::Group(cg) => format!("{}{}", prefix, cg.name()),
+                CgroupRow::Process(p) => format!("{}{}", prefix, p.name),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn cgroup_rows_expand_and_collapse() {
+        let mut s = cgroup_state();
+        // Collapsed: top-level groups by CPU
+        assert_eq!(row_names(&s), ["system.slice", "init.scope"]);
+        assert_eq!(s.selected_row_process().map(|p| p.pid), None);
+
+        assert!(s.toggle_selected_cgroup());
+        s.cgroup_expanded.insert("/system.slice/nginx.service".to_string());
+        assert_eq!(
+            row_names(&s),
+            [
+                "system.slice",
+                "├── nginx.service",
+                "│   ├── nginx-worker",
+                "│   └── nginx",
+                "└── cron.service",
+                "init.scope",
+            ]
+        );
+        assert_eq!(s.process_row_count(), 6);
+        s.selected_process = 2;
+        assert_eq!(s.selected_process_info(), Some((11, "nginx-worker".to_string())));
+        assert!(!s.toggle_selected_cgroup());
+
+        s.selected_process = 0;
+        assert!(s.toggle_selected_cgroup());
+        assert_eq!(s.process_row_count(), 2);
+    }
+
+    #[test]
+    fn cgroup_rows_filter_shows_matching_branches() {
+        let mut s = cgroup_state();
+        s.filter_text = "cron".to_string();
+        assert_eq!(
+            row_names(&s),
+            ["system.slice", "└── cron.service", "    └── cron"]
+        );
+        s.filter_text = "nothing".to_string();
+        assert!(s.cgroup_rows().is_empty());
+
+        // Tree and cgroup views are exclusive
+        s.tree_view = true;
+        s.toggle_cgroup_view();
+        s.toggle_cgroup_view();
+        assert!(s.cgroup_view && !s.tree_view);
+    }
+
     // ── set_status ────────────────────────────────────────────────
 
     #[test]
@@ -1831,7 +2622,7 @@ mod tests {
 
     #[test]
     fn cycle_lang_skips_cjk_when_unsupported() {
-        let shutdown_mgr = ShutdownManager::new(false, 100.0, 95.0, 30, 30, 0, 24);
+        let shutdown_mgr = ShutdownManager::new(false, 30, 30, 0, 24);
         let glyphs = Glyphs::new(crate::ui::glyphs::GlyphMode::Unicode);
         let mut s = AppState::new(
             100,
@@ -1855,7 +2646,7 @@ mod tests {
 
     #[test]
     fn cycle_lang_includes_cjk_when_supported() {
-        let shutdown_mgr = ShutdownManager::new(false, 100.0, 95.0, 30, 30, 0, 24);
+        let shutdown_mgr = ShutdownManager::new(false, 30, 30, 0, 24);
         let glyphs = Glyphs::new(crate::ui::glyphs::GlyphMode::Unicode);
         let mut s = AppState::new(
             100,
@@ -1998,6 +2789,10 @@ mod tests {
             user: "tester".to_string(),
             parent_pid: Some(1),
             thread_count: Some(4),
+            gpu_usage: 0.0,
+            gpu_memory_bytes: 0,
+            net_rx_bytes_per_sec: 0,
+            net_tx_bytes_per_sec: 0,
             start_time: 0,
             disk_read_bytes: 0,
             disk_write_bytes: 0,
@@ -2051,6 +2846,151 @@ mod tests {
         );
     }
 
+    #[test]
+    fn detail_tabs_switch_and_threads_refresh() {
+        let mut state = make_state();
+        let proc = make_test_process();
+        state.open_process_detail(&proc);
+        state.load_process_detail_extra();
+
+        // Our own process: readable /proc, at least the test thread
+        let detail = state.process_detail.as_ref().unwrap();
+        assert!(detail.inspection.memory.is_some());
+        assert!(!detail.inspection.threads.is_empty());
+
+        state.detail_scroll = 5;
+        state.set_detail_tab(state.detail_tab.next());
+        assert_eq!(state.detail_tab, DetailTab::Memory);
+        assert_eq!(state.detail_scroll, 0);
+        assert_eq!(DetailTab::Overview.prev(), DetailTab::Environment);
+
+        state.refresh_detail_threads();
+        let detail = state.process_detail.as_ref().unwrap();
+        assert!(!detail.inspection.threads.is_empty());
+
+        state.close_process_detail();
+        assert_eq!(state.detail_tab, DetailTab::Overview);
+    }
+
+    #[test]
+    fn replay_keeps_live_data_updating_underneath() {
+        let mut state = make_state();
+        let system = |cpu: f32| SystemSnapshot {
+            global_cpu_usage: cpu,
+            cpu_usages: vec![cpu],
+            cpu_count: 1,
+            total_memory: 100,
+            used_memory: 50,
+            total_swap: 0,
+            used_swap: 0,
+            load_avg_1: 0.0,
+            load_avg_5: 0.0,
+            load_avg_15: 0.0,
+            uptime: 0,
+            hostname: "test".to_string(),
+            os_name: "Linux".to_string(),
+            total_processes: 1,
+            networks: Vec::new(),
+            disks: Vec::new(),
+            cpu_temp: None,
+            gpus: vec![],
+            cgroups: vec![],
+            battery: None,
+        };
+        let live_cpu = |state: &AppState| state.system.as_ref().unwrap().global_cpu_usage;
+        let shown_cpu = |state: &AppState| state.shown_system().unwrap().global_cpu_usage;
+        state.update(system(10.0), vec![make_process(1, "live", 10.0, 0)], vec![]);
+
+        let frame = ReplayFrame {
+            ts: 5_000,
+            system: system(90.0),
+            processes: vec![
+                make_process(2, "old", 1.0, 0),
+                make_process(3, "busy", 90.0, 0),
+            ],
+            alerts: vec![],
+            active_alerts: vec![],
+            cpu_history: vec![80, 90],
+            mem_history: vec![50, 50],
+        };
+        state.show_replay_frame(frame, 5_500);
+        assert_eq!(shown_cpu(&state), 90.0);
+        // Sorted like live data (CPU, descending)
+        assert_eq!(state.shown_processes()[0].name, "busy");
+        assert_eq!(state.filtered_processes()[0].name, "busy");
+        assert_eq!(state.shown_cpu_history(), &[80, 90]);
+        let replay = state.replay.as_ref().unwrap();
+        assert_eq!((replay.frame_ts, replay.cursor_ms), (5_000, 5_500));
+
+        // The live fields never hold replayed data
+        assert_eq!(live_cpu(&state), 10.0);
+        assert_eq!(state.processes[0].name, "live");
+        state.update(system(20.0), vec![make_process(1, "live", 20.0, 0)], vec![]);
+        assert_eq!(live_cpu(&state), 20.0);
+        assert_eq!(shown_cpu(&state), 90.0);
+        assert_eq!(state.shown_cpu_history(), &[80, 90]);
+
+        assert!(state.stop_replay());
+        assert!(!state.stop_replay());
+        assert_eq!(shown_cpu(&state), 20.0);
+        assert_eq!(state.shown_cpu_history(), &[10, 20]);
+        assert_eq!(state.shown_processes()[0].name, "live");
+    }
+
+    #[test]
+    fn replay_playback_advances_by_speed() {
+        let mut state = make_state();
+        let frame = ReplayFrame {
+            ts: 0,
+            system: SystemSnapshot {
+                global_cpu_usage: 0.0,
+                cpu_usages: vec![],
+                cpu_count: 1,
+                total_memory: 0,
+                used_memory: 0,
+                total_swap: 0,
+                used_swap: 0,
+                load_avg_1: 0.0,
+                load_avg_5: 0.0,
+                load_avg_15: 0.0,
+                uptime: 0,
+                hostname: "test".to_string(),
+                os_name: "Linux".to_string(),
+                total_processes: 0,
+                networks: Vec::new(),
+                disks: Vec::new(),
+                cpu_temp: None,
+                gpus: vec![],
+                cgroups: vec![],
+                battery: None,
+            },
+            processes: vec![],
+            alerts: vec![],
+            active_alerts: vec![],
+            cpu_history: vec![],
+            mem_history: vec![],
+        };
+        state.show_replay_frame(frame, 0);
+        let replay = state.replay.as_mut().unwrap();
+        let start = replay.last_advance;
+        let later = start + std::time::Duration::from_millis(REPLAY_FRAME_MS);
+        assert_eq!(replay.advance(later), None, "paused");
+
+        replay.toggle_playing();
+        let start = replay.last_advance;
+        assert_eq!(replay.advance(start), None, "not due yet");
+        let later = start + std::time::Duration::from_secs(1);
+        let speed = replay.speed() as i64;
+        assert_eq!(replay.advance(later), Some(1000 * speed));
+
+        replay.faster();
+        assert!(replay.speed() as i64 > speed);
+        for _ in 0..REPLAY_SPEEDS.len() {
+            replay.slower();
+        }
+        assert_eq!(replay.speed(), REPLAY_SPEEDS[0]);
+    }
+
     #[test]
     fn close_process_detail_clears_loading_state() {
         let mut state = make_state();
@@ -2,6 +2,7 @@
 
 use crate::ui::glyphs::GlyphMode;
 use std::io::Write;
+use std::path::Path;
 
 /// Truncate a string to `max_len` characters, appending "..." if truncated.
 pub fn truncate_str(s: &str, max_len: usize) -> String {
@@ -205,6 +206,21 @@ pub fn loading_dots(tick: u64) -> &'static str {
     }
 }
 
+// ── sysfs / procfs reads ─────────────────────────────────────────
+
+/// A one-value pseudo-file (sysfs attribute, cgroup control file), trimmed.
+/// `None` if unreadable or empty.
+pub fn read_trimmed(path: &Path) -> Option<String> {
+    let s = std::fs::read_to_string(path).ok()?;
+    let s = s.trim();
+    (!s.is_empty()).then(|| s.to_string())
+}
+
+/// A pseudo-file holding one integer. `None` for `max` and other non-numbers.
+pub fn read_u64(path: &Path) -> Option<u64> {
+    read_trimmed(path)?.parse().ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;